@@ -1,7 +1,10 @@
+use chrono::NaiveTime;
+
 use crate::app::Screen;
+use crate::types::{LabelSelector, RecentTarget};
 
 /// All possible actions in the application (command pattern)
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 #[allow(dead_code)]
 pub enum Action {
     // Navigation
@@ -9,15 +12,34 @@ pub enum Action {
     GoBack,
     Quit,
 
+    /// Back to the context list, then on to the same namespace/deployment in the new context
+    SwitchContext,
+
     // Selection
     SelectContext(String),
     SelectNamespace(String),
     SelectDeployment(String),
+    OpenRecent(RecentTarget),
+    TogglePin,
+    CycleDeploymentSort,
+    ToggleTerminatingNamespaces,
 
     // UI toggles
     ToggleCommandPalette,
     ToggleHelp,
 
+    // Confirmation modal
+    ConfirmAccept,
+    ConfirmCancel,
+    /// A destructive action the user already confirmed (runs without asking again)
+    Confirmed(Box<Action>),
+
+    // Help overlay scrolling
+    HelpUp,
+    HelpDown,
+    HelpPageUp,
+    HelpPageDown,
+
     // Command palette
     PaletteUp,
     PaletteDown,
@@ -25,6 +47,23 @@ pub enum Action {
     PaletteInput(char),
     PaletteBackspace,
     PaletteClose,
+    /// Open the palette's argument prompt for a parameterized command
+    OpenPrompt(PromptKind),
+
+    // Parameterized commands (run once their prompt is submitted)
+    SetTailLines(i64),
+    GoToTime(NaiveTime),
+    ScaleDeployment(i32),
+    SaveKeySet(String),
+    LoadKeySet(String),
+    AddNote(String),
+    /// Filter the list by labels (None clears the filter)
+    SetLabelSelector(Option<LabelSelector>),
+
+    // Cluster actions
+    RestartDeployment,
+    DeleteSelectedPod,
+    DeletePod(String),
 
     // List navigation
     ListUp,
@@ -43,6 +82,11 @@ pub enum Action {
     ClearFilter,
     ToggleCaseSensitive,
 
+    // Find in log viewer
+    OpenFind,
+    FindNext,
+    FindPrev,
+
     // Refresh
     RefreshContexts,
     RefreshNamespaces,
@@ -56,31 +100,134 @@ pub enum Action {
     PageUp,
     PageDown,
     ToggleAutoScroll,
+    /// Stop the log streams altogether, or resume them from where they stopped
+    ToggleStreaming,
     ToggleTimestamps,
     ToggleLocalTime,
+    /// Show time since the previous line instead of the time of day
+    ToggleTimeDeltas,
     TogglePodNames,
+    ToggleLineNumbers,
+    /// Cycle day/hour separator rows (off, days, hours)
+    CycleTimeMarkers,
     ToggleJsonPrettyPrint,
     ToggleStats,
+    /// Hide or restore the header, stats and filter bars
+    ToggleZen,
+    /// Cycle line density (compact, normal, detailed)
+    CycleDensity,
     ToggleJsonKeyFilter,
+    ToggleJsonFold,
+    CycleJsonFoldDepth,
+    ToggleRawView,
+
+    // Full view of a truncated line
+    FetchFullLine,
+    FullLineScroll(isize),
+    CloseFullLine,
     JsonKeyUp,
     JsonKeyDown,
     JsonKeyToggle,
     JsonKeySelectAll,
     JsonKeyClearAll,
+    JsonKeyToggleExclude,
+
+    // Schema drift report
+    ToggleSchemaDrift,
+    SchemaDriftUp,
+    SchemaDriftDown,
+
+    // Parse diagnostics overlay
+    ToggleParseDiagnostics,
+    ParseDiagnosticsUp,
+    ParseDiagnosticsDown,
     JsonKeyInput(char),
     JsonKeyBackspace,
     JsonKeyClearSearch,
     JsonKeySelectPattern,
     ClearLogs,
     ExportLogs,
+    ExportLogsTo(String),
+    /// Open the top visible line in `$EDITOR` (pretty JSON for JSON lines)
+    OpenLineInEditor,
+    /// Open the view, as exported, in `$PAGER`
+    OpenViewInPager,
+    /// Copy the command line that reopens this view
+    CopyReproCommand,
+    /// Stop (or go back to) passing over mesh sidecar containers
+    ToggleContainerExclusion,
+    /// Follow excluded sidecars' access logs next to the app container, or stop
+    ToggleSidecarLogs,
+    /// Filter to the request id of the top visible line
+    FollowRequest,
+    /// Run a palette command registered by a Lua plugin, by index
+    RunPluginCommand(usize),
+    LoadOlderLogs,
+    GrowBuffer,
+    ShrinkBuffer,
+
+    // Bookmarks
+    ToggleBookmark,
+    ToggleBookmarks,
+    BookmarkUp,
+    BookmarkDown,
+    BookmarkJump,
+    BookmarkDelete,
+
+    // Minimap
+    ToggleMinimap,
+
+    // Nodes
+    ToggleNodeNames,
+    CycleNodeFilter,
+
+    // Compare mode
+    ToggleCompare,
+    CycleCompare,
+
+    // Pod panel
+    TogglePodLegend,
+    /// Raise the minimum level shown, wrapping from error back to trace
+    CycleMinLevel,
+    TogglePodPanel,
+    PodPanelUp,
+    PodPanelDown,
+    PodPanelToggleMute,
+    /// Start streaming the pod selected in the pod panel (when sampling)
+    PodPanelStreamPod,
+    /// Double the number of sampled pods
+    WidenSample,
+
+    // Columns overlay
+    ToggleColumns,
+    ColumnUp,
+    ColumnDown,
+    ColumnMoveUp,
+    ColumnMoveDown,
+    ColumnWiden,
+    ColumnNarrow,
+    ColumnToggleVisible,
+    JumpNextError,
+    JumpPrevError,
 
     // Time range
     CycleTimeRange,
     CycleTimeRangeBack,
 
-    // Error handling
+    // Notifications
     ShowError(String),
     DismissError,
+    RetryError,
+    DismissNotification,
+    ToggleNotifications,
+
+    // Diagnostics
+    ToggleDebugLogging,
+    TogglePerfHud,
+    ToggleUsageStats,
+    ToggleStreamParams,
+    StreamParamsUp,
+    StreamParamsDown,
 
     // Tick (for periodic updates)
     Tick,
@@ -88,3 +235,117 @@ pub enum Action {
     // Render request
     Render,
 }
+
+/// Palette commands that ask for an argument before running
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PromptKind {
+    TailLines,
+    GoToTime,
+    ScaleTo,
+    SaveKeySet,
+    LoadKeySet,
+    LabelSelector,
+    AddNote,
+}
+
+impl PromptKind {
+    /// Prompt title
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::TailLines => "Tail lines per pod",
+            Self::GoToTime => "Go to time",
+            Self::ScaleTo => "Scale to replicas",
+            Self::SaveKeySet => "Save JSON key set as",
+            Self::LoadKeySet => "Load JSON key set",
+            Self::LabelSelector => "Filter by labels (empty clears)",
+            Self::AddNote => "Note on the top line",
+        }
+    }
+
+    /// What typed into the palette search jumps straight to this prompt
+    pub fn prefix(&self) -> Option<&'static str> {
+        match self {
+            Self::TailLines => Some("t:"),
+            _ => None,
+        }
+    }
+
+    /// Format hint shown while the prompt is empty
+    pub fn placeholder(&self) -> &'static str {
+        match self {
+            Self::TailLines => "e.g. 500",
+            Self::GoToTime => "HH:MM or HH:MM:SS",
+            Self::ScaleTo => "e.g. 3",
+            Self::SaveKeySet | Self::LoadKeySet => "e.g. http view",
+            Self::LabelSelector => "e.g. team=payments,tier!=db",
+            Self::AddNote => "e.g. latency spike starts after the deploy",
+        }
+    }
+
+    /// Turn the typed argument into the action it runs
+    pub fn parse(&self, input: &str) -> Result<Action, String> {
+        let input = input.trim();
+        match self {
+            Self::TailLines => match input.parse::<i64>() {
+                Ok(lines) if lines > 0 => Ok(Action::SetTailLines(lines)),
+                _ => Err("Expected a positive number of lines".to_string()),
+            },
+            Self::GoToTime => NaiveTime::parse_from_str(input, "%H:%M:%S")
+                .or_else(|_| NaiveTime::parse_from_str(input, "%H:%M"))
+                .map(Action::GoToTime)
+                .map_err(|_| "Expected HH:MM or HH:MM:SS".to_string()),
+            Self::ScaleTo => match input.parse::<i32>() {
+                Ok(replicas) if replicas >= 0 => Ok(Action::ScaleDeployment(replicas)),
+                _ => Err("Expected a replica count of 0 or more".to_string()),
+            },
+            Self::SaveKeySet | Self::LoadKeySet if input.is_empty() => {
+                Err("Expected a key set name".to_string())
+            }
+            Self::SaveKeySet => Ok(Action::SaveKeySet(input.to_string())),
+            Self::LoadKeySet => Ok(Action::LoadKeySet(input.to_string())),
+            Self::LabelSelector if input.is_empty() => Ok(Action::SetLabelSelector(None)),
+            Self::LabelSelector => {
+                LabelSelector::parse(input).map(|s| Action::SetLabelSelector(Some(s)))
+            }
+            Self::AddNote if input.is_empty() => Err("Expected a note".to_string()),
+            Self::AddNote => Ok(Action::AddNote(input.to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prompt_parse() {
+        assert!(matches!(
+            PromptKind::TailLines.parse(" 250 "),
+            Ok(Action::SetTailLines(250))
+        ));
+        assert!(PromptKind::TailLines.parse("0").is_err());
+        assert_eq!(PromptKind::TailLines.prefix(), Some("t:"));
+
+        let expected = NaiveTime::from_hms_opt(14, 5, 0).unwrap();
+        assert!(
+            matches!(PromptKind::GoToTime.parse("14:05"), Ok(Action::GoToTime(t)) if t == expected)
+        );
+        assert!(matches!(
+            PromptKind::GoToTime.parse("14:05:09"),
+            Ok(Action::GoToTime(t)) if t == NaiveTime::from_hms_opt(14, 5, 9).unwrap()
+        ));
+        assert!(PromptKind::GoToTime.parse("25:00").is_err());
+
+        assert!(matches!(
+            PromptKind::ScaleTo.parse("0"),
+            Ok(Action::ScaleDeployment(0))
+        ));
+        assert!(PromptKind::ScaleTo.parse("-1").is_err());
+
+        assert!(matches!(
+            PromptKind::AddNote.parse(" retries start here "),
+            Ok(Action::AddNote(note)) if note == "retries start here"
+        ));
+        assert!(PromptKind::AddNote.parse("  ").is_err());
+    }
+}
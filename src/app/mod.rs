@@ -1,7 +1,18 @@
 //! Application state and actions
 
 mod action;
+mod notifications;
+mod perf;
+mod sample;
 mod state;
+mod usage;
 
-pub use action::Action;
-pub use state::{AppState, Screen};
+pub use action::{Action, PromptKind};
+pub use notifications::{Notifications, Severity};
+pub use perf::PerfStats;
+pub use sample::{SampleMode, Sampling};
+pub use state::{
+    AppState, ErrorBanner, FullLine, JsonFold, Loading, Note, PendingConfirm, Screen, SearchMode,
+    SwitchTarget, TimeMarkers, UiState,
+};
+pub use usage::UsageStats;
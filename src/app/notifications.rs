@@ -0,0 +1,143 @@
+use chrono::{DateTime, Local};
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+/// Toasts shown at once; older ones are pushed off (they stay in history)
+const MAX_VISIBLE: usize = 4;
+
+/// Notifications kept for the history overlay
+const MAX_HISTORY: usize = 100;
+
+/// How serious a notification is
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Severity {
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+impl Severity {
+    /// How long a toast stays on screen
+    pub fn timeout(&self) -> Duration {
+        match self {
+            Severity::Info | Severity::Success => Duration::from_secs(3),
+            Severity::Warning => Duration::from_secs(6),
+            Severity::Error => Duration::from_secs(10),
+        }
+    }
+
+    pub fn icon(&self) -> &'static str {
+        match self {
+            Severity::Info => "ℹ",
+            Severity::Success => "✓",
+            Severity::Warning => "⚠",
+            Severity::Error => "✗",
+        }
+    }
+}
+
+/// A single message for the user
+#[derive(Clone, Debug)]
+pub struct Notification {
+    pub severity: Severity,
+    pub message: String,
+    /// Wall clock time for the history overlay
+    pub at: DateTime<Local>,
+    /// Monotonic time for expiry
+    shown: Instant,
+}
+
+/// Toasts currently on screen plus a history of everything shown
+#[derive(Default)]
+pub struct Notifications {
+    active: VecDeque<Notification>,
+    history: VecDeque<Notification>,
+}
+
+impl Notifications {
+    /// Show a new toast
+    pub fn push(&mut self, severity: Severity, message: String) {
+        let notification = self.record(severity, message);
+        if self.active.len() == MAX_VISIBLE {
+            self.active.pop_front();
+        }
+        self.active.push_back(notification);
+    }
+
+    /// Add to the history without showing a toast (for messages shown elsewhere)
+    pub fn record(&mut self, severity: Severity, message: String) -> Notification {
+        let notification = Notification {
+            severity,
+            message,
+            at: Local::now(),
+            shown: Instant::now(),
+        };
+        if self.history.len() == MAX_HISTORY {
+            self.history.pop_front();
+        }
+        self.history.push_back(notification.clone());
+        notification
+    }
+
+    /// Drop toasts whose timer ran out; returns whether any were removed
+    pub fn prune(&mut self) -> bool {
+        self.prune_at(Instant::now())
+    }
+
+    fn prune_at(&mut self, now: Instant) -> bool {
+        let before = self.active.len();
+        self.active
+            .retain(|n| now.duration_since(n.shown) < n.severity.timeout());
+        self.active.len() != before
+    }
+
+    /// Dismiss the newest toast
+    pub fn dismiss(&mut self) {
+        self.active.pop_back();
+    }
+
+    /// Toasts on screen, oldest first
+    pub fn active(&self) -> impl Iterator<Item = &Notification> {
+        self.active.iter()
+    }
+
+    /// Everything shown this session, newest first
+    pub fn history(&self) -> impl Iterator<Item = &Notification> {
+        self.history.iter().rev()
+    }
+
+    pub fn history_len(&self) -> usize {
+        self.history.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_notifications_expire_by_severity_and_cap() {
+        let mut notifications = Notifications::default();
+        notifications.push(Severity::Success, "exported".to_string());
+        notifications.push(Severity::Error, "failed".to_string());
+
+        let start = notifications.active[0].shown;
+        assert!(!notifications.prune_at(start + Duration::from_secs(1)));
+        // Success times out before the error does
+        assert!(notifications.prune_at(start + Duration::from_secs(4)));
+        let left: Vec<_> = notifications.active().map(|n| n.severity).collect();
+        assert_eq!(left, vec![Severity::Error]);
+        assert!(notifications.prune_at(start + Duration::from_secs(11)));
+        assert_eq!(notifications.active().count(), 0);
+
+        for i in 0..6 {
+            notifications.push(Severity::Info, format!("n{}", i));
+        }
+        assert_eq!(notifications.active().count(), MAX_VISIBLE);
+        assert_eq!(notifications.active().next().unwrap().message, "n2");
+        // History keeps everything, newest first
+        assert_eq!(notifications.history_len(), 8);
+        assert_eq!(notifications.history().next().unwrap().message, "n5");
+    }
+}
@@ -0,0 +1,89 @@
+//! Timings and throughput for the performance HUD
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// How often per-pod ingest rates are recomputed
+const RATE_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Numbers behind the F12 overlay, for "kubescope feels sluggish" reports
+#[derive(Default)]
+pub struct PerfStats {
+    /// How long the last frame took to draw
+    pub render: Duration,
+    /// Slowest frame since the HUD was opened
+    pub render_max: Duration,
+    /// How long the last filter recompute took, and how many lines it kept
+    pub filter: Option<(Duration, usize)>,
+    /// Entries waiting in the log channel, and its capacity
+    pub queue: (usize, usize),
+    /// Lines per second for each pod, busiest first
+    pub rates: Vec<(String, f64)>,
+    /// Per-pod line counts at the last rate sample
+    last_sample: Option<(Instant, HashMap<String, u64>)>,
+}
+
+impl PerfStats {
+    pub fn record_render(&mut self, took: Duration) {
+        self.render = took;
+        self.render_max = self.render_max.max(took);
+    }
+
+    pub fn record_filter(&mut self, took: Duration, kept: usize) {
+        self.filter = Some((took, kept));
+    }
+
+    /// Forget peaks and rates so a reopened HUD starts fresh
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Update ingest rates from cumulative per-pod line counts (at most once per interval)
+    pub fn sample_rates(&mut self, counts: HashMap<String, u64>) {
+        self.sample_rates_at(Instant::now(), counts);
+    }
+
+    fn sample_rates_at(&mut self, now: Instant, counts: HashMap<String, u64>) {
+        if let Some((at, previous)) = &self.last_sample {
+            let elapsed = now.duration_since(*at);
+            if elapsed < RATE_INTERVAL {
+                return;
+            }
+            let secs = elapsed.as_secs_f64();
+            self.rates = counts
+                .iter()
+                .map(|(pod, count)| {
+                    // Counts restart from zero when the buffer is cleared
+                    let delta = count.saturating_sub(previous.get(pod).copied().unwrap_or(0));
+                    (pod.clone(), delta as f64 / secs)
+                })
+                .collect();
+            self.rates
+                .sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        }
+        self.last_sample = Some((now, counts));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sample_rates_per_interval() {
+        let start = Instant::now();
+        let mut perf = PerfStats::default();
+        perf.sample_rates_at(start, HashMap::from([("a".into(), 10), ("b".into(), 0)]));
+        assert!(perf.rates.is_empty());
+
+        // Too soon - keep the old sample
+        perf.sample_rates_at(start + Duration::from_millis(300), HashMap::new());
+        assert!(perf.rates.is_empty());
+
+        perf.sample_rates_at(
+            start + Duration::from_secs(2),
+            HashMap::from([("a".into(), 30), ("b".into(), 40)]),
+        );
+        assert_eq!(perf.rates, vec![("b".into(), 20.0), ("a".into(), 10.0)]);
+    }
+}
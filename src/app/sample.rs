@@ -0,0 +1,130 @@
+//! Streaming a subset of pods for very wide deployments
+//!
+//! With hundreds of replicas, following every pod floods both the viewer and
+//! the API server. A sample streams the first `size` pods in a pick order that
+//! stays fixed for the session, so widening it only ever adds pods.
+
+use std::collections::BTreeSet;
+use std::collections::hash_map::RandomState;
+use std::hash::BuildHasher;
+
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+use crate::types::PodInfo;
+
+/// Which pods a sample prefers
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum SampleMode {
+    /// Most recently created pods first
+    #[default]
+    Newest,
+    /// A random pick (fixed for the session)
+    Random,
+}
+
+impl SampleMode {
+    pub fn label(&self) -> &'static str {
+        match self {
+            SampleMode::Newest => "newest",
+            SampleMode::Random => "random",
+        }
+    }
+}
+
+/// Stream at most `size` pods of a deployment
+#[derive(Clone, Debug)]
+pub struct Sampling {
+    pub size: usize,
+    pub mode: SampleMode,
+    /// Seeds the random order
+    seed: RandomState,
+}
+
+impl Sampling {
+    pub fn new(size: usize, mode: SampleMode) -> Self {
+        Self {
+            size,
+            mode,
+            seed: RandomState::new(),
+        }
+    }
+
+    /// Pod names, the ones to sample first at the front
+    pub fn order<'a>(&self, pods: &'a [PodInfo]) -> Vec<&'a str> {
+        let mut pods: Vec<&PodInfo> = pods.iter().collect();
+        match self.mode {
+            // Name breaks ties so the order doesn't depend on the list order
+            SampleMode::Newest => pods.sort_by(|a, b| {
+                b.created_at
+                    .cmp(&a.created_at)
+                    .then_with(|| a.name.cmp(&b.name))
+            }),
+            SampleMode::Random => pods.sort_by_key(|p| self.seed.hash_one(&p.name)),
+        }
+        pods.into_iter().map(|p| p.name.as_str()).collect()
+    }
+
+    /// The initial sample, or None when every pod fits
+    pub fn pick(&self, pods: &[PodInfo]) -> Option<BTreeSet<String>> {
+        (pods.len() > self.size).then(|| {
+            self.order(pods)
+                .into_iter()
+                .take(self.size)
+                .map(str::to_string)
+                .collect()
+        })
+    }
+
+    /// Up to `count` pods that aren't in `sampled` yet, in pick order
+    pub fn next<'a>(
+        &self,
+        pods: &'a [PodInfo],
+        sampled: &BTreeSet<String>,
+        count: usize,
+    ) -> Vec<&'a str> {
+        self.order(pods)
+            .into_iter()
+            .filter(|name| !sampled.contains(*name))
+            .take(count)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, Utc};
+
+    fn pods(count: i64) -> Vec<PodInfo> {
+        let base = Utc::now();
+        (0..count)
+            .map(|i| {
+                let mut pod = PodInfo::new(format!("api-{}", i), "default".into());
+                pod.created_at = Some(base + Duration::seconds(i));
+                pod
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_newest_sample_widens_in_order() {
+        let pods = pods(5);
+        let sampling = Sampling::new(2, SampleMode::Newest);
+        let sampled = sampling.pick(&pods).unwrap();
+        assert_eq!(sampled.iter().collect::<Vec<_>>(), vec!["api-3", "api-4"]);
+        assert_eq!(sampling.next(&pods, &sampled, 2), vec!["api-2", "api-1"]);
+
+        // Small deployments aren't sampled at all
+        assert_eq!(Sampling::new(5, SampleMode::Newest).pick(&pods), None);
+    }
+
+    #[test]
+    fn test_random_order_is_stable() {
+        let pods = pods(20);
+        let sampling = Sampling::new(4, SampleMode::Random);
+        assert_eq!(sampling.pick(&pods), sampling.pick(&pods));
+        assert_eq!(sampling.order(&pods).len(), 20);
+    }
+}
@@ -1,10 +1,25 @@
+use chrono::{DateTime, Local, NaiveTime, Utc};
+use ratatui::style::Color;
 use ratatui::widgets::ListState;
-use std::collections::HashSet;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::sync::{Arc, LazyLock};
+use std::time::{Duration, Instant};
 use tokio::sync::mpsc;
 
-use super::Action;
-use crate::logs::CompiledFilter;
-use crate::types::{ArcLogEntry, ContextInfo, DeploymentInfo, NamespaceInfo, PodInfo, TimeRange};
+use super::{Action, Notifications, PerfStats, Sampling, Severity, UsageStats};
+use crate::config::{Column, ColumnLayout, Density, LinkTarget, LinkTemplates};
+use crate::k8s::ApiError;
+use crate::logs::{
+    Backfill, CompiledFilter, FilterPipeline, JsonFormat, JsonKeyStat, KeySchema, LogBuffer,
+    ProblemReport, SchemaDrift, StreamParams, request_id,
+};
+use crate::session::{Densities, KeyPick, KeySets, RecentTargets};
+use crate::setup::SetupReport;
+use crate::types::{
+    ArcLogEntry, ContextInfo, DeploymentInfo, DeploymentSort, LabelSelector, LogEntry, LogLevel,
+    NamespaceInfo, Pins, PodInfo, RecentTarget, TimeRange, TimeWindow,
+};
+use crate::ui::{Theme, assign_pod_colors, hashed_pod_color};
 
 /// Cache for filtered log results to avoid re-filtering on every render
 #[derive(Default)]
@@ -15,6 +30,12 @@ pub struct FilterCache {
     cached_case_insensitive: bool,
     /// Cached JSON visible keys
     cached_json_keys: HashSet<String>,
+    /// Cached muted pods
+    cached_muted_pods: HashSet<String>,
+    /// Cached node filter
+    cached_node_filter: Option<String>,
+    /// Cached level threshold
+    cached_min_level: LogLevel,
     /// Buffer entry count when cache was built
     cached_log_count: usize,
     /// The cached filtered entries
@@ -23,13 +44,336 @@ pub struct FilterCache {
     pub is_valid: bool,
 }
 
+/// Find-in-view state: matches are highlighted and stepped through without filtering
+#[derive(Default)]
+pub struct FindState {
+    /// Active find query (None = not searching)
+    pub query: Option<CompiledFilter>,
+    /// Indexes of matching entries in the filtered view, ascending
+    pub matches: Vec<usize>,
+    /// Match most recently jumped to
+    pub current: Option<ArcLogEntry>,
+    /// Query changed since matches were computed
+    stale: bool,
+}
+
+impl FindState {
+    /// Replace the query, dropping old matches
+    pub fn set_query(&mut self, query: Option<CompiledFilter>) {
+        self.query = query;
+        self.matches.clear();
+        self.current = None;
+        self.stale = true;
+    }
+
+    /// Whether matches must be recomputed even if the view didn't change
+    pub fn is_stale(&self) -> bool {
+        self.stale
+    }
+
+    /// Recompute matches against the filtered view
+    pub fn refresh(&mut self, entries: &[ArcLogEntry]) {
+        self.matches = match &self.query {
+            Some(query) => entries
+                .iter()
+                .enumerate()
+                .filter(|(_, e)| query.matches(e))
+                .map(|(idx, _)| idx)
+                .collect(),
+            None => Vec::new(),
+        };
+        self.stale = false;
+    }
+
+    /// 1-based position of the current match among all matches
+    pub fn position(&self, entries: &[ArcLogEntry]) -> Option<usize> {
+        let current = self.current.as_ref()?;
+        let idx = entries.iter().position(|e| Arc::ptr_eq(e, current))?;
+        self.matches.binary_search(&idx).ok().map(|i| i + 1)
+    }
+
+    /// View index of the next (or previous) match after `from`, wrapping around
+    ///
+    /// `from = None` means "before the first line" going forward and "after the
+    /// last line" going backward.
+    pub fn step(&self, from: Option<usize>, forward: bool) -> Option<usize> {
+        if forward {
+            self.matches
+                .iter()
+                .copied()
+                .find(|&m| from.is_none_or(|f| m > f))
+                .or_else(|| self.matches.first().copied())
+        } else {
+            self.matches
+                .iter()
+                .rev()
+                .copied()
+                .find(|&m| from.is_none_or(|f| m < f))
+                .or_else(|| self.matches.last().copied())
+        }
+    }
+}
+
+/// Live preview of the pattern being typed in the filter bar
+///
+/// The pattern is compiled on every keystroke for inline highlighting; the
+/// matching line count is computed off-thread once typing pauses.
+#[derive(Default)]
+pub struct SearchPreview {
+    /// Compiled input (None when empty or invalid)
+    pub query: Option<CompiledFilter>,
+    /// Compile error for the current input
+    pub error: Option<String>,
+    /// Matching lines from the last finished count
+    pub count: Option<usize>,
+    /// Bumped on every edit so stale background counts are discarded
+    generation: u64,
+    /// When the input last changed, while a count is still owed
+    changed_at: Option<Instant>,
+}
+
+impl SearchPreview {
+    /// Drop the preview, invalidating any count still in flight
+    pub fn reset(&mut self) {
+        *self = Self {
+            generation: self.generation + 1,
+            ..Self::default()
+        };
+    }
+
+    /// Recompile after an edit and schedule a new count
+    fn update(&mut self, query: Option<Result<CompiledFilter, regex::Error>>) {
+        self.reset();
+        match query {
+            Some(Ok(query)) => {
+                self.query = Some(query);
+                self.changed_at = Some(Instant::now());
+            }
+            Some(Err(e)) => self.error = Some(e.to_string()),
+            None => {}
+        }
+    }
+
+    /// Take the pending count request once input has been idle for `debounce`
+    pub fn due(&mut self, debounce: Duration) -> Option<(u64, CompiledFilter)> {
+        let changed_at = self.changed_at?;
+        if changed_at.elapsed() < debounce {
+            return None;
+        }
+        self.changed_at = None;
+        self.query.clone().map(|q| (self.generation, q))
+    }
+
+    /// Store a finished count if it's for the current input
+    pub fn finish(&mut self, generation: u64, count: usize) -> bool {
+        if generation != self.generation {
+            return false;
+        }
+        self.count = Some(count);
+        true
+    }
+
+    /// Whether a count is scheduled or running
+    pub fn is_counting(&self) -> bool {
+        self.query.is_some() && self.count.is_none()
+    }
+}
+
+/// A destructive action waiting for the user to confirm it
+pub struct PendingConfirm {
+    pub title: String,
+    pub message: String,
+    /// Label for the confirm key ("Clear", "Delete", ...)
+    pub confirm_label: &'static str,
+    /// Action to run once confirmed
+    pub action: Action,
+}
+
+/// Where to end up again after switching context (names are re-resolved there)
+pub struct SwitchTarget {
+    pub namespace: String,
+    pub deployment: Option<String>,
+}
+
+/// An error shown as a modal until dismissed
+pub struct ErrorBanner {
+    pub message: String,
+    /// Known cluster failure, shown with what to do about it
+    pub kind: Option<ApiError>,
+    /// Action that retries whatever failed
+    pub retry: Option<Action>,
+}
+
+/// Spinner frames for in-flight loads
+const SPINNER: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+
+/// A cluster request in flight, shown as a spinner on the current screen
+pub struct Loading {
+    pub label: String,
+    started: Instant,
+}
+
+impl Loading {
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            started: Instant::now(),
+        }
+    }
+
+    /// Spinner frame and label, e.g. "⠹ Loading namespaces…"
+    pub fn display(&self) -> String {
+        let frame = (self.started.elapsed().as_millis() / 100) as usize % SPINNER.len();
+        format!("{} {}…", SPINNER[frame], self.label)
+    }
+}
+
+/// An observation written down while watching, kept for exports
+#[derive(Clone, Debug)]
+pub struct Note {
+    /// The line's timestamp, or when the note was written if it has none
+    pub at: DateTime<Utc>,
+    /// The line it's about (None if the view was empty)
+    pub entry: Option<ArcLogEntry>,
+    pub text: String,
+}
+
+/// A truncated line re-read in full, shown in its own overlay
+pub struct FullLine {
+    /// Buffer id of the entry it belongs to
+    pub entry_id: u64,
+    /// The line without its timestamp (pretty-printed if JSON), once fetched
+    pub text: Option<String>,
+    /// Pod dashboard and trace links for the line, by label
+    pub links: Vec<(&'static str, String)>,
+    /// First row shown
+    pub scroll: usize,
+    /// `text` wrapped to `wrap_width`, rebuilt when the width changes
+    rows: Vec<String>,
+    wrap_width: usize,
+}
+
+impl FullLine {
+    /// Waiting on the fetch
+    pub fn loading(entry_id: u64) -> Self {
+        Self {
+            entry_id,
+            text: None,
+            links: Vec::new(),
+            scroll: 0,
+            rows: Vec::new(),
+            wrap_width: 0,
+        }
+    }
+
+    /// Fill in the fetched line
+    pub fn set_line(&mut self, line: &str) {
+        let content = match line.split_once(' ') {
+            Some((stamp, rest)) if DateTime::parse_from_rfc3339(stamp).is_ok() => rest,
+            _ => line,
+        };
+        let text = serde_json::from_str::<serde_json::Value>(content)
+            .ok()
+            .filter(|v| v.is_object())
+            .and_then(|v| serde_json::to_string_pretty(&v).ok())
+            .unwrap_or_else(|| content.to_string());
+        self.text = Some(text);
+        self.wrap_width = 0;
+    }
+
+    /// The text wrapped to `width` chars per row
+    pub fn rows(&mut self, width: usize) -> &[String] {
+        let width = width.max(1);
+        if width != self.wrap_width {
+            self.wrap_width = width;
+            self.rows = self
+                .text
+                .as_deref()
+                .unwrap_or_default()
+                .lines()
+                .flat_map(|line| {
+                    let chars: Vec<char> = line.chars().collect();
+                    if chars.is_empty() {
+                        return vec![String::new()];
+                    }
+                    chars
+                        .chunks(width)
+                        .map(|chunk| chunk.iter().collect())
+                        .collect()
+                })
+                .collect();
+        }
+        self.scroll = self.scroll.min(self.rows.len().saturating_sub(1));
+        &self.rows
+    }
+
+    /// Move the first shown row (clamped to the text when next rendered)
+    pub fn scroll_by(&mut self, delta: isize) {
+        self.scroll = self.scroll.saturating_add_signed(delta);
+    }
+}
+
+/// How one JSON entry is laid out in pretty-print mode
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JsonFold {
+    /// Shown on a single line, as with pretty print off
+    Collapsed,
+    /// Containers nested this deep are folded
+    Depth(usize),
+    /// Everything expanded
+    Expanded,
+}
+
+/// Separator rows drawn where the time moves past a boundary
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TimeMarkers {
+    Off,
+    /// A row when the day changes
+    #[default]
+    Days,
+    /// A row every hour (and day)
+    Hours,
+}
+
+impl TimeMarkers {
+    pub fn next(&self) -> Self {
+        match self {
+            TimeMarkers::Off => TimeMarkers::Days,
+            TimeMarkers::Days => TimeMarkers::Hours,
+            TimeMarkers::Hours => TimeMarkers::Off,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            TimeMarkers::Off => "off",
+            TimeMarkers::Days => "days",
+            TimeMarkers::Hours => "hours",
+        }
+    }
+}
+
+/// What the filter bar input is editing
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SearchMode {
+    /// Hide lines that don't match
+    #[default]
+    Filter,
+    /// Highlight and jump between matches
+    Find,
+}
+
 impl FilterCache {
     /// Check if cache needs to be invalidated based on current state
+    #[allow(clippy::too_many_arguments)]
     pub fn needs_refresh(
         &self,
         filter: Option<&CompiledFilter>,
         case_insensitive: bool,
         json_keys: &HashSet<String>,
+        muted_pods: &HashSet<String>,
+        node_filter: Option<&str>,
+        min_level: LogLevel,
         current_log_count: usize,
     ) -> bool {
         if !self.is_valid {
@@ -57,21 +401,43 @@ impl FilterCache {
             return true;
         }
 
+        // Check if muted pods changed
+        if self.cached_muted_pods != *muted_pods {
+            return true;
+        }
+
+        // Check if node filter changed
+        if self.cached_node_filter.as_deref() != node_filter {
+            return true;
+        }
+
+        // Check if the level threshold changed
+        if self.cached_min_level != min_level {
+            return true;
+        }
+
         false
     }
 
     /// Update the cache with new filtered results
+    #[allow(clippy::too_many_arguments)]
     pub fn update(
         &mut self,
         filter: Option<&CompiledFilter>,
         case_insensitive: bool,
         json_keys: &HashSet<String>,
+        muted_pods: &HashSet<String>,
+        node_filter: Option<&str>,
+        min_level: LogLevel,
         log_count: usize,
         entries: Vec<ArcLogEntry>,
     ) {
         self.cached_filter_pattern = filter.map(|f| f.pattern().to_string());
         self.cached_case_insensitive = case_insensitive;
         self.cached_json_keys = json_keys.clone();
+        self.cached_muted_pods = muted_pods.clone();
+        self.cached_node_filter = node_filter.map(str::to_string);
+        self.cached_min_level = min_level;
         self.cached_log_count = log_count;
         self.cached_entries = entries;
         self.is_valid = true;
@@ -85,6 +451,9 @@ pub enum Screen {
     NamespaceSelect,
     DeploymentSelect,
     LogViewer,
+    RecentSelect,
+    /// Splash shown while the kubeconfig and CLI target load
+    Connecting,
 }
 
 /// UI-specific transient state
@@ -99,14 +468,71 @@ pub struct UiState {
     /// Current search input text
     pub search_input: String,
 
+    /// Whether the input edits the filter or the find query
+    pub search_mode: SearchMode,
+
+    /// Find-in-view query and matches
+    pub find: FindState,
+
+    /// Live preview of the pattern being typed
+    pub search_preview: SearchPreview,
+
     /// Is help overlay visible?
     pub help_visible: bool,
 
+    /// Scroll offset of the help overlay
+    pub help_scroll: u16,
+
+    /// Destructive action waiting for confirmation (shown as a modal)
+    pub confirm: Option<PendingConfirm>,
+
     /// List state for selection screens
     pub list_state: ListState,
 
-    /// Error message to display (if any)
-    pub error_message: Option<String>,
+    /// Toasts on screen and the session's notification history
+    pub notifications: Notifications,
+
+    /// Notification history overlay visible?
+    pub notifications_visible: bool,
+
+    /// Error modal (shown on every screen until dismissed)
+    pub error: Option<ErrorBanner>,
+
+    /// Namespace/deployment/pod load in flight (clearing it cancels the load)
+    pub loading: Option<Loading>,
+
+    /// Performance HUD visible?
+    pub perf_visible: bool,
+
+    /// Timings and rates shown by the performance HUD
+    pub perf: PerfStats,
+
+    /// Session stats overlay visible?
+    pub usage_visible: bool,
+
+    /// Lines, rates, filters and targets seen this session
+    pub usage: UsageStats,
+
+    /// Stream parameters overlay visible?
+    pub stream_params_visible: bool,
+
+    /// Log request each current stream was opened with (refreshed while shown)
+    pub stream_params: Vec<StreamParams>,
+
+    /// Scroll offset in the stream parameters overlay
+    pub stream_params_scroll: u16,
+
+    /// Namespace/deployment to reopen after "Switch Context"
+    pub switch_target: Option<SwitchTarget>,
+
+    /// Startup steps already finished (the running one is `loading`)
+    pub startup_steps: Vec<String>,
+
+    /// Why startup failed, shown on the connecting screen
+    pub startup_error: Option<String>,
+
+    /// What's missing for a first run, shown as a guide instead of the error
+    pub setup: Option<SetupReport>,
 
     // Log viewer specific state
     /// Scroll position in log viewer
@@ -121,9 +547,30 @@ pub struct UiState {
     /// Show pod names in log viewer?
     pub show_pod_names: bool,
 
+    /// Show per-pod line numbers instead of buffer ids?
+    pub per_pod_line_numbers: bool,
+
+    /// Day/hour separator rows in the log view
+    pub time_markers: TimeMarkers,
+
+    /// Show each line's time as the delta from the line above
+    pub time_deltas: bool,
+
     /// JSON pretty-print enabled?
     pub json_pretty_print: bool,
 
+    /// Pretty-print layout from the config file
+    pub json_format: JsonFormat,
+
+    /// Fold depth for entries without their own fold (None = fully expanded)
+    pub json_fold_depth: Option<usize>,
+
+    /// Entries folded or expanded individually, by buffer id
+    pub json_folds: HashMap<u64, JsonFold>,
+
+    /// Entries showing the line as received under the message, by buffer id
+    pub raw_views: HashSet<u64>,
+
     /// Currently active filter (None = show all)
     pub active_filter: Option<CompiledFilter>,
 
@@ -136,15 +583,55 @@ pub struct UiState {
     /// Show statistics panel?
     pub stats_visible: bool,
 
+    /// Zen mode: header, stats and filter bars hidden for more log rows
+    pub zen: bool,
+
+    /// How much of the line prefix is shown
+    pub density: Density,
+
+    /// Lines below this level are hidden (trace shows everything)
+    pub min_level: LogLevel,
+
     /// JSON key filter mode active?
     pub json_key_filter_active: bool,
 
     /// Selected JSON keys to display (empty = show all)
     pub json_visible_keys: std::collections::HashSet<String>,
 
+    /// Hide the picked JSON keys instead of showing only them
+    pub json_keys_exclude: bool,
+
     /// All discovered JSON keys from logs
     pub json_available_keys: Vec<String>,
 
+    /// Share and sample value per key, from recent lines when the key filter opened
+    pub json_key_stats: HashMap<String, JsonKeyStat>,
+
+    /// Schema drift report visible?
+    pub schema_drift_visible: bool,
+
+    /// Drift events and key types, from when the report opened
+    pub schema_drifts: Vec<SchemaDrift>,
+    pub schema_keys: Vec<KeySchema>,
+
+    /// Scroll offset in the drift report
+    pub schema_drift_scroll: u16,
+
+    /// Parse diagnostics overlay visible?
+    pub parse_diagnostics_visible: bool,
+
+    /// Problem lines per kind, from when the overlay opened
+    pub parse_reports: Vec<ProblemReport>,
+
+    /// Scroll offset in the parse diagnostics overlay
+    pub parse_diagnostics_scroll: u16,
+
+    /// Truncated line fetched in full (overlay open while set)
+    pub full_line: Option<FullLine>,
+
+    /// Drift events already announced with a toast
+    pub schema_drifts_announced: usize,
+
     /// Current selection in key filter (index into filtered list)
     pub json_key_selection: usize,
 
@@ -157,11 +644,107 @@ pub struct UiState {
     /// Selected time range for log filtering
     pub time_range: TimeRange,
 
+    /// Fixed window from `--since`/`--until`, used instead of `time_range` while set
+    pub time_window: TimeWindow,
+
     /// Show timestamps in local time (vs UTC)
     pub use_local_time: bool,
 
     /// Cache for filtered log results
     pub filter_cache: FilterCache,
+
+    /// Bookmarked log entries (kept for the whole session)
+    pub bookmarks: Vec<ArcLogEntry>,
+
+    /// Is the bookmarks overlay visible?
+    pub bookmarks_visible: bool,
+
+    /// Current selection in the bookmarks overlay
+    pub bookmark_selection: usize,
+
+    /// Notes on lines, in the order written (noted lines are also bookmarked)
+    pub notes: Vec<Note>,
+
+    /// Entry shown at the top of the log viewport (updated on render)
+    pub top_visible_entry: Option<ArcLogEntry>,
+
+    /// Bookmark to scroll to on the next render
+    pub pending_jump: Option<ArcLogEntry>,
+
+    /// Show the log level minimap next to the scrollbar?
+    pub minimap_visible: bool,
+
+    /// Is an older-history fetch in flight?
+    pub history_loading: bool,
+
+    /// No more older history is available for the current streams
+    pub history_exhausted: bool,
+
+    /// tail_lines used by the most recent history request
+    pub history_depth: i64,
+
+    /// When the log streams were stopped with ToggleStreaming (None while streaming)
+    pub streams_stopped_at: Option<DateTime<Utc>>,
+
+    /// The streams were stopped for inactivity; the next key resumes them
+    pub idle: bool,
+
+    /// Tail size set from the palette, replacing --tail for later stream restarts
+    pub tail_lines_override: Option<i64>,
+
+    /// Sort order for the deployment list
+    pub deployment_sort: DeploymentSort,
+
+    /// Leave Terminating namespaces out of the namespace list
+    pub hide_terminating: bool,
+
+    /// Only list namespaces whose labels match
+    pub namespace_selector: Option<LabelSelector>,
+
+    /// Only list deployments whose labels match
+    pub deployment_selector: Option<LabelSelector>,
+
+    /// Is the pod panel open?
+    pub pod_panel_visible: bool,
+
+    /// Currently selected pod in the pod panel
+    pub pod_panel_selection: usize,
+
+    /// Pods whose lines are hidden from the log view
+    pub muted_pods: HashSet<String>,
+
+    /// Pods streamed when only a sample of the deployment is (None = all)
+    pub sampled_pods: Option<BTreeSet<String>>,
+
+    /// Initial backfill progress per streamed pod
+    pub backfill: HashMap<String, Backfill>,
+
+    /// Show node names column?
+    pub show_node_names: bool,
+
+    /// Show the namespace/workload column?
+    pub show_source: bool,
+
+    /// Only show lines from pods on this node
+    pub node_filter: Option<String>,
+
+    /// Pods shown side by side in compare mode (left, right)
+    pub compare: Option<(String, String)>,
+
+    /// Colors assigned to the streamed pods
+    pub pod_colors: HashMap<String, Color>,
+
+    /// Is the pod color legend shown?
+    pub pod_legend_visible: bool,
+
+    /// Log line prefix columns, in display order
+    pub columns: ColumnLayout,
+
+    /// Is the columns overlay open?
+    pub columns_visible: bool,
+
+    /// Currently selected column in the columns overlay
+    pub column_selection: usize,
 }
 
 impl Default for UiState {
@@ -170,38 +753,127 @@ impl Default for UiState {
             command_palette_open: false,
             search_active: false,
             search_input: String::new(),
+            search_mode: SearchMode::default(),
+            find: FindState::default(),
+            search_preview: SearchPreview::default(),
             help_visible: false,
+            help_scroll: 0,
+            confirm: None,
             list_state: ListState::default(),
-            error_message: None,
+            notifications: Notifications::default(),
+            notifications_visible: false,
+            error: None,
+            loading: None,
+            perf_visible: false,
+            perf: PerfStats::default(),
+            usage_visible: false,
+            usage: UsageStats::default(),
+            stream_params_visible: false,
+            stream_params: Vec::new(),
+            stream_params_scroll: 0,
+            switch_target: None,
+            startup_steps: Vec::new(),
+            startup_error: None,
+            setup: None,
             // Log viewer defaults
             log_scroll: 0,
             auto_scroll: true,
             show_timestamps: true,
             show_pod_names: true,
+            per_pod_line_numbers: false,
+            time_markers: TimeMarkers::default(),
+            time_deltas: false,
             json_pretty_print: false,
+            json_format: JsonFormat::default(),
+            json_fold_depth: None,
+            json_folds: HashMap::new(),
+            raw_views: HashSet::new(),
             // Filter defaults
             active_filter: None,
             filter_error: None,
             filter_case_insensitive: true,
             // Stats panel
             stats_visible: false,
+            zen: false,
+            density: Density::default(),
+            min_level: LogLevel::Trace,
             // JSON key filter
             json_key_filter_active: false,
             json_visible_keys: std::collections::HashSet::new(),
+            json_keys_exclude: false,
             json_available_keys: Vec::new(),
+            json_key_stats: HashMap::new(),
+            schema_drift_visible: false,
+            schema_drifts: Vec::new(),
+            schema_keys: Vec::new(),
+            schema_drift_scroll: 0,
+            parse_diagnostics_visible: false,
+            parse_reports: Vec::new(),
+            parse_diagnostics_scroll: 0,
+            full_line: None,
+            schema_drifts_announced: 0,
             json_key_selection: 0,
             json_key_search: String::new(),
             json_key_scroll: 0,
             // Time range
             time_range: TimeRange::default(),
+            time_window: TimeWindow::default(),
             // Local time display (default to local time for better UX)
             use_local_time: true,
             // Filter cache
             filter_cache: FilterCache::default(),
+            // Bookmarks
+            bookmarks: Vec::new(),
+            bookmarks_visible: false,
+            bookmark_selection: 0,
+            notes: Vec::new(),
+            top_visible_entry: None,
+            pending_jump: None,
+            // Minimap
+            minimap_visible: true,
+            // Scrollback
+            history_loading: false,
+            history_exhausted: false,
+            history_depth: 0,
+            streams_stopped_at: None,
+            idle: false,
+            tail_lines_override: None,
+            // Deployment list
+            deployment_sort: DeploymentSort::default(),
+            hide_terminating: false,
+            namespace_selector: None,
+            deployment_selector: None,
+            // Pod panel
+            pod_panel_visible: false,
+            pod_panel_selection: 0,
+            muted_pods: HashSet::new(),
+            sampled_pods: None,
+            backfill: HashMap::new(),
+            // Node display
+            show_node_names: false,
+            show_source: false,
+            node_filter: None,
+            compare: None,
+            // Pod colors
+            pod_colors: HashMap::new(),
+            pod_legend_visible: false,
+            // Column layout
+            columns: ColumnLayout::default(),
+            columns_visible: false,
+            column_selection: 0,
         }
     }
 }
 
+impl UiState {
+    /// Drop the folds and raw views kept by buffer id, after the buffer is
+    /// cleared and ids start again from 0
+    pub fn forget_entry_views(&mut self) {
+        self.json_folds.clear();
+        self.raw_views.clear();
+    }
+}
+
 /// Global application state
 pub struct AppState {
     /// Current screen being displayed
@@ -216,21 +888,51 @@ pub struct AppState {
     /// Selected Kubernetes context
     pub selected_context: Option<String>,
 
-    /// Available namespaces
+    /// Namespaces shown in the list (`all_namespaces` after the namespace filters)
     pub namespaces: Vec<NamespaceInfo>,
 
+    /// Every namespace in the selected context
+    pub all_namespaces: Vec<NamespaceInfo>,
+
     /// Selected namespace
     pub selected_namespace: Option<String>,
 
-    /// Available deployments
+    /// Deployments shown in the list (`all_deployments` after the label filter)
     pub deployments: Vec<DeploymentInfo>,
 
+    /// Every deployment in the selected namespace
+    pub all_deployments: Vec<DeploymentInfo>,
+
     /// Selected deployment
     pub selected_deployment: Option<String>,
 
     /// Pods belonging to the selected deployment
     pub pods: Vec<PodInfo>,
 
+    /// Recently viewed context/namespace/deployment targets
+    pub recent_targets: RecentTargets,
+
+    /// Saved JSON key filter selections
+    pub key_sets: KeySets,
+
+    /// Line density per deployment
+    pub densities: Densities,
+
+    /// Pinned items, sorted to the top of their lists
+    pub pinned: Pins,
+
+    /// User pod color overrides as (name pattern, color)
+    pub pod_color_overrides: Vec<(String, Color)>,
+
+    /// Stream only a sample of deployments wider than this
+    pub sampling: Option<Sampling>,
+
+    /// Leave pods from the deployment's previous ReplicaSets unstreamed
+    pub skip_old_replicasets: bool,
+
+    /// Dashboard and trace URL templates from the config file
+    pub links: LinkTemplates,
+
     /// UI state
     pub ui_state: UiState,
 
@@ -242,9 +944,6 @@ pub struct AppState {
 
     /// Dirty flag for rendering - only render when true
     pub render_dirty: bool,
-
-    /// Last known log count for change detection
-    pub last_log_count: usize,
 }
 
 impl AppState {
@@ -258,15 +957,24 @@ impl AppState {
             contexts: Vec::new(),
             selected_context: None,
             namespaces: Vec::new(),
+            all_namespaces: Vec::new(),
+            all_deployments: Vec::new(),
             selected_namespace: None,
             deployments: Vec::new(),
             selected_deployment: None,
             pods: Vec::new(),
+            recent_targets: RecentTargets::default(),
+            key_sets: KeySets::default(),
+            densities: Densities::default(),
+            pinned: Pins::default(),
+            pod_color_overrides: Vec::new(),
+            sampling: None,
+            skip_old_replicasets: false,
+            links: LinkTemplates::default(),
             ui_state,
             should_quit: false,
             action_tx,
             render_dirty: true, // Start dirty to ensure initial render
-            last_log_count: 0,
         }
     }
 
@@ -277,6 +985,136 @@ impl AppState {
         self.ui_state.list_state.select(Some(0));
     }
 
+    /// The selected context/namespace/deployment, once all three are picked
+    pub fn current_target(&self) -> Option<RecentTarget> {
+        Some(RecentTarget {
+            context: self.selected_context.clone()?,
+            namespace: self.selected_namespace.clone()?,
+            deployment: self.selected_deployment.clone()?,
+        })
+    }
+
+    /// Save the JSON key filter picks for the current deployment
+    pub fn remember_json_keys(&mut self) {
+        if let Some(target) = self.current_target() {
+            self.key_sets.remember(&target, self.json_key_pick());
+            self.key_sets.save();
+        }
+    }
+
+    /// Bring back the JSON key filter picked last time for this deployment
+    pub fn restore_json_keys(&mut self) {
+        let Some(keys) = self
+            .current_target()
+            .and_then(|target| self.key_sets.for_target(&target))
+        else {
+            return;
+        };
+        let count = keys.keys.len();
+        let verb = if keys.exclude { "hiding" } else { "showing" };
+        self.apply_json_key_pick(&keys);
+        self.notify(
+            Severity::Info,
+            format!(
+                "Restored JSON key filter ({} {} keys, K to change)",
+                verb, count
+            ),
+        );
+    }
+
+    /// The current key filter picks, for saving
+    pub fn json_key_pick(&self) -> KeyPick {
+        KeyPick {
+            keys: self.ui_state.json_visible_keys.iter().cloned().collect(),
+            exclude: self.ui_state.json_keys_exclude,
+        }
+    }
+
+    /// Switch the key filter to saved picks
+    pub fn apply_json_key_pick(&mut self, pick: &KeyPick) {
+        self.ui_state.json_visible_keys = pick.keys.iter().cloned().collect();
+        self.ui_state.json_keys_exclude = pick.exclude;
+    }
+
+    /// The selected context's kubeconfig namespace, if it has one that exists
+    pub fn context_namespace(&self) -> Option<&str> {
+        let selected = self.selected_context.as_ref()?;
+        let namespace = self
+            .contexts
+            .iter()
+            .find(|c| &c.name == selected)?
+            .namespace
+            .as_deref()?;
+        self.namespaces
+            .iter()
+            .any(|n| n.name == namespace)
+            .then_some(namespace)
+    }
+
+    /// Replace the namespace list, keeping the namespace filters applied
+    pub fn set_namespaces(&mut self, namespaces: Vec<NamespaceInfo>) {
+        self.all_namespaces = namespaces;
+        self.filter_namespaces();
+    }
+
+    /// Rebuild the shown namespaces after the list or a namespace filter changed
+    pub fn filter_namespaces(&mut self) {
+        let ui = &self.ui_state;
+        self.namespaces = self
+            .all_namespaces
+            .iter()
+            .filter(|ns| !(ui.hide_terminating && ns.is_terminating()))
+            .filter(|ns| {
+                ui.namespace_selector
+                    .as_ref()
+                    .is_none_or(|selector| selector.matches(&ns.labels))
+            })
+            .cloned()
+            .collect();
+        self.sort_pinned();
+    }
+
+    /// Replace the deployment list, keeping the label filter applied
+    pub fn set_deployments(&mut self, deployments: Vec<DeploymentInfo>) {
+        self.all_deployments = deployments;
+        self.filter_deployments();
+    }
+
+    /// Rebuild the shown deployments after the list or the label filter changed
+    pub fn filter_deployments(&mut self) {
+        let selector = self.ui_state.deployment_selector.as_ref();
+        self.deployments = self
+            .all_deployments
+            .iter()
+            .filter(|d| selector.is_none_or(|s| s.matches(&d.labels)))
+            .cloned()
+            .collect();
+        self.sort_pinned();
+    }
+
+    /// Name of the namespace under the cursor
+    pub fn selected_namespace_name(&self) -> Option<String> {
+        self.selected_index()
+            .and_then(|idx| self.namespaces.get(idx))
+            .map(|n| n.name.clone())
+    }
+
+    /// Name of the deployment under the cursor
+    pub fn selected_deployment_name(&self) -> Option<String> {
+        self.selected_index()
+            .and_then(|idx| self.deployments.get(idx))
+            .map(|d| d.name.clone())
+    }
+
+    /// Put the namespace cursor on the context's kubeconfig namespace
+    pub fn select_context_namespace(&mut self) {
+        if let Some(namespace) = self.context_namespace()
+            && let Some(idx) = self.namespaces.iter().position(|n| n.name == namespace)
+        {
+            self.ui_state.list_state.select(Some(idx));
+        }
+    }
+
     /// Go back to previous screen
     pub fn go_back(&mut self) -> bool {
         if let Some(prev_screen) = self.screen_stack.pop() {
@@ -294,7 +1132,8 @@ impl AppState {
             Screen::ContextSelect => self.contexts.len(),
             Screen::NamespaceSelect => self.namespaces.len(),
             Screen::DeploymentSelect => self.deployments.len(),
-            Screen::LogViewer => 0,
+            Screen::LogViewer | Screen::Connecting => 0,
+            Screen::RecentSelect => self.recent_targets.targets.len(),
         }
     }
 
@@ -338,55 +1177,616 @@ impl AppState {
         self.ui_state.list_state.select(Some(i));
     }
 
+    /// Sort selection lists so pinned items come first, then by name
+    /// (deployments use the selected deployment sort order instead)
+    pub fn sort_pinned(&mut self) {
+        let pins = &self.pinned;
+        let sort = self.ui_state.deployment_sort;
+        self.contexts
+            .sort_by(|a, b| pin_order(&pins.contexts, &a.name, &b.name));
+        self.namespaces
+            .sort_by(|a, b| pin_order(&pins.namespaces, &a.name, &b.name));
+        self.deployments.sort_by(|a, b| {
+            let unpinned = |d: &DeploymentInfo| !pins.deployments.contains(&d.name);
+            unpinned(a)
+                .cmp(&unpinned(b))
+                .then_with(|| sort.compare(a, b))
+        });
+    }
+
+    /// Pin or unpin the selected item on the current selection screen
+    pub fn toggle_pin(&mut self) {
+        let Some(idx) = self.selected_index() else {
+            return;
+        };
+        let (pins, name) = match self.current_screen {
+            Screen::ContextSelect => (
+                &mut self.pinned.contexts,
+                self.contexts.get(idx).map(|c| c.name.clone()),
+            ),
+            Screen::NamespaceSelect => (
+                &mut self.pinned.namespaces,
+                self.namespaces.get(idx).map(|n| n.name.clone()),
+            ),
+            Screen::DeploymentSelect => (
+                &mut self.pinned.deployments,
+                self.deployments.get(idx).map(|d| d.name.clone()),
+            ),
+            Screen::LogViewer | Screen::RecentSelect | Screen::Connecting => return,
+        };
+        let Some(name) = name else {
+            return;
+        };
+        if !pins.remove(&name) {
+            pins.insert(name.clone());
+        }
+
+        self.sort_pinned();
+
+        // Keep the cursor on the item that moved
+        let new_idx = match self.current_screen {
+            Screen::ContextSelect => self.contexts.iter().position(|c| c.name == name),
+            Screen::NamespaceSelect => self.namespaces.iter().position(|n| n.name == name),
+            Screen::DeploymentSelect => self.deployments.iter().position(|d| d.name == name),
+            Screen::LogViewer | Screen::RecentSelect | Screen::Connecting => None,
+        };
+        self.ui_state.list_state.select(new_idx.or(Some(0)));
+    }
+
+    /// Assign distinct colors to the current pod set
+    pub fn assign_pod_colors(&mut self) {
+        self.ui_state.pod_colors = assign_pod_colors(
+            self.pods.iter().map(|p| p.name.as_str()),
+            &self.pod_color_overrides,
+            &Theme::current().pod_colors,
+        );
+    }
+
+    /// Color for a pod's lines (pods no longer in the set fall back to a hash)
+    pub fn pod_color(&self, pod_name: &str) -> Color {
+        self.ui_state
+            .pod_colors
+            .get(pod_name)
+            .copied()
+            .unwrap_or_else(|| hashed_pod_color(pod_name, &Theme::current().pod_colors))
+    }
+
+    /// Pick the pods to stream for a freshly loaded pod list
+    pub fn sample_pods(&mut self) {
+        self.ui_state.sampled_pods = self.sampling.as_ref().and_then(|s| s.pick(&self.pods));
+    }
+
+    /// Pods still loading their initial backfill
+    pub fn backfilling(&self) -> usize {
+        self.ui_state
+            .backfill
+            .values()
+            .filter(|b| matches!(b, Backfill::Connecting | Backfill::Loading(_)))
+            .count()
+    }
+
+    /// Whether a pod's logs are streamed
+    pub fn is_streamed(&self, pod_name: &str) -> bool {
+        let skipped = self.skip_old_replicasets
+            && self
+                .pods
+                .iter()
+                .any(|p| p.name == pod_name && p.old_replica_set);
+        !skipped
+            && self
+                .ui_state
+                .sampled_pods
+                .as_ref()
+                .is_none_or(|sampled| sampled.contains(pod_name))
+    }
+
+    /// The pods to stream: the sample, or every pod
+    pub fn streamed_pods(&self) -> Vec<PodInfo> {
+        self.pods
+            .iter()
+            .filter(|p| self.is_streamed(&p.name))
+            .cloned()
+            .collect()
+    }
+
+    /// Double the sample, returning the pods it adds
+    pub fn widen_sample(&mut self) -> Vec<PodInfo> {
+        let (Some(sampling), Some(sampled)) = (&self.sampling, &self.ui_state.sampled_pods) else {
+            return Vec::new();
+        };
+        let added: Vec<String> = sampling
+            .next(&self.pods, sampled, sampled.len().max(1))
+            .into_iter()
+            .map(str::to_string)
+            .collect();
+        self.add_to_sample(&added)
+    }
+
+    /// Add the pod selected in the pod panel to the sample, if it isn't streamed
+    pub fn sample_selected_pod(&mut self) -> Vec<PodInfo> {
+        let Some(pod) = self.pods.get(self.ui_state.pod_panel_selection) else {
+            return Vec::new();
+        };
+        let name = pod.name.clone();
+        self.add_to_sample(&[name])
+    }
+
+    fn add_to_sample(&mut self, names: &[String]) -> Vec<PodInfo> {
+        let Some(sampled) = self.ui_state.sampled_pods.as_mut() else {
+            return Vec::new();
+        };
+        let added: Vec<PodInfo> = self
+            .pods
+            .iter()
+            .filter(|p| names.contains(&p.name) && sampled.insert(p.name.clone()))
+            .cloned()
+            .collect();
+        // Once every pod is in, it's no longer a sample
+        if sampled.len() >= self.pods.len() {
+            self.ui_state.sampled_pods = None;
+        }
+        added
+    }
+
+    /// Mute or unmute the pod selected in the pod panel
+    pub fn toggle_selected_pod_mute(&mut self) {
+        let Some(pod) = self.pods.get(self.ui_state.pod_panel_selection) else {
+            return;
+        };
+        if !self.ui_state.muted_pods.remove(&pod.name) {
+            self.ui_state.muted_pods.insert(pod.name.clone());
+        }
+    }
+
+    /// Cycle the node filter through the nodes of the streamed pods (then off)
+    pub fn cycle_node_filter(&mut self) {
+        let nodes: BTreeSet<&String> = self
+            .pods
+            .iter()
+            .filter_map(|p| p.node_name.as_ref())
+            .collect();
+
+        let next = match &self.ui_state.node_filter {
+            None => nodes.iter().next().map(|n| n.to_string()),
+            Some(current) => nodes
+                .iter()
+                .skip_while(|n| **n != current)
+                .nth(1)
+                .map(|n| n.to_string()),
+        };
+        self.ui_state.node_filter = next;
+    }
+
+    /// Turn compare mode on (pod panel selection vs the next pod) or off
+    pub fn toggle_compare(&mut self) {
+        if self.ui_state.compare.take().is_some() {
+            return;
+        }
+        if self.pods.len() < 2 {
+            self.notify(Severity::Warning, "Compare needs at least two pods");
+            return;
+        }
+        let left = self.ui_state.pod_panel_selection.min(self.pods.len() - 1);
+        let right = (left + 1) % self.pods.len();
+        self.ui_state.compare = Some((self.pods[left].name.clone(), self.pods[right].name.clone()));
+    }
+
+    /// Move the right compare pane on to the next pod (skipping the left one)
+    pub fn cycle_compare(&mut self) {
+        let Some((left, right)) = &self.ui_state.compare else {
+            return;
+        };
+        let others: Vec<&String> = self
+            .pods
+            .iter()
+            .map(|p| &p.name)
+            .filter(|name| *name != left)
+            .collect();
+        let Some(first) = others.first() else {
+            return;
+        };
+        let next = others
+            .iter()
+            .skip_while(|name| **name != right)
+            .nth(1)
+            .unwrap_or(first);
+        let next = next.to_string();
+        if let Some((_, right)) = &mut self.ui_state.compare {
+            *right = next;
+        }
+    }
+
+    /// Whether a prefix column is currently shown (line number and level always are)
+    pub fn column_enabled(&self, column: Column) -> bool {
+        match column {
+            Column::Time => self.ui_state.show_timestamps,
+            Column::Pod => self.ui_state.show_pod_names,
+            Column::Node => self.ui_state.show_node_names,
+            Column::Source => self.ui_state.show_source,
+            Column::Line | Column::Level => true,
+        }
+    }
+
+    /// Whether the log viewer draws `column`, after the density's say
+    pub fn column_shown(&self, column: Column) -> bool {
+        self.ui_state
+            .density
+            .shows(column)
+            .unwrap_or_else(|| self.column_enabled(column))
+    }
+
+    /// Switch to the next line density and remember it for the deployment
+    pub fn cycle_density(&mut self) {
+        let density = self.ui_state.density.next();
+        self.ui_state.density = density;
+        if let Some(target) = self.current_target() {
+            self.densities.remember(&target, density);
+            self.densities.save();
+        }
+        self.notify(Severity::Info, format!("Density: {}", density.label()));
+    }
+
+    /// Switch to the density last used for this deployment
+    pub fn restore_density(&mut self) {
+        self.ui_state.density = self
+            .current_target()
+            .and_then(|target| self.densities.for_target(&target))
+            .unwrap_or_default();
+    }
+
+    /// Show or hide the column selected in the columns overlay
+    pub fn toggle_selected_column(&mut self) {
+        let Some((column, _)) = self
+            .ui_state
+            .columns
+            .columns
+            .get(self.ui_state.column_selection)
+        else {
+            return;
+        };
+        match column {
+            Column::Time => self.ui_state.show_timestamps = !self.ui_state.show_timestamps,
+            Column::Pod => self.ui_state.show_pod_names = !self.ui_state.show_pod_names,
+            Column::Node => self.ui_state.show_node_names = !self.ui_state.show_node_names,
+            Column::Source => self.ui_state.show_source = !self.ui_state.show_source,
+            Column::Line | Column::Level => {}
+        }
+    }
+
+    /// Put the list cursor back on the item called `name` after the list was
+    /// replaced, or on the first item if it's gone
+    pub fn reselect_by_name(&mut self, name: Option<&str>) {
+        let idx = name.and_then(|name| match self.current_screen {
+            Screen::NamespaceSelect => self.namespaces.iter().position(|n| n.name == name),
+            Screen::DeploymentSelect => self.deployments.iter().position(|d| d.name == name),
+            _ => None,
+        });
+        self.ui_state.list_state.select(Some(idx.unwrap_or(0)));
+    }
+
     /// Get currently selected index
     pub fn selected_index(&self) -> Option<usize> {
         self.ui_state.list_state.selected()
     }
 
-    /// Show an error message
-    pub fn show_error(&mut self, msg: String) {
-        self.ui_state.error_message = Some(msg);
+    /// Status bar text: the load spinner while one is running, otherwise `idle`
+    pub fn status_text(&self, idle: String) -> String {
+        self.ui_state
+            .loading
+            .as_ref()
+            .map(Loading::display)
+            .unwrap_or(idle)
     }
 
-    /// Dismiss the error message
-    pub fn dismiss_error(&mut self) {
-        self.ui_state.error_message = None;
+    /// Show an error modal, optionally offering to retry
+    ///
+    /// The error also goes into the notification history.
+    pub fn show_error(&mut self, msg: String, kind: Option<ApiError>, retry: Option<Action>) {
+        self.ui_state
+            .notifications
+            .record(Severity::Error, msg.clone());
+        self.ui_state.error = Some(ErrorBanner {
+            message: msg,
+            kind,
+            retry,
+        });
+    }
+
+    /// Show a toast notification
+    pub fn notify(&mut self, severity: Severity, msg: impl Into<String>) {
+        self.ui_state.notifications.push(severity, msg.into());
+    }
+
+    /// Ask before running a destructive action
+    pub fn ask_confirm(
+        &mut self,
+        title: impl Into<String>,
+        message: impl Into<String>,
+        confirm_label: &'static str,
+        action: Action,
+    ) {
+        self.ui_state.confirm = Some(PendingConfirm {
+            title: title.into(),
+            message: message.into(),
+            confirm_label,
+            action,
+        });
     }
 
     /// Start search/filter input mode
     pub fn start_search(&mut self) {
         self.ui_state.search_active = true;
+        self.ui_state.search_mode = SearchMode::Filter;
+        self.ui_state.search_input.clear();
+        self.ui_state.search_preview.reset();
+        self.ui_state.filter_error = None;
+    }
+
+    /// Start find input mode
+    pub fn start_find(&mut self) {
+        self.ui_state.search_active = true;
+        self.ui_state.search_mode = SearchMode::Find;
         self.ui_state.search_input.clear();
+        self.ui_state.search_preview.reset();
         self.ui_state.filter_error = None;
     }
 
-    /// Cancel search/filter input and clear filter
+    /// Cancel search/filter input and clear the filter (or find query) being edited
     pub fn cancel_search(&mut self) {
         self.ui_state.search_active = false;
         self.ui_state.search_input.clear();
-        self.ui_state.active_filter = None;
+        self.ui_state.search_preview.reset();
+        match self.ui_state.search_mode {
+            SearchMode::Filter => self.ui_state.active_filter = None,
+            SearchMode::Find => self.ui_state.find.set_query(None),
+        }
         self.ui_state.filter_error = None;
     }
 
-    /// Apply the current search input as a filter
-    pub fn apply_filter(&mut self) {
+    /// Apply the search input to whichever of filter/find is being edited
+    pub fn submit_search(&mut self) {
+        self.ui_state.search_preview.reset();
+        match self.ui_state.search_mode {
+            SearchMode::Filter => {
+                self.apply_filter();
+                // Reset scroll to top when applying filter
+                self.ui_state.log_scroll = 0;
+            }
+            SearchMode::Find => self.apply_find(),
+        }
+    }
+
+    /// Compile a pattern with the current case sensitivity setting
+    fn compile_pattern(&self, pattern: &str) -> Result<CompiledFilter, regex::Error> {
+        if self.ui_state.filter_case_insensitive {
+            CompiledFilter::new_case_insensitive(pattern)
+        } else {
+            CompiledFilter::new(pattern)
+        }
+    }
+
+    /// Apply the search input as a find query and jump to the first match below the top line
+    pub fn apply_find(&mut self) {
         self.ui_state.search_active = false;
         self.ui_state.filter_error = None;
 
         if self.ui_state.search_input.is_empty() {
-            self.ui_state.active_filter = None;
+            self.ui_state.find.set_query(None);
             return;
         }
 
-        let result = if self.ui_state.filter_case_insensitive {
-            CompiledFilter::new_case_insensitive(&self.ui_state.search_input)
+        match self.compile_pattern(&self.ui_state.search_input) {
+            Ok(query) => {
+                self.ui_state.find.set_query(Some(query));
+                let ui = &mut self.ui_state;
+                ui.find.refresh(&ui.filter_cache.cached_entries);
+                let top = self.top_visible_index();
+                self.jump_to_match_from(top.and_then(|t| t.checked_sub(1)), true);
+            }
+            Err(e) => {
+                self.ui_state.filter_error = Some(format!("Invalid regex: {}", e));
+                self.ui_state.search_active = true; // Keep input open to fix
+            }
+        }
+    }
+
+    /// Lines the preview count runs over: the buffer for a filter, the current view for find
+    pub fn search_preview_entries(&self, log_buffer: &LogBuffer) -> Vec<ArcLogEntry> {
+        match self.ui_state.search_mode {
+            SearchMode::Filter => self
+                .filter_pipeline()
+                .without_text()
+                .apply(log_buffer.all()),
+            SearchMode::Find => self.ui_state.filter_cache.cached_entries.clone(),
+        }
+    }
+
+    /// Toast schema drift the buffer has recorded since the last call
+    ///
+    /// Returns whether anything was announced.
+    pub fn announce_schema_drift(&mut self, log_buffer: &LogBuffer) -> bool {
+        let total = log_buffer.schema_drift_count();
+        let announced = self.ui_state.schema_drifts_announced;
+        self.ui_state.schema_drifts_announced = total;
+        // Fewer than before means the buffer was cleared
+        if total <= announced {
+            return false;
+        }
+        let new = log_buffer.schema_drifts_since(announced);
+        let Some(latest) = new.last() else {
+            return false;
+        };
+        let message = if new.len() == 1 {
+            format!("Schema drift: {} (S for report)", latest.describe())
         } else {
-            CompiledFilter::new(&self.ui_state.search_input)
+            format!(
+                "Schema drift: {} and {} more (S for report)",
+                latest.describe(),
+                new.len() - 1
+            )
         };
+        self.notify(Severity::Warning, message);
+        true
+    }
+
+    /// JSON keys a line must carry to stay in view (none when hiding keys)
+    pub fn json_filter_keys(&self) -> &HashSet<String> {
+        static NO_KEYS: LazyLock<HashSet<String>> = LazyLock::new(HashSet::new);
+        if self.ui_state.json_keys_exclude {
+            &NO_KEYS
+        } else {
+            &self.ui_state.json_visible_keys
+        }
+    }
+
+    /// The filters that decide which lines are in view
+    pub fn filter_pipeline(&self) -> FilterPipeline<'_> {
+        FilterPipeline {
+            text: self.ui_state.active_filter.as_ref(),
+            json_keys: self.json_filter_keys(),
+            muted_pods: &self.ui_state.muted_pods,
+            node: self.ui_state.node_filter.as_deref(),
+            min_level: self.ui_state.min_level,
+        }
+    }
 
-        match result {
+    /// Recompile the active filter and find query after a case sensitivity change
+    pub fn recompile_queries(&mut self) {
+        if let Some(pattern) = self
+            .ui_state
+            .active_filter
+            .as_ref()
+            .filter(|f| f.has_pattern())
+            .map(|f| f.pattern().to_string())
+        {
+            let inverted = self
+                .ui_state
+                .active_filter
+                .as_ref()
+                .is_some_and(|f| f.is_inverted());
+            if let Ok(filter) = self.compile_pattern(&pattern) {
+                self.ui_state.active_filter =
+                    Some(if inverted { filter.inverted() } else { filter });
+            }
+        }
+        if let Some(pattern) = self
+            .ui_state
+            .find
+            .query
+            .as_ref()
+            .map(|q| q.pattern().to_string())
+            && let Ok(query) = self.compile_pattern(&pattern)
+        {
+            let current = self.ui_state.find.current.take();
+            self.ui_state.find.set_query(Some(query));
+            self.ui_state.find.current = current;
+        }
+    }
+
+    /// Jump to the first line at or after a time of day (in the displayed timezone)
+    ///
+    /// The day is taken from the newest line, going back a day if that time
+    /// hasn't happened yet on it.
+    pub fn jump_to_time(&mut self, time: NaiveTime) {
+        let entries = &self.ui_state.filter_cache.cached_entries;
+        let Some(newest) = entries.iter().rev().find_map(|e| e.timestamp) else {
+            self.notify(Severity::Warning, "No timestamped lines to jump to");
+            return;
+        };
+
+        let to_utc = |date: chrono::NaiveDate| -> Option<DateTime<Utc>> {
+            let naive = date.and_time(time);
+            if self.ui_state.use_local_time {
+                naive
+                    .and_local_timezone(Local)
+                    .earliest()
+                    .map(|t| t.with_timezone(&Utc))
+            } else {
+                Some(naive.and_utc())
+            }
+        };
+        let newest_date = if self.ui_state.use_local_time {
+            newest.with_timezone(&Local).date_naive()
+        } else {
+            newest.date_naive()
+        };
+        let target = to_utc(newest_date)
+            .filter(|t| *t <= newest)
+            .or_else(|| newest_date.pred_opt().and_then(to_utc));
+
+        let found = target.and_then(|target| {
+            entries
+                .iter()
+                .find(|e| e.timestamp.is_some_and(|ts| ts >= target))
+                .cloned()
+        });
+        match found {
+            Some(entry) => {
+                self.ui_state.pending_jump = Some(entry);
+                self.ui_state.auto_scroll = false;
+            }
+            None => self.notify(
+                Severity::Warning,
+                format!("No lines at or after {}", time.format("%H:%M:%S")),
+            ),
+        }
+    }
+
+    /// View index of the entry at the top of the viewport
+    fn top_visible_index(&self) -> Option<usize> {
+        let top = self.ui_state.top_visible_entry.as_ref()?;
+        self.ui_state
+            .filter_cache
+            .cached_entries
+            .iter()
+            .position(|e| Arc::ptr_eq(e, top))
+    }
+
+    /// Jump to the next (or previous) find match, starting from the current match
+    pub fn jump_to_match(&mut self, forward: bool) {
+        if self.ui_state.find.is_stale() {
+            let ui = &mut self.ui_state;
+            ui.find.refresh(&ui.filter_cache.cached_entries);
+        }
+        let from = match &self.ui_state.find.current {
+            Some(current) => self
+                .ui_state
+                .filter_cache
+                .cached_entries
+                .iter()
+                .position(|e| Arc::ptr_eq(e, current)),
+            None => self.top_visible_index(),
+        };
+        self.jump_to_match_from(from, forward);
+    }
+
+    fn jump_to_match_from(&mut self, from: Option<usize>, forward: bool) {
+        let Some(idx) = self.ui_state.find.step(from, forward) else {
+            return;
+        };
+        if let Some(entry) = self.ui_state.filter_cache.cached_entries.get(idx) {
+            self.ui_state.find.current = Some(entry.clone());
+            self.ui_state.pending_jump = Some(entry.clone());
+            self.ui_state.auto_scroll = false;
+        }
+    }
+
+    /// Apply the current search input as a filter
+    pub fn apply_filter(&mut self) {
+        self.ui_state.search_active = false;
+        self.ui_state.filter_error = None;
+
+        if self.ui_state.search_input.is_empty() {
+            self.ui_state.active_filter = None;
+            return;
+        }
+
+        match self.compile_pattern(&self.ui_state.search_input) {
             Ok(filter) => {
                 self.ui_state.active_filter = Some(filter);
+                self.ui_state
+                    .usage
+                    .record_filter(&self.ui_state.search_input);
             }
             Err(e) => {
                 self.ui_state.filter_error = Some(format!("Invalid regex: {}", e));
@@ -395,9 +1795,10 @@ impl AppState {
         }
     }
 
-    /// Clear the active filter
+    /// Clear the active filter and find query
     pub fn clear_filter(&mut self) {
         self.ui_state.active_filter = None;
+        self.ui_state.find.set_query(None);
         self.ui_state.search_input.clear();
         self.ui_state.filter_error = None;
     }
@@ -405,10 +1806,626 @@ impl AppState {
     /// Add a character to search input
     pub fn search_input_char(&mut self, c: char) {
         self.ui_state.search_input.push(c);
+        self.update_search_preview();
     }
 
     /// Remove last character from search input
     pub fn search_input_backspace(&mut self) {
         self.ui_state.search_input.pop();
+        self.update_search_preview();
+    }
+
+    /// Clear the search input
+    pub fn search_input_clear(&mut self) {
+        self.ui_state.search_input.clear();
+        self.update_search_preview();
+    }
+
+    /// Recompile the search input for the live preview
+    pub fn update_search_preview(&mut self) {
+        let query = (!self.ui_state.search_input.is_empty())
+            .then(|| self.compile_pattern(&self.ui_state.search_input));
+        self.ui_state.search_preview.update(query);
+    }
+
+    /// How a JSON entry is laid out in pretty-print mode
+    pub fn json_fold(&self, entry: &LogEntry) -> JsonFold {
+        self.ui_state.json_folds.get(&entry.id).copied().unwrap_or(
+            match self.ui_state.json_fold_depth {
+                Some(depth) => JsonFold::Depth(depth),
+                None => JsonFold::Expanded,
+            },
+        )
+    }
+
+    /// Expand the JSON entry at the top of the viewport, or collapse it if it
+    /// is already fully expanded
+    pub fn toggle_json_fold(&mut self) {
+        let Some(entry) = self.ui_state.top_visible_entry.clone() else {
+            return;
+        };
+        if !entry.is_json {
+            self.notify(Severity::Info, "Top line is not JSON");
+            return;
+        }
+        let next = match self.json_fold(&entry) {
+            JsonFold::Expanded => JsonFold::Collapsed,
+            JsonFold::Collapsed | JsonFold::Depth(_) => JsonFold::Expanded,
+        };
+        self.ui_state.json_folds.insert(entry.id, next);
+    }
+
+    /// The truncated entry at the top of the viewport, to fetch in full
+    ///
+    /// An untruncated line with links opens straight away and returns `None`.
+    pub fn open_full_line(&mut self) -> Option<ArcLogEntry> {
+        let entry = self.ui_state.top_visible_entry.clone()?;
+        let links = self.entry_links(&entry);
+        if !entry.truncated {
+            if links.is_empty() {
+                self.notify(Severity::Info, "Top line isn't truncated");
+            } else {
+                // Nothing to fetch, the overlay is just for the links
+                let mut full_line = FullLine::loading(entry.id);
+                full_line.set_line(&entry.raw);
+                full_line.links = links;
+                self.ui_state.full_line = Some(full_line);
+            }
+            return None;
+        }
+        if entry.timestamp.is_none() {
+            self.notify(
+                Severity::Warning,
+                "Top line has no timestamp to fetch it by",
+            );
+            return None;
+        }
+        let mut full_line = FullLine::loading(entry.id);
+        full_line.links = links;
+        self.ui_state.full_line = Some(full_line);
+        Some(entry)
+    }
+
+    /// Terminal title for the current view, `kubescope: ns/deployment@context`
+    /// in the log viewer and as much of it as has been picked elsewhere
+    pub fn window_title(&self) -> String {
+        let picked = match self.current_screen {
+            Screen::NamespaceSelect => 1,
+            Screen::DeploymentSelect => 2,
+            Screen::LogViewer => 3,
+            _ => 0,
+        };
+        let context = self.selected_context.as_deref().filter(|_| picked >= 1);
+        let namespace = self.selected_namespace.as_deref().filter(|_| picked >= 2);
+        let deployment = self.selected_deployment.as_deref().filter(|_| picked >= 3);
+        match (context, namespace, deployment) {
+            (Some(context), Some(namespace), Some(deployment)) => {
+                format!("kubescope: {}/{}@{}", namespace, deployment, context)
+            }
+            (Some(context), Some(namespace), None) => {
+                format!("kubescope: {}@{}", namespace, context)
+            }
+            (Some(context), _, _) => format!("kubescope: {}", context),
+            _ => "kubescope".to_string(),
+        }
+    }
+
+    /// The placeholders link templates are filled in with for the open deployment
+    fn link_target(&self) -> LinkTarget<'_> {
+        LinkTarget {
+            context: self.selected_context.as_deref().unwrap_or_default(),
+            namespace: self.selected_namespace.as_deref().unwrap_or_default(),
+            deployment: self.selected_deployment.as_deref().unwrap_or_default(),
+            ..LinkTarget::default()
+        }
+    }
+
+    /// Link to the open deployment's dashboard, if a template is configured
+    pub fn deployment_link(&self) -> Option<String> {
+        self.links.deployment_url(&self.link_target())
+    }
+
+    /// Filter to the request id of the line at the top of the viewport, so a
+    /// sidecar's access log line shows next to the app lines for its request
+    pub fn follow_request(&mut self) {
+        let Some(entry) = self.ui_state.top_visible_entry.clone() else {
+            return;
+        };
+        let Some(id) = request_id(&entry, &self.links.trace_keys) else {
+            self.notify(Severity::Info, "Top line has no request id");
+            return;
+        };
+        self.ui_state.search_input = regex::escape(&id);
+        self.apply_filter();
+        self.notify(Severity::Info, format!("Following request {}", id));
+    }
+
+    /// Pod dashboard and trace links for a line, for the templates configured
+    pub fn entry_links(&self, entry: &LogEntry) -> Vec<(&'static str, String)> {
+        let trace_id = self.links.trace_id(entry);
+        let target = LinkTarget {
+            pod: entry.pod_name(),
+            trace_id: trace_id.as_deref().unwrap_or_default(),
+            ..self.link_target()
+        };
+        let mut links = Vec::new();
+        if let Some(url) = self.links.pod_url(&target) {
+            links.push(("Pod dashboard", url));
+        }
+        if trace_id.is_some()
+            && let Some(url) = self.links.trace_url(&target)
+        {
+            links.push(("Trace", url));
+        }
+        links
+    }
+
+    /// Show or hide the raw line under the entry at the top of the viewport
+    pub fn toggle_raw_view(&mut self) {
+        let Some(entry) = self.ui_state.top_visible_entry.clone() else {
+            return;
+        };
+        if !self.ui_state.raw_views.remove(&entry.id) {
+            self.ui_state.raw_views.insert(entry.id);
+        }
+    }
+
+    /// Step the default fold depth: fully expanded, 1, 2, 3, then expanded again
+    pub fn cycle_json_fold_depth(&mut self) {
+        self.ui_state.json_fold_depth = match self.ui_state.json_fold_depth {
+            None => Some(1),
+            Some(depth) if depth < 3 => Some(depth + 1),
+            Some(_) => None,
+        };
+    }
+
+    /// Bookmark the entry at the top of the viewport, or remove it if already bookmarked
+    pub fn toggle_bookmark(&mut self) {
+        let Some(entry) = self.ui_state.top_visible_entry.clone() else {
+            return;
+        };
+
+        if let Some(pos) = self
+            .ui_state
+            .bookmarks
+            .iter()
+            .position(|b| Arc::ptr_eq(b, &entry))
+        {
+            self.remove_bookmark(pos);
+        } else {
+            self.ui_state.bookmarks.push(entry);
+        }
+    }
+
+    /// Remove a bookmark and the notes on its line
+    pub fn remove_bookmark(&mut self, pos: usize) {
+        let entry = self.ui_state.bookmarks.remove(pos);
+        self.ui_state
+            .notes
+            .retain(|note| !note.entry.as_ref().is_some_and(|e| Arc::ptr_eq(e, &entry)));
+    }
+
+    /// Note the line at the top of the viewport (bookmarking it), or the current time
+    pub fn add_note(&mut self, text: String) {
+        let entry = self.ui_state.top_visible_entry.clone();
+        let at = entry
+            .as_ref()
+            .and_then(|e| e.timestamp)
+            .unwrap_or_else(Utc::now);
+        if let Some(entry) = &entry
+            && !self.is_bookmarked(entry)
+        {
+            self.ui_state.bookmarks.push(entry.clone());
+        }
+        self.ui_state.notes.push(Note { at, entry, text });
+    }
+
+    /// Notes written on an entry (by identity)
+    pub fn notes_for<'a>(&'a self, entry: &'a LogEntry) -> impl Iterator<Item = &'a Note> {
+        self.ui_state.notes.iter().filter(move |note| {
+            note.entry
+                .as_ref()
+                .is_some_and(|e| std::ptr::eq(e.as_ref(), entry))
+        })
+    }
+
+    /// Queue a jump to the next (or previous) error relative to the top of the viewport
+    pub fn jump_to_error(&mut self, forward: bool) {
+        let entries = &self.ui_state.filter_cache.cached_entries;
+        let current = self
+            .ui_state
+            .top_visible_entry
+            .as_ref()
+            .and_then(|top| entries.iter().position(|e| Arc::ptr_eq(e, top)));
+
+        let is_error = |e: &&ArcLogEntry| matches!(e.level, LogLevel::Error | LogLevel::Fatal);
+        let target = if forward {
+            let start = current.map_or(0, |i| i + 1);
+            entries.iter().skip(start).find(is_error)
+        } else {
+            let end = current.unwrap_or(entries.len());
+            entries.iter().take(end).rev().find(is_error)
+        };
+
+        if let Some(entry) = target {
+            self.ui_state.pending_jump = Some(entry.clone());
+            self.ui_state.auto_scroll = false;
+        }
+    }
+
+    /// Check if an entry is bookmarked (by identity, not content)
+    pub fn is_bookmarked(&self, entry: &LogEntry) -> bool {
+        self.ui_state
+            .bookmarks
+            .iter()
+            .any(|b| std::ptr::eq(b.as_ref(), entry))
+    }
+}
+
+/// Ordering for selection lists: pinned first, then alphabetical
+fn pin_order(pins: &BTreeSet<String>, a: &str, b: &str) -> std::cmp::Ordering {
+    (!pins.contains(a), a).cmp(&(!pins.contains(b), b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::LogEntry;
+
+    #[test]
+    fn test_find_state_steps_and_wraps() {
+        let entries: Vec<ArcLogEntry> = ["ok", "boom", "ok", "boom again", "ok"]
+            .iter()
+            .enumerate()
+            .map(|(i, msg)| Arc::new(LogEntry::new("pod".into(), i as i64, msg.to_string())))
+            .collect();
+
+        let mut find = FindState::default();
+        find.set_query(Some(CompiledFilter::new("boom").unwrap()));
+        assert!(find.is_stale());
+        find.refresh(&entries);
+        assert!(!find.is_stale());
+        assert_eq!(find.matches, vec![1, 3]);
+
+        assert_eq!(find.step(None, true), Some(1));
+        assert_eq!(find.step(Some(1), true), Some(3));
+        assert_eq!(find.step(Some(3), true), Some(1));
+        assert_eq!(find.step(Some(1), false), Some(3));
+        assert_eq!(find.step(None, false), Some(3));
+
+        find.current = Some(entries[3].clone());
+        assert_eq!(find.position(&entries), Some(2));
+    }
+
+    #[test]
+    fn test_search_preview_discards_stale_counts() {
+        let mut preview = SearchPreview::default();
+        preview.update(Some(CompiledFilter::new("err")));
+        let (generation, _) = preview.due(Duration::ZERO).expect("count is due");
+        assert!(preview.due(Duration::ZERO).is_none());
+        assert!(preview.is_counting());
+
+        // Typing again before the count lands makes it stale
+        preview.update(Some(CompiledFilter::new("erro")));
+        assert!(!preview.finish(generation, 3));
+        assert_eq!(preview.count, None);
+
+        let (generation, _) = preview.due(Duration::ZERO).expect("count is due");
+        assert!(preview.finish(generation, 2));
+        assert_eq!(preview.count, Some(2));
+
+        preview.update(Some(CompiledFilter::new("(")));
+        assert!(preview.error.is_some());
+        assert!(preview.due(Duration::ZERO).is_none());
+    }
+
+    #[test]
+    fn test_pinned_items_sort_first_and_keep_the_cursor() {
+        let (tx, _rx) = mpsc::unbounded_channel();
+        let mut state = AppState::new(tx);
+        state.pinned.namespaces.insert("payments".into());
+        state.set_namespaces(
+            ["default", "kube-system", "payments", "search"]
+                .map(|name| NamespaceInfo::new(name.into(), "Active".into()))
+                .into(),
+        );
+        let names = |state: &AppState| -> Vec<String> {
+            state.namespaces.iter().map(|n| n.name.clone()).collect()
+        };
+        assert_eq!(
+            names(&state),
+            ["payments", "default", "kube-system", "search"]
+        );
+
+        // Pinning another keeps both first, by name, with the cursor on it
+        state.current_screen = Screen::NamespaceSelect;
+        state.ui_state.list_state.select(Some(3));
+        state.toggle_pin();
+        assert_eq!(
+            names(&state),
+            ["payments", "search", "default", "kube-system"]
+        );
+        assert_eq!(state.selected_namespace_name().as_deref(), Some("search"));
+
+        // Unpinned, it goes back among the rest
+        state.toggle_pin();
+        assert_eq!(
+            names(&state),
+            ["payments", "default", "kube-system", "search"]
+        );
+        assert_eq!(state.selected_namespace_name().as_deref(), Some("search"));
+    }
+
+    #[test]
+    fn test_status_text_shows_spinner_while_loading() {
+        let (tx, _rx) = mpsc::unbounded_channel();
+        let mut state = AppState::new(tx);
+        assert_eq!(state.status_text("3 contexts".into()), "3 contexts");
+
+        state.ui_state.loading = Some(Loading::new("Loading namespaces"));
+        let text = state.status_text("3 contexts".into());
+        assert!(text.ends_with(" Loading namespaces…"), "{}", text);
+    }
+
+    #[test]
+    fn test_full_line_wraps_and_clamps_scroll() {
+        let mut full = FullLine::loading(7);
+        full.set_line("2024-01-15T10:30:00.123Z abcdefghij");
+        assert_eq!(full.rows(4), ["abcd", "efgh", "ij"]);
+
+        full.scroll_by(isize::MAX);
+        full.rows(4);
+        assert_eq!(full.scroll, 2);
+        full.scroll_by(-1);
+        assert_eq!(full.scroll, 1);
+
+        full.set_line(r#"{"msg":"hi"}"#);
+        assert_eq!(full.rows(80), ["{", "  \"msg\": \"hi\"", "}"]);
+    }
+
+    #[test]
+    fn test_hidden_json_keys_do_not_filter_lines() {
+        let (tx, _rx) = mpsc::unbounded_channel();
+        let mut state = AppState::new(tx);
+        state.ui_state.json_visible_keys.insert("trace_id".into());
+        assert_eq!(state.json_filter_keys().len(), 1);
+
+        state.ui_state.json_keys_exclude = true;
+        assert!(state.json_filter_keys().is_empty());
+        let pick = state.json_key_pick();
+        assert!(pick.exclude && pick.keys.contains("trace_id"));
+    }
+
+    #[test]
+    fn test_compare_pairs_and_cycles_pods() {
+        use crate::types::PodInfo;
+
+        let (tx, _rx) = mpsc::unbounded_channel();
+        let mut state = AppState::new(tx);
+        state.pods = vec![PodInfo::new("api-a".into(), "default".into())];
+        state.toggle_compare();
+        assert_eq!(state.ui_state.compare, None);
+
+        state.pods = ["api-a", "api-b", "api-c"]
+            .iter()
+            .map(|n| PodInfo::new(n.to_string(), "default".into()))
+            .collect();
+        state.ui_state.pod_panel_selection = 1;
+        state.toggle_compare();
+        assert_eq!(
+            state.ui_state.compare,
+            Some(("api-b".into(), "api-c".into()))
+        );
+
+        // The right pane never lands on the left pod
+        state.cycle_compare();
+        assert_eq!(
+            state.ui_state.compare,
+            Some(("api-b".into(), "api-a".into()))
+        );
+        state.cycle_compare();
+        assert_eq!(
+            state.ui_state.compare,
+            Some(("api-b".into(), "api-c".into()))
+        );
+
+        state.toggle_compare();
+        assert_eq!(state.ui_state.compare, None);
+    }
+
+    #[test]
+    fn test_widening_sample_adds_pods_until_all_stream() {
+        use crate::app::{SampleMode, Sampling};
+        use crate::types::PodInfo;
+
+        let (tx, _rx) = mpsc::unbounded_channel();
+        let mut state = AppState::new(tx);
+        state.pods = ["api-a", "api-b", "api-c", "api-d", "api-e"]
+            .iter()
+            .map(|n| PodInfo::new(n.to_string(), "default".into()))
+            .collect();
+        state.sampling = Some(Sampling::new(2, SampleMode::Newest));
+        state.sample_pods();
+        assert_eq!(state.streamed_pods().len(), 2);
+        assert!(!state.is_streamed("api-e"));
+
+        // Picking a pod by hand adds just that one
+        state.ui_state.pod_panel_selection = 4;
+        let added = state.sample_selected_pod();
+        assert_eq!(added.len(), 1);
+        assert!(state.is_streamed("api-e"));
+        assert!(state.sample_selected_pod().is_empty());
+
+        assert_eq!(state.widen_sample().len(), 2);
+        assert_eq!(state.ui_state.sampled_pods, None);
+        assert!(state.widen_sample().is_empty());
+
+        // Leftovers from an old ReplicaSet can be left out entirely
+        state.pods[0].old_replica_set = true;
+        assert!(state.is_streamed("api-a"));
+        state.skip_old_replicasets = true;
+        assert!(!state.is_streamed("api-a"));
+        assert_eq!(state.streamed_pods().len(), 4);
+    }
+
+    #[test]
+    fn test_json_fold_toggles_top_entry() {
+        let (tx, _rx) = mpsc::unbounded_channel();
+        let mut state = AppState::new(tx);
+        let mut entry = LogEntry::new("pod".into(), 1, r#"{"a":{"b":1}}"#.into());
+        entry.id = 7;
+        entry.is_json = true;
+        let entry = Arc::new(entry);
+        state.ui_state.top_visible_entry = Some(entry.clone());
+
+        state.ui_state.json_fold_depth = Some(1);
+        assert_eq!(state.json_fold(&entry), JsonFold::Depth(1));
+        state.toggle_json_fold();
+        assert_eq!(state.json_fold(&entry), JsonFold::Expanded);
+        state.toggle_json_fold();
+        assert_eq!(state.json_fold(&entry), JsonFold::Collapsed);
+
+        // Entries without their own fold follow the default depth
+        let other = LogEntry::new("pod".into(), 2, "{}".into());
+        state.cycle_json_fold_depth();
+        assert_eq!(state.json_fold(&other), JsonFold::Depth(2));
+        state.cycle_json_fold_depth();
+        state.cycle_json_fold_depth();
+        assert_eq!(state.json_fold(&other), JsonFold::Expanded);
+
+        // Ids start again after a clear, so folds don't carry over
+        state.toggle_raw_view();
+        state.ui_state.forget_entry_views();
+        state.ui_state.json_fold_depth = Some(1);
+        assert_eq!(state.json_fold(&entry), JsonFold::Depth(1));
+        assert!(state.ui_state.raw_views.is_empty());
+    }
+
+    #[test]
+    fn test_select_context_namespace() {
+        use crate::types::{ContextInfo, NamespaceInfo};
+
+        let (tx, _rx) = mpsc::unbounded_channel();
+        let mut state = AppState::new(tx);
+        state.contexts = vec![ContextInfo::new(
+            "staging".into(),
+            "cluster".into(),
+            "user".into(),
+            Some("payments".into()),
+            true,
+        )];
+        state.set_namespaces(
+            ["default", "payments"]
+                .iter()
+                .map(|n| NamespaceInfo::new(n.to_string(), "Active".into()))
+                .collect(),
+        );
+        assert_eq!(state.context_namespace(), None);
+
+        state.selected_context = Some("staging".into());
+        assert_eq!(state.context_namespace(), Some("payments"));
+        state.select_context_namespace();
+        assert_eq!(state.ui_state.list_state.selected(), Some(1));
+
+        // A kubeconfig namespace that doesn't exist in the cluster is ignored
+        state.namespaces.pop();
+        assert_eq!(state.context_namespace(), None);
+    }
+
+    #[test]
+    fn test_namespace_filters() {
+        use crate::types::{LabelSelector, NamespaceInfo};
+
+        let (tx, _rx) = mpsc::unbounded_channel();
+        let mut state = AppState::new(tx);
+        let namespace = |name: &str, status: &str, team: Option<&str>| {
+            let mut ns = NamespaceInfo::new(name.into(), status.into());
+            if let Some(team) = team {
+                ns.labels.insert("team".into(), team.into());
+            }
+            ns
+        };
+        state.set_namespaces(vec![
+            namespace("checkout", "Active", Some("payments")),
+            namespace("ledger", "Terminating", Some("payments")),
+            namespace("search", "Active", None),
+        ]);
+        let shown = |state: &AppState| -> Vec<String> {
+            state.namespaces.iter().map(|n| n.name.clone()).collect()
+        };
+        assert_eq!(shown(&state), ["checkout", "ledger", "search"]);
+
+        state.ui_state.hide_terminating = true;
+        state.filter_namespaces();
+        assert_eq!(shown(&state), ["checkout", "search"]);
+
+        state.ui_state.namespace_selector = Some(LabelSelector::parse("team=payments").unwrap());
+        state.filter_namespaces();
+        assert_eq!(shown(&state), ["checkout"]);
+
+        state.ui_state.hide_terminating = false;
+        state.ui_state.namespace_selector = Some(LabelSelector::parse("!team").unwrap());
+        state.filter_namespaces();
+        assert_eq!(shown(&state), ["search"]);
+
+        state.ui_state.namespace_selector =
+            Some(LabelSelector::parse("team != payments, team").unwrap());
+        state.filter_namespaces();
+        assert!(shown(&state).is_empty());
+        assert!(LabelSelector::parse("=payments").is_err());
+    }
+
+    #[test]
+    fn test_deployment_label_filter_survives_reload() {
+        use crate::types::{DeploymentInfo, LabelSelector};
+
+        let (tx, _rx) = mpsc::unbounded_channel();
+        let mut state = AppState::new(tx);
+        let deployments = || {
+            ["api", "cart", "worker"]
+                .iter()
+                .map(|name| {
+                    let mut d = DeploymentInfo::new(name.to_string(), "shared".into());
+                    let team = if *name == "worker" {
+                        "search"
+                    } else {
+                        "checkout"
+                    };
+                    d.labels.insert("team".into(), team.into());
+                    d
+                })
+                .collect::<Vec<_>>()
+        };
+        state.ui_state.deployment_selector = Some(LabelSelector::parse("team=checkout").unwrap());
+        state.set_deployments(deployments());
+        assert_eq!(state.deployments.len(), 2);
+        assert_eq!(state.all_deployments.len(), 3);
+
+        // A refreshed list keeps the filter
+        state.set_deployments(deployments());
+        assert!(state.deployments.iter().all(|d| d.name != "worker"));
+
+        state.ui_state.deployment_selector = None;
+        state.filter_deployments();
+        assert_eq!(state.deployments.len(), 3);
+    }
+
+    #[test]
+    fn test_window_title_follows_navigation() {
+        let (tx, _rx) = mpsc::unbounded_channel();
+        let mut state = AppState::new(tx);
+        assert_eq!(state.window_title(), "kubescope");
+
+        state.selected_context = Some("prod".into());
+        state.selected_namespace = Some("payments".into());
+        state.selected_deployment = Some("api".into());
+        state.current_screen = Screen::LogViewer;
+        assert_eq!(state.window_title(), "kubescope: payments/api@prod");
+
+        // Going back drops what's no longer open, even if it's still remembered
+        state.current_screen = Screen::DeploymentSelect;
+        assert_eq!(state.window_title(), "kubescope: payments@prod");
+        state.current_screen = Screen::NamespaceSelect;
+        assert_eq!(state.window_title(), "kubescope: prod");
     }
 }
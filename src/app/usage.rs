@@ -0,0 +1,165 @@
+//! Session summary: what this run of kubescope saw, kept on this machine only
+//!
+//! Nothing is sent anywhere; the numbers are for the stats overlay and the
+//! `--stats` summary printed on exit, e.g. to paste into an incident timeline.
+
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Local, Utc};
+
+use crate::types::{LogEntry, LogLevel};
+
+/// Shortest stretch a peak rate is measured over
+const RATE_INTERVAL: Duration = Duration::from_secs(1);
+
+#[derive(Clone, Debug)]
+pub struct UsageStats {
+    pub started_at: DateTime<Utc>,
+    started: Instant,
+    pub lines: u64,
+    /// Error and fatal lines
+    pub errors: u64,
+    pub warnings: u64,
+    /// Most lines per second seen over a rate interval
+    pub peak_rate: f64,
+    /// Filter patterns applied, first use first
+    pub filters: Vec<String>,
+    /// `context/namespace/deployment` of each target streamed
+    pub targets: Vec<String>,
+    /// Line count at the last rate sample
+    last_sample: (Instant, u64),
+}
+
+impl Default for UsageStats {
+    fn default() -> Self {
+        let now = Instant::now();
+        Self {
+            started_at: Utc::now(),
+            started: now,
+            lines: 0,
+            errors: 0,
+            warnings: 0,
+            peak_rate: 0.0,
+            filters: Vec::new(),
+            targets: Vec::new(),
+            last_sample: (now, 0),
+        }
+    }
+}
+
+impl UsageStats {
+    pub fn record_lines<'a>(&mut self, entries: impl IntoIterator<Item = &'a LogEntry>) {
+        for entry in entries {
+            self.lines += 1;
+            match entry.level {
+                LogLevel::Error | LogLevel::Fatal => self.errors += 1,
+                LogLevel::Warn => self.warnings += 1,
+                _ => {}
+            }
+        }
+    }
+
+    pub fn record_filter(&mut self, pattern: &str) {
+        if !self.filters.iter().any(|f| f == pattern) {
+            self.filters.push(pattern.to_string());
+        }
+    }
+
+    pub fn record_target(&mut self, target: String) {
+        if !self.targets.contains(&target) {
+            self.targets.push(target);
+        }
+    }
+
+    /// Update the peak rate (at most once per interval)
+    pub fn sample_rate(&mut self) {
+        self.sample_rate_at(Instant::now());
+    }
+
+    fn sample_rate_at(&mut self, now: Instant) {
+        let (at, lines) = self.last_sample;
+        let elapsed = now.duration_since(at);
+        if elapsed < RATE_INTERVAL {
+            return;
+        }
+        let rate = self.lines.saturating_sub(lines) as f64 / elapsed.as_secs_f64();
+        self.peak_rate = self.peak_rate.max(rate);
+        self.last_sample = (now, self.lines);
+    }
+
+    pub fn duration(&self) -> Duration {
+        self.started.elapsed()
+    }
+
+    /// Label and value rows, shared by the overlay and the exit summary
+    pub fn rows(&self) -> Vec<(&'static str, String)> {
+        let secs = self.duration().as_secs();
+        let none = || "-".to_string();
+        vec![
+            (
+                "started",
+                self.started_at
+                    .with_timezone(&Local)
+                    .format("%Y-%m-%d %H:%M:%S")
+                    .to_string(),
+            ),
+            (
+                "duration",
+                format!("{}h {:02}m {:02}s", secs / 3600, secs / 60 % 60, secs % 60),
+            ),
+            ("lines", self.lines.to_string()),
+            ("peak rate", format!("{:.0} lines/s", self.peak_rate)),
+            ("errors", self.errors.to_string()),
+            ("warnings", self.warnings.to_string()),
+            (
+                "targets",
+                Some(self.targets.join(", "))
+                    .filter(|t| !t.is_empty())
+                    .unwrap_or_else(none),
+            ),
+            (
+                "filters",
+                Some(self.filters.join(", "))
+                    .filter(|f| !f.is_empty())
+                    .unwrap_or_else(none),
+            ),
+        ]
+    }
+
+    /// Plain text summary, one `label: value` per line
+    pub fn summary(&self) -> String {
+        self.rows()
+            .into_iter()
+            .map(|(label, value)| format!("{:<10} {}\n", format!("{}:", label), value))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logs::LogParser;
+
+    #[test]
+    fn test_usage_stats_count_lines_and_peak_rate() {
+        let mut usage = UsageStats::default();
+        let start = usage.last_sample.0;
+        let lines: Vec<LogEntry> = ["ERROR boom", "WARN slow", "INFO ok", "FATAL down"]
+            .iter()
+            .map(|line| LogParser::parse(line, "api-1", 1))
+            .collect();
+        usage.record_lines(&lines);
+        usage.sample_rate_at(start + Duration::from_secs(2));
+        usage.record_lines(&lines[..2]);
+        usage.sample_rate_at(start + Duration::from_secs(3));
+        assert_eq!((usage.lines, usage.errors, usage.warnings), (6, 3, 2));
+        assert_eq!(usage.peak_rate, 2.0);
+
+        usage.record_filter("timeout");
+        usage.record_filter("timeout");
+        usage.record_target("prod/payments/api".into());
+        let summary = usage.summary();
+        assert!(summary.contains("filters:   timeout\n"));
+        assert!(summary.contains("targets:   prod/payments/api\n"));
+    }
+}
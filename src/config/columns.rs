@@ -0,0 +1,266 @@
+//! Log line prefix column layout
+//!
+//! The prefix shown before each log message (line number, timestamp, pod,
+//! node, source and level) is described by an ordered list of columns with widths.
+//! It can be set in the config file and adjusted at runtime from the
+//! columns overlay.
+
+use serde::{Deserialize, Serialize};
+
+/// A column in the log line prefix
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Column {
+    Line,
+    Time,
+    Pod,
+    Node,
+    /// Namespace/workload the line came from (hidden by default)
+    Source,
+    Level,
+}
+
+impl Column {
+    /// All columns in their default order
+    pub const ALL: [Column; 6] = [
+        Column::Line,
+        Column::Time,
+        Column::Pod,
+        Column::Node,
+        Column::Source,
+        Column::Level,
+    ];
+
+    /// Get display label for this column
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Line => "Line number",
+            Self::Time => "Timestamp",
+            Self::Pod => "Pod",
+            Self::Node => "Node",
+            Self::Source => "Source",
+            Self::Level => "Level",
+        }
+    }
+
+    /// Width used when none is configured
+    pub fn default_width(&self) -> usize {
+        match self {
+            Self::Line => 5,
+            Self::Time => 8,
+            Self::Pod => 10,
+            Self::Node => 15,
+            Self::Source => 20,
+            Self::Level => 3,
+        }
+    }
+
+    /// Allowed width range for this column
+    fn width_range(&self) -> (usize, usize) {
+        match self {
+            Self::Line => (3, 10),
+            // HH:MM (5) up to HH:MM:SS.mmm (12)
+            Self::Time => (5, 12),
+            Self::Pod | Self::Node => (4, 63),
+            // namespace (63) + "/" + deployment name
+            Self::Source => (4, 127),
+            Self::Level => (1, 3),
+        }
+    }
+
+    /// Clamp a width to the allowed range for this column
+    pub fn clamp_width(&self, width: usize) -> usize {
+        let (min, max) = self.width_range();
+        width.clamp(min, max)
+    }
+}
+
+/// How much of the prefix the log viewer shows, on top of the column toggles
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Density {
+    /// No line numbers, node or source, and HH:MM times
+    Compact,
+    /// The columns as configured and toggled
+    #[default]
+    Normal,
+    /// Full dates, pod/container and node
+    Detailed,
+}
+
+impl Density {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Compact => "compact",
+            Self::Normal => "normal",
+            Self::Detailed => "detailed",
+        }
+    }
+
+    /// Cycle compact -> normal -> detailed
+    pub fn next(&self) -> Self {
+        match self {
+            Self::Compact => Self::Normal,
+            Self::Normal => Self::Detailed,
+            Self::Detailed => Self::Compact,
+        }
+    }
+
+    /// Whether `column` is shown regardless of its toggle, if this density decides
+    pub fn shows(&self, column: Column) -> Option<bool> {
+        match (self, column) {
+            (Self::Compact, Column::Line | Column::Node | Column::Source) => Some(false),
+            (Self::Detailed, Column::Time | Column::Node) => Some(true),
+            _ => None,
+        }
+    }
+
+    /// Width of `column` at this density, given its configured width
+    pub fn width(&self, column: Column, width: usize) -> usize {
+        match (self, column) {
+            (Self::Compact, Column::Time) => 5,
+            // YYYY-MM-DD HH:MM:SS.mmm
+            (Self::Detailed, Column::Time) => 23,
+            // Room for pod/container
+            (Self::Detailed, Column::Pod) => width.max(DETAILED_POD_WIDTH),
+            _ => width,
+        }
+    }
+}
+
+/// Pod column width in the detailed density
+const DETAILED_POD_WIDTH: usize = 40;
+
+/// A column entry as written in the config file
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ColumnSpec {
+    pub column: Column,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub width: Option<usize>,
+}
+
+/// Ordered prefix columns with resolved widths
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ColumnLayout {
+    pub columns: Vec<(Column, usize)>,
+}
+
+impl Default for ColumnLayout {
+    fn default() -> Self {
+        Self {
+            columns: Column::ALL
+                .iter()
+                .map(|c| (*c, c.default_width()))
+                .collect(),
+        }
+    }
+}
+
+impl ColumnLayout {
+    /// Build a layout from config entries
+    ///
+    /// Duplicates are ignored, widths are clamped, and columns that are not
+    /// listed are appended in their default order.
+    pub fn from_specs(specs: &[ColumnSpec]) -> Self {
+        let mut columns: Vec<(Column, usize)> = Vec::new();
+        for spec in specs {
+            if columns.iter().any(|(c, _)| *c == spec.column) {
+                continue;
+            }
+            let width = spec.width.unwrap_or(spec.column.default_width());
+            columns.push((spec.column, spec.column.clamp_width(width)));
+        }
+        for column in Column::ALL {
+            if !columns.iter().any(|(c, _)| *c == column) {
+                columns.push((column, column.default_width()));
+            }
+        }
+        Self { columns }
+    }
+
+    /// Swap the column at `idx` with the one before it
+    pub fn move_up(&mut self, idx: usize) -> bool {
+        if idx == 0 || idx >= self.columns.len() {
+            return false;
+        }
+        self.columns.swap(idx, idx - 1);
+        true
+    }
+
+    /// Swap the column at `idx` with the one after it
+    pub fn move_down(&mut self, idx: usize) -> bool {
+        if idx + 1 >= self.columns.len() {
+            return false;
+        }
+        self.columns.swap(idx, idx + 1);
+        true
+    }
+
+    /// Grow or shrink the column at `idx` by `delta`, within its allowed range
+    pub fn resize(&mut self, idx: usize, delta: isize) {
+        if let Some((column, width)) = self.columns.get_mut(idx) {
+            *width = column.clamp_width(width.saturating_add_signed(delta));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_specs_dedupes_clamps_and_appends_missing() {
+        let specs = [
+            ColumnSpec {
+                column: Column::Pod,
+                width: Some(200),
+            },
+            ColumnSpec {
+                column: Column::Level,
+                width: None,
+            },
+            ColumnSpec {
+                column: Column::Pod,
+                width: Some(6),
+            },
+        ];
+        let layout = ColumnLayout::from_specs(&specs);
+        assert_eq!(
+            layout.columns,
+            vec![
+                (Column::Pod, 63),
+                (Column::Level, 3),
+                (Column::Line, 5),
+                (Column::Time, 8),
+                (Column::Node, 15),
+                (Column::Source, 20),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_density_overrides_columns() {
+        assert_eq!(Density::Compact.shows(Column::Line), Some(false));
+        assert_eq!(Density::Compact.shows(Column::Pod), None);
+        assert_eq!(Density::Compact.width(Column::Time, 12), 5);
+        assert_eq!(Density::Normal.shows(Column::Node), None);
+        assert_eq!(Density::Normal.width(Column::Time, 12), 12);
+        assert_eq!(Density::Detailed.shows(Column::Node), Some(true));
+        assert_eq!(Density::Detailed.width(Column::Pod, 63), 63);
+        assert_eq!(Density::Detailed.next(), Density::Compact);
+    }
+
+    #[test]
+    fn test_move_and_resize() {
+        let mut layout = ColumnLayout::default();
+        assert!(!layout.move_up(0));
+        assert!(layout.move_down(0));
+        assert_eq!(layout.columns[1].0, Column::Line);
+        assert!(!layout.move_down(layout.columns.len() - 1));
+
+        layout.resize(1, -10);
+        assert_eq!(layout.columns[1], (Column::Line, 3));
+        layout.resize(1, 2);
+        assert_eq!(layout.columns[1], (Column::Line, 5));
+    }
+}
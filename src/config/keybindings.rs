@@ -1,7 +1,7 @@
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use std::collections::HashMap;
 
-use crate::app::Action;
+use crate::app::{Action, PromptKind, Screen};
 
 /// A key combination
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
@@ -39,6 +39,36 @@ impl KeyBinding {
             modifiers: event.modifiers,
         }
     }
+
+    /// Human-readable key name for help text ("Ctrl+f", "G", "Space")
+    pub fn label(&self) -> String {
+        let key = match self.code {
+            KeyCode::Char(' ') => "Space".to_string(),
+            KeyCode::Char(c) => c.to_string(),
+            KeyCode::Up => "↑".to_string(),
+            KeyCode::Down => "↓".to_string(),
+            KeyCode::Left => "←".to_string(),
+            KeyCode::Right => "→".to_string(),
+            KeyCode::PageUp => "PgUp".to_string(),
+            KeyCode::PageDown => "PgDn".to_string(),
+            KeyCode::Backspace => "Bksp".to_string(),
+            KeyCode::Delete => "Del".to_string(),
+            other => other.to_string(),
+        };
+        let mut label = String::new();
+        if self.modifiers.contains(KeyModifiers::CONTROL) {
+            label.push_str("Ctrl+");
+        }
+        if self.modifiers.contains(KeyModifiers::ALT) {
+            label.push_str("Alt+");
+        }
+        // Shifted characters already show as themselves ("G", "#")
+        if self.modifiers.contains(KeyModifiers::SHIFT) && !matches!(self.code, KeyCode::Char(_)) {
+            label.push_str("Shift+");
+        }
+        label.push_str(&key);
+        label
+    }
 }
 
 /// Context for keybindings
@@ -50,8 +80,45 @@ pub enum KeyContext {
     FilterInput,
     CommandPalette,
     JsonKeyFilter,
+    Bookmarks,
+    PodPanel,
+    Columns,
+    Help,
+    Confirm,
+    Notifications,
+    SchemaDrift,
+    ParseDiagnostics,
+    StreamParams,
+    FullLine,
+    Error,
 }
 
+impl KeyContext {
+    /// Every context, in the order the help overlay lists them
+    pub const ALL: [KeyContext; 17] = [
+        KeyContext::Global,
+        KeyContext::ListNavigation,
+        KeyContext::LogViewer,
+        KeyContext::FilterInput,
+        KeyContext::Bookmarks,
+        KeyContext::PodPanel,
+        KeyContext::Columns,
+        KeyContext::JsonKeyFilter,
+        KeyContext::SchemaDrift,
+        KeyContext::ParseDiagnostics,
+        KeyContext::StreamParams,
+        KeyContext::FullLine,
+        KeyContext::CommandPalette,
+        KeyContext::Error,
+        KeyContext::Confirm,
+        KeyContext::Notifications,
+        KeyContext::Help,
+    ];
+}
+
+/// Rows moved per page in the full line overlay
+const FULL_LINE_PAGE: isize = 20;
+
 /// Keybinding configuration
 pub struct KeyBindings {
     bindings: HashMap<KeyContext, HashMap<KeyBinding, Action>>,
@@ -68,6 +135,17 @@ impl KeyBindings {
             Action::ToggleCommandPalette,
         );
         global.insert(KeyBinding::new(KeyCode::Char('?')), Action::ToggleHelp);
+        global.insert(
+            KeyBinding::shift(KeyCode::Char('H')),
+            Action::ToggleNotifications,
+        );
+        global.insert(
+            KeyBinding::new(KeyCode::Backspace),
+            Action::DismissNotification,
+        );
+        global.insert(KeyBinding::new(KeyCode::F(10)), Action::ToggleStreamParams);
+        global.insert(KeyBinding::new(KeyCode::F(11)), Action::ToggleUsageStats);
+        global.insert(KeyBinding::new(KeyCode::F(12)), Action::TogglePerfHud);
         global.insert(KeyBinding::new(KeyCode::Esc), Action::GoBack);
         global.insert(KeyBinding::ctrl(KeyCode::Char('c')), Action::Quit);
         global.insert(KeyBinding::new(KeyCode::Char('q')), Action::Quit);
@@ -81,6 +159,27 @@ impl KeyBindings {
         list_nav.insert(KeyBinding::new(KeyCode::Up), Action::ListUp);
         list_nav.insert(KeyBinding::new(KeyCode::Enter), Action::ListSelect);
         list_nav.insert(KeyBinding::new(KeyCode::Char('/')), Action::OpenSearch);
+        list_nav.insert(
+            KeyBinding::new(KeyCode::Char('r')),
+            Action::Navigate(Screen::RecentSelect),
+        );
+        list_nav.insert(KeyBinding::new(KeyCode::Char('p')), Action::TogglePin);
+        list_nav.insert(
+            KeyBinding::new(KeyCode::Char('s')),
+            Action::CycleDeploymentSort,
+        );
+        list_nav.insert(
+            KeyBinding::new(KeyCode::Char('t')),
+            Action::ToggleTerminatingNamespaces,
+        );
+        list_nav.insert(
+            KeyBinding::new(KeyCode::Char(':')),
+            Action::OpenPrompt(PromptKind::LabelSelector),
+        );
+        list_nav.insert(
+            KeyBinding::shift(KeyCode::Char(':')),
+            Action::OpenPrompt(PromptKind::LabelSelector),
+        );
         bindings.insert(KeyContext::ListNavigation, list_nav);
 
         // Log viewer bindings - less-like navigation
@@ -115,6 +214,9 @@ impl KeyBindings {
             KeyBinding::new(KeyCode::Char('f')),
             Action::ToggleAutoScroll,
         );
+        log_viewer.insert(KeyBinding::new(KeyCode::Char('x')), Action::ToggleStreaming);
+        log_viewer.insert(KeyBinding::new(KeyCode::Char('+')), Action::WidenSample);
+        log_viewer.insert(KeyBinding::shift(KeyCode::Char('+')), Action::WidenSample);
         log_viewer.insert(
             KeyBinding::new(KeyCode::Char('t')),
             Action::ToggleTimestamps,
@@ -123,7 +225,23 @@ impl KeyBindings {
             KeyBinding::shift(KeyCode::Char('T')),
             Action::ToggleLocalTime,
         );
+        log_viewer.insert(
+            KeyBinding::ctrl(KeyCode::Char('t')),
+            Action::ToggleTimeDeltas,
+        );
         log_viewer.insert(KeyBinding::new(KeyCode::Char('p')), Action::TogglePodNames);
+        log_viewer.insert(
+            KeyBinding::new(KeyCode::Char('#')),
+            Action::ToggleLineNumbers,
+        );
+        log_viewer.insert(
+            KeyBinding::shift(KeyCode::Char('#')),
+            Action::ToggleLineNumbers,
+        );
+        log_viewer.insert(
+            KeyBinding::shift(KeyCode::Char('D')),
+            Action::CycleTimeMarkers,
+        );
         log_viewer.insert(
             KeyBinding::shift(KeyCode::Char('J')),
             Action::ToggleJsonPrettyPrint,
@@ -131,23 +249,153 @@ impl KeyBindings {
         log_viewer.insert(KeyBinding::new(KeyCode::Char('c')), Action::ClearLogs);
         log_viewer.insert(KeyBinding::new(KeyCode::Char('/')), Action::OpenSearch);
         log_viewer.insert(KeyBinding::new(KeyCode::Char('n')), Action::ClearFilter);
+        log_viewer.insert(KeyBinding::shift(KeyCode::Char('F')), Action::OpenFind);
+        log_viewer.insert(KeyBinding::new(KeyCode::Char('.')), Action::FindNext);
+        log_viewer.insert(KeyBinding::new(KeyCode::Char(',')), Action::FindPrev);
         log_viewer.insert(
             KeyBinding::new(KeyCode::Char('i')),
             Action::ToggleCaseSensitive,
         );
         log_viewer.insert(KeyBinding::new(KeyCode::Char('s')), Action::ToggleStats);
+        log_viewer.insert(KeyBinding::new(KeyCode::Char('b')), Action::ToggleZen);
+        log_viewer.insert(KeyBinding::new(KeyCode::Char('d')), Action::CycleDensity);
         log_viewer.insert(KeyBinding::new(KeyCode::Char('e')), Action::ExportLogs);
+        log_viewer.insert(
+            KeyBinding::new(KeyCode::Char('o')),
+            Action::OpenLineInEditor,
+        );
+        log_viewer.insert(
+            KeyBinding::shift(KeyCode::Char('E')),
+            Action::OpenViewInPager,
+        );
         log_viewer.insert(
             KeyBinding::shift(KeyCode::Char('K')),
             Action::ToggleJsonKeyFilter,
         );
+        log_viewer.insert(
+            KeyBinding::shift(KeyCode::Char('S')),
+            Action::ToggleSchemaDrift,
+        );
+        log_viewer.insert(
+            KeyBinding::shift(KeyCode::Char('U')),
+            Action::ToggleParseDiagnostics,
+        );
+        log_viewer.insert(KeyBinding::new(KeyCode::Char('z')), Action::ToggleJsonFold);
+        log_viewer.insert(KeyBinding::new(KeyCode::Char('v')), Action::ToggleRawView);
+        log_viewer.insert(KeyBinding::new(KeyCode::Char('w')), Action::FetchFullLine);
+        log_viewer.insert(
+            KeyBinding::shift(KeyCode::Char('Z')),
+            Action::CycleJsonFoldDepth,
+        );
         log_viewer.insert(KeyBinding::new(KeyCode::Char('r')), Action::CycleTimeRange);
         log_viewer.insert(
             KeyBinding::shift(KeyCode::Char('R')),
             Action::CycleTimeRangeBack,
         );
+        log_viewer.insert(KeyBinding::new(KeyCode::Char('m')), Action::ToggleBookmark);
+        log_viewer.insert(
+            KeyBinding::shift(KeyCode::Char('A')),
+            Action::OpenPrompt(PromptKind::AddNote),
+        );
+        log_viewer.insert(
+            KeyBinding::new(KeyCode::Char('\'')),
+            Action::ToggleBookmarks,
+        );
+        log_viewer.insert(KeyBinding::shift(KeyCode::Char('M')), Action::ToggleMinimap);
+        log_viewer.insert(KeyBinding::new(KeyCode::Char(']')), Action::JumpNextError);
+        log_viewer.insert(KeyBinding::new(KeyCode::Char('[')), Action::JumpPrevError);
+        log_viewer.insert(
+            KeyBinding::shift(KeyCode::Char('P')),
+            Action::TogglePodPanel,
+        );
+        log_viewer.insert(KeyBinding::new(KeyCode::Char('l')), Action::TogglePodLegend);
+        log_viewer.insert(KeyBinding::shift(KeyCode::Char('L')), Action::CycleMinLevel);
+        log_viewer.insert(
+            KeyBinding::shift(KeyCode::Char('N')),
+            Action::ToggleNodeNames,
+        );
+        log_viewer.insert(
+            KeyBinding::shift(KeyCode::Char('O')),
+            Action::CycleNodeFilter,
+        );
+        log_viewer.insert(KeyBinding::shift(KeyCode::Char('V')), Action::ToggleCompare);
+        log_viewer.insert(KeyBinding::new(KeyCode::Tab), Action::CycleCompare);
+        log_viewer.insert(KeyBinding::shift(KeyCode::Char('C')), Action::ToggleColumns);
         bindings.insert(KeyContext::LogViewer, log_viewer);
 
+        // Bookmarks overlay bindings
+        let mut bookmarks = HashMap::new();
+        bookmarks.insert(KeyBinding::new(KeyCode::Up), Action::BookmarkUp);
+        bookmarks.insert(KeyBinding::new(KeyCode::Down), Action::BookmarkDown);
+        bookmarks.insert(KeyBinding::new(KeyCode::Char('k')), Action::BookmarkUp);
+        bookmarks.insert(KeyBinding::new(KeyCode::Char('j')), Action::BookmarkDown);
+        bookmarks.insert(KeyBinding::new(KeyCode::Enter), Action::BookmarkJump);
+        bookmarks.insert(KeyBinding::new(KeyCode::Char('d')), Action::BookmarkDelete);
+        bookmarks.insert(KeyBinding::new(KeyCode::Delete), Action::BookmarkDelete);
+        bookmarks.insert(KeyBinding::new(KeyCode::Esc), Action::ToggleBookmarks);
+        bookmarks.insert(
+            KeyBinding::new(KeyCode::Char('\'')),
+            Action::ToggleBookmarks,
+        );
+        bookmarks.insert(
+            KeyBinding::ctrl(KeyCode::Char('c')),
+            Action::ToggleBookmarks,
+        );
+        bindings.insert(KeyContext::Bookmarks, bookmarks);
+
+        // Pod panel bindings
+        let mut pod_panel = HashMap::new();
+        pod_panel.insert(KeyBinding::new(KeyCode::Up), Action::PodPanelUp);
+        pod_panel.insert(KeyBinding::new(KeyCode::Down), Action::PodPanelDown);
+        pod_panel.insert(KeyBinding::new(KeyCode::Char('k')), Action::PodPanelUp);
+        pod_panel.insert(KeyBinding::new(KeyCode::Char('j')), Action::PodPanelDown);
+        pod_panel.insert(
+            KeyBinding::new(KeyCode::Char(' ')),
+            Action::PodPanelToggleMute,
+        );
+        pod_panel.insert(KeyBinding::new(KeyCode::Enter), Action::PodPanelToggleMute);
+        pod_panel.insert(
+            KeyBinding::new(KeyCode::Char('a')),
+            Action::PodPanelStreamPod,
+        );
+        pod_panel.insert(
+            KeyBinding::shift(KeyCode::Char('D')),
+            Action::DeleteSelectedPod,
+        );
+        pod_panel.insert(KeyBinding::new(KeyCode::Esc), Action::TogglePodPanel);
+        pod_panel.insert(
+            KeyBinding::shift(KeyCode::Char('P')),
+            Action::TogglePodPanel,
+        );
+        pod_panel.insert(KeyBinding::ctrl(KeyCode::Char('c')), Action::TogglePodPanel);
+        bindings.insert(KeyContext::PodPanel, pod_panel);
+
+        // Columns overlay bindings
+        let mut columns = HashMap::new();
+        columns.insert(KeyBinding::new(KeyCode::Up), Action::ColumnUp);
+        columns.insert(KeyBinding::new(KeyCode::Down), Action::ColumnDown);
+        columns.insert(KeyBinding::new(KeyCode::Char('k')), Action::ColumnUp);
+        columns.insert(KeyBinding::new(KeyCode::Char('j')), Action::ColumnDown);
+        columns.insert(KeyBinding::shift(KeyCode::Char('K')), Action::ColumnMoveUp);
+        columns.insert(
+            KeyBinding::shift(KeyCode::Char('J')),
+            Action::ColumnMoveDown,
+        );
+        columns.insert(KeyBinding::new(KeyCode::Right), Action::ColumnWiden);
+        columns.insert(KeyBinding::new(KeyCode::Left), Action::ColumnNarrow);
+        columns.insert(KeyBinding::new(KeyCode::Char('+')), Action::ColumnWiden);
+        columns.insert(KeyBinding::shift(KeyCode::Char('+')), Action::ColumnWiden);
+        columns.insert(KeyBinding::new(KeyCode::Char('-')), Action::ColumnNarrow);
+        columns.insert(
+            KeyBinding::new(KeyCode::Char(' ')),
+            Action::ColumnToggleVisible,
+        );
+        columns.insert(KeyBinding::new(KeyCode::Enter), Action::ColumnToggleVisible);
+        columns.insert(KeyBinding::new(KeyCode::Esc), Action::ToggleColumns);
+        columns.insert(KeyBinding::shift(KeyCode::Char('C')), Action::ToggleColumns);
+        columns.insert(KeyBinding::ctrl(KeyCode::Char('c')), Action::ToggleColumns);
+        bindings.insert(KeyContext::Columns, columns);
+
         // JSON key filter bindings
         let mut json_keys = HashMap::new();
         json_keys.insert(KeyBinding::new(KeyCode::Up), Action::JsonKeyUp);
@@ -167,6 +415,10 @@ impl KeyBindings {
             KeyBinding::ctrl(KeyCode::Char('x')),
             Action::JsonKeyClearAll,
         );
+        json_keys.insert(
+            KeyBinding::ctrl(KeyCode::Char('e')),
+            Action::JsonKeyToggleExclude,
+        );
         json_keys.insert(KeyBinding::new(KeyCode::Esc), Action::ToggleJsonKeyFilter);
         json_keys.insert(
             KeyBinding::shift(KeyCode::Char('K')),
@@ -182,6 +434,107 @@ impl KeyBindings {
         );
         bindings.insert(KeyContext::JsonKeyFilter, json_keys);
 
+        // Schema drift report bindings
+        let mut schema_drift = HashMap::new();
+        schema_drift.insert(KeyBinding::new(KeyCode::Up), Action::SchemaDriftUp);
+        schema_drift.insert(KeyBinding::new(KeyCode::Down), Action::SchemaDriftDown);
+        schema_drift.insert(KeyBinding::new(KeyCode::Char('k')), Action::SchemaDriftUp);
+        schema_drift.insert(KeyBinding::new(KeyCode::Char('j')), Action::SchemaDriftDown);
+        schema_drift.insert(KeyBinding::new(KeyCode::Esc), Action::ToggleSchemaDrift);
+        schema_drift.insert(
+            KeyBinding::shift(KeyCode::Char('S')),
+            Action::ToggleSchemaDrift,
+        );
+        schema_drift.insert(
+            KeyBinding::ctrl(KeyCode::Char('c')),
+            Action::ToggleSchemaDrift,
+        );
+        bindings.insert(KeyContext::SchemaDrift, schema_drift);
+
+        // Parse diagnostics overlay bindings
+        let mut parse_diagnostics = HashMap::new();
+        parse_diagnostics.insert(KeyBinding::new(KeyCode::Up), Action::ParseDiagnosticsUp);
+        parse_diagnostics.insert(KeyBinding::new(KeyCode::Down), Action::ParseDiagnosticsDown);
+        parse_diagnostics.insert(
+            KeyBinding::new(KeyCode::Char('k')),
+            Action::ParseDiagnosticsUp,
+        );
+        parse_diagnostics.insert(
+            KeyBinding::new(KeyCode::Char('j')),
+            Action::ParseDiagnosticsDown,
+        );
+        parse_diagnostics.insert(
+            KeyBinding::new(KeyCode::Esc),
+            Action::ToggleParseDiagnostics,
+        );
+        parse_diagnostics.insert(
+            KeyBinding::shift(KeyCode::Char('U')),
+            Action::ToggleParseDiagnostics,
+        );
+        parse_diagnostics.insert(
+            KeyBinding::ctrl(KeyCode::Char('c')),
+            Action::ToggleParseDiagnostics,
+        );
+        bindings.insert(KeyContext::ParseDiagnostics, parse_diagnostics);
+
+        // Stream parameters overlay bindings
+        let mut stream_params = HashMap::new();
+        stream_params.insert(KeyBinding::new(KeyCode::Up), Action::StreamParamsUp);
+        stream_params.insert(KeyBinding::new(KeyCode::Down), Action::StreamParamsDown);
+        stream_params.insert(KeyBinding::new(KeyCode::Char('k')), Action::StreamParamsUp);
+        stream_params.insert(
+            KeyBinding::new(KeyCode::Char('j')),
+            Action::StreamParamsDown,
+        );
+        stream_params.insert(KeyBinding::new(KeyCode::Esc), Action::ToggleStreamParams);
+        stream_params.insert(KeyBinding::new(KeyCode::F(10)), Action::ToggleStreamParams);
+        stream_params.insert(
+            KeyBinding::ctrl(KeyCode::Char('c')),
+            Action::ToggleStreamParams,
+        );
+        bindings.insert(KeyContext::StreamParams, stream_params);
+
+        // Full line overlay bindings
+        let mut full_line = HashMap::new();
+        full_line.insert(KeyBinding::new(KeyCode::Down), Action::FullLineScroll(1));
+        full_line.insert(
+            KeyBinding::new(KeyCode::Char('j')),
+            Action::FullLineScroll(1),
+        );
+        full_line.insert(KeyBinding::new(KeyCode::Up), Action::FullLineScroll(-1));
+        full_line.insert(
+            KeyBinding::new(KeyCode::Char('k')),
+            Action::FullLineScroll(-1),
+        );
+        full_line.insert(
+            KeyBinding::new(KeyCode::PageDown),
+            Action::FullLineScroll(FULL_LINE_PAGE),
+        );
+        full_line.insert(
+            KeyBinding::ctrl(KeyCode::Char('f')),
+            Action::FullLineScroll(FULL_LINE_PAGE),
+        );
+        full_line.insert(
+            KeyBinding::new(KeyCode::PageUp),
+            Action::FullLineScroll(-FULL_LINE_PAGE),
+        );
+        full_line.insert(
+            KeyBinding::ctrl(KeyCode::Char('b')),
+            Action::FullLineScroll(-FULL_LINE_PAGE),
+        );
+        full_line.insert(
+            KeyBinding::new(KeyCode::Char('g')),
+            Action::FullLineScroll(isize::MIN),
+        );
+        full_line.insert(
+            KeyBinding::shift(KeyCode::Char('G')),
+            Action::FullLineScroll(isize::MAX),
+        );
+        full_line.insert(KeyBinding::new(KeyCode::Esc), Action::CloseFullLine);
+        full_line.insert(KeyBinding::new(KeyCode::Char('w')), Action::CloseFullLine);
+        full_line.insert(KeyBinding::ctrl(KeyCode::Char('c')), Action::CloseFullLine);
+        bindings.insert(KeyContext::FullLine, full_line);
+
         // Filter input bindings (when search bar is active)
         let mut filter_input = HashMap::new();
         filter_input.insert(KeyBinding::new(KeyCode::Enter), Action::ApplyFilter);
@@ -208,9 +561,169 @@ impl KeyBindings {
         palette.insert(KeyBinding::ctrl(KeyCode::Char('c')), Action::PaletteClose);
         bindings.insert(KeyContext::CommandPalette, palette);
 
+        // Confirmation modal bindings
+        let mut confirm = HashMap::new();
+        confirm.insert(KeyBinding::new(KeyCode::Char('y')), Action::ConfirmAccept);
+        confirm.insert(KeyBinding::shift(KeyCode::Char('Y')), Action::ConfirmAccept);
+        confirm.insert(KeyBinding::new(KeyCode::Enter), Action::ConfirmAccept);
+        confirm.insert(KeyBinding::new(KeyCode::Char('n')), Action::ConfirmCancel);
+        confirm.insert(KeyBinding::shift(KeyCode::Char('N')), Action::ConfirmCancel);
+        confirm.insert(KeyBinding::new(KeyCode::Esc), Action::ConfirmCancel);
+        confirm.insert(KeyBinding::ctrl(KeyCode::Char('c')), Action::ConfirmCancel);
+        bindings.insert(KeyContext::Confirm, confirm);
+
+        // Error modal bindings
+        let mut error = HashMap::new();
+        error.insert(KeyBinding::new(KeyCode::Esc), Action::DismissError);
+        error.insert(KeyBinding::new(KeyCode::Enter), Action::DismissError);
+        error.insert(KeyBinding::ctrl(KeyCode::Char('c')), Action::DismissError);
+        error.insert(KeyBinding::new(KeyCode::Char('r')), Action::RetryError);
+        bindings.insert(KeyContext::Error, error);
+
+        // Notification history overlay bindings
+        let mut notifications = HashMap::new();
+        notifications.insert(KeyBinding::new(KeyCode::Esc), Action::ToggleNotifications);
+        notifications.insert(
+            KeyBinding::shift(KeyCode::Char('H')),
+            Action::ToggleNotifications,
+        );
+        notifications.insert(
+            KeyBinding::ctrl(KeyCode::Char('c')),
+            Action::ToggleNotifications,
+        );
+        bindings.insert(KeyContext::Notifications, notifications);
+
+        // Help overlay bindings
+        let mut help = HashMap::new();
+        help.insert(KeyBinding::new(KeyCode::Up), Action::HelpUp);
+        help.insert(KeyBinding::new(KeyCode::Down), Action::HelpDown);
+        help.insert(KeyBinding::new(KeyCode::Char('k')), Action::HelpUp);
+        help.insert(KeyBinding::new(KeyCode::Char('j')), Action::HelpDown);
+        help.insert(KeyBinding::new(KeyCode::PageUp), Action::HelpPageUp);
+        help.insert(KeyBinding::new(KeyCode::PageDown), Action::HelpPageDown);
+        help.insert(KeyBinding::ctrl(KeyCode::Char('b')), Action::HelpPageUp);
+        help.insert(KeyBinding::ctrl(KeyCode::Char('f')), Action::HelpPageDown);
+        help.insert(KeyBinding::new(KeyCode::Esc), Action::ToggleHelp);
+        help.insert(KeyBinding::new(KeyCode::Char('?')), Action::ToggleHelp);
+        help.insert(KeyBinding::new(KeyCode::Char('q')), Action::ToggleHelp);
+        help.insert(KeyBinding::ctrl(KeyCode::Char('c')), Action::ToggleHelp);
+        bindings.insert(KeyContext::Help, help);
+
         Self { bindings }
     }
 
+    /// Labels of the keys bound to `action` in a context (plain keys first, no duplicates)
+    pub fn keys_for(&self, context: &KeyContext, action: &Action) -> Vec<String> {
+        let Some(context_bindings) = self.bindings.get(context) else {
+            return Vec::new();
+        };
+        let mut keys: Vec<&KeyBinding> = context_bindings
+            .iter()
+            .filter(|(_, bound)| *bound == action)
+            .map(|(key, _)| key)
+            .collect();
+        keys.sort_by_key(|key| {
+            let modified = key
+                .modifiers
+                .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT);
+            (modified, !matches!(key.code, KeyCode::Char(_)), key.label())
+        });
+
+        let mut labels: Vec<String> = Vec::new();
+        for key in keys {
+            let label = key.label();
+            if !labels.contains(&label) {
+                labels.push(label);
+            }
+        }
+        labels
+    }
+
+    /// Every action bound in a context
+    pub fn actions_in(&self, context: &KeyContext) -> Vec<&Action> {
+        self.bindings
+            .get(context)
+            .map(|b| b.values().collect())
+            .unwrap_or_default()
+    }
+
+    /// Handle key event while a confirmation modal is open
+    pub fn get_confirm_action(&self, key: &KeyEvent) -> Option<Action> {
+        let binding = KeyBinding::from_event(key);
+
+        self.bindings
+            .get(&KeyContext::Confirm)?
+            .get(&binding)
+            .cloned()
+    }
+
+    /// Handle key event while the error modal is open
+    pub fn get_error_action(&self, key: &KeyEvent) -> Option<Action> {
+        let binding = KeyBinding::from_event(key);
+
+        self.bindings
+            .get(&KeyContext::Error)?
+            .get(&binding)
+            .cloned()
+    }
+
+    /// Handle key event while the notification history is open
+    pub fn get_notifications_action(&self, key: &KeyEvent) -> Option<Action> {
+        let binding = KeyBinding::from_event(key);
+
+        self.bindings
+            .get(&KeyContext::Notifications)?
+            .get(&binding)
+            .cloned()
+    }
+
+    /// Handle key event while the schema drift report is open
+    pub fn get_schema_drift_action(&self, key: &KeyEvent) -> Option<Action> {
+        let binding = KeyBinding::from_event(key);
+
+        self.bindings
+            .get(&KeyContext::SchemaDrift)?
+            .get(&binding)
+            .cloned()
+    }
+
+    /// Handle key event while the parse diagnostics overlay is open
+    pub fn get_parse_diagnostics_action(&self, key: &KeyEvent) -> Option<Action> {
+        let binding = KeyBinding::from_event(key);
+
+        self.bindings
+            .get(&KeyContext::ParseDiagnostics)?
+            .get(&binding)
+            .cloned()
+    }
+
+    /// Handle key event while the stream parameters overlay is open
+    pub fn get_stream_params_action(&self, key: &KeyEvent) -> Option<Action> {
+        let binding = KeyBinding::from_event(key);
+
+        self.bindings
+            .get(&KeyContext::StreamParams)?
+            .get(&binding)
+            .cloned()
+    }
+
+    /// Handle key event while the full line overlay is open
+    pub fn get_full_line_action(&self, key: &KeyEvent) -> Option<Action> {
+        let binding = KeyBinding::from_event(key);
+
+        self.bindings
+            .get(&KeyContext::FullLine)?
+            .get(&binding)
+            .cloned()
+    }
+
+    /// Handle key event while the help overlay is open
+    pub fn get_help_action(&self, key: &KeyEvent) -> Option<Action> {
+        let binding = KeyBinding::from_event(key);
+
+        self.bindings.get(&KeyContext::Help)?.get(&binding).cloned()
+    }
+
     /// Look up action for key event in given context
     pub fn get_action(&self, context: KeyContext, key: &KeyEvent) -> Option<Action> {
         let binding = KeyBinding::from_event(key);
@@ -292,6 +805,36 @@ impl KeyBindings {
 
         None
     }
+
+    /// Handle key event while the pod panel has focus
+    pub fn get_pod_panel_action(&self, key: &KeyEvent) -> Option<Action> {
+        let binding = KeyBinding::from_event(key);
+
+        self.bindings
+            .get(&KeyContext::PodPanel)?
+            .get(&binding)
+            .cloned()
+    }
+
+    /// Handle key event in columns overlay mode
+    pub fn get_columns_action(&self, key: &KeyEvent) -> Option<Action> {
+        let binding = KeyBinding::from_event(key);
+
+        self.bindings
+            .get(&KeyContext::Columns)?
+            .get(&binding)
+            .cloned()
+    }
+
+    /// Handle key event in bookmarks overlay mode
+    pub fn get_bookmarks_action(&self, key: &KeyEvent) -> Option<Action> {
+        let binding = KeyBinding::from_event(key);
+
+        self.bindings
+            .get(&KeyContext::Bookmarks)?
+            .get(&binding)
+            .cloned()
+    }
 }
 
 impl Default for KeyBindings {
@@ -0,0 +1,147 @@
+//! Dashboard and trace URL templates
+//!
+//! Set from the `[links]` table in the config file. Templates use `{context}`,
+//! `{namespace}`, `{deployment}`, `{pod}` and `{trace_id}` placeholders, which
+//! are URL-encoded as they're filled in. Filled-in links are shown as OSC 8
+//! hyperlinks in terminals that support them.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::types::LogEntry;
+
+/// JSON keys a trace id is looked for under unless configured otherwise
+const DEFAULT_TRACE_KEYS: [&str; 4] = ["trace_id", "traceId", "trace.id", "traceID"];
+
+/// URL templates for the open deployment, a pod and a trace
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LinkTemplates {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deployment: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pod: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trace: Option<String>,
+    /// Top-level JSON keys holding a line's trace id, first match wins
+    pub trace_keys: Vec<String>,
+}
+
+impl Default for LinkTemplates {
+    fn default() -> Self {
+        Self {
+            deployment: None,
+            pod: None,
+            trace: None,
+            trace_keys: DEFAULT_TRACE_KEYS.iter().map(|k| k.to_string()).collect(),
+        }
+    }
+}
+
+/// What the placeholders are filled in with
+#[derive(Clone, Copy, Debug, Default)]
+pub struct LinkTarget<'a> {
+    pub context: &'a str,
+    pub namespace: &'a str,
+    pub deployment: &'a str,
+    pub pod: &'a str,
+    pub trace_id: &'a str,
+}
+
+impl LinkTemplates {
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    pub fn deployment_url(&self, target: &LinkTarget) -> Option<String> {
+        Some(fill(self.deployment.as_deref()?, target))
+    }
+
+    pub fn pod_url(&self, target: &LinkTarget) -> Option<String> {
+        Some(fill(self.pod.as_deref()?, target))
+    }
+
+    pub fn trace_url(&self, target: &LinkTarget) -> Option<String> {
+        Some(fill(self.trace.as_deref()?, target))
+    }
+
+    /// The trace id of a JSON line, if it carries one
+    pub fn trace_id(&self, entry: &LogEntry) -> Option<String> {
+        let fields = entry.fields.as_ref()?;
+        self.trace_keys
+            .iter()
+            .find_map(|key| match fields.get(key)? {
+                Value::String(id) if !id.is_empty() => Some(id.clone()),
+                Value::Number(id) => Some(id.to_string()),
+                _ => None,
+            })
+    }
+}
+
+/// Replace each placeholder with its URL-encoded value
+fn fill(template: &str, target: &LinkTarget) -> String {
+    [
+        ("{context}", target.context),
+        ("{namespace}", target.namespace),
+        ("{deployment}", target.deployment),
+        ("{pod}", target.pod),
+        ("{trace_id}", target.trace_id),
+    ]
+    .iter()
+    .fold(template.to_string(), |url, (placeholder, value)| {
+        url.replace(placeholder, &encode(value))
+    })
+}
+
+/// Percent-encode everything but RFC 3986 unreserved characters
+fn encode(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+            out.push(byte as char);
+        } else {
+            out.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logs::LogParser;
+
+    #[test]
+    fn test_templates_fill_encoded_placeholders() {
+        let links = LinkTemplates {
+            pod: Some("https://grafana/d/pods?var-ns={namespace}&var-pod={pod}".into()),
+            trace: Some("https://tempo/trace/{trace_id}".into()),
+            ..LinkTemplates::default()
+        };
+        let target = LinkTarget {
+            namespace: "payments",
+            pod: "api-7f9c",
+            trace_id: "a b/c",
+            ..LinkTarget::default()
+        };
+        assert_eq!(
+            links.pod_url(&target).as_deref(),
+            Some("https://grafana/d/pods?var-ns=payments&var-pod=api-7f9c")
+        );
+        assert_eq!(
+            links.trace_url(&target).as_deref(),
+            Some("https://tempo/trace/a%20b%2Fc")
+        );
+        assert_eq!(links.deployment_url(&target), None);
+    }
+
+    #[test]
+    fn test_trace_id_from_json_fields() {
+        let links = LinkTemplates::default();
+        let entry = LogParser::parse(r#"{"msg":"hi","traceId":"4bf92f35"}"#, "api-1", 1);
+        assert_eq!(links.trace_id(&entry).as_deref(), Some("4bf92f35"));
+
+        let plain = LogParser::parse("trace_id=4bf92f35", "api-1", 2);
+        assert_eq!(links.trace_id(&plain), None);
+    }
+}
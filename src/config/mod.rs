@@ -1,5 +1,9 @@
 //! Configuration and keybindings
 
+mod columns;
 mod keybindings;
+mod links;
 
+pub use columns::{Column, ColumnLayout, ColumnSpec, Density};
 pub use keybindings::{KeyBindings, KeyContext};
+pub use links::{LinkTarget, LinkTemplates};
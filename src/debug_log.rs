@@ -0,0 +1,91 @@
+//! Tracing output for kubescope itself
+//!
+//! Anything written to stderr draws over the TUI, so events are dropped unless
+//! `--log-file` names a file for them. The command palette can raise the level
+//! to debug while the app runs (useful when reporting streaming bugs).
+
+use std::fs::{self, OpenOptions};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, OnceLock};
+
+use anyhow::{Context, Result};
+use tracing_subscriber::fmt::writer::BoxMakeWriter;
+use tracing_subscriber::prelude::*;
+use tracing_subscriber::{EnvFilter, Registry, reload};
+
+/// Reload handle and destination, set once by `init`
+struct DebugLog {
+    filter: reload::Handle<EnvFilter, Registry>,
+    path: Option<PathBuf>,
+    debug: AtomicBool,
+}
+
+static DEBUG_LOG: OnceLock<DebugLog> = OnceLock::new();
+
+/// Where `--log-file` writes when given without a path
+pub fn default_path() -> Option<PathBuf> {
+    crate::session::kubescope_path("debug.log")
+}
+
+/// `RUST_LOG`, defaulting everything else to warnings
+fn base_filter() -> EnvFilter {
+    EnvFilter::from_default_env().add_directive(tracing::Level::WARN.into())
+}
+
+/// Install the tracing subscriber, appending to `path` if there is one
+pub fn init(path: Option<&Path>) -> Result<()> {
+    let writer = match path {
+        Some(path) => {
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            let file = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(path)
+                .with_context(|| format!("Failed to open log file {}", path.display()))?;
+            BoxMakeWriter::new(Mutex::new(file))
+        }
+        None => BoxMakeWriter::new(std::io::sink),
+    };
+
+    let (filter, handle) = reload::Layer::new(base_filter());
+    tracing_subscriber::registry()
+        .with(filter)
+        .with(
+            tracing_subscriber::fmt::layer()
+                .with_writer(writer)
+                .with_ansi(false),
+        )
+        .init();
+
+    let _ = DEBUG_LOG.set(DebugLog {
+        filter: handle,
+        path: path.map(Path::to_path_buf),
+        debug: AtomicBool::new(false),
+    });
+    Ok(())
+}
+
+/// Switch kubescope's own events between debug and the base level
+///
+/// Returns whether debug is now on and the file it goes to. Only available
+/// with `--log-file`, since otherwise nothing is written anywhere.
+pub fn toggle_debug() -> Result<(bool, PathBuf)> {
+    let log = DEBUG_LOG.get().context("Logging is not initialized")?;
+    let path = log
+        .path
+        .clone()
+        .context("Start kubescope with --log-file to capture debug logs")?;
+
+    let debug = !log.debug.load(Ordering::Relaxed);
+    let filter = if debug {
+        base_filter().add_directive("kubescope=debug".parse()?)
+    } else {
+        base_filter()
+    };
+    log.filter.reload(filter)?;
+    log.debug.store(debug, Ordering::Relaxed);
+    Ok((debug, path))
+}
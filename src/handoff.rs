@@ -0,0 +1,140 @@
+//! Handing a log line or the view over to `$EDITOR` / `$PAGER`
+//!
+//! The content goes to a file in the temp directory, which is kept so GUI
+//! editors that return straight away can still open it. The TUI steps aside
+//! while the program runs and redraws when it exits.
+
+use std::fs::{self, File, OpenOptions};
+use std::io;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result, bail};
+use tokio::process::Command;
+
+use crate::shell::shell_command;
+use crate::types::LogEntry;
+
+/// Which program gets the file
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Viewer {
+    /// `$VISUAL`, then `$EDITOR`, then vi (notepad on Windows)
+    Editor,
+    /// `$PAGER`, then less (more on Windows)
+    Pager,
+}
+
+impl Viewer {
+    fn command(self) -> String {
+        let (vars, fallback): (&[&str], _) = match self {
+            Viewer::Editor => (
+                &["VISUAL", "EDITOR"],
+                if cfg!(windows) { "notepad" } else { "vi" },
+            ),
+            Viewer::Pager => (&["PAGER"], if cfg!(windows) { "more" } else { "less" }),
+        };
+        vars.iter()
+            .filter_map(|var| std::env::var(var).ok())
+            .find(|command| !command.trim().is_empty())
+            .unwrap_or_else(|| fallback.to_string())
+    }
+}
+
+/// A line as it's handed over: pretty JSON for JSON lines, as logged otherwise
+///
+/// Returns the text and the file extension to give editors a syntax hint.
+pub fn entry_text(entry: &LogEntry) -> (String, &'static str) {
+    if entry.is_json
+        && let Ok(value) = serde_json::from_str::<serde_json::Value>(entry.display_text())
+        && let Ok(pretty) = serde_json::to_string_pretty(&value)
+    {
+        return (pretty + "\n", "json");
+    }
+    if let Some(fields) = &entry.fields
+        && let Ok(pretty) = serde_json::to_string_pretty(fields)
+    {
+        // Fields a plugin parsed out of a plain line
+        return (format!("{}\n\n{}\n", entry.display_text(), pretty), "log");
+    }
+    (format!("{}\n", entry.display_text()), "log")
+}
+
+/// Create the handoff file `name` (one per name and process) to write to
+///
+/// The temp directory is shared, so the file is made fresh rather than
+/// opened: a previous copy is removed first, and creating it fails instead of
+/// writing through a file or link someone else left at the path. On Unix only
+/// the user can read it.
+pub fn create_temp(name: &str) -> io::Result<(PathBuf, File)> {
+    let path = std::env::temp_dir().join(format!("kubescope-{}-{}", std::process::id(), name));
+    match fs::remove_file(&path) {
+        Err(e) if e.kind() != io::ErrorKind::NotFound => return Err(e),
+        _ => {}
+    }
+    let mut options = OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    let file = options.open(&path)?;
+    Ok((path, file))
+}
+
+/// Run the viewer on `path` and wait for it to exit
+///
+/// The command is run through the shell, so `EDITOR="code -w"` works.
+pub async fn open(viewer: Viewer, path: &Path) -> Result<()> {
+    let command = format!("{} {}", viewer.command(), quote(&path.to_string_lossy()));
+    let status = shell_command::<Command>(&command)
+        .status()
+        .await
+        .with_context(|| format!("Failed to run `{}`", command))?;
+    if !status.success() {
+        bail!("`{}` exited with {}", command, status);
+    }
+    Ok(())
+}
+
+/// Quote a path for the shell
+fn quote(path: &str) -> String {
+    if cfg!(windows) {
+        format!("\"{}\"", path)
+    } else {
+        format!("'{}'", path.replace('\'', r"'\''"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logs::LogParser;
+
+    #[test]
+    fn test_entry_text_pretty_prints_json() {
+        let entry = LogParser::parse(r#"{"level":"error","msg":"boom"}"#, "api-1", 1);
+        let (text, extension) = entry_text(&entry);
+        assert_eq!(extension, "json");
+        assert_eq!(text, "{\n  \"level\": \"error\",\n  \"msg\": \"boom\"\n}\n");
+
+        let entry = LogParser::parse("ERROR plain line", "api-1", 2);
+        assert_eq!(
+            entry_text(&entry),
+            ("ERROR plain line\n".to_string(), "log")
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_create_temp_replaces_a_private_file() {
+        use std::io::Write;
+        use std::os::unix::fs::PermissionsExt;
+
+        let (path, mut file) = create_temp("test-handoff.log").unwrap();
+        file.write_all(b"first").unwrap();
+        let (again, mut file) = create_temp("test-handoff.log").unwrap();
+        file.write_all(b"second").unwrap();
+        assert_eq!(path, again);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "second");
+        let mode = fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,274 @@
+//! Shell command hooks fired on events
+//!
+//! Set with `[[hooks]]` tables in the config file. Each hook runs its command
+//! through the shell when its event happens, with a JSON description of the
+//! event on stdin, so integrations (chat webhooks, paging) live in scripts
+//! rather than in kubescope.
+
+use std::process::Stdio;
+use std::time::{Duration, Instant};
+
+use anyhow::{Result, bail};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value, json};
+use tokio::io::AsyncWriteExt;
+use tokio::process::Command;
+
+use crate::shell::shell_command;
+
+/// Seconds between `line_matched` firings of one hook unless configured
+const DEFAULT_MATCH_COOLDOWN: u64 = 60;
+
+/// What a hook fires on
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HookEvent {
+    /// Log streams started for a deployment
+    StreamStarted,
+    /// A streamed line matched the hook's pattern
+    LineMatched,
+    /// A streamed pod's restart count went up
+    PodRestarted,
+}
+
+impl HookEvent {
+    fn name(self) -> &'static str {
+        match self {
+            HookEvent::StreamStarted => "stream_started",
+            HookEvent::LineMatched => "line_matched",
+            HookEvent::PodRestarted => "pod_restarted",
+        }
+    }
+}
+
+/// A hook as written in the config file
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HookSpec {
+    pub event: HookEvent,
+    /// Shell command, run with `sh -c` (`cmd /C` on Windows)
+    pub command: String,
+    /// Regex a line has to match, for `line_matched`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pattern: Option<String>,
+    /// Minimum seconds between firings
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cooldown: Option<u64>,
+}
+
+struct Hook {
+    spec: HookSpec,
+    pattern: Option<Regex>,
+    cooldown: Duration,
+    last_fired: Option<Instant>,
+}
+
+impl Hook {
+    /// Whether the cooldown has passed, counting this as a firing if so
+    fn due(&mut self, now: Instant) -> bool {
+        if self
+            .last_fired
+            .is_some_and(|last| now.duration_since(last) < self.cooldown)
+        {
+            return false;
+        }
+        self.last_fired = Some(now);
+        true
+    }
+}
+
+/// The configured hooks, ready to fire
+#[derive(Default)]
+pub struct Hooks {
+    hooks: Vec<Hook>,
+}
+
+impl Hooks {
+    /// Compile the configured hooks, rejecting invalid patterns
+    pub fn new(specs: &[HookSpec]) -> Result<Self> {
+        let mut hooks = Vec::with_capacity(specs.len());
+        for spec in specs {
+            let pattern = match (spec.event, &spec.pattern) {
+                (HookEvent::LineMatched, None) => {
+                    bail!("line_matched hook `{}` needs a pattern", spec.command)
+                }
+                (HookEvent::LineMatched, Some(pattern)) => {
+                    Some(Regex::new(pattern).map_err(|e| {
+                        anyhow::anyhow!("Invalid hook pattern {:?}: {}", pattern, e)
+                    })?)
+                }
+                (_, Some(_)) => bail!(
+                    "{} hook `{}` can't have a pattern, only line_matched hooks do",
+                    spec.event.name(),
+                    spec.command
+                ),
+                (_, None) => None,
+            };
+            let cooldown = spec.cooldown.unwrap_or(match spec.event {
+                HookEvent::LineMatched => DEFAULT_MATCH_COOLDOWN,
+                _ => 0,
+            });
+            hooks.push(Hook {
+                spec: spec.clone(),
+                pattern,
+                cooldown: Duration::from_secs(cooldown),
+                last_fired: None,
+            });
+        }
+        Ok(Self { hooks })
+    }
+
+    /// Whether any hook fires on `event`
+    pub fn wants(&self, event: HookEvent) -> bool {
+        self.hooks.iter().any(|h| h.spec.event == event)
+    }
+
+    /// Run the hooks for `event` with `payload` (plus an `event` field) on stdin
+    ///
+    /// Hooks still cooling down are skipped.
+    pub fn fire(&mut self, event: HookEvent, payload: Map<String, Value>) {
+        let commands = self.take_due(event, "", Instant::now());
+        spawn(commands, event, payload);
+    }
+
+    /// Run the `line_matched` hooks whose pattern matches `line`
+    ///
+    /// `payload` is only built when one of them is due.
+    pub fn fire_line(&mut self, line: &str, payload: impl FnOnce() -> Map<String, Value>) {
+        let commands = self.take_due(HookEvent::LineMatched, line, Instant::now());
+        if !commands.is_empty() {
+            spawn(commands, HookEvent::LineMatched, payload());
+        }
+    }
+
+    /// Commands of the hooks that fire for an event, marking them fired
+    fn take_due(&mut self, event: HookEvent, line: &str, now: Instant) -> Vec<String> {
+        self.hooks
+            .iter_mut()
+            .filter(|h| h.spec.event == event)
+            .filter(|h| h.pattern.as_ref().is_none_or(|p| p.is_match(line)))
+            .filter_map(|h| h.due(now).then(|| h.spec.command.clone()))
+            .collect()
+    }
+}
+
+/// Start each command in the background with the event's payload
+fn spawn(commands: Vec<String>, event: HookEvent, mut payload: Map<String, Value>) {
+    if commands.is_empty() {
+        return;
+    }
+    payload.insert("event".into(), json!(event.name()));
+    let input = Value::Object(payload).to_string();
+    for command in commands {
+        tokio::spawn(run(command, input.clone()));
+    }
+}
+
+/// Run a hook command with `input` on stdin, logging failures
+async fn run(command: String, input: String) {
+    let child = shell_command::<Command>(&command)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn();
+    let mut child = match child {
+        Ok(child) => child,
+        Err(e) => {
+            tracing::warn!(%command, error = %e, "failed to run hook");
+            return;
+        }
+    };
+    if let Some(mut stdin) = child.stdin.take() {
+        // A hook that doesn't read its input closes the pipe early; that's fine
+        let _ = stdin.write_all(input.as_bytes()).await;
+    }
+    match child.wait_with_output().await {
+        Ok(output) if output.status.success() => {
+            tracing::debug!(%command, "hook ran");
+        }
+        Ok(output) => tracing::warn!(
+            %command,
+            status = %output.status,
+            stderr = %String::from_utf8_lossy(&output.stderr).trim_end(),
+            "hook failed"
+        ),
+        Err(e) => tracing::warn!(%command, error = %e, "failed to wait for hook"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spec(event: HookEvent, command: &str, pattern: Option<&str>) -> HookSpec {
+        HookSpec {
+            event,
+            command: command.into(),
+            pattern: pattern.map(str::to_string),
+            cooldown: None,
+        }
+    }
+
+    #[test]
+    fn test_hooks_config_is_validated() {
+        assert!(Hooks::new(&[spec(HookEvent::LineMatched, "notify", None)]).is_err());
+        assert!(Hooks::new(&[spec(HookEvent::LineMatched, "notify", Some("("))]).is_err());
+        assert!(Hooks::new(&[spec(HookEvent::StreamStarted, "notify", Some("x"))]).is_err());
+
+        let hooks: toml::Value = toml::from_str(
+            r#"
+            [[hooks]]
+            event = "pod_restarted"
+            command = "./page.sh"
+            "#,
+        )
+        .unwrap();
+        let specs: Vec<HookSpec> = hooks["hooks"].clone().try_into().unwrap();
+        let hooks = Hooks::new(&specs).unwrap();
+        assert!(hooks.wants(HookEvent::PodRestarted));
+        assert!(!hooks.wants(HookEvent::StreamStarted));
+    }
+
+    #[test]
+    fn test_line_hooks_match_and_cool_down() {
+        let mut hooks = Hooks::new(&[
+            spec(HookEvent::LineMatched, "panic", Some("panic")),
+            spec(HookEvent::LineMatched, "oom", Some("OOMKilled")),
+            spec(HookEvent::StreamStarted, "started", None),
+        ])
+        .unwrap();
+        let now = Instant::now();
+        assert_eq!(
+            hooks.take_due(HookEvent::LineMatched, "thread panicked", now),
+            vec!["panic"]
+        );
+        assert!(
+            hooks
+                .take_due(HookEvent::LineMatched, "all good", now)
+                .is_empty()
+        );
+
+        // Cooling down for a minute
+        let later = now + Duration::from_secs(30);
+        assert!(
+            hooks
+                .take_due(HookEvent::LineMatched, "panic again", later)
+                .is_empty()
+        );
+        let later = now + Duration::from_secs(DEFAULT_MATCH_COOLDOWN);
+        assert_eq!(
+            hooks.take_due(HookEvent::LineMatched, "panic again", later),
+            vec!["panic"]
+        );
+
+        // Other events don't cool down by default
+        assert_eq!(
+            hooks.take_due(HookEvent::StreamStarted, "", now),
+            vec!["started"]
+        );
+        assert_eq!(
+            hooks.take_due(HookEvent::StreamStarted, "", now),
+            vec!["started"]
+        );
+    }
+}
@@ -0,0 +1,184 @@
+//! Minimal read-only HTTP server for the metrics and log sharing endpoints
+//!
+//! Handles `GET` requests one per connection (`Connection: close`), which is
+//! all curl and a Prometheus scraper need, without pulling in a web stack.
+
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use anyhow::{Context, Result};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+/// The parts of a request the handlers look at
+pub struct Request {
+    pub path: String,
+    /// Decoded query parameters, in order (keys may repeat)
+    pub query: Vec<(String, String)>,
+}
+
+impl Request {
+    /// Parse the request line of `GET /path?query HTTP/1.1`
+    fn parse(head: &str) -> Option<Self> {
+        let target = head.lines().next()?.split_whitespace().nth(1)?;
+        let (path, query) = target.split_once('?').unwrap_or((target, ""));
+        let query = query
+            .split('&')
+            .filter(|pair| !pair.is_empty())
+            .map(|pair| {
+                let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+                (decode(key), decode(value))
+            })
+            .collect();
+        Some(Self {
+            path: decode(path),
+            query,
+        })
+    }
+
+    /// First value of a query parameter
+    pub fn param(&self, key: &str) -> Option<&str> {
+        self.query
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Every value of a repeated query parameter
+    pub fn params<'a>(&'a self, key: &'a str) -> impl Iterator<Item = &'a str> {
+        self.query
+            .iter()
+            .filter(move |(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+pub struct Response {
+    status: &'static str,
+    content_type: &'static str,
+    body: String,
+}
+
+impl Response {
+    pub fn ok(content_type: &'static str, body: String) -> Self {
+        Self {
+            status: "200 OK",
+            content_type,
+            body,
+        }
+    }
+
+    pub fn bad_request(message: impl Into<String>) -> Self {
+        Self {
+            status: "400 Bad Request",
+            content_type: "text/plain; charset=utf-8",
+            body: message.into() + "\n",
+        }
+    }
+
+    pub fn not_found() -> Self {
+        Self {
+            status: "404 Not Found",
+            content_type: "text/plain; charset=utf-8",
+            body: "Not found\n".to_string(),
+        }
+    }
+}
+
+/// Bind `addr`, then answer requests with `handler` in the background
+pub async fn serve<F>(addr: SocketAddr, handler: F) -> Result<()>
+where
+    F: Fn(&Request) -> Response + Send + Sync + 'static,
+{
+    let listener = TcpListener::bind(addr)
+        .await
+        .with_context(|| format!("Failed to listen on {}", addr))?;
+    tracing::info!(%addr, "serving http");
+    let handler = Arc::new(handler);
+    tokio::spawn(async move {
+        loop {
+            match listener.accept().await {
+                Ok((stream, _)) => {
+                    let handler = Arc::clone(&handler);
+                    tokio::spawn(async move {
+                        if let Err(e) = respond(stream, handler.as_ref()).await {
+                            tracing::debug!(error = %e, "http request failed");
+                        }
+                    });
+                }
+                Err(e) => tracing::warn!(error = %e, "failed to accept http connection"),
+            }
+        }
+    });
+    Ok(())
+}
+
+/// Answer one request and close the connection
+async fn respond<F>(mut stream: TcpStream, handler: &F) -> std::io::Result<()>
+where
+    F: Fn(&Request) -> Response,
+{
+    // The request line is all that's needed; it fits in the first read
+    let mut head = [0u8; 4096];
+    let read = stream.read(&mut head).await?;
+    let head = String::from_utf8_lossy(&head[..read]);
+    let response = match Request::parse(&head) {
+        Some(request) if head.starts_with("GET ") => handler(&request),
+        Some(_) => Response::bad_request("Only GET is supported"),
+        None => Response::bad_request("Malformed request"),
+    };
+    let header = format!(
+        "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        response.status,
+        response.content_type,
+        response.body.len()
+    );
+    stream.write_all(header.as_bytes()).await?;
+    stream.write_all(response.body.as_bytes()).await?;
+    stream.shutdown().await
+}
+
+/// Percent-decode a URL component (`+` is a space in queries)
+fn decode(input: &str) -> String {
+    let bytes = input.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'+' => out.push(b' '),
+            b'%' if i + 2 < bytes.len() => {
+                let hex = std::str::from_utf8(&bytes[i + 1..i + 3]).ok();
+                match hex.and_then(|hex| u8::from_str_radix(hex, 16).ok()) {
+                    Some(byte) => {
+                        out.push(byte);
+                        i += 2;
+                    }
+                    None => out.push(b'%'),
+                }
+            }
+            byte => out.push(byte),
+        }
+        i += 1;
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_request_line_is_parsed_and_decoded() {
+        let request =
+            Request::parse("GET /logs?q=timeout+error&pod=api-1&pod=api%2D2&view HTTP/1.1\r\n")
+                .unwrap();
+        assert_eq!(request.path, "/logs");
+        assert_eq!(request.param("q"), Some("timeout error"));
+        assert_eq!(
+            request.params("pod").collect::<Vec<_>>(),
+            ["api-1", "api-2"]
+        );
+        assert_eq!(request.param("view"), Some(""));
+        assert_eq!(decode("100%"), "100%");
+    }
+}
@@ -1,15 +1,117 @@
 //! Kubernetes client for kubescope
 
 use anyhow::{Context, Result};
-use k8s_openapi::api::apps::v1::Deployment;
+use k8s_openapi::api::apps::v1::{Deployment, ReplicaSet};
 use k8s_openapi::api::core::v1::{Namespace, Pod};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
 use kube::Api;
-use kube::api::ListParams;
+use kube::api::{DeleteParams, ListParams, Patch, PatchParams};
 use kube::config::{AuthInfo, KubeConfigOptions, Kubeconfig, NamedAuthInfo};
+use std::collections::HashMap;
 
 use crate::token_cache;
 use crate::types::{ContainerInfo, ContextInfo, DeploymentInfo, NamespaceInfo, PodInfo, PodStatus};
 
+/// Annotation the deployment controller numbers each ReplicaSet's revision with
+const REVISION_ANNOTATION: &str = "deployment.kubernetes.io/revision";
+
+/// What went wrong talking to the cluster, for errors worth explaining
+///
+/// Anything not listed here is shown as the raw error chain.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ApiError {
+    /// Credentials were rejected (401) or the auth plugin couldn't get a token
+    AuthExpired,
+    /// Authenticated, but RBAC doesn't allow the request (403)
+    Forbidden,
+    /// The resource is gone, or the namespace doesn't exist (404)
+    NotFound,
+    /// The API server or the connection to it timed out
+    Timeout,
+    /// Nothing is listening at the API server address
+    ConnectionRefused,
+}
+
+impl ApiError {
+    /// Classify an error by walking its chain for the kube or io error that caused it
+    pub fn classify(err: &anyhow::Error) -> Option<Self> {
+        err.chain().find_map(|cause| {
+            let mut source: Option<&(dyn std::error::Error + 'static)> = Some(cause);
+            while let Some(e) = source {
+                if let Some(kind) = Self::from_error(e) {
+                    return Some(kind);
+                }
+                source = e.source();
+            }
+            None
+        })
+    }
+
+    /// Classify a single error, without looking at its sources
+    pub fn from_error(err: &(dyn std::error::Error + 'static)) -> Option<Self> {
+        if let Some(err) = err.downcast_ref::<kube::Error>() {
+            return match err {
+                kube::Error::Api(response) => match response.code {
+                    401 => Some(ApiError::AuthExpired),
+                    403 => Some(ApiError::Forbidden),
+                    404 => Some(ApiError::NotFound),
+                    408 | 504 => Some(ApiError::Timeout),
+                    _ => None,
+                },
+                kube::Error::Auth(_) => Some(ApiError::AuthExpired),
+                _ => None,
+            };
+        }
+        if let Some(err) = err.downcast_ref::<std::io::Error>() {
+            return match err.kind() {
+                std::io::ErrorKind::ConnectionRefused => Some(ApiError::ConnectionRefused),
+                std::io::ErrorKind::TimedOut => Some(ApiError::Timeout),
+                _ => None,
+            };
+        }
+        // Tower's timeout layer only says so in its message
+        err.to_string()
+            .contains("timed out")
+            .then_some(ApiError::Timeout)
+    }
+
+    /// Short heading for the error modal
+    pub fn title(&self) -> &'static str {
+        match self {
+            ApiError::AuthExpired => "Credentials expired",
+            ApiError::Forbidden => "Access denied",
+            ApiError::NotFound => "Not found",
+            ApiError::Timeout => "Timed out",
+            ApiError::ConnectionRefused => "Cluster unreachable",
+        }
+    }
+
+    /// What to do about it
+    pub fn guidance(&self) -> &'static str {
+        match self {
+            ApiError::AuthExpired => {
+                "Your token has expired or was rejected. Log in again (e.g. aws sso login, \
+                 gcloud auth login), then retry."
+            }
+            ApiError::Forbidden => {
+                "Your user isn't allowed to do this. Check the RBAC roles bound to it \
+                 (kubectl auth can-i)."
+            }
+            ApiError::NotFound => {
+                "It may have been deleted or renamed since the list was loaded. \
+                 Go back and refresh."
+            }
+            ApiError::Timeout => {
+                "The API server didn't answer in time. Check your VPN or network, then retry."
+            }
+            ApiError::ConnectionRefused => {
+                "Nothing answered at the cluster's address. Check the cluster is up and \
+                 your VPN or port-forward is connected."
+            }
+        }
+    }
+}
+
 /// Kubernetes client wrapper
 pub struct KubeClient {
     kubeconfig: Kubeconfig,
@@ -115,6 +217,19 @@ impl KubeClient {
         Ok(client)
     }
 
+    /// Build a new client for a context whose credentials were rejected
+    ///
+    /// Any cached token is dropped first so the exec plugin is asked for a
+    /// new one, which is then cached again.
+    pub async fn refresh_client(&self, context_name: &str) -> Result<kube::Client> {
+        if let Some(cluster_name) =
+            token_cache::extract_eks_cluster_name(&self.kubeconfig, context_name)
+        {
+            token_cache::clear_token(&cluster_name);
+        }
+        self.client_for_context(context_name).await
+    }
+
     /// Cache a fresh token for an EKS cluster after successful auth
     async fn cache_fresh_token(&self, context_name: &str) {
         // Only cache for EKS clusters
@@ -136,18 +251,14 @@ impl KubeClient {
             ])
             .output()
             .await
+            && output.status.success()
+            && let Ok(response) = serde_json::from_slice::<serde_json::Value>(&output.stdout)
+            && let Some(token) = response
+                .get("status")
+                .and_then(|s| s.get("token"))
+                .and_then(|t| t.as_str())
         {
-            if output.status.success() {
-                if let Ok(response) = serde_json::from_slice::<serde_json::Value>(&output.stdout) {
-                    if let Some(token) = response
-                        .get("status")
-                        .and_then(|s| s.get("token"))
-                        .and_then(|t| t.as_str())
-                    {
-                        token_cache::cache_token(&cluster_name, token);
-                    }
-                }
-            }
+            token_cache::cache_token(&cluster_name, token);
         }
     }
 
@@ -242,7 +353,12 @@ impl KubeClient {
                     .status
                     .and_then(|s| s.phase)
                     .unwrap_or_else(|| "Unknown".to_string());
-                NamespaceInfo::new(name, status)
+                let mut info = NamespaceInfo::new(name, status);
+                if let Some(labels) = ns.metadata.labels {
+                    info.labels = labels.into_iter().collect();
+                }
+                info.created_at = ns.metadata.creation_timestamp.map(|t| t.0);
+                info
             })
             .collect())
     }
@@ -282,6 +398,66 @@ impl KubeClient {
         Ok(Self::deployment_to_info(deploy, namespace))
     }
 
+    /// Set a deployment's replica count through its scale subresource
+    pub async fn scale_deployment(
+        &self,
+        client: &kube::Client,
+        namespace: &str,
+        name: &str,
+        replicas: i32,
+    ) -> Result<()> {
+        let deployments: Api<Deployment> = Api::namespaced(client.clone(), namespace);
+        let patch = serde_json::json!({ "spec": { "replicas": replicas } });
+        deployments
+            .patch_scale(name, &PatchParams::default(), &Patch::Merge(&patch))
+            .await
+            .context(format!(
+                "Failed to scale deployment '{}' in namespace '{}'",
+                name, namespace
+            ))?;
+        Ok(())
+    }
+
+    /// Trigger a rolling restart the way `kubectl rollout restart` does
+    pub async fn restart_deployment(
+        &self,
+        client: &kube::Client,
+        namespace: &str,
+        name: &str,
+    ) -> Result<()> {
+        let deployments: Api<Deployment> = Api::namespaced(client.clone(), namespace);
+        let patch = serde_json::json!({
+            "spec": { "template": { "metadata": { "annotations": {
+                "kubectl.kubernetes.io/restartedAt": chrono::Utc::now().to_rfc3339()
+            } } } }
+        });
+        deployments
+            .patch(name, &PatchParams::default(), &Patch::Merge(&patch))
+            .await
+            .context(format!(
+                "Failed to restart deployment '{}' in namespace '{}'",
+                name, namespace
+            ))?;
+        Ok(())
+    }
+
+    /// Delete a pod (its ReplicaSet starts a replacement)
+    pub async fn delete_pod(
+        &self,
+        client: &kube::Client,
+        namespace: &str,
+        name: &str,
+    ) -> Result<()> {
+        let pods: Api<Pod> = Api::namespaced(client.clone(), namespace);
+        pods.delete(name, &DeleteParams::default())
+            .await
+            .context(format!(
+                "Failed to delete pod '{}' in namespace '{}'",
+                name, namespace
+            ))?;
+        Ok(())
+    }
+
     /// Convert a k8s Deployment to DeploymentInfo
     fn deployment_to_info(deploy: Deployment, namespace: &str) -> DeploymentInfo {
         let name = deploy.metadata.name.unwrap_or_default();
@@ -305,6 +481,8 @@ impl KubeClient {
             info.labels = labels.into_iter().collect();
         }
 
+        info.created_at = deploy.metadata.creation_timestamp.map(|t| t.0);
+
         info
     }
 
@@ -317,28 +495,28 @@ impl KubeClient {
     ) -> Result<Vec<PodInfo>> {
         let pods: Api<Pod> = Api::namespaced(client.clone(), namespace);
 
-        // Build label selector from deployment's selector
-        let label_selector = deployment
-            .selector
-            .iter()
-            .map(|(k, v)| format!("{}={}", k, v))
-            .collect::<Vec<_>>()
-            .join(",");
+        let label_selector = format_selector(&deployment.selector);
 
-        let list = pods
-            .list(&ListParams::default().labels(&label_selector))
-            .await
-            .context(format!(
-                "Failed to list pods for deployment {}",
-                deployment.name
-            ))?;
+        let params = ListParams::default().labels(&label_selector);
+        let (list, current_replica_set) = tokio::join!(
+            pods.list(&params),
+            Self::current_replica_set(client, namespace, deployment, &params)
+        );
+        let list = list.context(format!(
+            "Failed to list pods for deployment {}",
+            deployment.name
+        ))?;
 
-        Ok(list
+        let mut pods: Vec<PodInfo> = list
             .items
             .into_iter()
             .map(|pod| {
-                let name = pod.metadata.name.unwrap_or_default();
-                let mut info = PodInfo::new(name, namespace.to_string());
+                let mut info = PodInfo::new(
+                    pod.metadata.name.clone().unwrap_or_default(),
+                    namespace.to_string(),
+                );
+                info.created_at = pod.metadata.creation_timestamp.clone().map(|t| t.0);
+                info.replica_set = owner_name(&pod.metadata, "ReplicaSet").map(str::to_string);
 
                 if let Some(spec) = &pod.spec {
                     info.node_name = spec.node_name.clone();
@@ -360,6 +538,11 @@ impl KubeClient {
                                 let mut container = ContainerInfo::new(cs.name);
                                 container.ready = cs.ready;
                                 container.restart_count = cs.restart_count;
+                                container.started_at = cs
+                                    .state
+                                    .and_then(|s| s.running)
+                                    .and_then(|r| r.started_at)
+                                    .map(|t| t.0);
                                 container
                             })
                             .collect();
@@ -368,6 +551,270 @@ impl KubeClient {
 
                 info
             })
-            .collect())
+            .collect();
+        group_by_replica_set(&mut pods, current_replica_set.as_deref());
+        Ok(pods)
+    }
+
+    /// The deployment's newest ReplicaSet (the highest revision)
+    ///
+    /// None if ReplicaSets can't be listed, in which case no pod counts as old.
+    async fn current_replica_set(
+        client: &kube::Client,
+        namespace: &str,
+        deployment: &DeploymentInfo,
+        params: &ListParams,
+    ) -> Option<String> {
+        let replica_sets: Api<ReplicaSet> = Api::namespaced(client.clone(), namespace);
+        let list = replica_sets.list(params).await.ok()?;
+        newest_replica_set(list.items, &deployment.name)?
+            .metadata
+            .name
+    }
+
+    /// The likely reason a deployment's selector matched no pods
+    ///
+    /// Checks the replica count, the current ReplicaSet, and pods that match
+    /// only part of the selector, falling back to naming the selector.
+    pub async fn diagnose_no_pods(
+        &self,
+        client: &kube::Client,
+        namespace: &str,
+        deployment: &DeploymentInfo,
+    ) -> String {
+        if deployment.replicas == 0 {
+            return "the deployment is scaled to zero replicas".to_string();
+        }
+
+        let selector = format_selector(&deployment.selector);
+        let replica_sets: Api<ReplicaSet> = Api::namespaced(client.clone(), namespace);
+        if let Ok(list) = replica_sets
+            .list(&ListParams::default().labels(&selector))
+            .await
+        {
+            match newest_replica_set(list.items, &deployment.name) {
+                None => {
+                    return "the deployment controller hasn't created a ReplicaSet for it yet"
+                        .to_string();
+                }
+                Some(rs) => {
+                    let name = rs.metadata.name.unwrap_or_default();
+                    let desired = rs.spec.and_then(|s| s.replicas).unwrap_or(0);
+                    let created = rs.status.map(|s| s.replicas).unwrap_or(0);
+                    if desired == 0 {
+                        return format!("its current ReplicaSet {} is scaled to zero", name);
+                    }
+                    if created == 0 {
+                        return format!(
+                            "ReplicaSet {} wants {} pods but has created none (check quota, \
+                             admission webhooks and `kubectl describe rs {}`)",
+                            name, desired, name
+                        );
+                    }
+                }
+            }
+        }
+
+        // Pods relabeled by hand, or a selector edited out from under them
+        let pods: Api<Pod> = Api::namespaced(client.clone(), namespace);
+        if let Ok(list) = pods.list(&ListParams::default()).await {
+            let labeled: Vec<(String, HashMap<String, String>)> = list
+                .items
+                .into_iter()
+                .map(|pod| {
+                    let labels = pod.metadata.labels.unwrap_or_default();
+                    (
+                        pod.metadata.name.unwrap_or_default(),
+                        labels.into_iter().collect(),
+                    )
+                })
+                .collect();
+            if let Some(reason) = partial_selector_match(&deployment.selector, &labeled) {
+                return reason;
+            }
+        }
+
+        format!("no pods in {} have the labels {}", namespace, selector)
+    }
+}
+
+/// A matchLabels selector in `kubectl -l` form, sorted so messages are stable
+fn format_selector(selector: &HashMap<String, String>) -> String {
+    let mut terms: Vec<String> = selector
+        .iter()
+        .map(|(k, v)| format!("{}={}", k, v))
+        .collect();
+    terms.sort();
+    terms.join(",")
+}
+
+/// The highest-revision ReplicaSet owned by the named deployment
+fn newest_replica_set(replica_sets: Vec<ReplicaSet>, deployment: &str) -> Option<ReplicaSet> {
+    replica_sets
+        .into_iter()
+        .filter(|rs| owner_name(&rs.metadata, "Deployment") == Some(deployment))
+        .filter_map(|rs| {
+            let revision = rs
+                .metadata
+                .annotations
+                .as_ref()?
+                .get(REVISION_ANNOTATION)?
+                .parse::<u64>()
+                .ok()?;
+            Some((revision, rs))
+        })
+        .max_by_key(|(revision, _)| *revision)
+        .map(|(_, rs)| rs)
+}
+
+/// Describe the pods that carry some of the selector's labels but not all
+fn partial_selector_match(
+    selector: &HashMap<String, String>,
+    pods: &[(String, HashMap<String, String>)],
+) -> Option<String> {
+    let matching = |labels: &HashMap<String, String>| {
+        selector
+            .iter()
+            .filter(|(k, v)| labels.get(*k) == Some(*v))
+            .count()
+    };
+    let partial: Vec<&(String, HashMap<String, String>)> = pods
+        .iter()
+        .filter(|(_, labels)| (1..selector.len()).contains(&matching(labels)))
+        .collect();
+    let (example, labels) = partial.first()?;
+    let mut missing: Vec<String> = selector
+        .iter()
+        .filter(|(k, v)| labels.get(*k) != Some(*v))
+        .map(|(k, v)| match labels.get(k) {
+            Some(actual) => format!("{}={} (has {})", k, v, actual),
+            None => format!("{}={}", k, v),
+        })
+        .collect();
+    missing.sort();
+    Some(format!(
+        "{} {} part of the selector but not {} (e.g. {}); were the labels changed?",
+        partial.len(),
+        if partial.len() == 1 {
+            "pod matches"
+        } else {
+            "pods match"
+        },
+        missing.join(", "),
+        example
+    ))
+}
+
+/// Name of the object's owner of the given kind
+fn owner_name<'a>(meta: &'a ObjectMeta, kind: &str) -> Option<&'a str> {
+    meta.owner_references
+        .as_ref()?
+        .iter()
+        .find(|owner| owner.kind == kind)
+        .map(|owner| owner.name.as_str())
+}
+
+/// Mark pods left over from previous ReplicaSets and order the list by
+/// ReplicaSet: the current one first, then the old ones, each by pod name
+fn group_by_replica_set(pods: &mut [PodInfo], current: Option<&str>) {
+    for pod in pods.iter_mut() {
+        pod.old_replica_set = current.is_some() && pod.replica_set.as_deref() != current;
+    }
+    pods.sort_by(|a, b| {
+        a.old_replica_set
+            .cmp(&b.old_replica_set)
+            .then_with(|| a.replica_set.cmp(&b.replica_set))
+            .then_with(|| a.name.cmp(&b.name))
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kube::core::ErrorResponse;
+
+    fn api_error(code: u16) -> anyhow::Error {
+        anyhow::Error::new(kube::Error::Api(ErrorResponse {
+            status: "Failure".into(),
+            message: "nope".into(),
+            reason: String::new(),
+            code,
+        }))
+        .context("Failed to list pods")
+    }
+
+    #[test]
+    fn test_classify_walks_the_error_chain() {
+        assert_eq!(
+            ApiError::classify(&api_error(401)),
+            Some(ApiError::AuthExpired)
+        );
+        assert_eq!(
+            ApiError::classify(&api_error(403)),
+            Some(ApiError::Forbidden)
+        );
+        assert_eq!(
+            ApiError::classify(&api_error(404)),
+            Some(ApiError::NotFound)
+        );
+        assert_eq!(ApiError::classify(&api_error(500)), None);
+
+        let refused =
+            anyhow::Error::new(std::io::Error::from(std::io::ErrorKind::ConnectionRefused))
+                .context("Failed to connect");
+        assert_eq!(
+            ApiError::classify(&refused),
+            Some(ApiError::ConnectionRefused)
+        );
+        assert_eq!(ApiError::classify(&anyhow::anyhow!("bad config")), None);
+    }
+
+    #[test]
+    fn test_pods_grouped_by_replica_set() {
+        let pod = |name: &str, rs: &str| {
+            let mut pod = PodInfo::new(name.into(), "default".into());
+            pod.replica_set = Some(rs.into());
+            pod
+        };
+        let mut pods = vec![
+            pod("api-old-b", "api-old"),
+            pod("api-new-z", "api-new"),
+            pod("api-old-a", "api-old"),
+            pod("api-new-y", "api-new"),
+        ];
+        group_by_replica_set(&mut pods, Some("api-new"));
+        let names: Vec<&str> = pods.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, ["api-new-y", "api-new-z", "api-old-a", "api-old-b"]);
+        assert!(!pods[1].old_replica_set && pods[2].old_replica_set);
+
+        // Without the ReplicaSet list nothing is marked old
+        group_by_replica_set(&mut pods, None);
+        assert!(pods.iter().all(|p| !p.old_replica_set));
+    }
+
+    #[test]
+    fn test_partial_selector_match_names_missing_labels() {
+        let labels = |pairs: &[(&str, &str)]| -> HashMap<String, String> {
+            pairs
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect()
+        };
+        let selector = labels(&[("app", "checkout"), ("tier", "api")]);
+        let pods = vec![
+            ("other-1".to_string(), labels(&[("app", "search")])),
+            (
+                "checkout-1".to_string(),
+                labels(&[("app", "checkout"), ("tier", "web")]),
+            ),
+        ];
+        assert_eq!(
+            partial_selector_match(&selector, &pods).as_deref(),
+            Some(
+                "1 pod matches part of the selector but not tier=api (has web) (e.g. checkout-1); \
+                 were the labels changed?"
+            )
+        );
+        assert_eq!(partial_selector_match(&selector, &pods[..1]), None);
     }
 }
@@ -1,9 +1,13 @@
-use std::collections::{BTreeSet, VecDeque};
+use chrono::{DateTime, Utc};
+use std::collections::{BTreeSet, HashMap, VecDeque};
 use std::sync::Arc;
-use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
 
+use dashmap::DashMap;
 use parking_lot::{Mutex, RwLock};
 
+use super::diagnostics::{ParseDiagnostics, ParseProblem, ProblemReport};
+use super::schema::{KeySchema, SchemaDrift, SchemaTracker};
 use crate::types::{ArcLogEntry, LogEntry, LogLevel};
 
 /// Lock-free atomic counters for each log level
@@ -66,6 +70,68 @@ impl AtomicLevelCounts {
     }
 }
 
+/// Counters for lines that didn't make it into the buffer intact
+#[derive(Default)]
+pub struct IngestStats {
+    /// Entries dropped because the channel was full (backpressure)
+    dropped: AtomicU64,
+    /// Entries cut short at the maximum line length
+    truncated: AtomicU64,
+    /// Entries that looked like JSON but failed to parse
+    parse_failures: AtomicU64,
+    /// Entries with invalid UTF-8 or control characters replaced
+    sanitized: AtomicU64,
+}
+
+impl IngestStats {
+    /// Record an entry dropped before reaching the buffer
+    pub fn record_dropped(&self) {
+        self.dropped.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Entries dropped before reaching the buffer so far
+    pub fn dropped_count(&self) -> u64 {
+        self.dropped.load(Ordering::Relaxed)
+    }
+
+    fn record(&self, entry: &LogEntry) {
+        if entry.truncated {
+            self.truncated.fetch_add(1, Ordering::Relaxed);
+        }
+        if entry.parse_failed {
+            self.parse_failures.fetch_add(1, Ordering::Relaxed);
+        }
+        if entry.sanitized {
+            self.sanitized.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    fn to_counts(&self) -> IngestCounts {
+        IngestCounts {
+            dropped: self.dropped.load(Ordering::Relaxed),
+            truncated: self.truncated.load(Ordering::Relaxed),
+            parse_failures: self.parse_failures.load(Ordering::Relaxed),
+            sanitized: self.sanitized.load(Ordering::Relaxed),
+        }
+    }
+
+    fn reset(&self) {
+        self.dropped.store(0, Ordering::Relaxed);
+        self.truncated.store(0, Ordering::Relaxed);
+        self.parse_failures.store(0, Ordering::Relaxed);
+        self.sanitized.store(0, Ordering::Relaxed);
+    }
+}
+
+/// Snapshot of ingest counters
+#[derive(Clone, Copy, Debug, Default)]
+pub struct IngestCounts {
+    pub dropped: u64,
+    pub truncated: u64,
+    pub parse_failures: u64,
+    pub sanitized: u64,
+}
+
 /// Thread-safe ring buffer for log entries
 #[derive(Clone)]
 #[allow(dead_code)]
@@ -73,8 +139,11 @@ pub struct LogBuffer {
     /// Internal storage - uses Arc<LogEntry> to avoid expensive clones during rendering
     entries: Arc<RwLock<VecDeque<ArcLogEntry>>>,
 
-    /// Maximum capacity
-    capacity: usize,
+    /// Maximum capacity (shared so it can be adjusted at runtime)
+    capacity: Arc<AtomicUsize>,
+
+    /// Approximate memory held by stored entries, in bytes
+    approx_bytes: Arc<AtomicUsize>,
 
     /// Next entry ID
     next_id: Arc<AtomicUsize>,
@@ -90,6 +159,18 @@ pub struct LogBuffer {
 
     /// Incrementally maintained set of JSON keys from logs
     json_keys: Arc<RwLock<BTreeSet<String>>>,
+
+    /// Dropped/truncated/unparsed line accounting (shared with stream tasks)
+    ingest: Arc<IngestStats>,
+
+    /// Lines received per pod since the last clear
+    pod_counts: Arc<DashMap<String, AtomicU64>>,
+
+    /// Per-key JSON types and drift seen in live lines
+    schema: Arc<Mutex<SchemaTracker>>,
+
+    /// Lines that failed to parse or had no level, with samples
+    diagnostics: Arc<Mutex<ParseDiagnostics>>,
 }
 
 /// Batch size for flushing pending entries
@@ -101,12 +182,17 @@ impl LogBuffer {
     pub fn new(capacity: usize) -> Self {
         Self {
             entries: Arc::new(RwLock::new(VecDeque::with_capacity(capacity))),
-            capacity,
+            capacity: Arc::new(AtomicUsize::new(capacity)),
+            approx_bytes: Arc::new(AtomicUsize::new(0)),
             next_id: Arc::new(AtomicUsize::new(0)),
             total_count: Arc::new(AtomicUsize::new(0)),
             level_counts: Arc::new(AtomicLevelCounts::default()),
             pending: Arc::new(Mutex::new(Vec::with_capacity(BATCH_FLUSH_SIZE))),
             json_keys: Arc::new(RwLock::new(BTreeSet::new())),
+            ingest: Arc::new(IngestStats::default()),
+            pod_counts: Arc::new(DashMap::new()),
+            schema: Arc::new(Mutex::new(SchemaTracker::default())),
+            diagnostics: Arc::new(Mutex::new(ParseDiagnostics::default())),
         }
     }
 
@@ -116,23 +202,11 @@ impl LogBuffer {
         // Increment atomic counter first (lock-free, used by len())
         self.total_count.fetch_add(1, Ordering::Relaxed);
 
-        // Track JSON keys incrementally (fast path - only new keys need write lock)
-        if let Some(fields) = &entry.fields {
-            let keys_read = self.json_keys.read();
-            let new_keys: Vec<_> = fields
-                .keys()
-                .filter(|k| !keys_read.contains(*k))
-                .cloned()
-                .collect();
-            drop(keys_read);
-
-            if !new_keys.is_empty() {
-                let mut keys_write = self.json_keys.write();
-                for key in new_keys {
-                    keys_write.insert(key);
-                }
-            }
-        }
+        self.ingest.record(&entry);
+        self.count_pod_line(&entry);
+        self.track_json_keys(&entry);
+        self.schema.lock().observe(&entry);
+        self.diagnose(&entry);
 
         // Add to staging buffer
         let mut pending = self.pending.lock();
@@ -144,23 +218,231 @@ impl LogBuffer {
         }
     }
 
+    /// Push entries received together, taking the staging lock once
+    pub fn push_batch(&self, batch: impl IntoIterator<Item = LogEntry>) {
+        let mut pending = self.pending.lock();
+        // Scrollback goes through prepend, so these are all live lines in order
+        let mut schema = self.schema.lock();
+        for entry in batch {
+            self.total_count.fetch_add(1, Ordering::Relaxed);
+            self.ingest.record(&entry);
+            self.count_pod_line(&entry);
+            self.track_json_keys(&entry);
+            schema.observe(&entry);
+            self.diagnose(&entry);
+            pending.push(entry);
+        }
+        drop(schema);
+
+        if pending.len() >= BATCH_FLUSH_SIZE {
+            self.flush_pending_locked(&mut pending);
+        }
+    }
+
+    /// Count a line for its stream (fast path - existing streams only need a shared lock)
+    ///
+    /// Sidecar lines count under `pod/container`, so a pod's count is its app container's.
+    fn count_pod_line(&self, entry: &LogEntry) {
+        let key = entry.source.stream_key();
+        if let Some(count) = self.pod_counts.get(key.as_ref()) {
+            count.fetch_add(1, Ordering::Relaxed);
+            return;
+        }
+        self.pod_counts
+            .entry(key.into_owned())
+            .or_insert_with(|| AtomicU64::new(0))
+            .fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Lines received for every pod since the last clear
+    pub fn pod_line_counts(&self) -> HashMap<String, u64> {
+        self.pod_counts
+            .iter()
+            .map(|c| (c.key().clone(), c.value().load(Ordering::Relaxed)))
+            .collect()
+    }
+
+    /// Lines received for a pod since the last clear
+    pub fn pod_line_count(&self, pod_name: &str) -> u64 {
+        self.pod_counts
+            .get(pod_name)
+            .map(|c| c.load(Ordering::Relaxed))
+            .unwrap_or(0)
+    }
+
+    /// Note a line that failed to parse or had no level (others skip the lock)
+    fn diagnose(&self, entry: &LogEntry) {
+        if ParseProblem::of(entry).is_some() {
+            self.diagnostics.lock().observe(entry);
+        }
+    }
+
+    /// Track JSON keys incrementally (fast path - only new keys need write lock)
+    fn track_json_keys(&self, entry: &LogEntry) {
+        let Some(fields) = &entry.fields else {
+            return;
+        };
+
+        let keys_read = self.json_keys.read();
+        let new_keys: Vec<_> = fields
+            .keys()
+            .filter(|k| !keys_read.contains(*k))
+            .cloned()
+            .collect();
+        drop(keys_read);
+
+        if !new_keys.is_empty() {
+            let mut keys_write = self.json_keys.write();
+            for key in new_keys {
+                keys_write.insert(key);
+            }
+        }
+    }
+
+    /// Remove the oldest entry and update counters (caller holds entries lock)
+    fn evict_front(&self, entries: &mut VecDeque<ArcLogEntry>) {
+        if let Some(evicted) = entries.pop_front() {
+            // Decrement level count for evicted entry
+            self.level_counts.decrement(evicted.level);
+            self.total_count.fetch_sub(1, Ordering::Relaxed);
+            self.approx_bytes
+                .fetch_sub(entry_bytes(&evicted), Ordering::Relaxed);
+        }
+    }
+
+    /// Shared ingest counters (stream tasks record drops here)
+    pub fn ingest_stats(&self) -> Arc<IngestStats> {
+        Arc::clone(&self.ingest)
+    }
+
+    /// Lines that failed to parse or had no level, per problem
+    pub fn parse_report(&self) -> Vec<ProblemReport> {
+        self.diagnostics.lock().reports()
+    }
+
+    /// Snapshot of dropped/truncated/unparsed counters
+    pub fn ingest_counts(&self) -> IngestCounts {
+        self.ingest.to_counts()
+    }
+
+    /// Number of entries dropped due to backpressure
+    pub fn dropped_count(&self) -> u64 {
+        self.ingest.dropped.load(Ordering::Relaxed)
+    }
+
+    /// Current maximum capacity
+    pub fn capacity(&self) -> usize {
+        self.capacity.load(Ordering::Relaxed)
+    }
+
+    /// Change the maximum capacity, evicting the oldest entries if shrinking
+    pub fn set_capacity(&self, capacity: usize) {
+        let capacity = capacity.max(1);
+        self.flush();
+
+        let mut entries = self.entries.write();
+        self.capacity.store(capacity, Ordering::Relaxed);
+        while entries.len() > capacity {
+            self.evict_front(&mut entries);
+        }
+        entries.shrink_to(capacity);
+    }
+
+    /// Approximate memory held by stored entries, in bytes
+    pub fn approx_bytes(&self) -> usize {
+        self.approx_bytes.load(Ordering::Relaxed)
+    }
+
+    /// Insert older entries (sorted oldest first) at the front of the buffer
+    ///
+    /// Never evicts newer entries: when the buffer is close to capacity only the
+    /// newest of the older entries that still fit are kept. Returns how many were added.
+    pub fn prepend(&self, older: Vec<LogEntry>) -> usize {
+        self.flush();
+
+        let mut entries = self.entries.write();
+        let room = self.capacity().saturating_sub(entries.len());
+        let skip = older.len().saturating_sub(room);
+        let mut added = 0;
+
+        for mut entry in older.into_iter().skip(skip).rev() {
+            self.ingest.record(&entry);
+            self.count_pod_line(&entry);
+            self.track_json_keys(&entry);
+            self.diagnose(&entry);
+            entry.id = self.next_id.fetch_add(1, Ordering::Relaxed) as u64;
+            self.level_counts.increment(entry.level);
+            self.total_count.fetch_add(1, Ordering::Relaxed);
+            self.approx_bytes
+                .fetch_add(entry_bytes(&entry), Ordering::Relaxed);
+            entries.push_front(Arc::new(entry));
+            added += 1;
+        }
+
+        added
+    }
+
+    /// Timestamp and line number of the oldest timestamped line per stream
+    /// (used as the cutoff and numbering origin for scrollback fetches)
+    ///
+    /// Keyed by `LogSource::stream_key`, so sidecar lines don't move their
+    /// pod's app container cutoff.
+    pub fn oldest_lines(&self) -> HashMap<String, (DateTime<Utc>, i64)> {
+        self.flush();
+
+        let mut oldest: HashMap<String, (DateTime<Utc>, i64)> = HashMap::new();
+        for entry in self.entries.read().iter() {
+            if let Some(ts) = entry.timestamp {
+                oldest
+                    .entry(entry.source.stream_key().into_owned())
+                    .and_modify(|o| {
+                        if ts < o.0 {
+                            *o = (ts, entry.line_number);
+                        }
+                    })
+                    .or_insert((ts, entry.line_number));
+            }
+        }
+        oldest
+    }
+
+    /// Timestamp and line number of the newest timestamped line per stream
+    /// (used to resume stopped streams where they left off), keyed like `oldest_lines`
+    pub fn newest_lines(&self) -> HashMap<String, (DateTime<Utc>, i64)> {
+        self.flush();
+
+        let mut newest: HashMap<String, (DateTime<Utc>, i64)> = HashMap::new();
+        for entry in self.entries.read().iter() {
+            if let Some(ts) = entry.timestamp {
+                newest
+                    .entry(entry.source.stream_key().into_owned())
+                    .and_modify(|n| {
+                        if ts >= n.0 {
+                            *n = (ts, entry.line_number);
+                        }
+                    })
+                    .or_insert((ts, entry.line_number));
+            }
+        }
+        newest
+    }
+
     /// Flush pending entries to main buffer (internal, caller holds pending lock)
     fn flush_pending_locked(&self, pending: &mut Vec<LogEntry>) {
         if pending.is_empty() {
             return;
         }
 
+        let capacity = self.capacity();
         let mut entries = self.entries.write();
         for mut entry in pending.drain(..) {
             entry.id = self.next_id.fetch_add(1, Ordering::Relaxed) as u64;
             // Increment level count for new entry
             self.level_counts.increment(entry.level);
-            if entries.len() >= self.capacity {
-                // Decrement level count for evicted entry
-                if let Some(evicted) = entries.pop_front() {
-                    self.level_counts.decrement(evicted.level);
-                    self.total_count.fetch_sub(1, Ordering::Relaxed);
-                }
+            self.approx_bytes
+                .fetch_add(entry_bytes(&entry), Ordering::Relaxed);
+            if entries.len() >= capacity {
+                self.evict_front(&mut entries);
             }
             entries.push_back(Arc::new(entry));
         }
@@ -177,6 +459,58 @@ impl LogBuffer {
         self.json_keys.read().iter().cloned().collect()
     }
 
+    /// How often each JSON key appears in the newest `sample` JSON lines, with
+    /// the most recent value seen for it
+    pub fn json_key_stats(&self, sample: usize) -> HashMap<String, JsonKeyStat> {
+        self.flush();
+        let entries = self.entries.read();
+        let sampled: Vec<&ArcLogEntry> = entries
+            .iter()
+            .rev()
+            .filter(|e| e.fields.is_some())
+            .take(sample)
+            .collect();
+
+        let mut stats: HashMap<String, JsonKeyStat> = HashMap::new();
+        for entry in &sampled {
+            let Some(fields) = &entry.fields else {
+                continue;
+            };
+            for (key, value) in fields {
+                let stat = stats.entry(key.clone()).or_insert_with(|| JsonKeyStat {
+                    share: 0.0,
+                    sample: value_preview(value),
+                });
+                stat.share += 1.0;
+            }
+        }
+        for stat in stats.values_mut() {
+            stat.share /= sampled.len() as f32;
+        }
+        stats
+    }
+
+    /// Schema drift events recorded so far
+    pub fn schema_drift_count(&self) -> usize {
+        self.schema.lock().total()
+    }
+
+    /// Drift events (oldest first) and every JSON key's types, for the drift report
+    pub fn schema_report(&self) -> (Vec<SchemaDrift>, Vec<KeySchema>) {
+        let schema = self.schema.lock();
+        (schema.drifts().to_vec(), schema.key_schemas())
+    }
+
+    /// Drift events recorded after the first `seen`, oldest first
+    pub fn schema_drifts_since(&self, seen: usize) -> Vec<SchemaDrift> {
+        let schema = self.schema.lock();
+        let new = schema
+            .total()
+            .saturating_sub(seen)
+            .min(schema.drifts().len());
+        schema.drifts()[schema.drifts().len() - new..].to_vec()
+    }
+
     /// Get all entries (Arc clones are cheap - just reference count increment)
     /// Flushes pending entries first to ensure consistency
     pub fn all(&self) -> Vec<ArcLogEntry> {
@@ -197,10 +531,11 @@ impl LogBuffer {
             .collect()
     }
 
-    /// Get entries filtered by log level (minimum level)
+    /// Get entries filtered by log level (minimum level), pending ones included like `all`
     pub fn by_level(&self, min_level: LogLevel) -> Vec<ArcLogEntry> {
-        let min_ord = level_ordinal(min_level);
-        self.filtered(|e| level_ordinal(e.level) >= min_ord)
+        self.flush();
+        let min_ord = min_level.ordinal();
+        self.filtered(|e| e.level.ordinal() >= min_ord)
     }
 
     /// Get entry count per log level (O(1) lock-free via atomic counters)
@@ -238,7 +573,12 @@ impl LogBuffer {
         self.json_keys.write().clear();
         self.next_id.store(0, Ordering::SeqCst);
         self.total_count.store(0, Ordering::SeqCst);
+        self.approx_bytes.store(0, Ordering::SeqCst);
         self.level_counts.reset();
+        self.ingest.reset();
+        self.pod_counts.clear();
+        self.schema.lock().clear();
+        self.diagnostics.lock().clear();
     }
 
     /// Get the last N entries
@@ -273,16 +613,246 @@ impl LevelCounts {
     }
 }
 
-/// Get ordinal for log level comparison
-#[allow(dead_code)]
-fn level_ordinal(level: LogLevel) -> u8 {
-    match level {
-        LogLevel::Trace => 0,
-        LogLevel::Debug => 1,
-        LogLevel::Info => 2,
-        LogLevel::Warn => 3,
-        LogLevel::Error => 4,
-        LogLevel::Fatal => 5,
-        LogLevel::Unknown => 2, // Treat unknown as info level
+/// A JSON key's presence in a sample of recent lines
+#[derive(Clone, Debug, PartialEq)]
+pub struct JsonKeyStat {
+    /// Fraction of sampled JSON lines carrying the key (0.0 - 1.0)
+    pub share: f32,
+    /// Its value on the newest line that has it, on one line
+    pub sample: String,
+}
+
+/// Longest value preview kept for the key filter (in chars)
+const PREVIEW_CHARS: usize = 60;
+
+/// One-line preview of a JSON value (strings unquoted, containers compact)
+fn value_preview(value: &serde_json::Value) -> String {
+    let text = match value {
+        serde_json::Value::String(s) => s.clone(),
+        other => other.to_string(),
+    };
+    let text = text.replace(['\n', '\r'], " ");
+    if text.chars().count() > PREVIEW_CHARS {
+        let kept: String = text.chars().take(PREVIEW_CHARS).collect();
+        format!("{}…", kept)
+    } else {
+        text
+    }
+}
+
+/// Rough heap + inline size of an entry (strings only; parsed JSON fields estimated via raw,
+/// the source is shared across a pod's entries and not counted)
+fn entry_bytes(entry: &LogEntry) -> usize {
+    let fields = if entry.fields.is_some() {
+        entry.raw.len()
+    } else {
+        0
+    };
+    std::mem::size_of::<LogEntry>()
+        + entry.raw.len()
+        + entry.pretty_printed.as_ref().map_or(0, |p| p.len())
+        + entry.original.as_ref().map_or(0, |o| o.len())
+        + fields
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{LogSource, SourceKind};
+
+    fn entry(raw: &str) -> LogEntry {
+        LogEntry::new("pod".to_string(), 1, raw.to_string())
+    }
+
+    #[test]
+    fn test_prepend_inserts_before_existing() {
+        let buffer = LogBuffer::new(10);
+        buffer.push(entry("new"));
+        buffer.flush();
+
+        let added = buffer.prepend(vec![entry("old-1"), entry("old-2")]);
+        assert_eq!(added, 2);
+        assert_eq!(buffer.len(), 3);
+
+        let raws: Vec<_> = buffer.all().iter().map(|e| e.raw.clone()).collect();
+        assert_eq!(raws, vec!["old-1", "old-2", "new"]);
+    }
+
+    #[test]
+    fn test_ingest_counts() {
+        let buffer = LogBuffer::new(10);
+        let mut truncated = entry("cut");
+        truncated.truncated = true;
+        let mut failed = entry("{broken");
+        failed.parse_failed = true;
+
+        buffer.push(truncated);
+        buffer.push(failed);
+        buffer.push(entry("fine"));
+        buffer.ingest_stats().record_dropped();
+
+        let counts = buffer.ingest_counts();
+        assert_eq!(counts.truncated, 1);
+        assert_eq!(counts.parse_failures, 1);
+        assert_eq!(counts.dropped, 1);
+
+        buffer.clear();
+        assert_eq!(buffer.ingest_counts().dropped, 0);
+    }
+
+    #[test]
+    fn test_push_batch_matches_push() {
+        let buffer = LogBuffer::new(3);
+        buffer.push_batch((0..5).map(|i| entry(&format!("line-{}", i))));
+
+        assert_eq!(buffer.len(), 5);
+        assert_eq!(buffer.pod_line_count("pod"), 5);
+        // Capacity still applies to batched entries
+        let raws: Vec<_> = buffer.all().iter().map(|e| e.raw.clone()).collect();
+        assert_eq!(raws, vec!["line-2", "line-3", "line-4"]);
+    }
+
+    #[test]
+    fn test_json_key_stats_share_and_newest_sample() {
+        let buffer = LogBuffer::new(10);
+        for (raw, status) in [("a", Some(500)), ("b", None), ("c", Some(200))] {
+            let mut e = entry(raw);
+            let mut fields = HashMap::new();
+            fields.insert("msg".to_string(), serde_json::json!(raw));
+            if let Some(status) = status {
+                fields.insert("status".to_string(), serde_json::json!(status));
+            }
+            e.fields = Some(fields);
+            buffer.push(e);
+        }
+        buffer.push(entry("plain text is not sampled"));
+
+        let stats = buffer.json_key_stats(100);
+        assert_eq!(stats["msg"].share, 1.0);
+        assert!((stats["status"].share - 2.0 / 3.0).abs() < 1e-6);
+        assert_eq!(stats["status"].sample, "200");
+        assert_eq!(stats["msg"].sample, "c");
+
+        // Only the newest line is sampled
+        let stats = buffer.json_key_stats(1);
+        assert_eq!(stats["status"].share, 1.0);
+    }
+
+    #[test]
+    fn test_pod_line_counts() {
+        let buffer = LogBuffer::new(10);
+        buffer.push(entry("a"));
+        buffer.push(entry("b"));
+        let mut other = entry("c");
+        other.source = Arc::new(LogSource::pod("other"));
+        buffer.push(other);
+
+        assert_eq!(buffer.pod_line_count("pod"), 2);
+        assert_eq!(buffer.pod_line_count("other"), 1);
+        assert_eq!(buffer.pod_line_count("missing"), 0);
+
+        buffer.clear();
+        assert_eq!(buffer.pod_line_count("pod"), 0);
+    }
+
+    #[test]
+    fn test_oldest_and_newest_lines_per_pod() {
+        let buffer = LogBuffer::new(10);
+        let base = chrono::Utc::now();
+        for (pod, line, secs) in [("a", 5, 10), ("a", 6, 20), ("b", 1, 5), ("b", 2, 1)] {
+            let mut e = LogEntry::new(pod.to_string(), line, "x".to_string());
+            e.timestamp = Some(base + chrono::Duration::seconds(secs));
+            buffer.push(e);
+        }
+        buffer.push(LogEntry::new(
+            "c".to_string(),
+            1,
+            "no timestamp".to_string(),
+        ));
+
+        let oldest = buffer.oldest_lines();
+        assert_eq!(oldest["a"], (base + chrono::Duration::seconds(10), 5));
+        assert_eq!(oldest["b"], (base + chrono::Duration::seconds(1), 2));
+        assert!(!oldest.contains_key("c"));
+
+        let newest = buffer.newest_lines();
+        assert_eq!(newest["a"], (base + chrono::Duration::seconds(20), 6));
+        assert_eq!(newest["b"], (base + chrono::Duration::seconds(5), 1));
+        assert!(!newest.contains_key("c"));
+    }
+
+    #[test]
+    fn test_sidecar_lines_are_kept_apart_from_their_pod() {
+        let buffer = LogBuffer::new(10);
+        let base = chrono::Utc::now();
+        let sidecar = Arc::new(LogSource {
+            pod: "a".into(),
+            container: Some("istio-proxy".into()),
+            kind: SourceKind::Sidecar,
+            ..Default::default()
+        });
+        // App lines 1-2, with the proxy's lines around them
+        for (line, secs, proxy) in [(1, 10, true), (1, 11, false), (2, 12, false), (2, 13, true)] {
+            let mut e = LogEntry::new("a".to_string(), line, "x".to_string());
+            e.timestamp = Some(base + chrono::Duration::seconds(secs));
+            if proxy {
+                e.source = Arc::clone(&sidecar);
+            }
+            buffer.push(e);
+        }
+
+        assert_eq!(buffer.pod_line_count("a"), 2);
+        assert_eq!(buffer.pod_line_count("a/istio-proxy"), 2);
+        let oldest = buffer.oldest_lines();
+        assert_eq!(oldest["a"], (base + chrono::Duration::seconds(11), 1));
+        assert_eq!(
+            oldest["a/istio-proxy"],
+            (base + chrono::Duration::seconds(10), 1)
+        );
+        let newest = buffer.newest_lines();
+        assert_eq!(newest["a"], (base + chrono::Duration::seconds(12), 2));
+        assert_eq!(
+            newest["a/istio-proxy"],
+            (base + chrono::Duration::seconds(13), 2)
+        );
+    }
+
+    #[test]
+    fn test_set_capacity_evicts_oldest() {
+        let buffer = LogBuffer::new(10);
+        for i in 0..5 {
+            buffer.push(entry(&format!("line-{}", i)));
+        }
+        buffer.flush();
+        let bytes_before = buffer.approx_bytes();
+
+        buffer.set_capacity(2);
+        assert_eq!(buffer.capacity(), 2);
+        assert_eq!(buffer.len(), 2);
+        assert!(buffer.approx_bytes() < bytes_before);
+
+        let raws: Vec<_> = buffer.all().iter().map(|e| e.raw.clone()).collect();
+        assert_eq!(raws, vec!["line-3", "line-4"]);
+
+        // Growing keeps existing entries and accepts more
+        buffer.set_capacity(4);
+        buffer.push(entry("line-5"));
+        buffer.flush();
+        assert_eq!(buffer.len(), 3);
+    }
+
+    #[test]
+    fn test_prepend_never_evicts_newer_entries() {
+        let buffer = LogBuffer::new(3);
+        buffer.push(entry("a"));
+        buffer.push(entry("b"));
+        buffer.flush();
+
+        // Only one slot left: keep the newest of the older batch
+        let added = buffer.prepend(vec![entry("old-1"), entry("old-2")]);
+        assert_eq!(added, 1);
+
+        let raws: Vec<_> = buffer.all().iter().map(|e| e.raw.clone()).collect();
+        assert_eq!(raws, vec!["old-2", "a", "b"]);
     }
 }
@@ -0,0 +1,190 @@
+//! Parse diagnostics: lines kubescope couldn't make sense of
+//!
+//! Counts lines that looked like JSON but didn't parse, and lines with no
+//! detectable level (the ones shown as `???`), per pod and with a few recent
+//! samples, so the logging format can be fixed at the source.
+
+use std::collections::{BTreeMap, HashMap, VecDeque};
+
+use chrono::{DateTime, Utc};
+
+use crate::types::{LogEntry, LogLevel};
+
+/// Samples kept per problem; older ones are dropped
+const MAX_SAMPLES: usize = 5;
+
+/// Characters of a line kept in a sample
+const SAMPLE_CHARS: usize = 240;
+
+/// What was wrong with a line
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ParseProblem {
+    /// Started with `{` but wasn't valid JSON
+    BrokenJson,
+    /// No level field or level word, shown as `???`
+    NoLevel,
+}
+
+impl ParseProblem {
+    /// The problem a line has, if any
+    pub fn of(entry: &LogEntry) -> Option<Self> {
+        if entry.parse_failed {
+            Some(ParseProblem::BrokenJson)
+        } else if entry.level == LogLevel::Unknown {
+            Some(ParseProblem::NoLevel)
+        } else {
+            None
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ParseProblem::BrokenJson => "Invalid JSON",
+            ParseProblem::NoLevel => "No detectable level",
+        }
+    }
+}
+
+/// A line that had the problem, with what was wrong with it
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParseSample {
+    pub pod: String,
+    pub text: String,
+    /// The JSON error, or the keys a JSON line had instead of a level
+    pub detail: String,
+    pub at: DateTime<Utc>,
+}
+
+/// Everything seen for one problem, for the diagnostics overlay
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProblemReport {
+    pub problem: ParseProblem,
+    pub count: u64,
+    /// Lines per pod, most first
+    pub pods: Vec<(String, u64)>,
+    /// Newest last
+    pub samples: Vec<ParseSample>,
+}
+
+#[derive(Debug, Default)]
+struct ProblemState {
+    count: u64,
+    pods: HashMap<String, u64>,
+    samples: VecDeque<ParseSample>,
+}
+
+/// Problem lines seen since the buffer started (or was cleared)
+#[derive(Debug, Default)]
+pub struct ParseDiagnostics {
+    problems: BTreeMap<ParseProblem, ProblemState>,
+}
+
+impl ParseDiagnostics {
+    /// Count a line if it has a problem
+    pub fn observe(&mut self, entry: &LogEntry) {
+        let Some(problem) = ParseProblem::of(entry) else {
+            return;
+        };
+        let state = self.problems.entry(problem).or_default();
+        state.count += 1;
+        *state.pods.entry(entry.pod_name().to_string()).or_default() += 1;
+
+        if state.samples.len() >= MAX_SAMPLES {
+            state.samples.pop_front();
+        }
+        state.samples.push_back(ParseSample {
+            pod: entry.pod_name().to_string(),
+            text: entry.display_text().chars().take(SAMPLE_CHARS).collect(),
+            detail: detail(problem, entry),
+            at: entry.timestamp.unwrap_or_else(Utc::now),
+        });
+    }
+
+    /// A report per problem seen, invalid JSON first
+    pub fn reports(&self) -> Vec<ProblemReport> {
+        self.problems
+            .iter()
+            .map(|(problem, state)| {
+                let mut pods: Vec<(String, u64)> = state
+                    .pods
+                    .iter()
+                    .map(|(pod, count)| (pod.clone(), *count))
+                    .collect();
+                pods.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+                ProblemReport {
+                    problem: *problem,
+                    count: state.count,
+                    pods,
+                    samples: state.samples.iter().cloned().collect(),
+                }
+            })
+            .collect()
+    }
+
+    pub fn clear(&mut self) {
+        self.problems.clear();
+    }
+}
+
+/// Why the line had the problem, as far as can be told
+fn detail(problem: ParseProblem, entry: &LogEntry) -> String {
+    match problem {
+        ParseProblem::BrokenJson => {
+            match serde_json::from_str::<serde_json::Value>(entry.display_text()) {
+                Err(e) => e.to_string(),
+                Ok(_) => "not a JSON object".to_string(),
+            }
+        }
+        ParseProblem::NoLevel => match &entry.fields {
+            _ if entry.truncated => "cut at the line limit before a level".to_string(),
+            Some(fields) if fields.is_empty() => "JSON without keys".to_string(),
+            Some(fields) => {
+                let mut keys: Vec<&str> = fields.keys().map(String::as_str).collect();
+                keys.sort_unstable();
+                format!("no level key among: {}", keys.join(", "))
+            }
+            None => "plain text without a level word".to_string(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logs::LogParser;
+
+    #[test]
+    fn test_diagnostics_count_and_sample_problem_lines() {
+        let mut diagnostics = ParseDiagnostics::default();
+        for (pod, line) in [
+            ("api-1", r#"{"level":"info","msg":"ok"}"#),
+            ("api-1", r#"{"level":"info","msg":"cut"#),
+            ("api-2", r#"{"msg":"no level","user":"ada"}"#),
+            ("api-2", "just some words"),
+            ("api-2", "ERROR plain but leveled"),
+        ] {
+            diagnostics.observe(&LogParser::parse(line, pod, 1));
+        }
+        let reports = diagnostics.reports();
+        assert_eq!(reports.iter().map(|r| r.count).sum::<u64>(), 3);
+        assert_eq!(reports[0].problem, ParseProblem::BrokenJson);
+        assert_eq!(reports[0].count, 1);
+        assert_eq!(reports[0].pods, vec![("api-1".to_string(), 1)]);
+        assert!(reports[0].samples[0].detail.contains("EOF"));
+
+        assert_eq!(reports[1].problem, ParseProblem::NoLevel);
+        assert_eq!(reports[1].count, 2);
+        assert_eq!(
+            reports[1].samples[0].detail,
+            "no level key among: msg, user"
+        );
+
+        for i in 0..10 {
+            diagnostics.observe(&LogParser::parse(&format!("words {}", i), "api-3", 1));
+        }
+        let reports = diagnostics.reports();
+        assert_eq!(reports[1].samples.len(), MAX_SAMPLES);
+        assert_eq!(reports[1].samples[MAX_SAMPLES - 1].text, "words 9");
+        assert_eq!(reports[1].pods[0], ("api-3".to_string(), 10));
+    }
+}
@@ -91,13 +91,13 @@ impl CompiledFilter {
         }
 
         // Check pod filter
-        if !self.pods.is_empty() && !self.pods.contains(&entry.pod_name) {
+        if !self.pods.is_empty() && !self.pods.contains(entry.pod_name()) {
             return self.invert;
         }
 
         // Check regex pattern
         let text_match = match &self.regex {
-            Some(re) => re.is_match(&entry.raw),
+            Some(re) => re.is_match(entry.display_text()),
             None => true,
         };
 
@@ -105,6 +105,8 @@ impl CompiledFilter {
     }
 
     /// Find all match positions in a string (for highlighting)
+    ///
+    /// Pass the entry's `display_text`, the same text `matches` looks at.
     pub fn find_matches(&self, text: &str) -> Vec<(usize, usize)> {
         match &self.regex {
             Some(re) => re.find_iter(text).map(|m| (m.start(), m.end())).collect(),
@@ -131,6 +133,11 @@ impl CompiledFilter {
     pub fn is_case_insensitive(&self) -> bool {
         self.case_insensitive
     }
+
+    /// Check if filter match is inverted
+    pub fn is_inverted(&self) -> bool {
+        self.invert
+    }
 }
 
 impl std::fmt::Debug for CompiledFilter {
@@ -192,6 +199,17 @@ mod tests {
         assert!(!filter.matches(&entry));
     }
 
+    #[test]
+    fn test_filter_ignores_timestamp_prefix() {
+        let entry = crate::logs::LogParser::parse("2024-01-15T10:30:00Z an error", "pod", 1);
+        assert!(!CompiledFilter::new("10:30").unwrap().matches(&entry));
+
+        // Offsets line up with the displayed text
+        let filter = CompiledFilter::new("error").unwrap();
+        assert!(filter.matches(&entry));
+        assert_eq!(filter.find_matches(entry.display_text()), vec![(3, 8)]);
+    }
+
     #[test]
     fn test_level_filter() {
         let filter = FilterPresets::errors_only();
@@ -0,0 +1,207 @@
+//! Framings used by log shippers: GELF and RFC 5424 syslog
+//!
+//! GELF lines are JSON already, but their `level` is a syslog severity, not
+//! whatever `numeric_levels` says. Syslog lines are split into fields (host,
+//! app, structured data, ...) so they filter and fold like JSON lines.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use serde_json::{Map, Value};
+
+use super::levels;
+use crate::types::LogLevel;
+
+/// Facility names by number (RFC 5424 section 6.2.1)
+const FACILITIES: [&str; 24] = [
+    "kern", "user", "mail", "daemon", "auth", "syslog", "lpr", "news", "uucp", "cron", "authpriv",
+    "ftp", "ntp", "audit", "alert", "clock", "local0", "local1", "local2", "local3", "local4",
+    "local5", "local6", "local7",
+];
+
+/// The level of a GELF message, if the fields are one
+///
+/// GELF always has `version` and `short_message`; a missing level means alert.
+pub fn gelf_level(fields: &HashMap<String, Value>) -> Option<LogLevel> {
+    if !fields.contains_key("version") || !fields.contains_key("short_message") {
+        return None;
+    }
+    let severity = fields.get("level").map_or(Some(1), Value::as_u64)?;
+    Some(levels::syslog_level(severity))
+}
+
+/// A syslog line in RFC 5424 framing
+pub struct SyslogLine {
+    pub fields: HashMap<String, Value>,
+    pub level: LogLevel,
+    pub timestamp: Option<DateTime<Utc>>,
+}
+
+/// Parse `<PRI>VERSION TIMESTAMP HOST APP PROCID MSGID [SD] MSG`
+pub fn parse_rfc5424(line: &str) -> Option<SyslogLine> {
+    let rest = line.strip_prefix('<')?;
+    let (pri, rest) = rest.split_once('>')?;
+    if pri.is_empty() || pri.len() > 3 || !pri.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let pri: u64 = pri.parse().ok()?;
+    if pri > 191 {
+        return None;
+    }
+    let (version, rest) = rest.split_once(' ')?;
+    if version.is_empty() || !version.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    let mut rest = rest;
+    let mut header = [""; 5];
+    for part in &mut header {
+        let (token, tail) = rest.split_once(' ').unwrap_or((rest, ""));
+        if token.is_empty() {
+            return None;
+        }
+        *part = token;
+        rest = tail;
+    }
+    let [timestamp, host, app, procid, msgid] = header;
+    let timestamp = match timestamp {
+        "-" => None,
+        ts => Some(DateTime::parse_from_rfc3339(ts).ok()?.with_timezone(&Utc)),
+    };
+
+    let (facility, severity) = (pri / 8, pri % 8);
+    let level = levels::syslog_level(severity);
+    let mut fields = HashMap::new();
+    fields.insert(
+        "facility".to_string(),
+        Value::from(FACILITIES[facility as usize]),
+    );
+    fields.insert("severity".to_string(), Value::from(level.name()));
+    for (key, value) in [
+        ("host", host),
+        ("app", app),
+        ("procid", procid),
+        ("msgid", msgid),
+    ] {
+        if value != "-" {
+            fields.insert(key.to_string(), Value::from(value));
+        }
+    }
+
+    let msg = match rest.strip_prefix('-') {
+        Some(msg) => msg,
+        None if rest.starts_with('[') => {
+            let (elements, msg) = parse_structured_data(rest)?;
+            for (id, params) in elements {
+                fields.entry(id).or_insert(Value::Object(params));
+            }
+            msg
+        }
+        None => return None,
+    };
+    // The message may start with a UTF-8 byte order mark
+    let msg = msg.strip_prefix(' ').unwrap_or(msg);
+    let msg = msg.strip_prefix('\u{feff}').unwrap_or(msg);
+    if !msg.is_empty() {
+        fields.insert("msg".to_string(), Value::from(msg));
+    }
+
+    Some(SyslogLine {
+        fields,
+        level,
+        timestamp,
+    })
+}
+
+/// An SD-ID and its params
+type SdElement = (String, Map<String, Value>);
+
+/// `[id key="value" ...]...`, and the text after it
+fn parse_structured_data(mut input: &str) -> Option<(Vec<SdElement>, &str)> {
+    let mut elements = Vec::new();
+    while let Some(element) = input.strip_prefix('[') {
+        let end = element.find([' ', ']'])?;
+        let id = &element[..end];
+        if id.is_empty() {
+            return None;
+        }
+        let mut params = Map::new();
+        let mut rest = &element[end..];
+        loop {
+            rest = rest.trim_start_matches(' ');
+            if let Some(after) = rest.strip_prefix(']') {
+                rest = after;
+                break;
+            }
+            let (name, after) = rest.split_once("=\"")?;
+            let (value, after) = quoted_value(after)?;
+            params.insert(name.to_string(), Value::from(value));
+            rest = after;
+        }
+        elements.push((id.to_string(), params));
+        input = rest;
+    }
+    Some((elements, input))
+}
+
+/// A param value up to its closing quote, with `\"`, `\\` and `\]` unescaped
+fn quoted_value(input: &str) -> Option<(String, &str)> {
+    let mut value = String::new();
+    let mut chars = input.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' => return Some((value, &input[i + 1..])),
+            '\\' => match chars.next()? {
+                (_, escaped @ ('"' | '\\' | ']')) => value.push(escaped),
+                (_, other) => {
+                    value.push('\\');
+                    value.push(other);
+                }
+            },
+            c => value.push(c),
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logs::LogParser;
+
+    #[test]
+    fn test_parse_rfc5424_and_gelf() {
+        let line = r#"<165>1 2003-10-11T22:14:15.003Z mymachine.example.com evntslog - ID47 [exampleSDID@32473 iut="3" eventSource="App \"x\""][meta seq="1"] An application event"#;
+        let syslog = parse_rfc5424(line).unwrap();
+        assert_eq!(syslog.level, LogLevel::Info);
+        assert_eq!(
+            syslog.timestamp,
+            Some("2003-10-11T22:14:15.003Z".parse().unwrap())
+        );
+        let fields = &syslog.fields;
+        assert_eq!(fields["facility"], "local4");
+        assert_eq!(fields["host"], "mymachine.example.com");
+        assert_eq!(fields["app"], "evntslog");
+        assert!(!fields.contains_key("procid"));
+        assert_eq!(fields["exampleSDID@32473"]["eventSource"], "App \"x\"");
+        assert_eq!(fields["meta"]["seq"], "1");
+        assert_eq!(fields["msg"], "An application event");
+
+        let syslog = parse_rfc5424("<11>1 - web01 nginx 42 - - upstream timed out").unwrap();
+        assert_eq!(syslog.level, LogLevel::Error);
+        assert_eq!(syslog.fields["msg"], "upstream timed out");
+
+        assert!(parse_rfc5424("<34>Oct 11 22:14:15 mymachine su: failed").is_none());
+        assert!(parse_rfc5424("<999>1 - - - - - -").is_none());
+
+        // Parsed lines behave like JSON lines
+        let entry = LogParser::parse("<12>1 - web01 api - - - slow query", "api-1", 1);
+        assert!(entry.is_json);
+        assert_eq!(entry.level, LogLevel::Warn);
+
+        let gelf = r#"{"version":"1.1","host":"web01","short_message":"boom","level":3}"#;
+        assert_eq!(LogParser::parse(gelf, "api-1", 1).level, LogLevel::Error);
+        let bunyan = r#"{"level":30,"msg":"ok"}"#;
+        assert_eq!(LogParser::parse(bunyan, "api-1", 1).level, LogLevel::Info);
+    }
+}
@@ -0,0 +1,292 @@
+//! Pretty printing of JSON log lines
+//!
+//! Used instead of `serde_json::to_string_pretty` so the output can be
+//! shaped for reading: nested objects and arrays past a fold depth are
+//! collapsed to a one-line summary, huge string values (base64 blobs,
+//! embedded payloads) are cut short, and pinned keys come first.
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// Indentation per nesting level (matches serde_json's pretty printer)
+const INDENT: &str = "  ";
+
+/// String values longer than this (in chars) are elided unless configured otherwise
+pub const DEFAULT_MAX_VALUE_LEN: usize = 256;
+
+/// Top-level keys shown first unless configured otherwise
+const DEFAULT_PINNED_KEYS: [&str; 5] = ["level", "msg", "message", "error", "err"];
+
+/// How JSON entries are laid out in pretty-print mode
+///
+/// Set from the `[json]` table in the config file.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct JsonFormat {
+    /// Collapse objects and arrays nested this deep (1 = only top-level keys
+    /// are expanded); unset expands everything
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fold_depth: Option<usize>,
+    /// Cut string values longer than this many chars (0 = never)
+    pub max_value_len: usize,
+    /// Top-level keys shown first, in this order; the rest follow alphabetically
+    pub pinned_keys: Vec<String>,
+}
+
+impl Default for JsonFormat {
+    fn default() -> Self {
+        Self {
+            fold_depth: None,
+            max_value_len: DEFAULT_MAX_VALUE_LEN,
+            pinned_keys: DEFAULT_PINNED_KEYS.iter().map(|k| k.to_string()).collect(),
+        }
+    }
+}
+
+impl JsonFormat {
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Pretty print `value`, folding containers at or past `fold_depth`
+    ///
+    /// With `elide`, long string values are shortened first.
+    pub fn pretty(&self, value: &Value, fold_depth: Option<usize>, elide: bool) -> String {
+        let mut out = String::new();
+        if elide && self.has_long_values(value) {
+            let mut value = value.clone();
+            self.elide(&mut value);
+            self.write_value(&mut out, &value, 0, fold_depth);
+        } else {
+            self.write_value(&mut out, value, 0, fold_depth);
+        }
+        out
+    }
+
+    /// Single-line JSON with long values shortened, or None when nothing was
+    /// long enough to need it (so the raw line can be shown as is)
+    pub fn compact_elided(&self, json_str: &str) -> Option<String> {
+        // Nothing can be over the limit if the whole line isn't
+        if self.max_value_len == 0 || json_str.len() <= self.max_value_len {
+            return None;
+        }
+        let mut value: Value = serde_json::from_str(json_str).ok()?;
+        if !self.elide(&mut value) {
+            return None;
+        }
+        let Value::Object(map) = &value else {
+            return Some(value.to_string());
+        };
+        let fields: Vec<String> = self
+            .ordered(map)
+            .into_iter()
+            .map(|(key, child)| format!("{}:{}", Value::String(key.clone()), child))
+            .collect();
+        Some(format!("{{{}}}", fields.join(",")))
+    }
+
+    /// An object's entries with pinned keys first
+    fn ordered<'a>(&self, map: &'a Map<String, Value>) -> Vec<(&'a String, &'a Value)> {
+        let mut entries: Vec<(&String, &Value)> = self
+            .pinned_keys
+            .iter()
+            .filter_map(|key| map.get_key_value(key))
+            .collect();
+        entries.extend(
+            map.iter()
+                .filter(|(key, _)| !self.pinned_keys.contains(key)),
+        );
+        entries
+    }
+
+    fn write_value(
+        &self,
+        out: &mut String,
+        value: &Value,
+        depth: usize,
+        fold_depth: Option<usize>,
+    ) {
+        let folded = depth > 0 && fold_depth.is_some_and(|d| depth >= d);
+        match value {
+            Value::Object(map) if map.is_empty() => out.push_str("{}"),
+            Value::Array(items) if items.is_empty() => out.push_str("[]"),
+            Value::Object(map) if folded => {
+                out.push_str(&format!("{{… {} {}}}", map.len(), plural(map.len(), "key")));
+            }
+            Value::Array(items) if folded => {
+                out.push_str(&format!(
+                    "[… {} {}]",
+                    items.len(),
+                    plural(items.len(), "item")
+                ));
+            }
+            Value::Object(map) => {
+                // Only the top level is reordered; nested objects keep key order
+                let entries = if depth == 0 {
+                    self.ordered(map)
+                } else {
+                    map.iter().collect()
+                };
+                out.push('{');
+                for (i, (key, child)) in entries.into_iter().enumerate() {
+                    out.push_str(if i == 0 { "\n" } else { ",\n" });
+                    push_indent(out, depth + 1);
+                    out.push_str(&Value::String(key.clone()).to_string());
+                    out.push_str(": ");
+                    self.write_value(out, child, depth + 1, fold_depth);
+                }
+                out.push('\n');
+                push_indent(out, depth);
+                out.push('}');
+            }
+            Value::Array(items) => {
+                out.push('[');
+                for (i, child) in items.iter().enumerate() {
+                    out.push_str(if i == 0 { "\n" } else { ",\n" });
+                    push_indent(out, depth + 1);
+                    self.write_value(out, child, depth + 1, fold_depth);
+                }
+                out.push('\n');
+                push_indent(out, depth);
+                out.push(']');
+            }
+            scalar => out.push_str(&scalar.to_string()),
+        }
+    }
+
+    /// Shorten long strings in place, keeping their first `max_value_len` chars
+    ///
+    /// Returns whether anything was shortened.
+    fn elide(&self, value: &mut Value) -> bool {
+        let children: Box<dyn Iterator<Item = &mut Value>> = match value {
+            Value::String(s) => {
+                let Some(short) = self.elided(s) else {
+                    return false;
+                };
+                *s = short;
+                return true;
+            }
+            Value::Array(items) => Box::new(items.iter_mut()),
+            Value::Object(map) => Box::new(map.values_mut()),
+            _ => return false,
+        };
+        // Visit every child, not just up to the first change
+        let mut changed = false;
+        for child in children {
+            changed |= self.elide(child);
+        }
+        changed
+    }
+
+    fn has_long_values(&self, value: &Value) -> bool {
+        match value {
+            Value::String(s) => self.elided(s).is_some(),
+            Value::Array(items) => items.iter().any(|v| self.has_long_values(v)),
+            Value::Object(map) => map.values().any(|v| self.has_long_values(v)),
+            _ => false,
+        }
+    }
+
+    /// "first chars…(+N chars)" for a string over the limit
+    fn elided(&self, s: &str) -> Option<String> {
+        if self.max_value_len == 0 || s.len() <= self.max_value_len {
+            return None;
+        }
+        let total = s.chars().count();
+        if total <= self.max_value_len {
+            return None;
+        }
+        let kept: String = s.chars().take(self.max_value_len).collect();
+        Some(format!("{}…(+{} chars)", kept, total - self.max_value_len))
+    }
+}
+
+fn push_indent(out: &mut String, depth: usize) {
+    for _ in 0..depth {
+        out.push_str(INDENT);
+    }
+}
+
+fn plural(count: usize, noun: &str) -> String {
+    if count == 1 {
+        noun.to_string()
+    } else {
+        format!("{}s", noun)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_pretty_matches_serde_when_unfolded() {
+        let value = json!({"msg": "hi", "ctx": {"user": {"id": 7}, "tags": ["a", "b"]}, "e": []});
+        let format = JsonFormat {
+            pinned_keys: Vec::new(),
+            ..JsonFormat::default()
+        };
+        assert_eq!(
+            format.pretty(&value, None, true),
+            serde_json::to_string_pretty(&value).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_pretty_folds_nested_containers() {
+        let value = json!({"msg": "hi", "ctx": {"user": {"id": 7}, "tags": ["a"]}});
+        let format = JsonFormat::default();
+        assert_eq!(
+            format.pretty(&value, Some(1), true),
+            "{\n  \"msg\": \"hi\",\n  \"ctx\": {… 2 keys}\n}"
+        );
+        assert_eq!(
+            format.pretty(&value, Some(2), true),
+            "{\n  \"msg\": \"hi\",\n  \"ctx\": {\n    \"tags\": [… 1 item],\n    \"user\": {… 1 key}\n  }\n}"
+        );
+    }
+
+    #[test]
+    fn test_long_values_are_elided() {
+        let format = JsonFormat {
+            max_value_len: 4,
+            ..JsonFormat::default()
+        };
+        let value = json!({"blob": "aGVsbG8gd29ybGQ=", "ok": "abcd"});
+        assert_eq!(
+            format.pretty(&value, None, true),
+            "{\n  \"blob\": \"aGVs…(+12 chars)\",\n  \"ok\": \"abcd\"\n}"
+        );
+        // Expanded entries show the full value
+        assert!(
+            format
+                .pretty(&value, None, false)
+                .contains("aGVsbG8gd29ybGQ=")
+        );
+
+        assert_eq!(
+            format.compact_elided(r#"{"blob":"aGVsbG8gd29ybGQ=","ok":"abcd"}"#),
+            Some(r#"{"blob":"aGVs…(+12 chars)","ok":"abcd"}"#.to_string())
+        );
+        assert_eq!(format.compact_elided(r#"{"ok":"abcd","n":1}"#), None);
+    }
+
+    #[test]
+    fn test_pinned_keys_come_first() {
+        let format = JsonFormat {
+            max_value_len: 4,
+            pinned_keys: vec!["msg".into(), "level".into()],
+            ..JsonFormat::default()
+        };
+        let value = json!({"a": 1, "level": "info", "msg": "hi", "z": {"level": 2, "b": 3}});
+        assert_eq!(
+            format.pretty(&value, Some(1), true),
+            "{\n  \"msg\": \"hi\",\n  \"level\": \"info\",\n  \"a\": 1,\n  \"z\": {… 2 keys}\n}"
+        );
+        assert_eq!(
+            format.compact_elided(r#"{"a":"long value","msg":"hi"}"#),
+            Some(r#"{"msg":"hi","a":"long…(+6 chars)"}"#.to_string())
+        );
+    }
+}
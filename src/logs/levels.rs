@@ -0,0 +1,186 @@
+//! Numeric level mapping from the `numeric_levels` config setting
+//!
+//! Loggers that write the level as a number disagree on what the numbers
+//! mean: Bunyan and pino count up from 10 (trace) to 60 (fatal), Python's
+//! logging from 10 (debug) to 50 (critical), and syslog severities run the
+//! other way, from 0 (emergency) to 7 (debug). A preset name picks one of
+//! these; a table gives each level the highest number that still maps to it.
+
+use std::collections::BTreeMap;
+use std::sync::OnceLock;
+
+use anyhow::{Result, bail};
+use serde::{Deserialize, Serialize};
+
+use crate::types::LogLevel;
+
+static NUMERIC_LEVELS: OnceLock<NumericLevels> = OnceLock::new();
+
+/// Bunyan's (and pino's) ranges, used unless configured otherwise
+const BUNYAN: &[(u64, LogLevel)] = &[
+    (10, LogLevel::Trace),
+    (20, LogLevel::Debug),
+    (30, LogLevel::Info),
+    (40, LogLevel::Warn),
+    (50, LogLevel::Error),
+    (u64::MAX, LogLevel::Fatal),
+];
+
+const PYTHON: &[(u64, LogLevel)] = &[
+    (10, LogLevel::Debug),
+    (20, LogLevel::Info),
+    (30, LogLevel::Warn),
+    (40, LogLevel::Error),
+    (u64::MAX, LogLevel::Fatal),
+];
+
+/// Emergency, alert and critical are all fatal; notice is info
+const SYSLOG: &[(u64, LogLevel)] = &[
+    (2, LogLevel::Fatal),
+    (3, LogLevel::Error),
+    (4, LogLevel::Warn),
+    (6, LogLevel::Info),
+    (u64::MAX, LogLevel::Debug),
+];
+
+/// The mapping as written in the config file
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum NumericLevelSpec {
+    /// `bunyan`, `pino`, `python` or `syslog`
+    Preset(String),
+    /// Level name to the highest number that maps to it
+    Bounds(BTreeMap<String, u64>),
+}
+
+/// Numbers to levels, as ascending upper bounds
+#[derive(Clone, Debug, PartialEq)]
+pub struct NumericLevels {
+    bounds: Vec<(u64, LogLevel)>,
+}
+
+impl Default for NumericLevels {
+    fn default() -> Self {
+        Self {
+            bounds: BUNYAN.to_vec(),
+        }
+    }
+}
+
+impl NumericLevels {
+    pub fn new(spec: &NumericLevelSpec) -> Result<Self> {
+        let bounds = match spec {
+            NumericLevelSpec::Preset(name) => match name.to_lowercase().as_str() {
+                "bunyan" | "pino" => BUNYAN.to_vec(),
+                "python" => PYTHON.to_vec(),
+                "syslog" => SYSLOG.to_vec(),
+                _ => bail!(
+                    "Unknown numeric_levels preset {:?} (use bunyan, pino, python or syslog, or a table)",
+                    name
+                ),
+            },
+            NumericLevelSpec::Bounds(table) => {
+                if table.is_empty() {
+                    bail!("numeric_levels table needs at least one level");
+                }
+                let mut bounds: Vec<(u64, LogLevel)> = Vec::with_capacity(table.len());
+                for (name, bound) in table {
+                    let level = match LogLevel::from_str(name) {
+                        LogLevel::Unknown => bail!(
+                            "Unknown level {:?} in numeric_levels (use trace, debug, info, warn, error or fatal)",
+                            name
+                        ),
+                        level => level,
+                    };
+                    if let Some((_, other)) = bounds.iter().find(|(b, _)| b == bound) {
+                        bail!(
+                            "numeric_levels gives {} to both {} and {}",
+                            bound,
+                            other.name(),
+                            level.name()
+                        );
+                    }
+                    bounds.push((*bound, level));
+                }
+                bounds.sort_by_key(|(bound, _)| *bound);
+                bounds
+            }
+        };
+        Ok(Self { bounds })
+    }
+
+    /// The level a number stands for; numbers past the last bound get its level
+    pub fn level(&self, number: u64) -> LogLevel {
+        self.bounds
+            .iter()
+            .find(|(bound, _)| number <= *bound)
+            .or(self.bounds.last())
+            .map_or(LogLevel::Unknown, |(_, level)| *level)
+    }
+}
+
+/// The level of a syslog severity (0 emergency to 7 debug)
+pub fn syslog_level(severity: u64) -> LogLevel {
+    NumericLevels {
+        bounds: SYSLOG.to_vec(),
+    }
+    .level(severity)
+}
+
+/// Set the mapping for the rest of the run
+pub fn init(spec: Option<&NumericLevelSpec>) -> Result<()> {
+    if let Some(spec) = spec {
+        let _ = NUMERIC_LEVELS.set(NumericLevels::new(spec)?);
+    }
+    Ok(())
+}
+
+/// The configured mapping, Bunyan's if none is
+pub fn get() -> &'static NumericLevels {
+    NUMERIC_LEVELS.get_or_init(NumericLevels::default)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_numeric_levels_presets_and_tables() {
+        let bunyan = NumericLevels::default();
+        assert_eq!(bunyan.level(10), LogLevel::Trace);
+        assert_eq!(bunyan.level(30), LogLevel::Info);
+        assert_eq!(bunyan.level(60), LogLevel::Fatal);
+
+        let syslog = NumericLevels::new(&NumericLevelSpec::Preset("syslog".into())).unwrap();
+        assert_eq!(syslog.level(0), LogLevel::Fatal);
+        assert_eq!(syslog.level(3), LogLevel::Error);
+        assert_eq!(syslog.level(5), LogLevel::Info);
+        assert_eq!(syslog.level(7), LogLevel::Debug);
+
+        let custom = NumericLevels::new(&NumericLevelSpec::Bounds(BTreeMap::from([
+            ("info".to_string(), 1),
+            ("warn".to_string(), 2),
+            ("error".to_string(), 3),
+        ])))
+        .unwrap();
+        assert_eq!(custom.level(0), LogLevel::Info);
+        assert_eq!(custom.level(2), LogLevel::Warn);
+        assert_eq!(custom.level(9), LogLevel::Error);
+
+        assert!(NumericLevels::new(&NumericLevelSpec::Preset("log4j".into())).is_err());
+        assert!(
+            NumericLevels::new(&NumericLevelSpec::Bounds(BTreeMap::from([
+                ("info".to_string(), 1),
+                ("warn".to_string(), 1),
+            ])))
+            .is_err()
+        );
+        assert!(
+            NumericLevels::new(&NumericLevelSpec::Bounds(BTreeMap::from([(
+                "loud".to_string(),
+                1
+            )])))
+            .is_err()
+        );
+    }
+}
@@ -1,11 +1,27 @@
 //! Log processing for kubescope
 
 mod buffer;
+mod diagnostics;
 mod filter;
+mod formats;
+mod json;
+pub mod levels;
 mod parser;
+mod pipeline;
+mod requests;
+pub mod rules;
+mod schema;
 mod stream;
 
-pub use buffer::LogBuffer;
+pub use buffer::{IngestStats, JsonKeyStat, LogBuffer};
+pub use diagnostics::{ParseProblem, ProblemReport};
 pub use filter::CompiledFilter;
-pub use parser::LogParser;
-pub use stream::LogStreamManager;
+pub use json::JsonFormat;
+pub use parser::{LogParser, MAX_LINE_BYTES};
+pub use pipeline::FilterPipeline;
+pub use requests::request_id;
+pub use schema::{DriftChange, KeySchema, SchemaDrift};
+pub use stream::{
+    Backfill, HistoryBatch, HistoryWindow, LogStreamManager, MESH_SIDECARS, RetentionGap,
+    StreamParams, StreamSource, fetch_full_line, find_retention_gaps,
+};
@@ -1,20 +1,88 @@
 use chrono::{DateTime, Utc};
 use serde_json::Value;
+use std::borrow::Cow;
 use std::collections::HashMap;
+use std::sync::Arc;
 
-use crate::types::{LogEntry, LogLevel};
+use super::{formats, levels, rules};
+use crate::types::{LogEntry, LogLevel, LogSource};
+
+/// Lines longer than this (in bytes) are truncated before parsing unless configured otherwise
+pub const MAX_LINE_BYTES: usize = 64 * 1024;
 
 /// Log parser for extracting structure from raw log lines
 pub struct LogParser;
 
 impl LogParser {
-    /// Parse a raw log line into a LogEntry
-    pub fn parse(raw: &str, pod_name: &str, line_number: u64) -> LogEntry {
-        let mut entry = LogEntry::new(pod_name.to_string(), line_number, raw.to_string());
+    /// Parse a raw log line into a LogEntry, for tests (streams parse with `parse_bytes`)
+    #[cfg(test)]
+    pub fn parse(raw: &str, pod_name: &str, line_number: i64) -> LogEntry {
+        Self::parse_from(
+            raw,
+            &Arc::new(LogSource::pod(pod_name)),
+            line_number,
+            MAX_LINE_BYTES,
+        )
+    }
+
+    /// Parse a line as read off the wire, which may not be valid UTF-8
+    ///
+    /// Invalid sequences become U+FFFD and the entry is marked sanitized, keeping
+    /// the original bytes (escaped) for the raw view.
+    pub fn parse_bytes(
+        bytes: &[u8],
+        source: &Arc<LogSource>,
+        line_number: i64,
+        max_bytes: usize,
+    ) -> LogEntry {
+        match std::str::from_utf8(bytes) {
+            Ok(raw) => Self::parse_from(raw, source, line_number, max_bytes),
+            Err(_) => {
+                let lossy = String::from_utf8_lossy(bytes);
+                let mut entry = Self::parse_from(&lossy, source, line_number, max_bytes);
+                entry.sanitized = true;
+                entry.original = Some(escape_bytes(bytes));
+                entry
+            }
+        }
+    }
+
+    /// A line's bytes as text safe to print, with no length limit
+    pub fn clean_line(bytes: &[u8]) -> String {
+        sanitize(&String::from_utf8_lossy(bytes)).into_owned()
+    }
+
+    /// Parse a raw log line from a known source, keeping at most `max_bytes` of it
+    pub fn parse_from(
+        raw: &str,
+        source: &Arc<LogSource>,
+        line_number: i64,
+        max_bytes: usize,
+    ) -> LogEntry {
+        // Cap overlong lines so a single runaway line can't dominate memory
+        let full_len = raw.len();
+        let truncated = full_len > max_bytes;
+        let raw = if truncated {
+            &raw[..Self::floor_char_boundary(raw, max_bytes)]
+        } else {
+            raw
+        };
+
+        // Control characters would be written straight to the terminal
+        let clean = sanitize(raw);
+        let mut entry = LogEntry::from_source(Arc::clone(source), line_number, String::new());
+        entry.truncated = truncated;
+        entry.truncated_bytes = full_len - raw.len();
+        if matches!(clean, Cow::Owned(_)) {
+            entry.sanitized = true;
+            entry.original = Some(escape_bytes(raw.as_bytes()));
+        }
 
         // Try to extract Kubernetes timestamp prefix (format: 2024-01-15T10:30:00.123456789Z)
-        let (timestamp, content) = Self::extract_k8s_timestamp(raw);
+        let (timestamp, content) = Self::extract_k8s_timestamp(&clean);
         entry.timestamp = timestamp;
+        // The content is always a suffix of the line
+        entry.text_start = clean.len() - content.len();
 
         // Try to parse as JSON
         if let Some((fields, level, pretty)) = Self::try_parse_json(content) {
@@ -22,11 +90,37 @@ impl LogParser {
             entry.fields = Some(fields);
             entry.level = level;
             entry.pretty_printed = Some(pretty);
+        } else if let Some(syslog) = formats::parse_rfc5424(content) {
+            // Syslog framing from a log shipper; its header and structured data become fields
+            entry.is_json = true;
+            entry.level = syslog.level;
+            entry.timestamp = entry.timestamp.or(syslog.timestamp);
+            entry.pretty_printed = serde_json::to_string_pretty(&syslog.fields).ok();
+            entry.fields = Some(syslog.fields);
+        } else if let Some(fields) = crate::plugins::get().and_then(|p| p.parse(content)) {
+            // A plugin parser understood the line; treat it like JSON from here on
+            entry.is_json = true;
+            entry.level = match Self::extract_level_from_json(&fields) {
+                LogLevel::Unknown => Self::extract_level_from_text(content),
+                level => level,
+            };
+            entry.pretty_printed = serde_json::to_string_pretty(&fields).ok();
+            entry.fields = Some(fields);
         } else {
+            // Truncated JSON is expected to fail, only count genuine parse failures
+            entry.parse_failed = !truncated && content.trim_start().starts_with('{');
             // If not JSON, try to extract level from plain text
             entry.level = Self::extract_level_from_text(content);
         }
 
+        if let Some(plugins) = crate::plugins::get() {
+            plugins.extract(content, &mut entry.fields);
+        }
+        if let Some(level) = rules::get().and_then(|r| r.relabel(&source.pod, content)) {
+            entry.level = level;
+        }
+
+        entry.raw = clean.into_owned();
         entry
     }
 
@@ -74,8 +168,9 @@ impl LogParser {
 
         let fields: HashMap<String, Value> = obj.clone().into_iter().collect();
 
-        // Extract level from common field names
-        let level = Self::extract_level_from_json(&fields);
+        // Extract level from common field names (GELF levels are syslog severities)
+        let level =
+            formats::gelf_level(&fields).unwrap_or_else(|| Self::extract_level_from_json(&fields));
 
         // Pretty print
         let pretty = serde_json::to_string_pretty(&value).unwrap_or_default();
@@ -85,7 +180,7 @@ impl LogParser {
 
     /// Extract log level from JSON fields
     fn extract_level_from_json(fields: &HashMap<String, Value>) -> LogLevel {
-        // Common field names for log level
+        // Common field names for log level; dotted names also match nested objects
         let level_fields = [
             "level",
             "lvl",
@@ -95,23 +190,21 @@ impl LogParser {
             "log_level",
             "Level",
             "LEVEL",
+            // OpenTelemetry log data model
+            "severityText",
+            "SeverityText",
+            "severity_text",
+            "attributes.level",
         ];
 
         for field in level_fields {
-            if let Some(value) = fields.get(field) {
+            if let Some(value) = Self::field_at(fields, field) {
                 match value {
                     Value::String(s) => return LogLevel::from_str(s),
                     Value::Number(n) => {
-                        // Some loggers use numeric levels
+                        // Some loggers use numeric levels (`numeric_levels` says which)
                         if let Some(num) = n.as_u64() {
-                            return match num {
-                                0..=10 => LogLevel::Trace,
-                                11..=20 => LogLevel::Debug,
-                                21..=30 => LogLevel::Info,
-                                31..=40 => LogLevel::Warn,
-                                41..=50 => LogLevel::Error,
-                                _ => LogLevel::Fatal,
-                            };
+                            return levels::get().level(num);
                         }
                     }
                     _ => {}
@@ -119,9 +212,35 @@ impl LogParser {
             }
         }
 
+        // OpenTelemetry severity numbers have their own scale (1-24, four per level)
+        for field in ["severityNumber", "SeverityNumber", "severity_number"] {
+            if let Some(num) = Self::field_at(fields, field).and_then(Value::as_u64) {
+                return match num {
+                    1..=4 => LogLevel::Trace,
+                    5..=8 => LogLevel::Debug,
+                    9..=12 => LogLevel::Info,
+                    13..=16 => LogLevel::Warn,
+                    17..=20 => LogLevel::Error,
+                    21..=24 => LogLevel::Fatal,
+                    _ => continue,
+                };
+            }
+        }
+
         LogLevel::Unknown
     }
 
+    /// The value at `path`: a top-level key as written (`log.level` in flat ECS),
+    /// or else the dotted path into nested objects (`{"log":{"level":...}}`)
+    fn field_at<'a>(fields: &'a HashMap<String, Value>, path: &str) -> Option<&'a Value> {
+        if let Some(value) = fields.get(path) {
+            return Some(value);
+        }
+        let (first, rest) = path.split_once('.')?;
+        rest.split('.')
+            .try_fold(fields.get(first)?, |value, key| value.get(key))
+    }
+
     /// Extract log level from plain text patterns
     fn extract_level_from_text(content: &str) -> LogLevel {
         let upper = content.to_uppercase();
@@ -206,6 +325,41 @@ impl LogParser {
     }
 }
 
+/// Characters that are unsafe to print: C0 and C1 controls and DEL, except tab
+fn is_unprintable(c: char) -> bool {
+    c != '\t' && c.is_control()
+}
+
+/// Drop unprintable characters, borrowing when there are none
+fn sanitize(raw: &str) -> Cow<'_, str> {
+    if raw.chars().any(is_unprintable) {
+        Cow::Owned(raw.chars().filter(|&c| !is_unprintable(c)).collect())
+    } else {
+        Cow::Borrowed(raw)
+    }
+}
+
+/// The line with invalid bytes and unprintable characters written as escapes
+/// ("\xff", "\x1b", "\u{9b}")
+fn escape_bytes(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len());
+    for chunk in bytes.utf8_chunks() {
+        for c in chunk.valid().chars() {
+            if !is_unprintable(c) {
+                out.push(c);
+            } else if c.is_ascii() {
+                out.push_str(&format!("\\x{:02x}", c as u32));
+            } else {
+                out.push_str(&format!("\\u{{{:x}}}", c as u32));
+            }
+        }
+        for byte in chunk.invalid() {
+            out.push_str(&format!("\\x{:02x}", byte));
+        }
+    }
+    out
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -215,6 +369,33 @@ mod tests {
         let line = "2024-01-15T10:30:00.123456789Z some log message";
         let entry = LogParser::parse(line, "test-pod", 1);
         assert!(entry.timestamp.is_some());
+        assert_eq!(entry.display_text(), "some log message");
+
+        // Short timestamps are stripped just the same
+        let entry = LogParser::parse("2024-01-15T10:30:00Z ok", "test-pod", 1);
+        assert_eq!(entry.display_text(), "ok");
+        let entry = LogParser::parse("no timestamp here", "test-pod", 1);
+        assert_eq!(entry.display_text(), "no timestamp here");
+    }
+
+    #[test]
+    fn test_parse_from_shares_source() {
+        let source = Arc::new(LogSource {
+            namespace: Some("payments".into()),
+            workload: Some("api".into()),
+            ..LogSource::pod("api-7d9f-x2k4p")
+        });
+        let first = LogParser::parse_from("one", &source, 1, MAX_LINE_BYTES);
+        let second = LogParser::parse_from("two", &source, 2, MAX_LINE_BYTES);
+        assert!(Arc::ptr_eq(&first.source, &second.source));
+        assert_eq!(first.short_pod_name(), "x2k4p");
+        assert_eq!(first.source.label(), "payments/api");
+
+        let other_cluster = LogSource {
+            cluster: Some("prod-eu".into()),
+            ..(*source).clone()
+        };
+        assert_eq!(other_cluster.label(), "payments/api@prod-eu");
     }
 
     #[test]
@@ -225,6 +406,20 @@ mod tests {
         assert_eq!(entry.level, LogLevel::Error);
     }
 
+    #[test]
+    fn test_parse_nested_and_otel_levels() {
+        let entry = LogParser::parse(r#"{"log":{"level":"error"},"msg":"x"}"#, "test-pod", 1);
+        assert_eq!(entry.level, LogLevel::Error);
+        let entry = LogParser::parse(r#"{"log.level":"warn","msg":"x"}"#, "test-pod", 1);
+        assert_eq!(entry.level, LogLevel::Warn);
+        let entry = LogParser::parse(r#"{"severityText":"DEBUG","body":"x"}"#, "test-pod", 1);
+        assert_eq!(entry.level, LogLevel::Debug);
+        let entry = LogParser::parse(r#"{"severityNumber":17,"body":"x"}"#, "test-pod", 1);
+        assert_eq!(entry.level, LogLevel::Error);
+        let entry = LogParser::parse(r#"{"log":"flat","msg":"x"}"#, "test-pod", 1);
+        assert_eq!(entry.level, LogLevel::Unknown);
+    }
+
     #[test]
     fn test_parse_text_level() {
         let line = "[ERROR] something went wrong";
@@ -232,6 +427,57 @@ mod tests {
         assert_eq!(entry.level, LogLevel::Error);
     }
 
+    #[test]
+    fn test_parse_marks_truncated_and_failed_lines() {
+        let long = "x".repeat(MAX_LINE_BYTES + 10);
+        let entry = LogParser::parse(&long, "test-pod", 1);
+        assert!(entry.truncated);
+        assert_eq!(entry.raw.len(), MAX_LINE_BYTES);
+        assert_eq!(entry.truncated_bytes, 10);
+
+        let source = Arc::new(LogSource::pod("test-pod"));
+        let entry = LogParser::parse_from("2024-01-15T10:30:00Z 0123456789", &source, 1, 25);
+        assert_eq!(entry.raw, "2024-01-15T10:30:00Z 0123");
+        assert_eq!(entry.truncated_bytes, 6);
+
+        let broken = r#"{"level":"error","msg":"unterminated"#;
+        let entry = LogParser::parse(broken, "test-pod", 2);
+        assert!(entry.parse_failed);
+        assert!(!entry.is_json);
+
+        let plain = "just text";
+        assert!(!LogParser::parse(plain, "test-pod", 3).parse_failed);
+    }
+
+    #[test]
+    fn test_parse_sanitizes_controls_and_invalid_utf8() {
+        let source = Arc::new(LogSource::pod("test-pod"));
+        let entry = LogParser::parse_bytes(
+            b"\x1b[31mred\x1b[0m\tok \xff\xfe",
+            &source,
+            1,
+            MAX_LINE_BYTES,
+        );
+        assert!(entry.sanitized);
+        assert_eq!(entry.raw, "[31mred[0m\tok \u{fffd}\u{fffd}");
+        assert_eq!(
+            entry.original.as_deref(),
+            Some("\\x1b[31mred\\x1b[0m\tok \\xff\\xfe")
+        );
+
+        let clean =
+            LogParser::parse_bytes("tab\tand ünïcode".as_bytes(), &source, 2, MAX_LINE_BYTES);
+        assert!(!clean.sanitized);
+        assert!(clean.original.is_none());
+
+        // Controls inside JSON strings are dropped before parsing
+        let json = LogParser::parse(r#"{"msg":"bell\u0007"}"#, "test-pod", 3);
+        assert!(json.is_json && !json.sanitized);
+        let json = LogParser::parse("{\"msg\":\"a\x07b\"}", "test-pod", 4);
+        assert!(json.sanitized && json.is_json);
+        assert_eq!(json.message(), "ab");
+    }
+
     #[test]
     fn test_parse_multibyte_utf8_no_panic() {
         // Box-drawing characters are 3 bytes each, this tests UTF-8 boundary handling
@@ -0,0 +1,111 @@
+use std::collections::HashSet;
+
+use crate::logs::CompiledFilter;
+use crate::logs::rules;
+use crate::types::{ArcLogEntry, LogEntry, LogLevel};
+
+/// Every filter the log view applies, in one place
+///
+/// The log pane, export and match counts all go through this so they agree
+/// on which lines are "in view".
+#[derive(Clone, Copy)]
+pub struct FilterPipeline<'a> {
+    /// Text/regex filter from the filter bar
+    pub text: Option<&'a CompiledFilter>,
+    /// JSON keys picked in the key filter (empty = no key filter)
+    pub json_keys: &'a HashSet<String>,
+    /// Pods hidden from the view (as are lines muted by `[[level_rules]]`)
+    pub muted_pods: &'a HashSet<String>,
+    /// Only show lines from this node
+    pub node: Option<&'a str>,
+    /// Hide lines below this level (trace keeps everything)
+    pub min_level: LogLevel,
+}
+
+impl<'a> FilterPipeline<'a> {
+    /// The same pipeline without the text filter (to preview a new one)
+    pub fn without_text(self) -> Self {
+        Self { text: None, ..self }
+    }
+
+    /// Whether an entry passes every stage
+    pub fn matches(&self, entry: &LogEntry) -> bool {
+        if self.muted_pods.contains(entry.pod_name()) {
+            return false;
+        }
+        if entry.level.ordinal() < self.min_level.ordinal() {
+            return false;
+        }
+        if rules::get().is_some_and(|r| r.mutes(entry)) {
+            return false;
+        }
+        if let Some(node) = self.node
+            && entry.node_name.as_deref() != Some(node)
+        {
+            return false;
+        }
+        if let Some(filter) = self.text
+            && !filter.matches(entry)
+        {
+            return false;
+        }
+        // Keep entries that have any of the selected keys
+        if !self.json_keys.is_empty() {
+            return entry
+                .fields
+                .as_ref()
+                .is_some_and(|fields| fields.keys().any(|k| self.json_keys.contains(k)));
+        }
+        true
+    }
+
+    /// Keep only the entries that pass (Arc clones are cheap)
+    pub fn apply(&self, mut entries: Vec<ArcLogEntry>) -> Vec<ArcLogEntry> {
+        entries.retain(|e| self.matches(e));
+        entries
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pipeline_applies_every_stage() {
+        let mut muted = LogEntry::new("muted".into(), 1, "boom".into());
+        muted.node_name = Some("node-a".into());
+        let mut other_node = LogEntry::new("pod".into(), 2, "boom".into());
+        other_node.node_name = Some("node-b".into());
+        let mut keep = LogEntry::new("pod".into(), 3, "boom".into());
+        keep.node_name = Some("node-a".into());
+        let mut quiet = LogEntry::new("pod".into(), 4, "fine".into());
+        quiet.node_name = Some("node-a".into());
+
+        let filter = CompiledFilter::new("boom").unwrap();
+        let json_keys = HashSet::new();
+        let muted_pods = HashSet::from(["muted".to_string()]);
+        let pipeline = FilterPipeline {
+            text: Some(&filter),
+            json_keys: &json_keys,
+            muted_pods: &muted_pods,
+            node: Some("node-a"),
+            min_level: LogLevel::Trace,
+        };
+
+        assert!(!pipeline.matches(&muted));
+        assert!(!pipeline.matches(&other_node));
+        assert!(pipeline.matches(&keep));
+        assert!(!pipeline.matches(&quiet));
+        assert!(pipeline.without_text().matches(&quiet));
+
+        let threshold = FilterPipeline {
+            min_level: LogLevel::Warn,
+            ..pipeline.without_text()
+        };
+        let mut warn = LogEntry::new("pod".into(), 5, "slow".into());
+        warn.node_name = Some("node-a".into());
+        warn.level = LogLevel::Warn;
+        assert!(threshold.matches(&warn));
+        assert!(!threshold.matches(&quiet));
+    }
+}
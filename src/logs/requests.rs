@@ -0,0 +1,80 @@
+//! Request ids, for following one request from the mesh proxy into the app
+//!
+//! Envoy (Istio) and Linkerd proxies log `x-request-id` with each access log
+//! line, and apps that propagate it log it too, so filtering on it lines up
+//! the proxy's view of a request with what the app did with it.
+
+use std::sync::LazyLock;
+
+use regex::Regex;
+use serde_json::Value;
+
+use crate::types::LogEntry;
+
+/// JSON keys a request id is looked for under, first match wins
+const REQUEST_ID_KEYS: [&str; 5] = [
+    "request_id",
+    "x_request_id",
+    "x-request-id",
+    "requestId",
+    "req_id",
+];
+
+/// Envoy's default request ids are UUIDs
+static UUID: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"\b[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12}\b")
+        .expect("valid UUID pattern")
+});
+
+/// The request id a line carries
+///
+/// Taken from a JSON line's request id field, then its `trace_keys`, then
+/// the first UUID in the line (the quoted `x-request-id` of Envoy's text
+/// access log).
+pub fn request_id(entry: &LogEntry, trace_keys: &[String]) -> Option<String> {
+    let from_fields = entry.fields.as_ref().and_then(|fields| {
+        REQUEST_ID_KEYS
+            .iter()
+            .copied()
+            .chain(trace_keys.iter().map(String::as_str))
+            .find_map(|key| match fields.get(key)? {
+                Value::String(id) if !id.is_empty() => Some(id.clone()),
+                Value::Number(id) => Some(id.to_string()),
+                _ => None,
+            })
+    });
+    from_fields.or_else(|| {
+        UUID.find(entry.display_text())
+            .map(|id| id.as_str().to_string())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logs::LogParser;
+
+    #[test]
+    fn test_request_id_from_access_log_and_app_lines() {
+        let envoy = r#"[2024-01-15T10:00:00.000Z] "GET /orders HTTP/1.1" 503 UF upstream_reset_before_response_started - "-" 0 91 3 - "-" "curl/8.4.0" "5b1d6a7e-0c2f-4e0b-9f4d-2b7d3c1a9e11" "api:8080" "10.0.0.7:8080""#;
+        let entry = LogParser::parse(envoy, "api-1", 1);
+        assert_eq!(
+            request_id(&entry, &[]).as_deref(),
+            Some("5b1d6a7e-0c2f-4e0b-9f4d-2b7d3c1a9e11")
+        );
+
+        let app = r#"{"msg":"order failed","request_id":"5b1d6a7e-0c2f-4e0b-9f4d-2b7d3c1a9e11"}"#;
+        let entry = LogParser::parse(app, "api-1", 2);
+        assert_eq!(
+            request_id(&entry, &[]).as_deref(),
+            Some("5b1d6a7e-0c2f-4e0b-9f4d-2b7d3c1a9e11")
+        );
+
+        let traced = LogParser::parse(r#"{"msg":"ok","traceId":"4bf92f35"}"#, "api-1", 3);
+        assert_eq!(request_id(&traced, &[]), None);
+        assert_eq!(
+            request_id(&traced, &["traceId".to_string()]).as_deref(),
+            Some("4bf92f35")
+        );
+    }
+}
@@ -0,0 +1,172 @@
+//! Level rules from the `[[level_rules]]` config tables
+//!
+//! A rule picks lines by a regex on their text and/or a pod name substring,
+//! then either relabels them (`level = "trace"` for health checks an app logs
+//! as Info) or mutes them at or below a level (`mute = "debug"` for a chatty
+//! pod). Relabeling happens when a line is parsed, so level counts, jumps and
+//! the minimap agree with it; muting happens in the filter pipeline, so the
+//! lines stay in the buffer.
+
+use std::sync::OnceLock;
+
+use anyhow::{Result, bail};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::types::{LogEntry, LogLevel};
+
+static RULES: OnceLock<LevelRules> = OnceLock::new();
+
+/// A level rule as written in the config file
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LevelRuleSpec {
+    /// Regex the line's text has to match
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pattern: Option<String>,
+    /// Substring the pod name has to contain
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pod: Option<String>,
+    /// Level to give matching lines
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub level: Option<String>,
+    /// Hide matching lines at or below this level
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mute: Option<String>,
+}
+
+struct LevelRule {
+    pattern: Option<Regex>,
+    pod: Option<String>,
+    level: Option<LogLevel>,
+    mute: Option<LogLevel>,
+}
+
+impl LevelRule {
+    fn matches(&self, pod: &str, text: &str) -> bool {
+        self.pod.as_ref().is_none_or(|p| pod.contains(p.as_str()))
+            && self.pattern.as_ref().is_none_or(|p| p.is_match(text))
+    }
+}
+
+/// The configured level rules, in config order
+#[derive(Default)]
+pub struct LevelRules {
+    rules: Vec<LevelRule>,
+}
+
+impl LevelRules {
+    /// Compile the configured rules, rejecting ones that can't do anything
+    pub fn new(specs: &[LevelRuleSpec]) -> Result<Self> {
+        let level = |name: &Option<String>| -> Result<Option<LogLevel>> {
+            match name.as_deref().map(LogLevel::from_str) {
+                Some(LogLevel::Unknown) => bail!(
+                    "Unknown level {:?} in level rule (use trace, debug, info, warn, error or fatal)",
+                    name.as_deref().unwrap_or_default()
+                ),
+                level => Ok(level),
+            }
+        };
+        let mut rules = Vec::with_capacity(specs.len());
+        for spec in specs {
+            if spec.pattern.is_none() && spec.pod.is_none() {
+                bail!("Level rule needs a pattern or a pod to match lines by");
+            }
+            if spec.level.is_none() && spec.mute.is_none() {
+                bail!("Level rule needs a level to give lines or a level to mute at");
+            }
+            let pattern = spec
+                .pattern
+                .as_deref()
+                .map(|p| {
+                    Regex::new(p)
+                        .map_err(|e| anyhow::anyhow!("Invalid level rule pattern {:?}: {}", p, e))
+                })
+                .transpose()?;
+            rules.push(LevelRule {
+                pattern,
+                pod: spec.pod.clone(),
+                level: level(&spec.level)?,
+                mute: level(&spec.mute)?,
+            });
+        }
+        Ok(Self { rules })
+    }
+
+    /// The level the first matching relabel rule gives a line
+    pub fn relabel(&self, pod: &str, text: &str) -> Option<LogLevel> {
+        self.rules
+            .iter()
+            .filter(|r| r.level.is_some())
+            .find(|r| r.matches(pod, text))
+            .and_then(|r| r.level)
+    }
+
+    /// Whether a mute rule hides the entry
+    pub fn mutes(&self, entry: &LogEntry) -> bool {
+        self.rules.iter().any(|r| {
+            r.mute
+                .is_some_and(|max| entry.level.ordinal() <= max.ordinal())
+                && r.matches(entry.pod_name(), entry.display_text())
+        })
+    }
+}
+
+/// Compile the rules for the rest of the run
+pub fn init(specs: &[LevelRuleSpec]) -> Result<()> {
+    if specs.is_empty() {
+        return Ok(());
+    }
+    let _ = RULES.set(LevelRules::new(specs)?);
+    Ok(())
+}
+
+/// The level rules, if any are configured
+pub fn get() -> Option<&'static LevelRules> {
+    RULES.get()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logs::LogParser;
+
+    fn spec(
+        pattern: Option<&str>,
+        pod: Option<&str>,
+        level: Option<&str>,
+        mute: Option<&str>,
+    ) -> LevelRuleSpec {
+        LevelRuleSpec {
+            pattern: pattern.map(str::to_string),
+            pod: pod.map(str::to_string),
+            level: level.map(str::to_string),
+            mute: mute.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_level_rules_relabel_and_mute() {
+        let rules = LevelRules::new(&[
+            spec(Some("health ?check"), None, Some("trace"), None),
+            spec(None, Some("worker-"), None, Some("debug")),
+        ])
+        .unwrap();
+        assert_eq!(
+            rules.relabel("api-1", "INFO GET /health check ok"),
+            Some(LogLevel::Trace)
+        );
+        assert_eq!(rules.relabel("api-1", "INFO GET /orders"), None);
+
+        let debug = LogParser::parse("DEBUG polling queue", "worker-7", 1);
+        let warn = LogParser::parse("WARN queue is backing up", "worker-7", 2);
+        let other = LogParser::parse("DEBUG polling queue", "api-1", 1);
+        assert!(rules.mutes(&debug));
+        assert!(!rules.mutes(&warn));
+        assert!(!rules.mutes(&other));
+
+        assert!(LevelRules::new(&[spec(None, None, Some("trace"), None)]).is_err());
+        assert!(LevelRules::new(&[spec(Some("x"), None, None, None)]).is_err());
+        assert!(LevelRules::new(&[spec(Some("x"), None, Some("loud"), None)]).is_err());
+        assert!(LevelRules::new(&[spec(Some("("), None, Some("trace"), None)]).is_err());
+    }
+}
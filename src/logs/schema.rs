@@ -0,0 +1,279 @@
+//! Schema drift detection for JSON log lines
+//!
+//! Tracks the type each top-level key carries and flags when a key that has
+//! been steady for a while suddenly switches type or stops appearing - the
+//! usual sign of a deploy that changed its log format.
+
+use std::collections::{BTreeSet, HashMap};
+
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+
+use crate::types::LogEntry;
+
+/// Lines a key must keep one type (or keep appearing) before a change is drift
+const STEADY_LINES: u32 = 20;
+
+/// Consecutive JSON lines without a steady key before it counts as gone
+const MISSING_LINES: u32 = 50;
+
+/// Drift events kept; older ones are dropped
+const MAX_DRIFTS: usize = 200;
+
+/// The JSON type of a value
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum JsonType {
+    Null,
+    Bool,
+    Number,
+    String,
+    Array,
+    Object,
+}
+
+impl JsonType {
+    pub fn of(value: &Value) -> Self {
+        match value {
+            Value::Null => JsonType::Null,
+            Value::Bool(_) => JsonType::Bool,
+            Value::Number(_) => JsonType::Number,
+            Value::String(_) => JsonType::String,
+            Value::Array(_) => JsonType::Array,
+            Value::Object(_) => JsonType::Object,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            JsonType::Null => "null",
+            JsonType::Bool => "bool",
+            JsonType::Number => "number",
+            JsonType::String => "string",
+            JsonType::Array => "array",
+            JsonType::Object => "object",
+        }
+    }
+}
+
+/// What changed about a key
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DriftChange {
+    TypeChanged { from: JsonType, to: JsonType },
+    Disappeared,
+}
+
+/// A key that changed type or went missing
+#[derive(Clone, Debug, PartialEq)]
+pub struct SchemaDrift {
+    pub key: String,
+    pub change: DriftChange,
+    /// Pod whose line showed the change
+    pub pod: String,
+    pub at: DateTime<Utc>,
+}
+
+impl SchemaDrift {
+    /// One-line summary ("status: number → string")
+    pub fn describe(&self) -> String {
+        match self.change {
+            DriftChange::TypeChanged { from, to } => {
+                format!("{}: {} → {}", self.key, from.label(), to.label())
+            }
+            DriftChange::Disappeared => format!("{}: no longer logged", self.key),
+        }
+    }
+}
+
+/// Everything seen for one key, for the drift report
+#[derive(Clone, Debug, PartialEq)]
+pub struct KeySchema {
+    pub key: String,
+    /// Every non-null type the key has carried
+    pub types: Vec<JsonType>,
+    /// JSON lines carrying the key
+    pub seen: u64,
+    /// Flagged as gone and not seen since
+    pub missing: bool,
+}
+
+#[derive(Debug, Default)]
+struct KeyState {
+    /// Current type (nulls don't count, optional fields are often null)
+    kind: Option<JsonType>,
+    /// Lines in a row the key has carried `kind`
+    kind_run: u32,
+    /// Lines in a row the key has appeared in
+    present_run: u32,
+    /// The present run before the current absence
+    last_present_run: u32,
+    /// Lines in a row the key has been absent from
+    missing_run: u32,
+    types: BTreeSet<JsonType>,
+    seen: u64,
+    missing: bool,
+}
+
+/// Per-key types and the drift seen so far
+#[derive(Debug, Default)]
+pub struct SchemaTracker {
+    keys: HashMap<String, KeyState>,
+    drifts: Vec<SchemaDrift>,
+    /// Drift events recorded in total (including dropped ones)
+    total: usize,
+}
+
+impl SchemaTracker {
+    /// Record a line's keys and types (non-JSON lines are ignored)
+    pub fn observe(&mut self, entry: &LogEntry) {
+        let Some(fields) = &entry.fields else {
+            return;
+        };
+        let at = entry.timestamp.unwrap_or_else(Utc::now);
+        let mut found = Vec::new();
+
+        for (key, value) in fields {
+            let state = self.keys.entry(key.clone()).or_default();
+            state.seen += 1;
+            state.present_run += 1;
+            state.missing_run = 0;
+            state.missing = false;
+
+            let kind = JsonType::of(value);
+            if kind == JsonType::Null {
+                continue;
+            }
+            state.types.insert(kind);
+            match state.kind {
+                Some(prev) if prev == kind => state.kind_run += 1,
+                Some(prev) => {
+                    if state.kind_run >= STEADY_LINES {
+                        found.push((
+                            key.clone(),
+                            DriftChange::TypeChanged {
+                                from: prev,
+                                to: kind,
+                            },
+                        ));
+                    }
+                    state.kind = Some(kind);
+                    state.kind_run = 1;
+                }
+                None => {
+                    state.kind = Some(kind);
+                    state.kind_run = 1;
+                }
+            }
+        }
+
+        for (key, state) in &mut self.keys {
+            if fields.contains_key(key) {
+                continue;
+            }
+            if state.missing_run == 0 {
+                state.last_present_run = state.present_run;
+                state.present_run = 0;
+            }
+            state.missing_run += 1;
+            if !state.missing
+                && state.last_present_run >= STEADY_LINES
+                && state.missing_run >= MISSING_LINES
+            {
+                state.missing = true;
+                found.push((key.clone(), DriftChange::Disappeared));
+            }
+        }
+
+        for (key, change) in found {
+            self.total += 1;
+            if self.drifts.len() >= MAX_DRIFTS {
+                self.drifts.remove(0);
+            }
+            self.drifts.push(SchemaDrift {
+                key,
+                change,
+                pod: entry.pod_name().to_string(),
+                at,
+            });
+        }
+    }
+
+    /// Drift events recorded since the tracker started (or was cleared)
+    pub fn total(&self) -> usize {
+        self.total
+    }
+
+    /// Kept drift events, oldest first
+    pub fn drifts(&self) -> &[SchemaDrift] {
+        &self.drifts
+    }
+
+    /// Every key seen, sorted by name
+    pub fn key_schemas(&self) -> Vec<KeySchema> {
+        let mut keys: Vec<KeySchema> = self
+            .keys
+            .iter()
+            .map(|(key, state)| KeySchema {
+                key: key.clone(),
+                types: state.types.iter().copied().collect(),
+                seen: state.seen,
+                missing: state.missing,
+            })
+            .collect();
+        keys.sort_by(|a, b| a.key.cmp(&b.key));
+        keys
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logs::LogParser;
+
+    fn observe(tracker: &mut SchemaTracker, raw: &str) {
+        tracker.observe(&LogParser::parse(raw, "api-1", 1));
+    }
+
+    #[test]
+    fn test_type_change_after_steady_run_is_drift() {
+        let mut tracker = SchemaTracker::default();
+        observe(&mut tracker, r#"{"status": "200"}"#);
+        // A change before the key has settled isn't flagged
+        observe(&mut tracker, r#"{"status": 200}"#);
+        assert_eq!(tracker.total(), 0);
+
+        for _ in 0..STEADY_LINES {
+            observe(&mut tracker, r#"{"status": 200, "err": null}"#);
+        }
+        observe(&mut tracker, r#"{"status": "200", "err": "boom"}"#);
+        assert_eq!(tracker.total(), 1);
+        assert_eq!(tracker.drifts()[0].describe(), "status: number → string");
+        assert_eq!(tracker.drifts()[0].pod, "api-1");
+
+        let status = &tracker.key_schemas()[1];
+        assert_eq!(status.types, vec![JsonType::Number, JsonType::String]);
+    }
+
+    #[test]
+    fn test_steady_key_that_stops_appearing_is_drift() {
+        let mut tracker = SchemaTracker::default();
+        for _ in 0..STEADY_LINES {
+            observe(&mut tracker, r#"{"msg": "hi", "user": 1}"#);
+        }
+        for _ in 0..MISSING_LINES {
+            observe(&mut tracker, r#"{"msg": "hi"}"#);
+        }
+        assert_eq!(tracker.total(), 1);
+        assert_eq!(tracker.drifts()[0].change, DriftChange::Disappeared);
+        assert!(tracker.key_schemas()[1].missing);
+
+        // Only flagged once, and cleared when it comes back
+        observe(&mut tracker, r#"{"msg": "hi"}"#);
+        observe(&mut tracker, r#"{"msg": "hi", "user": 1}"#);
+        assert_eq!(tracker.total(), 1);
+        assert!(!tracker.key_schemas()[1].missing);
+    }
+}
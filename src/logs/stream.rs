@@ -1,15 +1,143 @@
+use chrono::{DateTime, Utc};
 use dashmap::DashMap;
-use futures::{AsyncBufReadExt, TryStreamExt};
+use futures::{AsyncBufRead, AsyncBufReadExt};
 use k8s_openapi::api::core::v1::Pod;
 use kube::Api;
 use kube::api::LogParams;
+use std::collections::HashMap;
 use std::sync::Arc;
-use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::time::Duration;
 use tokio::sync::mpsc;
 use tokio_util::sync::CancellationToken;
 
-use crate::logs::LogParser;
-use crate::types::{LogEntry, PodInfo};
+use crate::k8s::ApiError;
+use crate::logs::{IngestStats, LogParser, MAX_LINE_BYTES};
+use crate::types::{LogEntry, LogSource, PodInfo, SourceKind, TimeWindow};
+
+/// Bytes from the start of a truncated line compared when fetching it in full
+const FULL_LINE_MATCH_BYTES: usize = 256;
+
+/// A backfilling stream that goes this long without a line has caught up
+const BACKFILL_QUIET: Duration = Duration::from_secs(2);
+
+/// Bytes read from the start of a pod's log to find its oldest retained line
+const RETENTION_PROBE_BYTES: i64 = 4096;
+
+/// Leeway before a late first line counts as missing history, so quiet
+/// containers and clock skew don't raise false alarms
+const RETENTION_SLACK_SECS: i64 = 60;
+
+/// Service mesh sidecars, passed over unless `exclude_containers` says otherwise
+pub const MESH_SIDECARS: [&str; 2] = ["istio-proxy", "linkerd-proxy"];
+
+/// Older log lines fetched on demand for scrollback
+pub struct HistoryBatch {
+    /// Entries older than what was already buffered, sorted oldest first
+    pub entries: Vec<LogEntry>,
+    /// True when every pod returned less than requested (start of logs reached)
+    pub exhausted: bool,
+    /// A pod's reply was cut off by `limit_bytes`; its lines were discarded
+    /// rather than leave a gap before the buffered ones
+    pub limited: bool,
+}
+
+/// How far back a history fetch reads
+#[derive(Clone, Copy, Debug)]
+pub enum HistoryWindow {
+    /// The last N lines of each pod (scrollback)
+    Tail(i64),
+    /// Every line from `since_seconds` ago (widening the time range)
+    ///
+    /// `floor` is the start of the range already streamed; it stands in as
+    /// the cutoff for pods that haven't logged anything yet.
+    Since {
+        since_seconds: i64,
+        floor: DateTime<Utc>,
+    },
+}
+
+/// A pod whose retained log starts after the start of the requested range
+#[derive(Clone, Debug, PartialEq)]
+pub struct RetentionGap {
+    pub pod: String,
+    /// Timestamp of the oldest line the kubelet still has
+    pub oldest: DateTime<Utc>,
+}
+
+/// Where a set of streamed pods comes from; recorded on every entry's
+/// `LogSource` so merged views stay attributable
+#[derive(Clone, Copy, Debug)]
+pub struct StreamSource<'a> {
+    pub namespace: &'a str,
+    /// Workload (deployment) owning the pods
+    pub workload: Option<&'a str>,
+}
+
+/// How far a pod's initial backfill (the tail or time range before live lines) has got
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Backfill {
+    /// Waiting for the API server to open the stream
+    Connecting,
+    /// Historical lines received so far
+    Loading(u64),
+    /// Caught up to live lines, after this many historical ones
+    Done(u64),
+    /// The stream couldn't be opened
+    Failed,
+}
+
+/// The log request a follow stream was opened with, for the stream parameters overlay
+#[derive(Clone, Debug)]
+pub struct StreamParams {
+    pub pod: String,
+    /// Streamed as a sidecar source next to the app container
+    pub sidecar: bool,
+    pub params: LogParams,
+}
+
+impl StreamParams {
+    /// Where the stream starts and whether it follows, e.g. `tailLines 100, follow, timestamps`
+    pub fn summary(&self) -> String {
+        let params = &self.params;
+        let mut parts = Vec::new();
+        if let Some(since) = params.since_time {
+            parts.push(format!("sinceTime {}", since.format("%Y-%m-%dT%H:%M:%SZ")));
+        } else if let Some(seconds) = params.since_seconds {
+            parts.push(format!("sinceSeconds {}", seconds));
+        } else if let Some(lines) = params.tail_lines {
+            parts.push(format!("tailLines {}", lines));
+        } else {
+            parts.push("whole log".to_string());
+        }
+        if let Some(bytes) = params.limit_bytes {
+            parts.push(format!("limitBytes {}", bytes));
+        }
+        parts.push(if params.follow { "follow" } else { "no follow" }.to_string());
+        if params.timestamps {
+            parts.push("timestamps".to_string());
+        }
+        if params.previous {
+            parts.push("previous".to_string());
+        }
+        parts.join(", ")
+    }
+}
+
+/// Where a follow stream starts reading
+#[derive(Clone, Copy, Debug)]
+enum StreamStart {
+    /// The last lines, or the last seconds, of the log
+    Recent {
+        tail_lines: Option<i64>,
+        since_seconds: Option<i64>,
+    },
+    /// From a point in time, skipping lines up to `seen` (already buffered)
+    After {
+        since: DateTime<Utc>,
+        seen: Option<DateTime<Utc>>,
+    },
+}
 
 /// Manages log streaming from multiple pods
 pub struct LogStreamManager {
@@ -20,124 +148,450 @@ pub struct LogStreamManager {
     tasks: Vec<tokio::task::JoinHandle<()>>,
 
     /// Line counter per pod (for line numbers) - lock-free concurrent map
-    line_counters: Arc<DashMap<String, AtomicU64>>,
+    line_counters: Arc<DashMap<String, AtomicI64>>,
+
+    /// Backfill progress per pod
+    backfill: Arc<DashMap<String, Backfill>>,
+
+    /// Log request each stream was opened with, by stream key
+    params: Arc<DashMap<String, StreamParams>>,
+
+    /// Pods whose stream was refused because the credentials expired, and
+    /// where each would have started
+    expired: Arc<DashMap<String, StreamStart>>,
+
+    /// Shared counters for dropped logs due to backpressure
+    ingest: Arc<IngestStats>,
+
+    /// Lines are cut to this many bytes as they are read
+    max_line_bytes: usize,
 
-    /// Counter for dropped logs due to backpressure
-    dropped_count: Arc<AtomicU64>,
+    /// Cap on the bytes of each one-shot history read (`limitBytes`)
+    limit_bytes: Option<i64>,
+
+    /// Clients for the extra clusters of a multi-cluster view, by context;
+    /// pods tagged with a cluster are read through these
+    clusters: HashMap<String, kube::Client>,
+
+    /// Fixed window from `--since`/`--until`; overrides the start of new streams
+    window: TimeWindow,
+
+    /// Containers passed over when picking the one to read (mesh sidecars)
+    excluded_containers: Vec<String>,
+
+    /// Also follow the excluded containers a pod runs, as sidecar sources
+    sidecar_logs: bool,
 }
 
 impl LogStreamManager {
-    /// Create a new log stream manager
-    pub fn new() -> Self {
+    /// Create a new log stream manager recording drops into the given counters
+    pub fn new(ingest: Arc<IngestStats>, max_line_bytes: usize, limit_bytes: Option<i64>) -> Self {
         Self {
             cancel: CancellationToken::new(),
             tasks: Vec::new(),
             line_counters: Arc::new(DashMap::new()),
-            dropped_count: Arc::new(AtomicU64::new(0)),
+            backfill: Arc::new(DashMap::new()),
+            params: Arc::new(DashMap::new()),
+            expired: Arc::new(DashMap::new()),
+            ingest,
+            max_line_bytes,
+            limit_bytes,
+            clusters: HashMap::new(),
+            window: TimeWindow::default(),
+            excluded_containers: Vec::new(),
+            sidecar_logs: false,
+        }
+    }
+
+    /// Read new streams over `window` instead of their tail or time range
+    ///
+    /// Streams start at `since`, and lines stamped after `until` are left out.
+    /// A window that ended in the past isn't followed, so its streams finish.
+    pub fn set_window(&mut self, window: TimeWindow) {
+        self.window = window;
+    }
+
+    /// Read each pod's first container not named in `excluded` in new streams
+    pub fn set_excluded_containers(&mut self, excluded: Vec<String>) {
+        self.excluded_containers = excluded;
+    }
+
+    pub fn excluded_containers(&self) -> &[String] {
+        &self.excluded_containers
+    }
+
+    /// Follow excluded containers (the mesh proxy's access log) next to the
+    /// app container in new streams, tagged as sidecar lines
+    pub fn set_sidecar_logs(&mut self, enabled: bool) {
+        self.sidecar_logs = enabled;
+    }
+
+    pub fn sidecar_logs(&self) -> bool {
+        self.sidecar_logs
+    }
+
+    /// The excluded containers of a pod followed as sidecar sources
+    fn sidecars<'a>(&self, pod: &'a PodInfo) -> Vec<&'a str> {
+        if !self.sidecar_logs {
+            return Vec::new();
         }
+        let read = pod
+            .log_container(&self.excluded_containers)
+            .map(|c| &c.name);
+        pod.containers
+            .iter()
+            .filter(|c| Some(&c.name) != read && self.excluded_containers.contains(&c.name))
+            .map(|c| c.name.as_str())
+            .collect()
+    }
+
+    /// Read pods tagged with `context` through `client` from now on
+    pub fn add_cluster(&mut self, context: &str, client: kube::Client) {
+        self.clusters.insert(context.to_string(), client);
     }
 
-    /// Get the number of dropped logs due to backpressure
-    pub fn dropped_count(&self) -> u64 {
-        self.dropped_count.load(Ordering::Relaxed)
+    /// Client for a pod's cluster, or `default` for the selected context
+    pub fn client_for(&self, cluster: Option<&str>, default: &kube::Client) -> kube::Client {
+        cluster
+            .and_then(|c| self.clusters.get(c))
+            .unwrap_or(default)
+            .clone()
+    }
+
+    fn pods_api(&self, client: &kube::Client, namespace: &str, pod: &PodInfo) -> Api<Pod> {
+        Api::namespaced(self.client_for(pod.cluster.as_deref(), client), namespace)
+    }
+
+    /// Compute how many historical lines to request from each pod
+    ///
+    /// With a `budget`, the total is divided evenly across pods. Independently of
+    /// the budget, the combined backfill is capped at `buffer_size` so a wide
+    /// deployment can't evict its own history before the user sees it.
+    /// Always requests at least one line per pod.
+    pub fn per_pod_tail_lines(
+        tail_lines: i64,
+        budget: Option<i64>,
+        pod_count: usize,
+        buffer_size: usize,
+    ) -> i64 {
+        let pods = pod_count.max(1) as i64;
+        let mut per_pod = tail_lines;
+
+        if let Some(budget) = budget {
+            per_pod = per_pod.min(budget / pods);
+        }
+        per_pod = per_pod.min(buffer_size as i64 / pods);
+
+        per_pod.max(1)
     }
 
     /// Start streaming logs from all pods
     pub fn start_streams(
         &mut self,
         client: kube::Client,
-        namespace: &str,
+        source: StreamSource,
         pods: &[PodInfo],
         log_tx: mpsc::Sender<LogEntry>,
         tail_lines: Option<i64>,
         since_seconds: Option<i64>,
     ) {
-        let pods_api: Api<Pod> = Api::namespaced(client, namespace);
-
         for pod in pods {
             // Initialize line counter for this pod (lock-free)
             self.line_counters
-                .insert(pod.name.clone(), AtomicU64::new(0));
+                .insert(pod.name.clone(), AtomicI64::new(0));
 
-            let task = self.spawn_pod_stream(
-                pods_api.clone(),
-                pod.name.clone(),
-                pod.containers.first().map(|c| c.name.clone()),
-                log_tx.clone(),
+            let start = StreamStart::Recent {
                 tail_lines,
                 since_seconds,
-            );
+            };
+            let api = self.pods_api(&client, source.namespace, pod);
+            for sidecar in self.sidecars(pod) {
+                let task = self.spawn_pod_stream(
+                    api.clone(),
+                    pod,
+                    Some(sidecar),
+                    source,
+                    log_tx.clone(),
+                    start,
+                );
+                self.tasks.push(task);
+            }
+            let task = self.spawn_pod_stream(api, pod, None, source, log_tx.clone(), start);
             self.tasks.push(task);
         }
     }
 
+    /// Restart streams stopped with `stop`, picking up where each pod left off
+    ///
+    /// Each pod is read with `sinceTime` from its newest buffered line, or from
+    /// `stopped_at` if it had none. `sinceTime` only has second precision, so
+    /// lines up to the newest buffered timestamp are skipped as already seen.
+    /// Line numbers carry on from the newest buffered line.
+    pub fn resume_streams(
+        &mut self,
+        client: kube::Client,
+        source: StreamSource,
+        pods: &[PodInfo],
+        log_tx: mpsc::Sender<LogEntry>,
+        stopped_at: DateTime<Utc>,
+        newest: HashMap<String, (DateTime<Utc>, i64)>,
+    ) {
+        for pod in pods {
+            let api = self.pods_api(&client, source.namespace, pod);
+            let sidecars = self.sidecars(pod).into_iter().map(Some);
+            for sidecar in std::iter::once(None).chain(sidecars) {
+                let key = stream_key(pod, sidecar);
+                let last = newest.get(&key).copied();
+                let line = last.map_or(0, |(_, line)| line);
+                self.line_counters.insert(key, AtomicI64::new(line));
+
+                let seen = last.map(|(ts, _)| ts);
+                let start = StreamStart::After {
+                    since: seen.unwrap_or(stopped_at),
+                    seen,
+                };
+                let task =
+                    self.spawn_pod_stream(api.clone(), pod, sidecar, source, log_tx.clone(), start);
+                self.tasks.push(task);
+            }
+        }
+    }
+
+    /// Whether any stream was refused with expired credentials
+    pub fn has_expired(&self) -> bool {
+        !self.expired.is_empty()
+    }
+
+    /// Retry the streams refused with expired credentials, once the client
+    /// has been rebuilt
+    ///
+    /// Each pod starts where it would have, and keeps its line numbering.
+    /// Pods no longer in `pods` are forgotten.
+    pub fn restart_expired(
+        &mut self,
+        client: kube::Client,
+        source: StreamSource,
+        pods: &[PodInfo],
+        log_tx: mpsc::Sender<LogEntry>,
+    ) {
+        for pod in pods {
+            let sidecars = self.sidecars(pod).into_iter().map(Some);
+            for sidecar in std::iter::once(None).chain(sidecars) {
+                if let Some((_, start)) = self.expired.remove(&stream_key(pod, sidecar)) {
+                    let api = self.pods_api(&client, source.namespace, pod);
+                    let task =
+                        self.spawn_pod_stream(api, pod, sidecar, source, log_tx.clone(), start);
+                    self.tasks.push(task);
+                }
+            }
+        }
+        self.expired.clear();
+    }
+
+    /// Give up on the streams refused with expired credentials
+    pub fn forget_expired(&mut self) {
+        self.expired.retain(|key, _| {
+            // Sidecar streams (`pod/container`) have no backfill to fail
+            if !key.contains('/') {
+                self.backfill.insert(key.clone(), Backfill::Failed);
+            }
+            false
+        });
+    }
+
+    /// Follow a pod's log container, or with `sidecar` that container instead
+    ///
+    /// Sidecar streams count lines and wait out expired credentials under
+    /// `pod/container`, and don't report backfill, which is per pod.
     fn spawn_pod_stream(
         &self,
         api: Api<Pod>,
-        pod_name: String,
-        container: Option<String>,
+        pod: &PodInfo,
+        sidecar: Option<&str>,
+        source: StreamSource,
         log_tx: mpsc::Sender<LogEntry>,
-        tail_lines: Option<i64>,
-        since_seconds: Option<i64>,
+        start: StreamStart,
     ) -> tokio::task::JoinHandle<()> {
+        let pod_name = pod.name.clone();
+        let key = stream_key(pod, sidecar);
+        let container = match sidecar {
+            Some(name) => Some(name.to_string()),
+            None => pod
+                .log_container(&self.excluded_containers)
+                .map(|c| c.name.clone()),
+        };
+        let node_name = pod.node_name.clone();
+        let log_source = Arc::new(LogSource {
+            namespace: Some(source.namespace.to_string()),
+            workload: source.workload.map(str::to_string),
+            pod: pod_name.clone(),
+            container: container.clone(),
+            kind: if sidecar.is_some() {
+                SourceKind::Sidecar
+            } else {
+                SourceKind::Live
+            },
+            cluster: pod.cluster.clone(),
+        });
         let cancel = self.cancel.clone();
         let line_counters = Arc::clone(&self.line_counters);
-        let dropped_count = Arc::clone(&self.dropped_count);
+        let backfill = if sidecar.is_some() {
+            Arc::new(DashMap::new())
+        } else {
+            Arc::clone(&self.backfill)
+        };
+        backfill.insert(pod_name.clone(), Backfill::Connecting);
+        let expired = Arc::clone(&self.expired);
+        let stream_params = Arc::clone(&self.params);
+        let is_sidecar = sidecar.is_some();
+        let ingest = Arc::clone(&self.ingest);
+        let max_line_bytes = self.max_line_bytes;
+        let window = self.window;
 
         tokio::spawn(async move {
-            let params = LogParams {
-                follow: true,
+            let mut params = LogParams {
+                follow: window.until.is_none_or(|until| until > Utc::now()),
                 container,
-                // Use since_seconds if provided, otherwise use tail_lines
-                tail_lines: if since_seconds.is_some() {
-                    None
-                } else {
-                    tail_lines
-                },
-                since_seconds,
                 timestamps: true,
                 ..Default::default()
             };
+            let mut seen = None;
+            match start {
+                StreamStart::Recent {
+                    tail_lines,
+                    since_seconds,
+                } => {
+                    // Use since_seconds if provided, otherwise use tail_lines
+                    params.tail_lines = if since_seconds.is_some() {
+                        None
+                    } else {
+                        tail_lines
+                    };
+                    params.since_seconds = since_seconds;
+                }
+                StreamStart::After { since, seen: last } => {
+                    params.since_time = Some(since);
+                    seen = last;
+                }
+            }
+            if let Some(since) = window.since
+                && params.since_time.is_none_or(|resume| resume < since)
+            {
+                params.tail_lines = None;
+                params.since_seconds = None;
+                params.since_time = Some(since);
+            }
+            stream_params.insert(
+                key.clone(),
+                StreamParams {
+                    pod: pod_name.clone(),
+                    sidecar: is_sidecar,
+                    params: params.clone(),
+                },
+            );
 
             match api.log_stream(&pod_name, &params).await {
                 Ok(stream) => {
-                    let mut lines = stream.lines();
+                    tracing::debug!(pod = %pod_name, ?start, "log stream started");
+                    // Lines stamped before the stream opened are backfill
+                    let opened = Utc::now();
+                    let mut backfilled = Some(0);
+                    backfill.insert(pod_name.clone(), Backfill::Loading(0));
+                    // Read raw bytes so a line with invalid UTF-8 doesn't end the stream
+                    let mut stream = std::pin::pin!(stream);
+                    // The line being read survives the quiet timer firing mid-line;
+                    // finished lines are swapped out into `finished`
+                    let mut buf = Vec::new();
+                    let mut cut = 0;
+                    let mut finished = Vec::new();
 
                     loop {
                         tokio::select! {
                             _ = cancel.cancelled() => break,
 
-                            result = lines.try_next() => {
+                            _ = tokio::time::sleep(BACKFILL_QUIET), if backfilled.is_some() => {
+                                if let Some(lines) = backfilled.take() {
+                                    backfill.insert(pod_name.clone(), Backfill::Done(lines));
+                                }
+                                continue;
+                            }
+
+                            result = read_line_capped(&mut stream, &mut buf, &mut cut, max_line_bytes) => {
                                 match result {
-                                    Ok(Some(line)) => {
-                                        // Increment line counter (lock-free via DashMap)
-                                        let line_number = line_counters
-                                            .entry(pod_name.clone())
-                                            .or_insert_with(|| AtomicU64::new(0))
-                                            .fetch_add(1, Ordering::Relaxed) + 1;
+                                    Ok(false) => {
+                                        // Stream ended (pod terminated?)
+                                        tracing::debug!(pod = %pod_name, "log stream ended");
+                                        if let Some(lines) = backfilled {
+                                            backfill.insert(pod_name.clone(), Backfill::Done(lines));
+                                        }
+                                        break;
+                                    }
+                                    Ok(true) => {
+                                        std::mem::swap(&mut buf, &mut finished);
+                                        buf.clear();
+                                        let dropped = std::mem::take(&mut cut);
+                                        // Don't leave half a character where the line was cut
+                                        let line = if dropped > 0 {
+                                            floor_utf8(&finished)
+                                        } else {
+                                            trim_line_end(&finished)
+                                        };
+                                        let dropped = dropped + finished.len() - line.len();
 
                                         // Parse the log line
-                                        let entry = LogParser::parse(&line, &pod_name, line_number);
+                                        let mut entry = LogParser::parse_bytes(line, &log_source, 0, max_line_bytes);
+                                        // Lines come in order, so one past the window ends it
+                                        if window.is_after(entry.timestamp) {
+                                            tracing::debug!(pod = %pod_name, "log stream reached the end of the window");
+                                            if let Some(lines) = backfilled {
+                                                backfill.insert(pod_name.clone(), Backfill::Done(lines));
+                                            }
+                                            break;
+                                        }
+                                        // Skip what a resumed stream re-reads; later lines are all new
+                                        if let Some(last) = seen {
+                                            if entry.timestamp.is_some_and(|ts| ts <= last) {
+                                                continue;
+                                            }
+                                            seen = None;
+                                        }
+                                        if let Some(lines) = backfilled.as_mut() {
+                                            if entry.timestamp.is_some_and(|ts| ts < opened) {
+                                                *lines += 1;
+                                                backfill.insert(pod_name.clone(), Backfill::Loading(*lines));
+                                            } else {
+                                                backfill.insert(pod_name.clone(), Backfill::Done(*lines));
+                                                backfilled = None;
+                                            }
+                                        }
+                                        // Increment line counter (lock-free via DashMap)
+                                        entry.line_number = line_counters
+                                            .entry(key.clone())
+                                            .or_insert_with(|| AtomicI64::new(0))
+                                            .fetch_add(1, Ordering::Relaxed) + 1;
+                                        entry.node_name = node_name.clone();
+                                        if dropped > 0 {
+                                            entry.truncated = true;
+                                            entry.truncated_bytes += dropped;
+                                        }
 
                                         // Send to channel with backpressure handling
                                         match log_tx.try_send(entry) {
                                             Ok(()) => {}
                                             Err(mpsc::error::TrySendError::Full(_)) => {
                                                 // Channel full - drop log and increment counter
-                                                dropped_count.fetch_add(1, Ordering::Relaxed);
+                                                ingest.record_dropped();
                                             }
                                             Err(mpsc::error::TrySendError::Closed(_)) => {
                                                 // Channel closed, stop streaming
+                                                tracing::debug!(pod = %pod_name, "log channel closed");
                                                 break;
                                             }
                                         }
                                     }
-                                    Ok(None) => {
-                                        // Stream ended (pod terminated?)
-                                        break;
-                                    }
-                                    Err(_) => {
+                                    Err(e) => {
                                         // Error reading stream
+                                        tracing::warn!(pod = %pod_name, error = %e, "log stream failed");
                                         break;
                                     }
                                 }
@@ -145,13 +599,140 @@ impl LogStreamManager {
                         }
                     }
                 }
-                Err(_) => {
+                Err(e) if ApiError::from_error(&e) == Some(ApiError::AuthExpired) => {
+                    // Left connecting; restarted once the client has new credentials
+                    tracing::info!(pod = %pod_name, "log stream refused, credentials expired");
+                    expired.insert(key, start);
+                }
+                Err(e) => {
                     // Failed to start log stream
+                    tracing::warn!(pod = %pod_name, error = %e, "failed to start log stream");
+                    backfill.insert(pod_name.clone(), Backfill::Failed);
                 }
             }
         })
     }
 
+    /// Fetch older history for all pods (one-shot, non-follow request)
+    ///
+    /// Each pod is re-read over `window` and only lines strictly older than the
+    /// oldest buffered timestamp for that pod are kept. With a tail window, pods
+    /// without any buffered timestamp are skipped to avoid duplicating lines. Kept
+    /// lines are numbered backwards from the oldest buffered line so per-pod
+    /// numbering stays contiguous.
+    ///
+    /// Reads are capped at `limit_bytes` when set. Follow streams aren't, since
+    /// the API server ends a followed stream once the limit is reached.
+    pub fn fetch_history(
+        &mut self,
+        client: kube::Client,
+        source: StreamSource,
+        pods: &[PodInfo],
+        history_tx: mpsc::UnboundedSender<HistoryBatch>,
+        window: HistoryWindow,
+        oldest: HashMap<String, (DateTime<Utc>, i64)>,
+    ) {
+        let cancel = self.cancel.clone();
+        let max_line_bytes = self.max_line_bytes;
+        let limit_bytes = self.limit_bytes;
+        let pods: Vec<(Api<Pod>, Arc<LogSource>, Option<String>)> = pods
+            .iter()
+            .map(|p| {
+                let log_source = LogSource {
+                    namespace: Some(source.namespace.to_string()),
+                    workload: source.workload.map(str::to_string),
+                    pod: p.name.clone(),
+                    container: p
+                        .log_container(&self.excluded_containers)
+                        .map(|c| c.name.clone()),
+                    kind: SourceKind::History,
+                    cluster: p.cluster.clone(),
+                };
+                let api = self.pods_api(&client, source.namespace, p);
+                (api, Arc::new(log_source), p.node_name.clone())
+            })
+            .collect();
+
+        let task = tokio::spawn(async move {
+            let fetches = pods.into_iter().map(|(api, log_source, node_name)| {
+                let cutoff = oldest.get(&log_source.pod).copied();
+                async move {
+                    // Live numbering starts at 1, so an empty pod's history ends at 0
+                    let cutoff = match (cutoff, window) {
+                        (Some(cutoff), _) => Some(cutoff),
+                        (None, HistoryWindow::Since { floor, .. }) => Some((floor, 1)),
+                        (None, HistoryWindow::Tail(_)) => None,
+                    };
+                    let Some((cutoff, first_line)) = cutoff else {
+                        return (Vec::new(), true, false);
+                    };
+                    let (tail_lines, since_seconds) = match window {
+                        HistoryWindow::Tail(lines) => (Some(lines), None),
+                        HistoryWindow::Since { since_seconds, .. } => (None, Some(since_seconds)),
+                    };
+
+                    let params = LogParams {
+                        follow: false,
+                        container: log_source.container.clone(),
+                        tail_lines,
+                        since_seconds,
+                        limit_bytes,
+                        timestamps: true,
+                        ..Default::default()
+                    };
+
+                    match api.logs(&log_source.pod, &params).await {
+                        // The cut keeps the oldest part of the tail, which doesn't
+                        // reach the buffered lines
+                        Ok(text) if limit_bytes.is_some_and(|l| text.len() as i64 >= l) => {
+                            (Vec::new(), true, true)
+                        }
+                        Ok(text) => {
+                            let fetched = text.lines().count();
+                            let mut entries: Vec<LogEntry> = text
+                                .lines()
+                                .map(|line| {
+                                    LogParser::parse_from(line, &log_source, 0, max_line_bytes)
+                                })
+                                .filter(|e| e.timestamp.is_some_and(|ts| ts < cutoff))
+                                .map(|mut e| {
+                                    e.node_name = node_name.clone();
+                                    e
+                                })
+                                .collect();
+                            number_before(&mut entries, first_line);
+                            // A time window is read whole in one go
+                            let done = tail_lines.is_none_or(|lines| (fetched as i64) < lines);
+                            (entries, done, false)
+                        }
+                        Err(_) => (Vec::new(), true, false),
+                    }
+                }
+            });
+
+            let results = tokio::select! {
+                _ = cancel.cancelled() => return,
+                results = futures::future::join_all(fetches) => results,
+            };
+
+            let exhausted = results.iter().all(|(_, done, _)| *done);
+            let limited = results.iter().any(|(_, _, limited)| *limited);
+            let mut entries: Vec<LogEntry> = results
+                .into_iter()
+                .flat_map(|(entries, _, _)| entries)
+                .collect();
+            // Interleave pods chronologically (stable, so per-pod order is preserved)
+            entries.sort_by_key(|e| e.timestamp);
+
+            let _ = history_tx.send(HistoryBatch {
+                entries,
+                exhausted,
+                limited,
+            });
+        });
+        self.tasks.push(task);
+    }
+
     /// Stop all streams
     pub fn stop(&mut self) {
         self.cancel.cancel();
@@ -159,10 +740,28 @@ impl LogStreamManager {
             task.abort();
         }
         self.line_counters.clear();
+        self.backfill.clear();
+        self.params.clear();
+        self.expired.clear();
         // Create a fresh cancellation token for future streams
         self.cancel = CancellationToken::new();
     }
 
+    /// The log request of every stream opened, app containers first, by pod
+    pub fn stream_params(&self) -> Vec<StreamParams> {
+        let mut params: Vec<StreamParams> = self.params.iter().map(|p| p.value().clone()).collect();
+        params.sort_by(|a, b| (&a.pod, a.sidecar).cmp(&(&b.pod, b.sidecar)));
+        params
+    }
+
+    /// Backfill progress of every streamed pod
+    pub fn backfill(&self) -> HashMap<String, Backfill> {
+        self.backfill
+            .iter()
+            .map(|entry| (entry.key().clone(), *entry.value()))
+            .collect()
+    }
+
     /// Check if any streams are still running
     #[allow(dead_code)]
     pub fn is_running(&self) -> bool {
@@ -176,9 +775,183 @@ impl LogStreamManager {
     }
 }
 
+/// Re-read a line that was truncated at ingest, in full
+///
+/// Lines are found by their API timestamp, so the entry needs one. Returns the
+/// line sanitized for display.
+pub async fn fetch_full_line(client: kube::Client, entry: &LogEntry) -> anyhow::Result<String> {
+    let (Some(timestamp), Some(namespace)) = (entry.timestamp, &entry.source.namespace) else {
+        anyhow::bail!("Line has no timestamp to look it up by");
+    };
+    // Match on the start of the line, or just its timestamp if it was sanitized
+    let stamp = entry.raw.split(' ').next().unwrap_or_default();
+    let prefix = if entry.sanitized {
+        stamp
+    } else {
+        &entry.raw[..floor_char_boundary(&entry.raw, FULL_LINE_MATCH_BYTES)]
+    };
+
+    let api: Api<Pod> = Api::namespaced(client, namespace);
+    let params = LogParams {
+        follow: false,
+        container: entry.source.container.clone(),
+        since_time: Some(timestamp),
+        timestamps: true,
+        ..Default::default()
+    };
+    let stream = api.log_stream(entry.pod_name(), &params).await?;
+    let mut stream = std::pin::pin!(stream);
+    let mut buf = Vec::new();
+    loop {
+        buf.clear();
+        if stream.read_until(b'\n', &mut buf).await? == 0 {
+            break;
+        }
+        let line = trim_line_end(buf.strip_suffix(b"\n").unwrap_or(&buf));
+        if line.starts_with(prefix.as_bytes()) {
+            return Ok(LogParser::clean_line(line));
+        }
+        // Past the line's timestamp without a match
+        if line_timestamp(line).is_some_and(|ts| ts > timestamp) {
+            break;
+        }
+    }
+    anyhow::bail!("Line is no longer in the pod's log (rotated or container restarted)")
+}
+
+/// Find pods whose log no longer reaches back to `since`
+///
+/// Reads the first few KiB of each running container's log for the oldest line
+/// the kubelet kept. A pod counts when that line is later than `since` even
+/// though the container was already running, meaning older lines were rotated
+/// away. Pods that can't be read are skipped.
+pub async fn find_retention_gaps(
+    client: kube::Client,
+    namespace: &str,
+    pods: &[PodInfo],
+    since: DateTime<Utc>,
+    excluded_containers: &[String],
+) -> Vec<RetentionGap> {
+    let api: Api<Pod> = Api::namespaced(client, namespace);
+    let probes = pods.iter().filter_map(|pod| {
+        let container = pod.log_container(excluded_containers)?;
+        let started = container.started_at?;
+        let params = LogParams {
+            follow: false,
+            container: Some(container.name.clone()),
+            limit_bytes: Some(RETENTION_PROBE_BYTES),
+            timestamps: true,
+            ..Default::default()
+        };
+        let api = api.clone();
+        Some(async move {
+            let text = api.logs(&pod.name, &params).await.ok()?;
+            let oldest = line_timestamp(text.lines().next()?.as_bytes())?;
+            is_retention_gap(oldest, since, started).then(|| RetentionGap {
+                pod: pod.name.clone(),
+                oldest,
+            })
+        })
+    });
+    futures::future::join_all(probes)
+        .await
+        .into_iter()
+        .flatten()
+        .collect()
+}
+
+/// Whether a log starting at `oldest` is missing lines asked for from `since`
+fn is_retention_gap(oldest: DateTime<Utc>, since: DateTime<Utc>, started: DateTime<Utc>) -> bool {
+    let slack = chrono::Duration::seconds(RETENTION_SLACK_SECS);
+    // Lines before the container started never existed, so only the part of
+    // the range the container was up for can be missing
+    oldest > since.max(started) + slack
+}
+
+/// The API timestamp at the start of a line
+fn line_timestamp(line: &[u8]) -> Option<DateTime<Utc>> {
+    let stamp = line.split(|&b| b == b' ').next()?;
+    let stamp = std::str::from_utf8(stamp).ok()?;
+    DateTime::parse_from_rfc3339(stamp)
+        .ok()
+        .map(|ts| ts.with_timezone(&Utc))
+}
+
+/// Read the rest of a line into `buf` without its newline, keeping at most `max_bytes`
+///
+/// The rest of an overlong line is read and discarded, counted in `dropped`.
+/// Returns whether a line is complete (a last line without a newline is at
+/// the end of the stream), or false at the end of the stream with nothing read.
+///
+/// Cancel safe: bytes are only consumed once they're in `buf` or counted in
+/// `dropped`, so a read dropped partway (by a `select!` timer) carries on
+/// where it stopped when called again with the same `buf` and `dropped`.
+async fn read_line_capped<R: AsyncBufRead + Unpin>(
+    reader: &mut R,
+    buf: &mut Vec<u8>,
+    dropped: &mut usize,
+    max_bytes: usize,
+) -> std::io::Result<bool> {
+    loop {
+        let available = reader.fill_buf().await?;
+        if available.is_empty() {
+            return Ok(!buf.is_empty() || *dropped > 0);
+        }
+        let newline = available.iter().position(|&b| b == b'\n');
+        let line_part = &available[..newline.unwrap_or(available.len())];
+        let keep = line_part.len().min(max_bytes.saturating_sub(buf.len()));
+        buf.extend_from_slice(&line_part[..keep]);
+        *dropped += line_part.len() - keep;
+        let used = newline.map_or(available.len(), |i| i + 1);
+        reader.consume_unpin(used);
+        if newline.is_some() {
+            return Ok(true);
+        }
+    }
+}
+
+/// What a pod's stream is tracked under: the pod, or `pod/container` for a
+/// sidecar (the `LogSource::stream_key` of its lines)
+fn stream_key(pod: &PodInfo, sidecar: Option<&str>) -> String {
+    match sidecar {
+        Some(container) => format!("{}/{}", pod.name, container),
+        None => pod.name.clone(),
+    }
+}
+
+/// Drop an incomplete UTF-8 sequence left at the end by a cut
+fn floor_utf8(bytes: &[u8]) -> &[u8] {
+    match std::str::from_utf8(bytes) {
+        Err(e) if e.error_len().is_none() => &bytes[..e.valid_up_to()],
+        _ => bytes,
+    }
+}
+
+/// A line without its trailing "\r" (the newline is already gone)
+fn trim_line_end(line: &[u8]) -> &[u8] {
+    line.strip_suffix(b"\r").unwrap_or(line)
+}
+
+/// Largest char boundary at or before `idx`
+fn floor_char_boundary(s: &str, mut idx: usize) -> usize {
+    idx = idx.min(s.len());
+    while !s.is_char_boundary(idx) {
+        idx -= 1;
+    }
+    idx
+}
+
+/// Number entries (oldest first) so the last one directly precedes `first_line`
+fn number_before(entries: &mut [LogEntry], first_line: i64) {
+    let count = entries.len() as i64;
+    for (i, entry) in entries.iter_mut().enumerate() {
+        entry.line_number = first_line - count + i as i64;
+    }
+}
+
 impl Default for LogStreamManager {
     fn default() -> Self {
-        Self::new()
+        Self::new(Arc::default(), MAX_LINE_BYTES, None)
     }
 }
 
@@ -187,3 +960,152 @@ impl Drop for LogStreamManager {
         self.stop();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stream_params_summary() {
+        let mut stream = StreamParams {
+            pod: "api-1".into(),
+            sidecar: false,
+            params: LogParams {
+                follow: true,
+                tail_lines: Some(100),
+                timestamps: true,
+                ..Default::default()
+            },
+        };
+        assert_eq!(stream.summary(), "tailLines 100, follow, timestamps");
+
+        stream.params.since_seconds = Some(900);
+        stream.params.follow = false;
+        assert_eq!(stream.summary(), "sinceSeconds 900, no follow, timestamps");
+    }
+
+    #[test]
+    fn test_retention_gap_needs_container_older_than_log() {
+        let at = |s: &str| DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc);
+        let since = at("2024-01-15T10:00:00Z");
+        let oldest = at("2024-01-15T10:30:00Z");
+
+        // Running since before the range, but the log starts half an hour in
+        assert!(is_retention_gap(oldest, since, at("2024-01-14T00:00:00Z")));
+        // Started late: nothing older was ever logged
+        assert!(!is_retention_gap(oldest, since, at("2024-01-15T10:29:30Z")));
+        // The log reaches back to the start of the range (within the slack)
+        assert!(!is_retention_gap(
+            at("2024-01-15T10:00:40Z"),
+            since,
+            at("2024-01-14T00:00:00Z")
+        ));
+
+        assert_eq!(
+            line_timestamp(b"2024-01-15T10:30:00.5+01:00 hello"),
+            Some(at("2024-01-15T09:30:00.5Z"))
+        );
+    }
+
+    #[test]
+    fn test_per_pod_tail_lines_without_budget() {
+        assert_eq!(
+            LogStreamManager::per_pod_tail_lines(100, None, 3, 10000),
+            100
+        );
+    }
+
+    #[test]
+    fn test_per_pod_tail_lines_divides_budget() {
+        // 50 replicas sharing a 1000 line budget
+        assert_eq!(
+            LogStreamManager::per_pod_tail_lines(100, Some(1000), 50, 10000),
+            20
+        );
+        // Budget larger than tail_lines * pods doesn't raise tail_lines
+        assert_eq!(
+            LogStreamManager::per_pod_tail_lines(100, Some(100000), 2, 10000),
+            100
+        );
+    }
+
+    #[test]
+    fn test_per_pod_tail_lines_capped_by_buffer() {
+        assert_eq!(
+            LogStreamManager::per_pod_tail_lines(500, None, 50, 10000),
+            200
+        );
+        // Never drops below one line per pod
+        assert_eq!(
+            LogStreamManager::per_pod_tail_lines(100, Some(10), 50, 10000),
+            1
+        );
+    }
+
+    #[test]
+    fn test_number_before_counts_down_to_first_line() {
+        let mut entries: Vec<LogEntry> = (0..3)
+            .map(|i| LogEntry::new("pod".to_string(), 0, format!("line-{}", i)))
+            .collect();
+        number_before(&mut entries, 1);
+        let numbers: Vec<i64> = entries.iter().map(|e| e.line_number).collect();
+        assert_eq!(numbers, vec![-2, -1, 0]);
+    }
+
+    #[test]
+    fn test_read_line_capped_discards_overlong_tail() {
+        // A tiny internal buffer so lines span several fill_buf calls
+        let input: &[u8] = b"short\r\n0123456789abcdef\nlast";
+        let mut reader = futures::io::BufReader::with_capacity(4, input);
+        let mut buf = Vec::new();
+        let mut read = || {
+            buf.clear();
+            let mut dropped = 0;
+            let line = futures::executor::block_on(read_line_capped(
+                &mut reader,
+                &mut buf,
+                &mut dropped,
+                8,
+            ))
+            .unwrap();
+            (
+                line.then_some(dropped),
+                String::from_utf8(trim_line_end(&buf).to_vec()).unwrap(),
+            )
+        };
+
+        assert_eq!(read(), (Some(0), "short".to_string()));
+        assert_eq!(read(), (Some(8), "01234567".to_string()));
+        assert_eq!(read(), (Some(0), "last".to_string()));
+        assert_eq!(read().0, None);
+
+        // A cut through a multi-byte char doesn't leave half of it behind
+        assert_eq!(floor_utf8("ab─".as_bytes()[..4].as_ref()), b"ab");
+    }
+
+    #[test]
+    fn test_read_line_capped_resumes_after_cancel() {
+        use futures::{FutureExt, TryStreamExt};
+
+        let (tx, rx) = futures::channel::mpsc::unbounded::<std::io::Result<Vec<u8>>>();
+        let mut reader = rx.into_async_read();
+        let (mut buf, mut dropped) = (Vec::new(), 0);
+
+        // Half a line, then nothing yet: the read is dropped while pending
+        tx.unbounded_send(Ok(b"2024-01-15T10:00:00Z par".to_vec()))
+            .unwrap();
+        assert!(
+            read_line_capped(&mut reader, &mut buf, &mut dropped, 16)
+                .now_or_never()
+                .is_none()
+        );
+
+        tx.unbounded_send(Ok(b"tial line\n".to_vec())).unwrap();
+        let line =
+            futures::executor::block_on(read_line_capped(&mut reader, &mut buf, &mut dropped, 16))
+                .unwrap();
+        assert!(line);
+        assert_eq!(buf, b"2024-01-15T10:00");
+        assert_eq!(dropped, 17);
+    }
+}
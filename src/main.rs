@@ -1,38 +1,75 @@
+use std::collections::BTreeMap;
 use std::fs::File;
 use std::io::Write;
+use std::net::SocketAddr;
 use std::path::PathBuf;
-use std::time::Duration;
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime};
 
 use anyhow::Result;
+use chrono::{DateTime, Utc};
 use clap::{Parser, Subcommand};
+use ratatui::style::Color;
 use serde::{Deserialize, Serialize};
 use tokio::sync::mpsc;
 
 mod app;
 mod config;
+mod debug_log;
+mod handoff;
+mod hooks;
+mod http;
 mod k8s;
 mod logs;
+mod metrics;
+mod picker;
+mod plugins;
+mod repro;
+mod session;
+mod setup;
+mod share;
+mod shell;
 mod token_cache;
 mod tui;
 mod types;
 mod ui;
 
-use app::{Action, AppState, Screen};
-use config::{KeyBindings, KeyContext};
-use k8s::KubeClient;
-use logs::{CompiledFilter, LogBuffer, LogStreamManager};
+use app::{
+    Action, AppState, Loading, PromptKind, SampleMode, Sampling, Screen, Severity, SwitchTarget,
+    UiState,
+};
+use config::{ColumnLayout, ColumnSpec, KeyBindings, KeyContext, LinkTemplates};
+use handoff::Viewer;
+use hooks::{HookEvent, HookSpec, Hooks};
+use k8s::{ApiError, KubeClient};
+use logs::levels::NumericLevelSpec;
+use logs::rules::LevelRuleSpec;
+use logs::{
+    CompiledFilter, HistoryBatch, HistoryWindow, JsonFormat, LogBuffer, LogStreamManager,
+    MAX_LINE_BYTES, MESH_SIDECARS, RetentionGap, StreamSource,
+};
+use picker::{PickerMode, Target};
+use session::{Densities, KeySets, ListingCache, RecentTargets, Session};
+use setup::SetupReport;
+use share::{SharedView, ViewFilters};
 use tui::{Event, EventHandler, Tui};
-use types::{DeploymentInfo, LogEntry, NamespaceInfo, PodInfo};
+use types::{
+    DeploymentInfo, LogEntry, NamespaceInfo, Pins, PodInfo, RecentTarget, TimeRange, TimeWindow,
+};
 use ui::components::{
-    Command, CommandPalette, CommandPaletteState, HelpOverlay, JsonKeyFilter, collect_json_keys,
-    log_viewer_commands,
+    BookmarkList, ColumnsOverlay, Command, CommandPalette, CommandPaletteState, ConfirmDialog,
+    ErrorModal, FullLineView, HelpOverlay, JsonKeyFilter, NotificationHistory,
+    ParseDiagnosticsReport, PerfHud, PodLegend, SchemaDriftReport, StreamParamsReport, Toasts,
+    UsageReport, collect_json_keys, commands_for, take_hyperlinks,
 };
 use ui::screens::{
-    ContextSelectScreen, DeploymentSelectScreen, LogViewerScreen, NamespaceSelectScreen,
+    ConnectingScreen, ContextSelectScreen, DeploymentSelectScreen, LogViewerScreen,
+    NamespaceSelectScreen, RecentSelectScreen, format_bytes,
 };
+use ui::{Theme, ThemeName};
 
 /// Configuration file structure for .kubescope
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 struct Config {
     /// Kubernetes context name
     context: Option<String>,
@@ -52,6 +89,65 @@ struct Config {
     buffer_size: Option<usize>,
     /// Number of historical log lines to fetch per pod
     tail_lines: Option<i64>,
+    /// Total historical lines to fetch, divided across pods
+    tail_budget: Option<i64>,
+    /// Lines longer than this many bytes are cut short
+    max_line_bytes: Option<usize>,
+    /// Bytes read per pod when loading older logs (kubelet limitBytes)
+    limit_bytes: Option<i64>,
+    /// Stop the log streams after this many minutes without a key press
+    idle_timeout: Option<u64>,
+    /// Stream only this many pods of wider deployments
+    sample: Option<usize>,
+    /// Which pods a sample prefers (newest, random)
+    sample_mode: Option<SampleMode>,
+    /// More contexts to stream the same namespace/deployment from
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    also_contexts: Vec<String>,
+    /// Don't stream pods left over from a deployment's previous ReplicaSets
+    #[serde(default)]
+    skip_old_replicasets: bool,
+    /// Containers not to read when a pod has others (default: mesh sidecars)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    exclude_containers: Option<Vec<String>>,
+    /// Also stream the excluded containers' logs (mesh access logs), tagged as sidecar lines
+    #[serde(default)]
+    sidecar_logs: bool,
+    /// Set the terminal title to the open view (on unless false)
+    title: Option<bool>,
+    /// Also set the tmux pane title when running inside tmux
+    #[serde(default)]
+    tmux_title: bool,
+    /// Load Lua plugins from the plugin directory (on unless false)
+    plugins: Option<bool>,
+    /// Color theme (dark, light, colorblind)
+    theme: Option<ThemeName>,
+    /// How the target is chosen when not given (builtin, external)
+    picker: Option<PickerMode>,
+    /// Pinned contexts, namespaces and deployments
+    #[serde(default, skip_serializing_if = "Pins::is_empty")]
+    pins: Pins,
+    /// Pod color overrides: pod name substring -> color name or #rrggbb
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pod_colors: BTreeMap<String, String>,
+    /// Log line prefix columns, in display order
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    columns: Vec<ColumnSpec>,
+    /// JSON pretty-print layout
+    #[serde(default, skip_serializing_if = "JsonFormat::is_default")]
+    json: JsonFormat,
+    /// Dashboard and trace URL templates
+    #[serde(default, skip_serializing_if = "LinkTemplates::is_default")]
+    links: LinkTemplates,
+    /// Shell commands run on events
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    hooks: Vec<HookSpec>,
+    /// Relabel or mute lines by pattern and pod
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    level_rules: Vec<LevelRuleSpec>,
+    /// What numeric levels in JSON lines mean
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    numeric_levels: Option<NumericLevelSpec>,
 }
 
 impl Config {
@@ -66,6 +162,15 @@ impl Config {
         }
     }
 
+    /// Read .kubescope again, reporting parse errors (no file is an empty config)
+    fn reload() -> Result<Self> {
+        match std::fs::read_to_string(".kubescope") {
+            Ok(content) => Ok(toml::from_str(&content)?),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Config::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
     /// Save config to .kubescope file
     fn save(&self) -> Result<()> {
         let content = toml::to_string_pretty(self)?;
@@ -74,6 +179,175 @@ impl Config {
     }
 }
 
+/// How often .kubescope is checked for edits
+const CONFIG_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Picks up edits to .kubescope while the TUI runs
+///
+/// A cheap mtime check on the Tick rather than a file system watcher: it's
+/// one small file, and a couple of seconds' delay doesn't matter for it.
+struct ConfigWatch {
+    /// The file as last applied
+    config: Config,
+    modified: Option<SystemTime>,
+    last_check: Instant,
+}
+
+impl ConfigWatch {
+    fn new() -> Self {
+        Self {
+            config: Config::load().unwrap_or_default(),
+            modified: Self::modified(),
+            last_check: Instant::now(),
+        }
+    }
+
+    fn modified() -> Option<SystemTime> {
+        std::fs::metadata(".kubescope")
+            .and_then(|m| m.modified())
+            .ok()
+    }
+
+    /// The file's new contents, when it changed since the last check
+    fn poll(&mut self) -> Option<Result<Config>> {
+        if self.last_check.elapsed() < CONFIG_POLL_INTERVAL {
+            return None;
+        }
+        self.last_check = Instant::now();
+        let modified = Self::modified();
+        if modified == self.modified {
+            return None;
+        }
+        self.modified = modified;
+        Some(Config::reload())
+    }
+}
+
+/// Apply the settings that changed between two versions of .kubescope
+///
+/// Everything is validated before anything is applied, so a bad edit leaves
+/// the session as it was. Returns what changed, and what needs a restart.
+fn apply_config_changes(
+    state: &mut AppState,
+    hooks: &mut Hooks,
+    old: &Config,
+    new: &Config,
+) -> Result<(Vec<&'static str>, Vec<&'static str>)> {
+    let filter_changed = old.filter != new.filter
+        || old.ignore_case != new.ignore_case
+        || old.invert_match != new.invert_match;
+    let filter = match &new.filter {
+        Some(pattern) if filter_changed => Some(
+            config_filter(pattern, new.ignore_case, new.invert_match)
+                .map_err(|e| anyhow::anyhow!("Invalid filter pattern '{}': {}", pattern, e))?,
+        ),
+        _ => None,
+    };
+    let pod_colors = parse_pod_colors(&new.pod_colors)?;
+    let new_hooks = if old.hooks != new.hooks {
+        Some(Hooks::new(&new.hooks)?)
+    } else {
+        None
+    };
+
+    let mut applied = Vec::new();
+    if filter_changed {
+        state.ui_state.filter_error = None;
+        match (filter, &new.filter) {
+            (Some(filter), Some(pattern)) => {
+                state.ui_state.active_filter = Some(filter);
+                state.ui_state.search_input = pattern.clone();
+                state.ui_state.filter_case_insensitive = new.ignore_case;
+                state.ui_state.usage.record_filter(pattern);
+            }
+            _ => {
+                state.ui_state.active_filter = None;
+                state.ui_state.search_input.clear();
+            }
+        }
+        applied.push("filter");
+    }
+    if old.theme != new.theme {
+        Theme::set(new.theme.unwrap_or_default());
+        applied.push("theme");
+    }
+    if old.pod_colors != new.pod_colors {
+        state.pod_color_overrides = pod_colors;
+        applied.push("pod colors");
+    }
+    if old.theme != new.theme || old.pod_colors != new.pod_colors {
+        state.assign_pod_colors();
+    }
+    if old.columns != new.columns {
+        state.ui_state.columns = ColumnLayout::from_specs(&new.columns);
+        applied.push("columns");
+    }
+    if old.json != new.json {
+        state.ui_state.json_fold_depth = new.json.fold_depth;
+        state.ui_state.json_format = new.json.clone();
+        applied.push("json");
+    }
+    if old.links != new.links {
+        state.links = new.links.clone();
+        applied.push("links");
+    }
+    if let Some(new_hooks) = new_hooks {
+        *hooks = new_hooks;
+        applied.push("hooks");
+    }
+
+    // Lines are relabeled as they're parsed, by rules fixed at startup
+    // (logs::rules keeps them in a OnceLock)
+    let mut restart = Vec::new();
+    if old.level_rules != new.level_rules {
+        restart.push("level_rules");
+    }
+    if old.numeric_levels != new.numeric_levels {
+        restart.push("numeric_levels");
+    }
+    if startup_settings(old) != startup_settings(new) {
+        restart.push("other settings");
+    }
+    Ok((applied, restart))
+}
+
+/// A config without the settings `apply_config_changes` applies or names,
+/// leaving the ones only read at startup (the target, streaming and buffer)
+fn startup_settings(config: &Config) -> Config {
+    Config {
+        filter: None,
+        ignore_case: false,
+        invert_match: false,
+        theme: None,
+        pod_colors: BTreeMap::new(),
+        columns: Vec::new(),
+        json: JsonFormat::default(),
+        links: LinkTemplates::default(),
+        hooks: Vec::new(),
+        level_rules: Vec::new(),
+        numeric_levels: None,
+        ..config.clone()
+    }
+}
+
+/// The filter a pattern and the case and invert toggles make
+fn config_filter(
+    pattern: &str,
+    ignore_case: bool,
+    invert_match: bool,
+) -> Result<CompiledFilter, regex::Error> {
+    let filter = if ignore_case {
+        CompiledFilter::new_case_insensitive(pattern)
+    } else {
+        CompiledFilter::new(pattern)
+    }?;
+    Ok(if invert_match {
+        filter.inverted()
+    } else {
+        filter
+    })
+}
+
 /// Kubescope - A terminal UI for viewing Kubernetes deployment logs
 #[derive(Parser, Debug)]
 #[command(name = "kubescope")]
@@ -102,6 +376,58 @@ struct Cli {
     #[arg(long, default_value = "100", global = true)]
     tail_lines: i64,
 
+    /// Total historical log lines to fetch, divided evenly across pods
+    #[arg(long, global = true)]
+    tail_budget: Option<i64>,
+
+    /// Cut log lines longer than this many bytes (default 65536)
+    #[arg(long, global = true)]
+    max_line_bytes: Option<usize>,
+
+    /// Cap each pod's older-log fetch at this many bytes (kubelet limitBytes)
+    #[arg(long, global = true)]
+    limit_bytes: Option<i64>,
+
+    /// Stop the log streams after this many minutes without a key press (0 = never)
+    #[arg(long, value_name = "MINUTES", global = true)]
+    idle_timeout: Option<u64>,
+
+    /// Stream only this many pods of deployments with more replicas
+    #[arg(long, value_name = "PODS", global = true)]
+    sample: Option<usize>,
+
+    /// Which pods a sample streams
+    #[arg(long, value_enum, global = true)]
+    sample_mode: Option<SampleMode>,
+
+    /// Also stream the same namespace/deployment from this context (repeatable)
+    #[arg(long = "also-context", value_name = "CONTEXT", global = true)]
+    also_contexts: Vec<String>,
+
+    /// Don't stream pods from a deployment's previous ReplicaSets (mid-rollout leftovers)
+    #[arg(long, global = true)]
+    skip_old_replicasets: bool,
+
+    /// Also stream excluded sidecars' logs (mesh access logs) next to the app container
+    #[arg(long, global = true)]
+    sidecar_logs: bool,
+
+    /// Don't set the terminal title to the open namespace/deployment@context
+    #[arg(long, global = true)]
+    no_title: bool,
+
+    /// Also set the tmux pane title when running inside tmux
+    #[arg(long, global = true)]
+    tmux_title: bool,
+
+    /// Don't load Lua plugins from the plugin directory
+    #[arg(long, global = true)]
+    no_plugins: bool,
+
+    /// Print a session summary (lines, peak rate, errors, filters, duration) on exit
+    #[arg(long, global = true)]
+    stats: bool,
+
     /// Filter pattern (regex) to pre-populate log filter
     #[arg(short = 'e', long = "filter", global = true)]
     filter: Option<String>,
@@ -114,15 +440,71 @@ struct Cli {
     #[arg(short = 'v', long = "invert-match", global = true)]
     invert_match: bool,
 
+    /// Only read lines from this time on (RFC 3339, or a duration back from now like 2h)
+    #[arg(long, value_name = "TIME", value_parser = parse_time_arg, global = true)]
+    since: Option<DateTime<Utc>>,
+
+    /// Leave out lines after this time (RFC 3339, or a duration back from now like 1h)
+    #[arg(long, value_name = "TIME", value_parser = parse_time_arg, global = true)]
+    until: Option<DateTime<Utc>>,
+
+    /// Color theme
+    #[arg(long, value_enum, global = true)]
+    theme: Option<ThemeName>,
+
+    /// Choose a missing context, namespace or deployment in fzf/skim before starting
+    #[arg(long, value_enum, global = true)]
+    picker: Option<PickerMode>,
+
     /// Ignore .kubescope config file
     #[arg(long, global = true)]
     no_config: bool,
+
+    /// Resume the last log viewer session (context, namespace, deployment, filter, time range)
+    #[arg(long, global = true)]
+    resume: bool,
+
+    /// Go straight to the context's kubeconfig namespace instead of listing namespaces
+    #[arg(long, global = true)]
+    use_context_namespace: bool,
+
+    /// Write kubescope's own tracing output to a file (default ~/.kubescope/debug.log)
+    #[arg(long, value_name = "PATH", global = true)]
+    log_file: Option<Option<PathBuf>>,
 }
 
 #[derive(Subcommand, Debug)]
 enum Commands {
     /// Initialize a .kubescope configuration file in the current directory
-    Init,
+    ///
+    /// Asks for each setting, unless any of the flags below or a positional
+    /// CONTEXT [NAMESPACE [DEPLOYMENT]] are given (for scripts)
+    Init {
+        /// Context to save, without asking
+        #[arg(long = "context", value_name = "CONTEXT")]
+        init_context: Option<String>,
+        /// Namespace to save, without asking
+        #[arg(long = "namespace", value_name = "NAMESPACE")]
+        init_namespace: Option<String>,
+        /// Deployment to save, without asking
+        #[arg(long = "deployment", value_name = "DEPLOYMENT")]
+        init_deployment: Option<String>,
+        /// Overwrite an existing .kubescope without asking
+        #[arg(long)]
+        force: bool,
+    },
+    /// Print a deployment's logs to stdout without the TUI (needs context, namespace and deployment)
+    Tail {
+        /// Serve Prometheus metrics (lines by pod and level) on this address, e.g. 127.0.0.1:9090
+        #[arg(long, value_name = "ADDR")]
+        metrics_addr: Option<SocketAddr>,
+    },
+    /// Run the TUI and share its log buffer read-only over HTTP, as ndjson on /logs
+    Serve {
+        /// Address to listen on
+        #[arg(long, value_name = "ADDR", default_value = "127.0.0.1:7777")]
+        addr: SocketAddr,
+    },
 }
 
 /// Resolved arguments after merging CLI args and config file
@@ -132,9 +514,48 @@ struct Args {
     deployment: Option<String>,
     buffer_size: usize,
     tail_lines: i64,
+    tail_budget: Option<i64>,
+    max_line_bytes: usize,
+    limit_bytes: Option<i64>,
+    idle_timeout: Option<Duration>,
+    sampling: Option<Sampling>,
+    also_contexts: Vec<String>,
+    skip_old_replicasets: bool,
+    exclude_containers: Vec<String>,
+    sidecar_logs: bool,
+    title: bool,
+    tmux_title: bool,
+    plugins: bool,
+    stats: bool,
     filter: Option<String>,
     ignore_case: bool,
     invert_match: bool,
+    time_range: TimeRange,
+    time_window: TimeWindow,
+    theme: ThemeName,
+    picker: PickerMode,
+    pins: Pins,
+    pod_colors: Vec<(String, Color)>,
+    columns: ColumnLayout,
+    json: JsonFormat,
+    links: LinkTemplates,
+    hooks: Vec<HookSpec>,
+    /// Share the log buffer over HTTP on this address (`kubescope serve`)
+    serve_addr: Option<SocketAddr>,
+    no_config: bool,
+    use_context_namespace: bool,
+}
+
+impl Args {
+    /// Historical lines to request from each pod for the given pod count
+    fn tail_lines_for(&self, pod_count: usize) -> i64 {
+        LogStreamManager::per_pod_tail_lines(
+            self.tail_lines,
+            self.tail_budget,
+            pod_count,
+            self.buffer_size,
+        )
+    }
 }
 
 #[tokio::main]
@@ -142,32 +563,87 @@ async fn main() -> Result<()> {
     let cli = Cli::parse();
 
     // Initialize tracing for debugging
-    tracing_subscriber::fmt()
-        .with_env_filter(
-            tracing_subscriber::EnvFilter::from_default_env()
-                .add_directive(tracing::Level::WARN.into()),
-        )
-        .with_writer(std::io::stderr)
-        .init();
+    let log_file = match cli.log_file.clone() {
+        Some(Some(path)) => Some(path),
+        Some(None) => Some(
+            debug_log::default_path()
+                .ok_or_else(|| anyhow::anyhow!("No home directory for the default log file"))?,
+        ),
+        None => None,
+    };
+    debug_log::init(log_file.as_deref())?;
 
     // Handle subcommands
-    if let Some(Commands::Init) = cli.command {
-        return run_init().await;
+    if let Some(Commands::Init {
+        init_context,
+        init_namespace,
+        init_deployment,
+        force,
+    }) = &cli.command
+    {
+        // The filter flags and the positional target are shared with the other modes
+        let flags = InitFlags {
+            context: init_context.clone().or_else(|| cli.context.clone()),
+            namespace: init_namespace.clone().or_else(|| cli.namespace.clone()),
+            deployment: init_deployment.clone().or_else(|| cli.deployment.clone()),
+            filter: cli.filter.clone(),
+            ignore_case: cli.ignore_case,
+            invert_match: cli.invert_match,
+            force: *force,
+        };
+        return if flags.is_scripted() {
+            run_init_scripted(flags)
+        } else {
+            run_init(flags.force).await
+        };
     }
 
+    // Headless mode runs after the settings below are resolved
+    let tail = match &cli.command {
+        Some(Commands::Tail { metrics_addr }) => Some(*metrics_addr),
+        _ => None,
+    };
+    let serve_addr = match &cli.command {
+        Some(Commands::Serve { addr }) => Some(*addr),
+        _ => None,
+    };
+
     // Load config file if present and not disabled
     let config = if cli.no_config { None } else { Config::load() };
 
-    // Merge CLI args with config file (CLI takes precedence)
-    let args = Args {
+    // Load the previous session when resuming
+    let session = if cli.resume {
+        Some(Session::load().ok_or_else(|| anyhow::anyhow!("No previous session to resume"))?)
+    } else {
+        None
+    };
+
+    // Level rules and the numeric level mapping apply as lines are parsed, in every mode
+    if let Some(config) = &config {
+        logs::levels::init(config.numeric_levels.as_ref())?;
+        logs::rules::init(&config.level_rules)?;
+    }
+
+    // Parse pod color overrides up front so typos are reported before the TUI starts
+    let pod_colors = config
+        .as_ref()
+        .map(|c| parse_pod_colors(&c.pod_colors))
+        .transpose()?
+        .unwrap_or_default();
+
+    // Merge CLI args with resumed session and config file (CLI takes precedence)
+    let mut args = Args {
         context: cli
             .context
+            .or_else(|| session.as_ref().map(|s| s.context.clone()))
             .or_else(|| config.as_ref().and_then(|c| c.context.clone())),
         namespace: cli
             .namespace
+            .or_else(|| session.as_ref().map(|s| s.namespace.clone()))
             .or_else(|| config.as_ref().and_then(|c| c.namespace.clone())),
         deployment: cli
             .deployment
+            .or_else(|| session.as_ref().map(|s| s.deployment.clone()))
             .or_else(|| config.as_ref().and_then(|c| c.deployment.clone())),
         buffer_size: config
             .as_ref()
@@ -177,32 +653,209 @@ async fn main() -> Result<()> {
             .as_ref()
             .and_then(|c| c.tail_lines)
             .unwrap_or(cli.tail_lines),
+        tail_budget: cli
+            .tail_budget
+            .or_else(|| config.as_ref().and_then(|c| c.tail_budget)),
+        max_line_bytes: cli
+            .max_line_bytes
+            .or_else(|| config.as_ref().and_then(|c| c.max_line_bytes))
+            .unwrap_or(MAX_LINE_BYTES),
+        limit_bytes: cli
+            .limit_bytes
+            .or_else(|| config.as_ref().and_then(|c| c.limit_bytes)),
+        idle_timeout: cli
+            .idle_timeout
+            .or_else(|| config.as_ref().and_then(|c| c.idle_timeout))
+            .filter(|&minutes| minutes > 0)
+            .map(|minutes| Duration::from_secs(minutes * 60)),
+        sampling: cli
+            .sample
+            .or_else(|| config.as_ref().and_then(|c| c.sample))
+            .filter(|&size| size > 0)
+            .map(|size| {
+                let mode = cli
+                    .sample_mode
+                    .or_else(|| config.as_ref().and_then(|c| c.sample_mode))
+                    .unwrap_or_default();
+                Sampling::new(size, mode)
+            }),
+        also_contexts: if cli.also_contexts.is_empty() {
+            config
+                .as_ref()
+                .map(|c| c.also_contexts.clone())
+                .unwrap_or_default()
+        } else {
+            cli.also_contexts
+        },
+        skip_old_replicasets: cli.skip_old_replicasets
+            || config.as_ref().is_some_and(|c| c.skip_old_replicasets),
+        exclude_containers: config
+            .as_ref()
+            .and_then(|c| c.exclude_containers.clone())
+            .unwrap_or_else(|| MESH_SIDECARS.map(str::to_string).to_vec()),
+        sidecar_logs: cli.sidecar_logs || config.as_ref().is_some_and(|c| c.sidecar_logs),
+        title: !cli.no_title && config.as_ref().and_then(|c| c.title).unwrap_or(true),
+        tmux_title: cli.tmux_title || config.as_ref().is_some_and(|c| c.tmux_title),
+        plugins: !cli.no_plugins && config.as_ref().and_then(|c| c.plugins).unwrap_or(true),
+        stats: cli.stats,
         filter: cli
             .filter
+            .or_else(|| session.as_ref().and_then(|s| s.filter.clone()))
             .or_else(|| config.as_ref().and_then(|c| c.filter.clone())),
-        ignore_case: cli.ignore_case || config.as_ref().is_some_and(|c| c.ignore_case),
-        invert_match: cli.invert_match || config.as_ref().is_some_and(|c| c.invert_match),
+        ignore_case: cli.ignore_case
+            || session.as_ref().is_some_and(|s| s.ignore_case)
+            || config.as_ref().is_some_and(|c| c.ignore_case),
+        invert_match: cli.invert_match
+            || session.as_ref().is_some_and(|s| s.invert_match)
+            || config.as_ref().is_some_and(|c| c.invert_match),
+        time_range: session.map(|s| s.time_range).unwrap_or_default(),
+        time_window: TimeWindow {
+            since: cli.since,
+            until: cli.until,
+        },
+        theme: cli
+            .theme
+            .or_else(|| config.as_ref().and_then(|c| c.theme))
+            .unwrap_or_default(),
+        picker: cli
+            .picker
+            .or_else(|| config.as_ref().and_then(|c| c.picker))
+            .unwrap_or_default(),
+        columns: config
+            .as_ref()
+            .map(|c| ColumnLayout::from_specs(&c.columns))
+            .unwrap_or_default(),
+        json: config.as_ref().map(|c| c.json.clone()).unwrap_or_default(),
+        links: config.as_ref().map(|c| c.links.clone()).unwrap_or_default(),
+        hooks: config.as_ref().map(|c| c.hooks.clone()).unwrap_or_default(),
+        serve_addr,
+        pins: config.map(|c| c.pins).unwrap_or_default(),
+        pod_colors,
+        no_config: cli.no_config,
+        use_context_namespace: cli.use_context_namespace,
+    };
+
+    if let TimeWindow {
+        since: Some(since),
+        until: Some(until),
+    } = args.time_window
+        && since >= until
+    {
+        anyhow::bail!("--since must be before --until");
+    }
+
+    // The external picker fills in the target before anything starts
+    let picked = match args.picker {
+        PickerMode::External => {
+            let target = Target {
+                context: &mut args.context,
+                namespace: &mut args.namespace,
+                deployment: &mut args.deployment,
+            };
+            picker::pick_target(target, args.use_context_namespace).await
+        }
+        PickerMode::Builtin => Ok(true),
     };
 
     // Run the application
-    let result = run_app(args).await;
+    let result = match picked {
+        // Backed out of the picker
+        Ok(false) => Ok(()),
+        Ok(true) => match tail {
+            Some(metrics_addr) => run_tail(args, metrics_addr).await,
+            None => run_app(args).await,
+        },
+        Err(e) => Err(e),
+    };
 
     // Handle any errors
     if let Err(e) = &result {
         eprintln!("Error: {:#}", e);
+        if let Some(kind) = ApiError::classify(e) {
+            eprintln!("{}: {}", kind.title(), kind.guidance());
+        }
     }
 
     result
 }
 
+/// Parse a `--since`/`--until` value
+fn parse_time_arg(value: &str) -> Result<DateTime<Utc>, String> {
+    types::parse_time_point(value, Utc::now())
+}
+
+/// Parse `pod_colors` config entries into (pattern, color) pairs
+fn parse_pod_colors(entries: &BTreeMap<String, String>) -> Result<Vec<(String, Color)>> {
+    entries
+        .iter()
+        .map(|(pattern, color)| {
+            let parsed = color.parse::<Color>().map_err(|_| {
+                anyhow::anyhow!("Invalid color '{}' for pod pattern '{}'", color, pattern)
+            })?;
+            Ok((pattern.clone(), parsed))
+        })
+        .collect()
+}
+
+/// `kubescope init` settings given as flags
+struct InitFlags {
+    context: Option<String>,
+    namespace: Option<String>,
+    deployment: Option<String>,
+    filter: Option<String>,
+    ignore_case: bool,
+    invert_match: bool,
+    force: bool,
+}
+
+impl InitFlags {
+    /// Whether any setting was given, so nothing should be asked
+    fn is_scripted(&self) -> bool {
+        self.context.is_some()
+            || self.namespace.is_some()
+            || self.deployment.is_some()
+            || self.filter.is_some()
+    }
+
+    /// The config these flags describe, with the filter validated
+    fn to_config(&self) -> Result<Config> {
+        if let Some(filter) = &self.filter {
+            CompiledFilter::new(filter)
+                .map_err(|e| anyhow::anyhow!("Invalid filter pattern '{}': {}", filter, e))?;
+        }
+        let has_filter = self.filter.is_some();
+        Ok(Config {
+            context: self.context.clone(),
+            namespace: self.namespace.clone(),
+            deployment: self.deployment.clone(),
+            filter: self.filter.clone(),
+            ignore_case: has_filter && self.ignore_case,
+            invert_match: has_filter && self.invert_match,
+            ..Config::default()
+        })
+    }
+}
+
+/// Write a .kubescope from flags alone, for scripts and onboarding docs
+fn run_init_scripted(flags: InitFlags) -> Result<()> {
+    let config = flags.to_config()?;
+    if PathBuf::from(".kubescope").exists() && !flags.force {
+        anyhow::bail!("A .kubescope file already exists (use --force to overwrite it)");
+    }
+    config.save()?;
+    println!("Configuration saved to .kubescope");
+    print_init_summary(&config);
+    Ok(())
+}
+
 /// Run the init command to create a .kubescope configuration file
-async fn run_init() -> Result<()> {
+async fn run_init(force: bool) -> Result<()> {
     use std::io::{self, BufRead};
 
     println!("Initializing .kubescope configuration file...\n");
 
     // Check if .kubescope already exists
-    if PathBuf::from(".kubescope").exists() {
+    if PathBuf::from(".kubescope").exists() && !force {
         print!("A .kubescope file already exists. Overwrite? [y/N]: ");
         std::io::Write::flush(&mut io::stdout())?;
 
@@ -356,6 +1009,16 @@ async fn run_init() -> Result<()> {
     config.save()?;
 
     println!("\nConfiguration saved to .kubescope");
+    print_init_summary(&config);
+
+    println!("\nRun 'kubescope' to start with this configuration.");
+    println!("Use --no-config to ignore this file.");
+
+    Ok(())
+}
+
+/// The settings `init` wrote
+fn print_init_summary(config: &Config) {
     println!("\nConfiguration:");
     if let Some(ctx) = &config.context {
         println!("  context: {}", ctx);
@@ -375,11 +1038,175 @@ async fn run_init() -> Result<()> {
             println!("  invert_match: true");
         }
     }
+}
 
-    println!("\nRun 'kubescope' to start with this configuration.");
-    println!("Use --no-config to ignore this file.");
+/// Bounds for runtime buffer capacity adjustment
+const MIN_BUFFER_CAPACITY: usize = 1_000;
+const MAX_BUFFER_CAPACITY: usize = 1_000_000;
+/// Lines the help overlay scrolls per page
+const HELP_PAGE: u16 = 10;
+/// Most log entries taken off the channel before the loop renders again
+const LOG_BATCH: usize = 1_000;
+/// Tick interval while something on screen animates or counts down
+const ACTIVE_TICK: Duration = Duration::from_millis(100);
+/// Tick interval otherwise (toast expiry doesn't need more)
+const IDLE_TICK: Duration = Duration::from_secs(1);
+/// How often the open deployment's pods are checked for pod_restarted hooks
+const POD_POLL_INTERVAL: Duration = Duration::from_secs(30);
+/// How long typing must pause before the filter bar preview counts matches
+const SEARCH_PREVIEW_DEBOUNCE: Duration = Duration::from_millis(200);
+/// Newest JSON lines sampled for the key filter's share and value preview
+const JSON_KEY_SAMPLE: usize = 2_000;
 
-    Ok(())
+/// Progress of the startup task that resolves the CLI target
+enum StartupEvent {
+    /// Step now running (the previous one finished)
+    Progress(String),
+    Ready(Box<Startup>),
+    Failed(anyhow::Error),
+}
+
+/// Where the CLI arguments lead, with everything loaded to show it
+struct Startup {
+    kube_client: KubeClient,
+    client: Option<kube::Client>,
+    contexts: Vec<types::ContextInfo>,
+    namespaces: Vec<NamespaceInfo>,
+    deployments: Vec<DeploymentInfo>,
+    pods: Vec<PodInfo>,
+    /// Why the deployment opened on the command line has no pods
+    no_pods_reason: Option<String>,
+    context: Option<String>,
+    namespace: Option<String>,
+    deployment: Option<String>,
+    screen_stack: Vec<Screen>,
+    screen: Screen,
+}
+
+/// Load the kubeconfig and fetch whatever the CLI arguments point at
+async fn connect(
+    (context, namespace, deployment): (Option<String>, Option<String>, Option<String>),
+    use_context_namespace: bool,
+    progress: &mpsc::UnboundedSender<StartupEvent>,
+) -> Result<Startup> {
+    let step = |msg: String| {
+        let _ = progress.send(StartupEvent::Progress(msg));
+    };
+
+    step("Loading kubeconfig".to_string());
+    let kube_client = match KubeClient::new().await {
+        Ok(kube_client) if !kube_client.get_contexts().is_empty() => kube_client,
+        result => {
+            // A first run is walked through setting up a kubeconfig
+            if let Some(report) = SetupReport::check() {
+                return Err(report.into());
+            }
+            result?
+        }
+    };
+    let mut startup = Startup {
+        kube_client,
+        client: None,
+        contexts: Vec::new(),
+        namespaces: Vec::new(),
+        deployments: Vec::new(),
+        pods: Vec::new(),
+        no_pods_reason: None,
+        context: None,
+        namespace: None,
+        deployment: None,
+        screen_stack: Vec::new(),
+        screen: Screen::ContextSelect,
+    };
+    let kube_client = &startup.kube_client;
+
+    // Fast path: when all three args (context, namespace, deployment) are provided,
+    // skip listing resources and fetch directly to minimize startup time
+    if let (Some(context_name), Some(namespace_name), Some(deployment_name)) =
+        (&context, &namespace, &deployment)
+    {
+        // This avoids listing all namespaces and all deployments (saves ~1s on EKS)
+        // Also skip loading contexts list (will be loaded lazily if user navigates back)
+        step(format!("Connecting to '{}'", context_name));
+        let client = kube_client.client_for_context(context_name).await?;
+
+        // Fetch deployment directly by name (not listing all)
+        step(format!("Fetching deployment '{}'", deployment_name));
+        let found = kube_client
+            .get_deployment(&client, namespace_name, deployment_name)
+            .await?;
+
+        step("Fetching pods".to_string());
+        startup.pods = kube_client
+            .get_pods_for_deployment(&client, namespace_name, &found)
+            .await?;
+        if startup.pods.is_empty() {
+            step("Looking for the missing pods".to_string());
+            startup.no_pods_reason = Some(
+                kube_client
+                    .diagnose_no_pods(&client, namespace_name, &found)
+                    .await,
+            );
+        }
+
+        startup.deployments = vec![found];
+        startup.client = Some(client);
+        startup.screen_stack = vec![
+            Screen::ContextSelect,
+            Screen::NamespaceSelect,
+            Screen::DeploymentSelect,
+        ];
+        startup.screen = Screen::LogViewer;
+        startup.context = context;
+        startup.namespace = namespace;
+        startup.deployment = deployment;
+        return Ok(startup);
+    }
+
+    // Non-fast path: load contexts for navigation
+    startup.contexts = kube_client.get_contexts();
+    let Some(context_name) = context else {
+        return Ok(startup);
+    };
+
+    // Partial args path: need to load some lists for navigation
+    let Some(context_info) = startup.contexts.iter().find(|c| c.name == context_name) else {
+        anyhow::bail!("Context '{}' not found in kubeconfig", context_name);
+    };
+    let namespace = namespace.or_else(|| {
+        use_context_namespace
+            .then(|| context_info.namespace.clone())
+            .flatten()
+    });
+
+    step(format!("Connecting to '{}'", context_name));
+    let client = kube_client.client_for_context(&context_name).await?;
+    step("Loading namespaces".to_string());
+    startup.namespaces = kube_client.get_namespaces(&client).await?;
+    startup.screen_stack.push(Screen::ContextSelect);
+
+    if let Some(namespace_name) = &namespace {
+        if !startup.namespaces.iter().any(|n| &n.name == namespace_name) {
+            anyhow::bail!(
+                "Namespace '{}' not found in context '{}'",
+                namespace_name,
+                context_name
+            );
+        }
+
+        // Load deployments (need full list for deployment select screen)
+        step("Loading deployments".to_string());
+        startup.deployments = kube_client.get_deployments(&client, namespace_name).await?;
+        startup.screen_stack.push(Screen::NamespaceSelect);
+        startup.screen = Screen::DeploymentSelect;
+        startup.namespace = namespace;
+    } else {
+        startup.screen = Screen::NamespaceSelect;
+    }
+
+    startup.client = Some(client);
+    startup.context = Some(context_name);
+    Ok(startup)
 }
 
 /// Internal actions for async operations
@@ -389,27 +1216,171 @@ enum InternalAction {
     LoadNamespaces(String),
     LoadDeployments(String),
     LoadPods(String, DeploymentInfo),
-    NamespacesLoaded(Vec<NamespaceInfo>),
+    /// Namespaces and the client of the context they were listed from
+    NamespacesLoaded(kube::Client, Vec<NamespaceInfo>),
     DeploymentsLoaded(Vec<DeploymentInfo>),
     PodsLoaded(Vec<PodInfo>),
+    /// Fresh statuses for the open deployment's pods, by namespace and deployment
+    PodStatusesLoaded(String, String, Vec<PodInfo>),
+    OpenRecent(RecentTarget),
+    /// A recent target's client and deployment, ready to load its pods
+    RecentOpened(RecentTarget, kube::Client, DeploymentInfo),
     StartLogStreaming,
     StopLogStreaming,
     RestartLogStreaming,
-    Error(String),
+    /// Read excluded containers (mesh sidecars) again, or stop
+    ToggleContainerExclusion,
+    /// Stream excluded containers as sidecar sources, or stop
+    ToggleSidecarLogs,
+    /// Start streams for more pods alongside the running ones
+    StreamPods(Vec<PodInfo>),
+    /// Stop the streams but keep the buffer, to resume from later
+    SuspendLogStreaming,
+    /// Restart suspended streams from the point they stopped
+    ResumeLogStreaming,
+    /// Fetch what a wider time range adds before the given, narrower one
+    ExpandTimeRange(TimeRange),
+    LoadOlderLogs,
+    ScaleDeployment(i32),
+    RestartDeployment,
+    DeletePod(String),
+    FetchFullLine(types::ArcLogEntry),
+    /// Suspend the TUI and open a file in the editor or pager
+    OpenExternal(Viewer, PathBuf),
+    CopyToClipboard(String),
+    /// A fetched line for the full line overlay, by buffer id
+    FullLineFetched(u64, Result<String, String>),
+    /// Pods whose retained log is shorter than the time range asked for
+    RetentionGaps(Vec<RetentionGap>),
+    SavePins,
+    /// Pods of the open deployment in an extra cluster, with its client
+    ClusterPodsLoaded {
+        context: String,
+        namespace: String,
+        deployment: String,
+        result: Result<(kube::Client, Vec<PodInfo>), String>,
+    },
+    /// Live namespaces replacing a cached list already on screen
+    NamespacesRefreshed(kube::Client, Vec<NamespaceInfo>),
+    /// Fetch a namespace's deployments again without navigating
+    RefreshDeployments(String),
+    /// Live deployments replacing a cached list, by namespace
+    DeploymentsRefreshed(String, Vec<DeploymentInfo>),
+    /// Rebuild the client after a stream was refused with expired credentials
+    RefreshCredentials,
+    /// A client rebuilt with fresh credentials, or why that failed
+    CredentialsRefreshed(Result<kube::Client, String>),
+    /// Outcome of a background scale, restart or delete, shown as a toast
+    Notify(Severity, String),
+    /// Failure to report in the error modal, with the action that retries it
+    Error(String, Option<ApiError>, Option<Action>),
+}
+
+/// Stream a deployment's logs to stdout, for piping or a log relay
+///
+/// Lines are printed as `pod line` (the line keeps its Kubernetes timestamp)
+/// after the `--filter` options. Ends when every pod's stream has ended, or on
+/// Ctrl-C.
+async fn run_tail(args: Args, metrics_addr: Option<SocketAddr>) -> Result<()> {
+    let (Some(context), Some(namespace), Some(deployment)) =
+        (&args.context, &args.namespace, &args.deployment)
+    else {
+        anyhow::bail!("tail needs a context, namespace and deployment");
+    };
+    let filter = match &args.filter {
+        Some(pattern) => {
+            let filter = if args.ignore_case {
+                CompiledFilter::new_case_insensitive(pattern)
+            } else {
+                CompiledFilter::new(pattern)
+            }
+            .map_err(|e| anyhow::anyhow!("Invalid filter pattern '{}': {}", pattern, e))?;
+            Some(if args.invert_match {
+                filter.inverted()
+            } else {
+                filter
+            })
+        }
+        None => None,
+    };
+    if args.plugins {
+        for error in plugins::init() {
+            eprintln!("Warning: {}", error);
+        }
+    }
+
+    let kube_client = KubeClient::new().await?;
+    let client = kube_client.client_for_context(context).await?;
+    let found = kube_client
+        .get_deployment(&client, namespace, deployment)
+        .await?;
+    let mut pods = kube_client
+        .get_pods_for_deployment(&client, namespace, &found)
+        .await?;
+    if pods.is_empty() {
+        let reason = kube_client
+            .diagnose_no_pods(&client, namespace, &found)
+            .await;
+        anyhow::bail!("No pods for {}: {}", deployment, reason);
+    }
+    if args.skip_old_replicasets {
+        pods.retain(|p| !p.old_replica_set);
+    }
+
+    let ingest = Arc::new(logs::IngestStats::default());
+    let metrics = Arc::new(metrics::TailMetrics::new(Arc::clone(&ingest)));
+    if let Some(addr) = metrics_addr {
+        metrics::serve(addr, Arc::clone(&metrics)).await?;
+    }
+
+    let (log_tx, mut log_rx) = mpsc::channel::<LogEntry>(args.buffer_size * 2);
+    let mut stream_manager = LogStreamManager::new(ingest, args.max_line_bytes, args.limit_bytes);
+    stream_manager.set_window(args.time_window);
+    stream_manager.set_excluded_containers(args.exclude_containers.clone());
+    stream_manager.start_streams(
+        client,
+        StreamSource {
+            namespace,
+            workload: Some(deployment),
+        },
+        &pods,
+        log_tx,
+        Some(args.tail_lines_for(pods.len())),
+        None,
+    );
+
+    let mut stdout = std::io::stdout().lock();
+    loop {
+        tokio::select! {
+            _ = tokio::signal::ctrl_c() => break,
+            entry = log_rx.recv() => {
+                // Every stream has ended
+                let Some(entry) = entry else { break };
+                let muted = logs::rules::get().is_some_and(|r| r.mutes(&entry));
+                let printed = !muted && filter.as_ref().is_none_or(|f| f.matches(&entry));
+                metrics.record(&entry, printed);
+                if printed && writeln!(stdout, "{} {}", entry.pod_name(), entry.raw).is_err() {
+                    // Reader went away (e.g. `| head`)
+                    break;
+                }
+            }
+        }
+    }
+    stream_manager.stop();
+    Ok(())
 }
 
 async fn run_app(args: Args) -> Result<()> {
+    // Resolve the theme before anything picks colors from it
+    Theme::set(args.theme);
+
     // Validate filter pattern early (before any expensive initialization)
-    if let Some(filter_pattern) = &args.filter {
-        let test_result = if args.ignore_case {
-            CompiledFilter::new_case_insensitive(filter_pattern)
-        } else {
-            CompiledFilter::new(filter_pattern)
-        };
-        if let Err(e) = test_result {
-            anyhow::bail!("Invalid filter pattern '{}': {}", filter_pattern, e);
-        }
+    if let Some(filter_pattern) = &args.filter
+        && let Err(e) = config_filter(filter_pattern, args.ignore_case, args.invert_match)
+    {
+        anyhow::bail!("Invalid filter pattern '{}': {}", filter_pattern, e);
     }
+    let mut hooks = Hooks::new(&args.hooks)?;
 
     // Create action channels
     let (action_tx, mut action_rx) = mpsc::unbounded_channel::<Action>();
@@ -417,151 +1388,138 @@ async fn run_app(args: Args) -> Result<()> {
     // Bounded channel for log entries - 2x buffer size provides headroom while preventing OOM
     let channel_capacity = args.buffer_size * 2;
     let (log_tx, mut log_rx) = mpsc::channel::<LogEntry>(channel_capacity);
+    // Older history batches fetched on demand when scrolling past the top
+    let (history_tx, mut history_rx) = mpsc::unbounded_channel::<HistoryBatch>();
+    // Match counts for the filter bar preview, tagged with the input generation
+    let (preview_tx, mut preview_rx) = mpsc::unbounded_channel::<(u64, usize)>();
 
     // Initialize state
     let mut state = AppState::new(action_tx.clone());
+    state.ui_state.time_range = args.time_range;
+    state.ui_state.time_window = args.time_window;
+    state.recent_targets = RecentTargets::load();
+    state.key_sets = KeySets::load();
+    state.densities = Densities::load();
+    state.pinned = session::load_pins().union(&args.pins);
+    state.pod_color_overrides = args.pod_colors.clone();
+    state.sampling = args.sampling.clone();
+    state.skip_old_replicasets = args.skip_old_replicasets;
+    state.ui_state.columns = args.columns.clone();
+    state.ui_state.json_fold_depth = args.json.fold_depth;
+    state.ui_state.json_format = args.json.clone();
+    state.links = args.links.clone();
+    if args.plugins {
+        for error in plugins::init() {
+            state.notify(Severity::Warning, error);
+        }
+    }
 
     // Track the active K8s client for the selected context
     let mut active_client: Option<kube::Client> = None;
+    // Background namespace/deployment/pod load, if one is running
+    let mut load_task: Option<tokio::task::JoinHandle<()>> = None;
 
     // Log buffer and stream manager
     let log_buffer = LogBuffer::new(args.buffer_size);
-    let mut stream_manager = LogStreamManager::new();
-
-    // Load kubeconfig
-    let kube_client = KubeClient::new().await?;
+    let mut stream_manager = LogStreamManager::new(
+        log_buffer.ingest_stats(),
+        args.max_line_bytes,
+        args.limit_bytes,
+    );
+    stream_manager.set_excluded_containers(args.exclude_containers.clone());
+    stream_manager.set_sidecar_logs(args.sidecar_logs);
+    // `kubescope serve`: the buffer over HTTP, with the view's filters for `view=1`
+    let shared_view: Option<SharedView> = match args.serve_addr {
+        Some(addr) => {
+            let view = SharedView::default();
+            share::serve(addr, log_buffer.clone(), Arc::clone(&view)).await?;
+            state.notify(
+                Severity::Info,
+                format!(
+                    "Sharing logs on http://{}/logs (add ?view=1 for this view)",
+                    addr
+                ),
+            );
+            Some(view)
+        }
+        None => None,
+    };
 
-    // Handle CLI arguments for direct navigation
-    // Fast path: when all three args (context, namespace, deployment) are provided,
-    // skip listing resources and fetch directly to minimize startup time
-    if let (Some(context_name), Some(namespace_name), Some(deployment_name)) =
-        (&args.context, &args.namespace, &args.deployment)
-    {
-        // Fast path: all args provided - skip validation lists, fetch directly
-        // This avoids listing all namespaces and all deployments (saves ~1s on EKS)
-        // Also skip loading contexts list (will be loaded lazily if user navigates back)
-        let client = kube_client.client_for_context(context_name).await?;
+    // Kubeconfig and cluster setup run behind the connecting screen, since
+    // auth plugins can take a while; events that need a client wait for it
+    let mut kube_client: Option<Arc<KubeClient>> = None;
+    let mut startup_failure: Option<anyhow::Error> = None;
+    let (startup_tx, mut startup_rx) = mpsc::unbounded_channel::<StartupEvent>();
+    state.current_screen = Screen::Connecting;
+    let use_context_namespace = args.use_context_namespace;
+    let target = (
+        args.context.clone(),
+        args.namespace.clone(),
+        args.deployment.clone(),
+    );
+    tokio::spawn(async move {
+        let event = match connect(target, use_context_namespace, &startup_tx).await {
+            Ok(startup) => StartupEvent::Ready(Box::new(startup)),
+            Err(e) => StartupEvent::Failed(e),
+        };
+        let _ = startup_tx.send(event);
+    });
 
-        // Fetch deployment directly by name (not listing all)
-        let deployment = kube_client
-            .get_deployment(&client, namespace_name, deployment_name)
-            .await?;
+    // Initialize TUI and event handler
+    let mut tui = Tui::new()?;
+    if args.title {
+        tui.enable_title(args.tmux_title)?;
+    }
+    let mut events = EventHandler::new(ACTIVE_TICK);
+    let keybindings = KeyBindings::new();
+    let mut palette_state = CommandPaletteState::default();
 
-        // Fetch pods for deployment
-        let pods = kube_client
-            .get_pods_for_deployment(&client, namespace_name, &deployment)
-            .await?;
-
-        // Set up state for log viewer
-        state.selected_context = Some(context_name.clone());
-        state.selected_namespace = Some(namespace_name.clone());
-        state.selected_deployment = Some(deployment_name.clone());
-        state.deployments = vec![deployment];
-        state.pods = pods;
-        state.screen_stack.push(Screen::ContextSelect);
-        state.screen_stack.push(Screen::NamespaceSelect);
-        state.screen_stack.push(Screen::DeploymentSelect);
-        state.current_screen = Screen::LogViewer;
-        active_client = Some(client.clone());
-
-        // Start log streaming
-        log_buffer.clear();
-        let since_seconds = state.ui_state.time_range.as_seconds();
-        stream_manager.start_streams(
-            client,
-            namespace_name,
-            &state.pods,
-            log_tx.clone(),
-            Some(args.tail_lines),
-            since_seconds,
-        );
-    } else {
-        // Non-fast path: load contexts for navigation
-        state.contexts = kube_client.get_contexts();
-
-        if let Some(context_name) = &args.context {
-            // Partial args path: need to load some lists for navigation
-            // Validate context exists
-            if !state.contexts.iter().any(|c| &c.name == context_name) {
-                anyhow::bail!("Context '{}' not found in kubeconfig", context_name);
-            }
-
-            // Connect to context and load namespaces
-            let client = kube_client.client_for_context(context_name).await?;
-            let namespaces = kube_client.get_namespaces(&client).await?;
-
-            state.selected_context = Some(context_name.clone());
-            state.namespaces = namespaces;
-            active_client = Some(client.clone());
-
-            if let Some(namespace_name) = &args.namespace {
-                // Validate namespace exists
-                if !state.namespaces.iter().any(|n| &n.name == namespace_name) {
-                    anyhow::bail!(
-                        "Namespace '{}' not found in context '{}'",
-                        namespace_name,
-                        context_name
-                    );
-                }
-
-                // Load deployments (need full list for deployment select screen)
-                let deployments = kube_client.get_deployments(&client, namespace_name).await?;
-                state.selected_namespace = Some(namespace_name.clone());
-                state.deployments = deployments;
-                state.screen_stack.push(Screen::ContextSelect);
-
-                // Go to deployment select (deployment not provided)
-                state.screen_stack.push(Screen::NamespaceSelect);
-                state.current_screen = Screen::DeploymentSelect;
-            } else {
-                // Go to namespace select
-                state.screen_stack.push(Screen::ContextSelect);
-                state.current_screen = Screen::NamespaceSelect;
-            }
-        }
-    }
-
-    // Initialize TUI and event handler (after K8s operations to minimize time-to-first-render)
-    let mut tui = Tui::new()?;
-    let mut events = EventHandler::new(Duration::from_millis(100));
-    let keybindings = KeyBindings::new();
-    let mut palette_state = CommandPaletteState::default();
-    let commands = log_viewer_commands();
-
-    // Apply CLI filter if provided (already validated at startup)
-    if let Some(filter_pattern) = &args.filter {
-        let mut filter = if args.ignore_case {
-            CompiledFilter::new_case_insensitive(filter_pattern)
-        } else {
-            CompiledFilter::new(filter_pattern)
-        }
-        .expect("Filter pattern was validated at startup");
-
-        if args.invert_match {
-            filter = filter.inverted();
-        }
-        state.ui_state.active_filter = Some(filter);
-        state.ui_state.search_input = filter_pattern.clone();
-        state.ui_state.filter_case_insensitive = args.ignore_case;
-    }
+    // Apply CLI filter if provided (already validated at startup)
+    if let Some(filter_pattern) = &args.filter {
+        let filter = config_filter(filter_pattern, args.ignore_case, args.invert_match)
+            .expect("Filter pattern was validated at startup");
+        state.ui_state.active_filter = Some(filter);
+        state.ui_state.search_input = filter_pattern.clone();
+        state.ui_state.filter_case_insensitive = args.ignore_case;
+        state.ui_state.usage.record_filter(filter_pattern);
+    }
 
     // Adaptive render rate limiting state
     let mut last_render_time = std::time::Instant::now();
     let mut prev_dropped_count: u64 = 0;
     let mut user_input_pending = false;
+    // Last key press, for stopping the streams of a forgotten session
+    let mut last_input = std::time::Instant::now();
+    // A client rebuild for expired credentials is in flight
+    let mut refreshing_credentials = false;
+    // Recent namespace/deployment lists, shown while the live ones load
+    let mut listings = ListingCache::load();
+    // A load picked from a cached list before the context's client was ready
+    let mut pending_load: Option<InternalAction> = None;
+    // Lines stamped before the streams started are backfill, which hooks skip
+    let mut hooks_live_since = chrono::Utc::now();
+    // Last pod status poll for pod_restarted hooks
+    let mut last_pod_poll = std::time::Instant::now();
+    // Edits to .kubescope apply live
+    let mut config_watch = (!args.no_config).then(ConfigWatch::new);
+    // Entries taken off the log channel per loop iteration
+    let mut log_batch: Vec<LogEntry> = Vec::with_capacity(LOG_BATCH);
+    // New log lines waiting to be drawn (coalesced separately from other changes)
+    let mut log_dirty = false;
+    // When a held-back render is due, so the loop wakes up for it
+    let mut render_at: Option<std::time::Instant> = None;
 
     // Render intervals: normal (100ms) vs throttled when under load (250ms)
     let normal_render_interval = Duration::from_millis(100);
     let throttled_render_interval = Duration::from_millis(250);
 
-    // Initial render (no dropped logs yet since streams haven't started)
+    // Initial render
     render(
         &mut tui,
         &mut state,
         &log_buffer,
         &mut palette_state,
-        &commands,
-        0, // dropped_count
+        &keybindings,
     )?;
 
     // Main event loop
@@ -573,20 +1531,67 @@ async fn run_app(args: Args) -> Result<()> {
                     Event::Key(key) => {
                         // Mark that user input occurred - always render immediately
                         user_input_pending = true;
+                        last_input = std::time::Instant::now();
 
-                        // If help overlay is visible, Esc closes it instead of going back
-                        if state.ui_state.help_visible {
-                            if key.code == crossterm::event::KeyCode::Esc || key.code == crossterm::event::KeyCode::Char('?') {
-                                state.ui_state.help_visible = false;
-                                continue;
+                        // Any key wakes an idle viewer, and does nothing else
+                        if state.ui_state.idle {
+                            state.ui_state.idle = false;
+                            let _ = internal_tx.send(InternalAction::ResumeLogStreaming);
+                        // The error modal takes all keys until dismissed
+                        } else if state.ui_state.error.is_some() {
+                            if let Some(action) = keybindings.get_error_action(&key) {
+                                let _ = action_tx.send(action);
+                            }
+                        // A confirmation modal takes all keys until answered
+                        } else if state.ui_state.confirm.is_some() {
+                            if let Some(action) = keybindings.get_confirm_action(&key) {
+                                let _ = action_tx.send(action);
+                            }
+                        // Help overlay takes all keys while open (scrolling, Esc closes it)
+                        } else if state.ui_state.notifications_visible {
+                            if let Some(action) = keybindings.get_notifications_action(&key) {
+                                let _ = action_tx.send(action);
+                            }
+                        } else if state.ui_state.help_visible {
+                            if let Some(action) = keybindings.get_help_action(&key) {
+                                let _ = action_tx.send(action);
+                            }
+                        } else if state.ui_state.stream_params_visible {
+                            if let Some(action) = keybindings.get_stream_params_action(&key) {
+                                let _ = action_tx.send(action);
+                            }
+                        } else if state.ui_state.full_line.is_some() && state.current_screen == Screen::LogViewer {
+                            if let Some(action) = keybindings.get_full_line_action(&key) {
+                                let _ = action_tx.send(action);
+                            }
+                        } else if state.ui_state.schema_drift_visible && state.current_screen == Screen::LogViewer {
+                            if let Some(action) = keybindings.get_schema_drift_action(&key) {
+                                let _ = action_tx.send(action);
+                            }
+                        } else if state.ui_state.parse_diagnostics_visible && state.current_screen == Screen::LogViewer {
+                            if let Some(action) = keybindings.get_parse_diagnostics_action(&key) {
+                                let _ = action_tx.send(action);
                             }
-                        }
-
                         // Check if command palette is open
-                        if palette_state.visible {
+                        } else if palette_state.visible {
                             if let Some(action) = keybindings.get_palette_action(&key) {
                                 let _ = action_tx.send(action);
                             }
+                        // Check if bookmarks overlay is open
+                        } else if state.ui_state.bookmarks_visible && state.current_screen == Screen::LogViewer {
+                            if let Some(action) = keybindings.get_bookmarks_action(&key) {
+                                let _ = action_tx.send(action);
+                            }
+                        // Check if columns overlay is open
+                        } else if state.ui_state.columns_visible && state.current_screen == Screen::LogViewer {
+                            if let Some(action) = keybindings.get_columns_action(&key) {
+                                let _ = action_tx.send(action);
+                            }
+                        // Check if pod panel has focus
+                        } else if state.ui_state.pod_panel_visible && state.current_screen == Screen::LogViewer {
+                            if let Some(action) = keybindings.get_pod_panel_action(&key) {
+                                let _ = action_tx.send(action);
+                            }
                         // Check if JSON key filter is open
                         } else if state.ui_state.json_key_filter_active && state.current_screen == Screen::LogViewer {
                             if let Some(action) = keybindings.get_json_key_filter_action(&key) {
@@ -601,7 +1606,9 @@ async fn run_app(args: Args) -> Result<()> {
                             let context = match state.current_screen {
                                 Screen::ContextSelect |
                                 Screen::NamespaceSelect |
-                                Screen::DeploymentSelect => KeyContext::ListNavigation,
+                                Screen::DeploymentSelect |
+                                Screen::RecentSelect => KeyContext::ListNavigation,
+                                Screen::Connecting => KeyContext::Global,
                                 Screen::LogViewer => KeyContext::LogViewer,
                             };
 
@@ -612,39 +1619,270 @@ async fn run_app(args: Args) -> Result<()> {
                         // Note: render_dirty will be set when action is processed
                     }
                     Event::Tick => {
-                        // Check if log count changed to trigger re-render
+                        // Expire toasts on every screen
+                        if state.ui_state.notifications.prune() {
+                            state.render_dirty = true;
+                        }
+                        state.ui_state.usage.sample_rate();
+                        if state.ui_state.usage_visible {
+                            state.render_dirty = true;
+                        }
+                        if state.ui_state.stream_params_visible {
+                            state.ui_state.stream_params = stream_manager.stream_params();
+                            state.render_dirty = true;
+                        }
+                        // Keep the load spinner turning
+                        if state.ui_state.loading.is_some() {
+                            state.render_dirty = true;
+                        }
+                        // Keep the performance HUD live
+                        if state.ui_state.perf_visible {
+                            let perf = &mut state.ui_state.perf;
+                            perf.queue = (log_tx.max_capacity() - log_tx.capacity(), log_tx.max_capacity());
+                            perf.sample_rates(log_buffer.pod_line_counts());
+                            state.render_dirty = true;
+                        }
+
+                        // Apply edits to .kubescope
+                        if let Some(watch) = &mut config_watch
+                            && let Some(reloaded) = watch.poll()
+                        {
+                            match reloaded.and_then(|config| {
+                                let changes = apply_config_changes(&mut state, &mut hooks, &watch.config, &config)?;
+                                watch.config = config;
+                                Ok(changes)
+                            }) {
+                                Ok((applied, restart)) => {
+                                    if !applied.is_empty() {
+                                        state.notify(Severity::Info, format!("Reloaded .kubescope ({})", applied.join(", ")));
+                                    }
+                                    if !restart.is_empty() {
+                                        state.notify(Severity::Warning, format!("{} change on restart", restart.join(" and ")));
+                                    }
+                                }
+                                Err(e) => state.notify(Severity::Warning, format!("Kept the current settings: .kubescope has an error: {}", e)),
+                            }
+                            state.render_dirty = true;
+                        }
+
+                        // Streams refused for expired credentials wait on a new client
+                        if stream_manager.has_expired() && !refreshing_credentials {
+                            refreshing_credentials = true;
+                            let _ = internal_tx.send(InternalAction::RefreshCredentials);
+                        }
+
+                        // Follow the initial backfill in the pod panel and log title
+                        let backfill = stream_manager.backfill();
+                        if backfill != state.ui_state.backfill {
+                            state.ui_state.backfill = backfill;
+                            state.render_dirty = true;
+                        }
+
+                        // Stop streaming for a session nobody is looking at
+                        if let Some(timeout) = args.idle_timeout
+                            && state.current_screen == Screen::LogViewer
+                            && state.ui_state.streams_stopped_at.is_none()
+                            && last_input.elapsed() >= timeout
+                        {
+                            stream_manager.stop();
+                            state.ui_state.history_loading = false;
+                            state.ui_state.streams_stopped_at = Some(chrono::Utc::now());
+                            state.ui_state.idle = true;
+                            state.render_dirty = true;
+                        }
+
+                        // Watch restart counts for pod_restarted hooks
+                        if hooks.wants(HookEvent::PodRestarted)
+                            && state.current_screen == Screen::LogViewer
+                            && state.ui_state.streams_stopped_at.is_none()
+                            && last_pod_poll.elapsed() >= POD_POLL_INTERVAL
+                            && let (Some(kube_client), Some(client), Some(namespace), Some(deployment)) = (
+                                kube_client.clone(),
+                                active_client.clone(),
+                                state.selected_namespace.clone(),
+                                state.selected_deployment.as_ref().and_then(|name| state.all_deployments.iter().find(|d| &d.name == name)).cloned(),
+                            )
+                        {
+                            last_pod_poll = std::time::Instant::now();
+                            let internal_tx = internal_tx.clone();
+                            tokio::spawn(async move {
+                                match kube_client.get_pods_for_deployment(&client, &namespace, &deployment).await {
+                                    Ok(pods) => {
+                                        let _ = internal_tx.send(InternalAction::PodStatusesLoaded(namespace, deployment.name, pods));
+                                    }
+                                    Err(e) => tracing::debug!(error = %e, "pod status poll failed"),
+                                }
+                            });
+                        }
+
                         if state.current_screen == Screen::LogViewer {
-                            let current_count = log_buffer.len();
-                            if current_count != state.last_log_count {
-                                state.last_log_count = current_count;
+                            if state.announce_schema_drift(&log_buffer) {
                                 state.render_dirty = true;
                             }
+
+                            // Count preview matches off-thread once typing pauses
+                            if let Some((generation, query)) =
+                                state.ui_state.search_preview.due(SEARCH_PREVIEW_DEBOUNCE)
+                            {
+                                let entries = state.search_preview_entries(&log_buffer);
+                                let preview_tx = preview_tx.clone();
+                                tokio::task::spawn_blocking(move || {
+                                    let count = entries.iter().filter(|e| query.matches(e)).count();
+                                    let _ = preview_tx.send((generation, count));
+                                });
+                            }
                         }
                     }
                     Event::Resize(_, _) => {
                         state.render_dirty = true;
                     }
                     Event::Error(e) => {
-                        state.show_error(e);
+                        state.notify(Severity::Error, e);
                         state.render_dirty = true;
                     }
                 }
             }
 
-            // Handle incoming log entries
-            Some(entry) = log_rx.recv() => {
-                log_buffer.push(entry);
-                // Don't mark dirty here - tick handler will check for changes
+            // Handle incoming log entries, draining bursts in one go
+            1.. = log_rx.recv_many(&mut log_batch, LOG_BATCH) => {
+                if hooks.wants(HookEvent::LineMatched) {
+                    for entry in log_batch.iter().filter(|e| e.timestamp.is_some_and(|ts| ts >= hooks_live_since)) {
+                        hooks.fire_line(entry.display_text(), || {
+                            let mut payload = hook_payload(&state);
+                            payload.insert("pod".into(), entry.pod_name().into());
+                            payload.insert("line".into(), entry.display_text().into());
+                            payload.insert("level".into(), entry.level.as_str().into());
+                            payload.insert("timestamp".into(), serde_json::json!(entry.timestamp));
+                            payload
+                        });
+                    }
+                }
+                state.ui_state.usage.record_lines(&log_batch);
+                log_buffer.push_batch(log_batch.drain(..));
+                // Drawn at the capped frame rate below, not once per batch
+                if state.current_screen == Screen::LogViewer {
+                    log_dirty = true;
+                }
+            }
+
+            // Wake up for a render held back by the frame rate cap
+            _ = tokio::time::sleep_until(tokio::time::Instant::from_std(
+                render_at.unwrap_or_else(std::time::Instant::now),
+            )), if render_at.is_some() => {}
+
+            // Handle older history fetched for scrollback
+            Some(batch) = history_rx.recv() => {
+                state.ui_state.history_loading = false;
+                state.ui_state.history_exhausted = batch.exhausted;
+
+                if batch.limited {
+                    state.notify(Severity::Warning, format!(
+                        "Older logs are past the {} read limit (limit_bytes)",
+                        format_bytes(args.limit_bytes.unwrap_or_default().max(0) as usize)
+                    ));
+                }
+                if batch.entries.is_empty() {
+                    if batch.exhausted && !batch.limited {
+                        state.notify(Severity::Info, "No older logs available");
+                    }
+                } else {
+                    let requested = batch.entries.len();
+                    let added = log_buffer.prepend(batch.entries);
+                    if added < requested {
+                        // Buffer is full, further fetches would be discarded
+                        state.ui_state.history_exhausted = true;
+                        state.notify(Severity::Warning, format!(
+                            "Loaded {} older logs ({} skipped, buffer full)",
+                            added,
+                            requested - added
+                        ));
+                    }
+                    // Keep the line that was at the top in place
+                    state.ui_state.pending_jump = state.ui_state.top_visible_entry.clone();
+                }
+                state.render_dirty = true;
+            }
+
+            // Handle finished filter bar preview counts
+            Some((generation, count)) = preview_rx.recv() => {
+                if state.ui_state.search_preview.finish(generation, count) {
+                    state.render_dirty = true;
+                }
             }
 
             // Handle user actions
             Some(action) = action_rx.recv() => {
+                let commands = commands_for(state.current_screen.clone());
                 handle_action(&mut state, &internal_tx, &log_buffer, &mut palette_state, &commands, action);
+                // Going back while a load runs cleared it; don't let the result navigate
+                if state.ui_state.loading.is_none()
+                    && let Some(task) = load_task.take()
+                {
+                    task.abort();
+                }
+                if let Some(view) = &shared_view {
+                    *view.write() = ViewFilters::from_state(&state);
+                }
                 state.render_dirty = true;  // Actions always trigger re-render
             }
 
-            // Handle internal async actions
-            Some(internal) = internal_rx.recv() => {
+            // Startup progress, then the resolved CLI target
+            Some(event) = startup_rx.recv() => {
+                match event {
+                    StartupEvent::Progress(step) => {
+                        if let Some(done) = state.ui_state.loading.take() {
+                            state.ui_state.startup_steps.push(done.label);
+                        }
+                        state.ui_state.loading = Some(Loading::new(step));
+                    }
+                    StartupEvent::Ready(startup) => {
+                        let startup = *startup;
+                        state.ui_state.loading = None;
+                        kube_client = Some(Arc::new(startup.kube_client));
+                        active_client = startup.client;
+                        state.contexts = startup.contexts;
+                        state.set_namespaces(startup.namespaces);
+                        state.set_deployments(startup.deployments);
+                        state.pods = startup.pods;
+                        state.selected_context = startup.context;
+                        state.selected_namespace = startup.namespace;
+                        state.selected_deployment = startup.deployment;
+                        state.screen_stack = startup.screen_stack;
+                        state.current_screen = startup.screen;
+                        // Pinned items first in every list loaded above
+                        state.sort_pinned();
+                        if state.current_screen == Screen::NamespaceSelect {
+                            state.select_context_namespace();
+                        }
+                        if state.current_screen == Screen::LogViewer {
+                            state.sample_pods();
+                            state.assign_pod_colors();
+                            record_recent_target(&mut state);
+                            state.restore_json_keys();
+                            state.restore_density();
+                            let _ = internal_tx.send(InternalAction::StartLogStreaming);
+                            if let Some(reason) = startup.no_pods_reason {
+                                state.notify(Severity::Warning, format!("No pods: {}", reason));
+                            }
+                        }
+                    }
+                    StartupEvent::Failed(e) => {
+                        // Stay on the connecting screen; quitting reports the error
+                        state.ui_state.loading = None;
+                        state.ui_state.startup_error = Some(format!("{:#}", e));
+                        match e.downcast::<SetupReport>() {
+                            // The guide explains it, so quitting isn't an error
+                            Ok(report) => state.ui_state.setup = Some(report),
+                            Err(e) => startup_failure = Some(e),
+                        }
+                    }
+                }
+                state.render_dirty = true;
+            }
+
+            // Handle internal async actions (once connected)
+            Some(internal) = internal_rx.recv(), if kube_client.is_some() => {
+                let Some(kube_client) = kube_client.clone() else { continue };
                 match internal {
                     InternalAction::LoadContexts => {
                         // Lazy load contexts (used when navigating back in fast path)
@@ -654,77 +1892,327 @@ async fn run_app(args: Args) -> Result<()> {
 
                     InternalAction::ContextsLoaded(contexts) => {
                         state.contexts = contexts;
+                        state.sort_pinned();
                         // Already on ContextSelect screen, just refresh
                     }
 
+                    // Loads run in the background so the spinner keeps turning;
+                    // starting another one cancels whatever was still running
                     InternalAction::LoadNamespaces(context_name) => {
-                        match kube_client.client_for_context(&context_name).await {
-                            Ok(client) => {
-                                match kube_client.get_namespaces(&client).await {
-                                    Ok(namespaces) => {
-                                        active_client = Some(client);
-                                        let _ = internal_tx.send(InternalAction::NamespacesLoaded(namespaces));
-                                    }
-                                    Err(e) => {
-                                        let _ = internal_tx.send(InternalAction::Error(
-                                            format!("Failed to load namespaces: {}", e)
-                                        ));
-                                    }
-                                }
-                            }
-                            Err(e) => {
-                                let _ = internal_tx.send(InternalAction::Error(
-                                    format!("Failed to connect to cluster: {}", e)
-                                ));
-                            }
+                        pending_load = None;
+                        // A recent listing shows straight away and is refreshed behind it
+                        let cached = listings.namespaces(&context_name);
+                        let refresh = cached.is_some();
+                        if let Some(namespaces) = cached {
+                            // The previous context's client can't serve this one's lists
+                            active_client = None;
+                            state.set_namespaces(namespaces);
+                            show_namespaces(&mut state, &action_tx, args.use_context_namespace);
+                            state.ui_state.loading = Some(Loading::new("Refreshing namespaces"));
+                        } else {
+                            state.ui_state.loading = Some(Loading::new("Loading namespaces"));
                         }
+                        let kube_client = kube_client.clone();
+                        let internal_tx = internal_tx.clone();
+                        replace_load_task(&mut load_task, tokio::spawn(async move {
+                            let result = match kube_client.client_for_context(&context_name).await {
+                                Ok(client) => match kube_client.get_namespaces(&client).await {
+                                    Ok(namespaces) if refresh => Ok(InternalAction::NamespacesRefreshed(client, namespaces)),
+                                    Ok(namespaces) => Ok(InternalAction::NamespacesLoaded(client, namespaces)),
+                                    Err(e) => Err(("Failed to load namespaces", e)),
+                                },
+                                Err(e) => Err(("Failed to connect to cluster", e)),
+                            };
+                            let _ = internal_tx.send(result.unwrap_or_else(|(what, e)| {
+                                InternalAction::Error(
+                                    format!("{}: {}", what, e),
+                                    ApiError::classify(&e),
+                                    Some(Action::SelectContext(context_name)),
+                                )
+                            }));
+                        }));
                     }
 
                     InternalAction::LoadDeployments(namespace) => {
-                        if let Some(client) = &active_client {
-                            match kube_client.get_deployments(client, &namespace).await {
-                                Ok(deployments) => {
-                                    let _ = internal_tx.send(InternalAction::DeploymentsLoaded(deployments));
-                                }
-                                Err(e) => {
-                                    let _ = internal_tx.send(InternalAction::Error(
-                                        format!("Failed to load deployments: {}", e)
-                                    ));
-                                }
+                        let context = state.selected_context.clone();
+                        let cached = context.as_deref().and_then(|c| listings.deployments(c, &namespace));
+                        let refresh = cached.is_some();
+                        if let Some(deployments) = cached {
+                            state.set_deployments(deployments);
+                            show_deployments(&mut state, &action_tx);
+                        }
+                        match active_client.clone() {
+                            Some(client) => {
+                                let label = if refresh { "Refreshing deployments" } else { "Loading deployments" };
+                                state.ui_state.loading = Some(Loading::new(label));
+                                replace_load_task(
+                                    &mut load_task,
+                                    spawn_deployments_load(&kube_client, client, context, namespace, &internal_tx, refresh),
+                                );
                             }
+                            // Shown from the cache before the context's client is ready
+                            None if refresh => pending_load = Some(InternalAction::RefreshDeployments(namespace)),
+                            None => pending_load = Some(InternalAction::LoadDeployments(namespace)),
+                        }
+                    }
+
+                    InternalAction::RefreshDeployments(namespace) => {
+                        if let Some(client) = active_client.clone() {
+                            state.ui_state.loading = Some(Loading::new("Refreshing deployments"));
+                            let context = state.selected_context.clone();
+                            replace_load_task(
+                                &mut load_task,
+                                spawn_deployments_load(&kube_client, client, context, namespace, &internal_tx, true),
+                            );
                         }
                     }
 
                     InternalAction::LoadPods(namespace, deployment) => {
-                        if let Some(client) = &active_client {
-                            match kube_client.get_pods_for_deployment(client, &namespace, &deployment).await {
-                                Ok(pods) => {
-                                    let _ = internal_tx.send(InternalAction::PodsLoaded(pods));
-                                }
-                                Err(e) => {
-                                    let _ = internal_tx.send(InternalAction::Error(
-                                        format!("Failed to load pods: {}", e)
-                                    ));
-                                }
-                            }
+                        if active_client.is_none() {
+                            // Picked from a cached list; runs once the client is ready
+                            pending_load = Some(InternalAction::LoadPods(namespace, deployment));
+                        } else if let Some(client) = active_client.clone() {
+                            state.ui_state.loading = Some(Loading::new("Loading pods"));
+                            let kube_client = kube_client.clone();
+                            let internal_tx = internal_tx.clone();
+                            let context = state.selected_context.clone();
+                            replace_load_task(&mut load_task, tokio::spawn(async move {
+                                let pods = retry_unauthorized(&kube_client, client.clone(), context.as_deref(), &internal_tx, |client| {
+                                    let kube_client = kube_client.clone();
+                                    let namespace = namespace.clone();
+                                    let deployment = deployment.clone();
+                                    async move { kube_client.get_pods_for_deployment(&client, &namespace, &deployment).await }
+                                })
+                                .await;
+                                let _ = internal_tx.send(
+                                    match pods {
+                                        // Say why rather than opening an empty viewer
+                                        Ok(pods) if pods.is_empty() => {
+                                            let reason = kube_client.diagnose_no_pods(&client, &namespace, &deployment).await;
+                                            InternalAction::Error(
+                                                format!("No pods for {}: {}", deployment.name, reason),
+                                                None,
+                                                Some(Action::SelectDeployment(deployment.name)),
+                                            )
+                                        }
+                                        Ok(pods) => InternalAction::PodsLoaded(pods),
+                                        Err(e) => InternalAction::Error(
+                                            format!("Failed to load pods: {}", e),
+                                            ApiError::classify(&e),
+                                            Some(Action::SelectDeployment(deployment.name)),
+                                        ),
+                                    },
+                                );
+                            }));
+                        }
+                    }
+
+                    InternalAction::NamespacesLoaded(client, namespaces) => {
+                        state.ui_state.loading = None;
+                        active_client = Some(client);
+                        if let Some(context) = &state.selected_context {
+                            listings.set_namespaces(context, namespaces.clone());
+                            listings.save();
                         }
+                        state.set_namespaces(namespaces);
+                        show_namespaces(&mut state, &action_tx, args.use_context_namespace);
                     }
 
-                    InternalAction::NamespacesLoaded(namespaces) => {
-                        state.namespaces = namespaces;
-                        state.navigate_to(Screen::NamespaceSelect);
+                    InternalAction::NamespacesRefreshed(client, namespaces) => {
+                        state.ui_state.loading = None;
+                        active_client = Some(client);
+                        if let Some(context) = &state.selected_context {
+                            listings.set_namespaces(context, namespaces.clone());
+                            listings.save();
+                        }
+                        let on_screen = state.current_screen == Screen::NamespaceSelect;
+                        let selected = state.selected_namespace_name();
+                        state.set_namespaces(namespaces);
+                        if on_screen {
+                            state.reselect_by_name(selected.as_deref());
+                        }
+                        // Whatever was picked from the cached lists meanwhile
+                        if let Some(load) = pending_load.take() {
+                            let _ = internal_tx.send(load);
+                        }
                     }
 
                     InternalAction::DeploymentsLoaded(deployments) => {
-                        state.deployments = deployments;
-                        state.navigate_to(Screen::DeploymentSelect);
+                        state.ui_state.loading = None;
+                        if let (Some(context), Some(namespace)) = (&state.selected_context, &state.selected_namespace) {
+                            listings.set_deployments(context, namespace, deployments.clone());
+                            listings.save();
+                        }
+                        state.set_deployments(deployments);
+                        show_deployments(&mut state, &action_tx);
+                    }
+
+                    InternalAction::DeploymentsRefreshed(namespace, deployments) => {
+                        state.ui_state.loading = None;
+                        if let Some(context) = &state.selected_context {
+                            listings.set_deployments(context, &namespace, deployments.clone());
+                            listings.save();
+                        }
+                        if state.selected_namespace.as_ref() == Some(&namespace) {
+                            let on_screen = state.current_screen == Screen::DeploymentSelect;
+                            let selected = state.selected_deployment_name();
+                            state.set_deployments(deployments);
+                            if on_screen {
+                                state.reselect_by_name(selected.as_deref());
+                            }
+                        }
+                    }
+
+                    InternalAction::OpenRecent(target) => {
+                        // Connect and fetch the deployment directly, like the CLI fast path
+                        state.ui_state.loading = Some(Loading::new(format!("Opening {}", target.label())));
+                        let kube_client = kube_client.clone();
+                        let internal_tx = internal_tx.clone();
+                        replace_load_task(&mut load_task, tokio::spawn(async move {
+                            let result = match kube_client.client_for_context(&target.context).await {
+                                Ok(client) => kube_client
+                                    .get_deployment(&client, &target.namespace, &target.deployment)
+                                    .await
+                                    .map(|deployment| (client, deployment)),
+                                Err(e) => Err(e),
+                            };
+                            let _ = internal_tx.send(match result {
+                                Ok((client, deployment)) => InternalAction::RecentOpened(target, client, deployment),
+                                Err(e) => InternalAction::Error(
+                                    format!("Failed to open {}: {}", target.label(), e),
+                                    ApiError::classify(&e),
+                                    Some(Action::OpenRecent(target)),
+                                ),
+                            });
+                        }));
+                    }
+
+                    InternalAction::RecentOpened(target, client, deployment) => {
+                        state.ui_state.loading = None;
+                        stream_manager.stop();
+                        if state.selected_context.as_ref() != Some(&target.context) {
+                            // Namespace list belongs to the previous context
+                            state.set_namespaces(Vec::new());
+                        }
+                        state.selected_context = Some(target.context);
+                        state.selected_namespace = Some(target.namespace.clone());
+                        state.selected_deployment = Some(target.deployment);
+                        state.set_deployments(vec![deployment.clone()]);
+                        active_client = Some(client);
+                        // Rebuild the stack so Esc walks back through the normal screens
+                        state.screen_stack = vec![Screen::ContextSelect, Screen::NamespaceSelect];
+                        state.current_screen = Screen::DeploymentSelect;
+                        let _ = internal_tx.send(InternalAction::LoadPods(target.namespace, deployment));
                     }
 
                     InternalAction::PodsLoaded(pods) => {
+                        state.ui_state.loading = None;
+                        let old = pods.iter().filter(|p| p.old_replica_set).count();
+                        if old > 0 {
+                            let skipping = if args.skip_old_replicasets { ", not streamed" } else { "" };
+                            state.notify(
+                                Severity::Warning,
+                                format!("{} of {} pods are from an old ReplicaSet (rollout in progress?){}", old, pods.len(), skipping),
+                            );
+                        }
                         state.pods = pods;
+                        state.sample_pods();
+                        state.assign_pod_colors();
+                        record_recent_target(&mut state);
+                        state.restore_json_keys();
+                        state.restore_density();
                         state.navigate_to(Screen::LogViewer);
                         // Start log streaming
                         let _ = internal_tx.send(InternalAction::StartLogStreaming);
+
+                        // The same deployment in the extra clusters joins as it loads
+                        if let (Some(namespace), Some(deployment)) =
+                            (state.selected_namespace.clone(), state.selected_deployment.clone())
+                        {
+                            for context in &args.also_contexts {
+                                if state.selected_context.as_ref() == Some(context) {
+                                    continue;
+                                }
+                                let kube_client = kube_client.clone();
+                                let internal_tx = internal_tx.clone();
+                                let target = (context.clone(), namespace.clone(), deployment.clone());
+                                tokio::spawn(async move {
+                                    let (context, namespace, deployment) = target;
+                                    let result = load_cluster_pods(&kube_client, &context, &namespace, &deployment)
+                                        .await
+                                        .map_err(|e| format!("{:#}", e));
+                                    let _ = internal_tx.send(InternalAction::ClusterPodsLoaded {
+                                        context,
+                                        namespace,
+                                        deployment,
+                                        result,
+                                    });
+                                });
+                            }
+                        }
+                    }
+
+                    InternalAction::PodStatusesLoaded(namespace, deployment, pods) => {
+                        // Ignore statuses for a deployment that's no longer open
+                        if state.selected_namespace.as_ref() != Some(&namespace)
+                            || state.selected_deployment.as_ref() != Some(&deployment)
+                        {
+                            continue;
+                        }
+                        let base = hook_payload(&state);
+                        for fresh in pods {
+                            let Some(pod) = state.pods.iter_mut().find(|p| p.cluster.is_none() && p.name == fresh.name) else {
+                                continue;
+                            };
+                            for container in &fresh.containers {
+                                let previous = pod
+                                    .containers
+                                    .iter()
+                                    .find(|c| c.name == container.name)
+                                    .map_or(0, |c| c.restart_count);
+                                if container.restart_count > previous {
+                                    let mut payload = base.clone();
+                                    payload.insert("pod".into(), fresh.name.clone().into());
+                                    payload.insert("container".into(), container.name.clone().into());
+                                    payload.insert("restart_count".into(), container.restart_count.into());
+                                    payload.insert("previous_restart_count".into(), previous.into());
+                                    hooks.fire(HookEvent::PodRestarted, payload);
+                                }
+                            }
+                            pod.status = fresh.status;
+                            pod.containers = fresh.containers;
+                            state.render_dirty = true;
+                        }
+                    }
+
+                    InternalAction::ClusterPodsLoaded { context, namespace, deployment, result } => {
+                        // Ignore loads for a deployment that's no longer open
+                        if state.selected_namespace.as_ref() != Some(&namespace)
+                            || state.selected_deployment.as_ref() != Some(&deployment)
+                        {
+                            continue;
+                        }
+                        match result {
+                            Ok((client, pods)) => {
+                                stream_manager.add_cluster(&context, client);
+                                state.pods.retain(|p| p.cluster.as_ref() != Some(&context));
+                                state.pods.extend(pods.iter().cloned());
+                                state.assign_pod_colors();
+                                // Tells the clusters' lines apart
+                                state.ui_state.show_source = true;
+                                state.notify(
+                                    Severity::Info,
+                                    format!("Streaming {} pods from {}", pods.len(), context),
+                                );
+                                let added: Vec<PodInfo> =
+                                    pods.into_iter().filter(|p| state.is_streamed(&p.name)).collect();
+                                let _ = internal_tx.send(InternalAction::StreamPods(added));
+                            }
+                            Err(msg) => state.notify(
+                                Severity::Warning,
+                                format!("Not streaming {}/{} from {}: {}", namespace, deployment, context, msg),
+                            ),
+                        }
                     }
 
                     InternalAction::StartLogStreaming => {
@@ -732,23 +2220,84 @@ async fn run_app(args: Args) -> Result<()> {
                             && let Some(namespace) = &state.selected_namespace {
                                 // Clear previous logs
                                 log_buffer.clear();
+                                state.ui_state.forget_entry_views();
                                 // Reset scroll and enable auto-scroll
                                 state.ui_state.log_scroll = 0;
                                 state.ui_state.auto_scroll = true;
-                                // Get time range
-                                let since_seconds = state.ui_state.time_range.as_seconds();
+                                let tail_lines = state
+                                    .ui_state
+                                    .tail_lines_override
+                                    .unwrap_or_else(|| args.tail_lines_for(state.streamed_pods().len()));
+                                reset_history(&mut state.ui_state, tail_lines);
+                                state.ui_state.streams_stopped_at = None;
+                                state.ui_state.idle = false;
+                                // Get time range (a --since/--until window overrides it)
+                                let window = state.ui_state.time_window;
+                                let since_seconds = if window.is_set() {
+                                    None
+                                } else {
+                                    state.ui_state.time_range.as_seconds()
+                                };
+                                stream_manager.set_window(window);
+                                state.ui_state.usage.record_target(format!(
+                                    "{}/{}/{}",
+                                    state.selected_context.as_deref().unwrap_or("-"),
+                                    namespace,
+                                    state.selected_deployment.as_deref().unwrap_or("-")
+                                ));
                                 // Start streaming
                                 stream_manager.start_streams(
                                     client.clone(),
-                                    namespace,
-                                    &state.pods,
+                                    StreamSource {
+                                        namespace,
+                                        workload: state.selected_deployment.as_deref(),
+                                    },
+                                    &state.streamed_pods(),
                                     log_tx.clone(),
-                                    Some(args.tail_lines),
+                                    Some(tail_lines),
                                     since_seconds,
                                 );
+                                if let Some(seconds) = since_seconds {
+                                    spawn_retention_check(&internal_tx, client, namespace, &state.streamed_pods(), stream_manager.excluded_containers(), seconds);
+                                }
+                                hooks_live_since = chrono::Utc::now();
+                                last_pod_poll = std::time::Instant::now();
+                                if hooks.wants(HookEvent::StreamStarted) {
+                                    let mut payload = hook_payload(&state);
+                                    let pods: Vec<String> = state.streamed_pods().into_iter().map(|p| p.name).collect();
+                                    payload.insert("pods".into(), serde_json::json!(pods));
+                                    hooks.fire(HookEvent::StreamStarted, payload);
+                                }
                             }
                     }
 
+                    InternalAction::ToggleContainerExclusion => {
+                        let excluding = stream_manager.excluded_containers().is_empty() && !args.exclude_containers.is_empty();
+                        if excluding {
+                            stream_manager.set_excluded_containers(args.exclude_containers.clone());
+                            state.notify(Severity::Info, format!("Skipping {}", args.exclude_containers.join(", ")));
+                        } else {
+                            stream_manager.set_excluded_containers(Vec::new());
+                            state.notify(Severity::Info, "Reading each pod's first container, sidecars included");
+                        }
+                        if state.current_screen == Screen::LogViewer {
+                            let _ = internal_tx.send(InternalAction::RestartLogStreaming);
+                        }
+                    }
+                    InternalAction::ToggleSidecarLogs => {
+                        let enabled = !stream_manager.sidecar_logs();
+                        stream_manager.set_sidecar_logs(enabled);
+                        if !enabled {
+                            state.notify(Severity::Info, "Sidecar access logs off");
+                        } else if stream_manager.excluded_containers().is_empty() {
+                            state.notify(Severity::Warning, "Sidecar access logs on, but no containers are excluded to stream");
+                        } else {
+                            state.notify(Severity::Info, format!("Streaming {} access logs too", stream_manager.excluded_containers().join(", ")));
+                        }
+                        if state.current_screen == Screen::LogViewer {
+                            let _ = internal_tx.send(InternalAction::RestartLogStreaming);
+                        }
+                    }
                     InternalAction::RestartLogStreaming => {
                         if let Some(client) = &active_client
                             && let Some(namespace) = &state.selected_namespace {
@@ -756,28 +2305,341 @@ async fn run_app(args: Args) -> Result<()> {
                                 stream_manager.stop();
                                 // Clear logs for fresh start with new time range
                                 log_buffer.clear();
+                                state.ui_state.forget_entry_views();
                                 state.ui_state.log_scroll = 0;
                                 state.ui_state.auto_scroll = true;
-                                // Get time range
-                                let since_seconds = state.ui_state.time_range.as_seconds();
+                                let tail_lines = state
+                                    .ui_state
+                                    .tail_lines_override
+                                    .unwrap_or_else(|| args.tail_lines_for(state.streamed_pods().len()));
+                                reset_history(&mut state.ui_state, tail_lines);
+                                state.ui_state.streams_stopped_at = None;
+                                state.ui_state.idle = false;
+                                // Get time range (a --since/--until window overrides it)
+                                let window = state.ui_state.time_window;
+                                let since_seconds = if window.is_set() {
+                                    None
+                                } else {
+                                    state.ui_state.time_range.as_seconds()
+                                };
+                                stream_manager.set_window(window);
                                 // Restart streaming with new time range
                                 stream_manager.start_streams(
                                     client.clone(),
-                                    namespace,
-                                    &state.pods,
+                                    StreamSource {
+                                        namespace,
+                                        workload: state.selected_deployment.as_deref(),
+                                    },
+                                    &state.streamed_pods(),
                                     log_tx.clone(),
-                                    Some(args.tail_lines),
+                                    Some(tail_lines),
                                     since_seconds,
                                 );
+                                if let Some(seconds) = since_seconds {
+                                    spawn_retention_check(&internal_tx, client, namespace, &state.streamed_pods(), stream_manager.excluded_containers(), seconds);
+                                }
                             }
                     }
 
+                    InternalAction::ExpandTimeRange(previous) => {
+                        if let Some(client) = &active_client
+                            && let Some(namespace) = &state.selected_namespace
+                            && let Some(floor_seconds) = previous.as_seconds()
+                        {
+                            match state.ui_state.time_range.as_seconds() {
+                                // Scrolling back fetches the rest on demand
+                                None => {
+                                    let tail_lines = state
+                                        .ui_state
+                                        .tail_lines_override
+                                        .unwrap_or_else(|| args.tail_lines_for(state.streamed_pods().len()));
+                                    reset_history(&mut state.ui_state, tail_lines);
+                                }
+                                Some(since_seconds) => {
+                                    state.ui_state.history_loading = true;
+                                    let floor = chrono::Utc::now() - chrono::Duration::seconds(floor_seconds);
+                                    stream_manager.fetch_history(
+                                        client.clone(),
+                                        StreamSource {
+                                            namespace,
+                                            workload: state.selected_deployment.as_deref(),
+                                        },
+                                        &state.streamed_pods(),
+                                        history_tx.clone(),
+                                        HistoryWindow::Since { since_seconds, floor },
+                                        log_buffer.oldest_lines(),
+                                    );
+                                    spawn_retention_check(&internal_tx, client, namespace, &state.streamed_pods(), stream_manager.excluded_containers(), since_seconds);
+                                }
+                            }
+                        }
+                    }
+
                     InternalAction::StopLogStreaming => {
                         stream_manager.stop();
+                        state.ui_state.history_loading = false;
+                        state.ui_state.streams_stopped_at = None;
+                        state.ui_state.idle = false;
                     }
 
-                    InternalAction::Error(msg) => {
-                        state.show_error(msg);
+                    InternalAction::StreamPods(pods) => {
+                        // Stopped streams pick the new pods up when resumed
+                        if let Some(client) = &active_client
+                            && let Some(namespace) = &state.selected_namespace
+                            && state.ui_state.streams_stopped_at.is_none()
+                        {
+                            let tail_lines = state
+                                .ui_state
+                                .tail_lines_override
+                                .unwrap_or_else(|| args.tail_lines_for(state.streamed_pods().len()));
+                            stream_manager.start_streams(
+                                client.clone(),
+                                StreamSource {
+                                    namespace,
+                                    workload: state.selected_deployment.as_deref(),
+                                },
+                                &pods,
+                                log_tx.clone(),
+                                Some(tail_lines),
+                                state
+                                    .ui_state
+                                    .time_range
+                                    .as_seconds()
+                                    .filter(|_| !state.ui_state.time_window.is_set()),
+                            );
+                        }
+                    }
+
+                    InternalAction::SuspendLogStreaming => {
+                        stream_manager.stop();
+                        state.ui_state.history_loading = false;
+                        state.ui_state.streams_stopped_at = Some(chrono::Utc::now());
+                        state.notify(Severity::Info, "Log streams stopped (x resumes)");
+                    }
+
+                    InternalAction::ResumeLogStreaming => {
+                        if let Some(client) = &active_client
+                            && let Some(namespace) = &state.selected_namespace
+                            && let Some(stopped_at) = state.ui_state.streams_stopped_at.take()
+                        {
+                            stream_manager.resume_streams(
+                                client.clone(),
+                                StreamSource {
+                                    namespace,
+                                    workload: state.selected_deployment.as_deref(),
+                                },
+                                &state.streamed_pods(),
+                                log_tx.clone(),
+                                stopped_at,
+                                log_buffer.newest_lines(),
+                            );
+                            state.notify(Severity::Info, "Log streams resumed");
+                        }
+                    }
+
+                    InternalAction::LoadOlderLogs => {
+                        if let Some(client) = &active_client
+                            && let Some(namespace) = &state.selected_namespace {
+                                // Double the window each time so repeated scrolling reaches further back
+                                let depth = state.ui_state.history_depth.max(1).saturating_mul(2);
+                                state.ui_state.history_depth = depth;
+                                stream_manager.fetch_history(
+                                    client.clone(),
+                                    StreamSource {
+                                        namespace,
+                                        workload: state.selected_deployment.as_deref(),
+                                    },
+                                    &state.streamed_pods(),
+                                    history_tx.clone(),
+                                    HistoryWindow::Tail(depth),
+                                    log_buffer.oldest_lines(),
+                                );
+                            } else {
+                                state.ui_state.history_loading = false;
+                            }
+                    }
+
+                    InternalAction::ScaleDeployment(replicas) => {
+                        if let Some(client) = active_client.clone()
+                            && let Some(namespace) = state.selected_namespace.clone()
+                            && let Some(deployment) = state.selected_deployment.clone()
+                        {
+                            let internal_tx = internal_tx.clone();
+                            tokio::spawn(async move {
+                                let (severity, message) = match kube_client.scale_deployment(&client, &namespace, &deployment, replicas).await {
+                                    Ok(()) => (Severity::Success, format!("Scaled {} to {} replicas", deployment, replicas)),
+                                    Err(e) => (Severity::Error, format!("Failed to scale {}: {}", deployment, e)),
+                                };
+                                let _ = internal_tx.send(InternalAction::Notify(severity, message));
+                            });
+                        } else {
+                            state.notify(Severity::Warning, "No deployment selected");
+                        }
+                    }
+
+                    InternalAction::RestartDeployment => {
+                        if let Some(client) = active_client.clone()
+                            && let Some(namespace) = state.selected_namespace.clone()
+                            && let Some(deployment) = state.selected_deployment.clone()
+                        {
+                            let internal_tx = internal_tx.clone();
+                            tokio::spawn(async move {
+                                let (severity, message) = match kube_client.restart_deployment(&client, &namespace, &deployment).await {
+                                    Ok(()) => (Severity::Success, format!("Restarting {}", deployment)),
+                                    Err(e) => (Severity::Error, format!("Failed to restart {}: {}", deployment, e)),
+                                };
+                                let _ = internal_tx.send(InternalAction::Notify(severity, message));
+                            });
+                        } else {
+                            state.notify(Severity::Warning, "No deployment selected");
+                        }
+                    }
+
+                    InternalAction::DeletePod(pod) => {
+                        if let Some(client) = &active_client
+                            && let Some(namespace) = state.selected_namespace.clone()
+                        {
+                            // Deleted in whichever cluster the pod came from
+                            let cluster = state.pods.iter().find(|p| p.name == pod).and_then(|p| p.cluster.as_deref());
+                            let client = stream_manager.client_for(cluster, client);
+                            let internal_tx = internal_tx.clone();
+                            tokio::spawn(async move {
+                                let (severity, message) = match kube_client.delete_pod(&client, &namespace, &pod).await {
+                                    Ok(()) => (Severity::Success, format!("Deleted pod {}", pod)),
+                                    Err(e) => (Severity::Error, format!("Failed to delete {}: {}", pod, e)),
+                                };
+                                let _ = internal_tx.send(InternalAction::Notify(severity, message));
+                            });
+                        }
+                    }
+
+                    InternalAction::Notify(severity, message) => {
+                        state.notify(severity, message);
+                    }
+
+                    InternalAction::FetchFullLine(entry) => {
+                        if let Some(client) = &active_client {
+                            let client = stream_manager.client_for(entry.source.cluster.as_deref(), client);
+                            let internal_tx = internal_tx.clone();
+                            tokio::spawn(async move {
+                                let result = logs::fetch_full_line(client, &entry)
+                                    .await
+                                    .map_err(|e| e.to_string());
+                                let _ = internal_tx.send(InternalAction::FullLineFetched(entry.id, result));
+                            });
+                        }
+                    }
+
+                    InternalAction::CopyToClipboard(text) => {
+                        tui.copy_to_clipboard(&text)?;
+                    }
+                    InternalAction::OpenExternal(viewer, path) => {
+                        // The program gets the terminal and the keyboard until it exits
+                        events.suspend().await;
+                        tui.suspend()?;
+                        let result = handoff::open(viewer, &path).await;
+                        tui.resume()?;
+                        events.resume();
+                        if let Err(e) = result {
+                            state.notify(Severity::Error, format!("{:#}", e));
+                        }
+                        state.render_dirty = true;
+                    }
+
+                    InternalAction::FullLineFetched(id, result) => {
+                        // Ignore fetches for an overlay that was closed or moved on
+                        if let Some(full_line) = state.ui_state.full_line.as_mut()
+                            && full_line.entry_id == id
+                        {
+                            match result {
+                                Ok(line) => full_line.set_line(&line),
+                                Err(e) => {
+                                    state.ui_state.full_line = None;
+                                    state.notify(Severity::Error, format!("Couldn't fetch the full line: {}", e));
+                                }
+                            }
+                        }
+                    }
+
+                    InternalAction::RetentionGaps(gaps) => {
+                        // Drop pods that aren't streamed any more
+                        let mut gaps: Vec<RetentionGap> = gaps
+                            .into_iter()
+                            .filter(|gap| state.pods.iter().any(|p| p.name == gap.pod))
+                            .collect();
+                        gaps.sort_by_key(|gap| std::cmp::Reverse(gap.oldest));
+                        if let Some(latest) = gaps.first() {
+                            let oldest = if state.ui_state.use_local_time {
+                                latest.oldest.with_timezone(&chrono::Local).format("%H:%M:%S").to_string()
+                            } else {
+                                latest.oldest.format("%H:%M:%S").to_string()
+                            };
+                            let pods = if gaps.len() == 1 {
+                                latest.pod.clone()
+                            } else {
+                                format!("{} pods", gaps.len())
+                            };
+                            state.notify(Severity::Warning, format!(
+                                "Logs don't reach back {}: {} only has lines from {} on (the kubelet rotated older ones away)",
+                                state.ui_state.time_range.label(),
+                                pods,
+                                oldest
+                            ));
+                        }
+                    }
+
+                    InternalAction::SavePins => {
+                        // .kubescope's [pins] are added on every start, so only the rest are saved
+                        if let Err(e) = session::save_pins(&state.pinned.difference(&args.pins)) {
+                            state.notify(Severity::Error, format!("Failed to save pins: {}", e));
+                        }
+                    }
+
+                    InternalAction::RefreshCredentials => {
+                        if let Some(context) = state.selected_context.clone() {
+                            let internal_tx = internal_tx.clone();
+                            tokio::spawn(async move {
+                                let result = kube_client
+                                    .refresh_client(&context)
+                                    .await
+                                    .map_err(|e| format!("Failed to refresh credentials for {}: {}", context, e));
+                                let _ = internal_tx.send(InternalAction::CredentialsRefreshed(result));
+                            });
+                        }
+                    }
+
+                    InternalAction::CredentialsRefreshed(result) => {
+                        refreshing_credentials = false;
+                        match result {
+                            Ok(client) => {
+                                if stream_manager.has_expired()
+                                    && let Some(namespace) = &state.selected_namespace
+                                {
+                                    stream_manager.restart_expired(
+                                        client.clone(),
+                                        StreamSource {
+                                            namespace,
+                                            workload: state.selected_deployment.as_deref(),
+                                        },
+                                        &state.streamed_pods(),
+                                        log_tx.clone(),
+                                    );
+                                    state.notify(Severity::Info, "Credentials refreshed, log streams reconnected");
+                                }
+                                active_client = Some(client);
+                            }
+                            Err(msg) => {
+                                // Don't keep retrying; stopping and resuming the streams tries again
+                                stream_manager.forget_expired();
+                                state.show_error(msg, Some(ApiError::AuthExpired), None);
+                            }
+                        }
+                    }
+
+                    InternalAction::Error(msg, kind, retry) => {
+                        state.ui_state.loading = None;
+                        state.ui_state.switch_target = None;
+                        state.show_error(msg, kind, retry);
                     }
                 }
                 state.render_dirty = true;  // Internal actions trigger re-render
@@ -785,29 +2647,43 @@ async fn run_app(args: Args) -> Result<()> {
         }
 
         if state.should_quit {
+            // Remember where we were so an accidental quit can be undone with --resume
+            if let Some(session) = Session::from_state(&state) {
+                session.save();
+            }
             break;
         }
 
         // Adaptive render rate limiting
-        let dropped_count = stream_manager.dropped_count();
+        let dropped_count = log_buffer.dropped_count();
         let is_under_load = dropped_count > prev_dropped_count;
-        let elapsed = last_render_time.elapsed();
+        let render_interval = if is_under_load {
+            throttled_render_interval
+        } else {
+            normal_render_interval
+        };
+        // Scrolled back, new lines only move the counters, so they can wait
+        let log_interval = if state.ui_state.auto_scroll {
+            render_interval
+        } else {
+            IDLE_TICK
+        };
 
         // Determine if we should render:
         // - Always render on user input (responsive UI)
-        // - Under load: throttle to 250ms intervals
-        // - Normal: render at 100ms intervals when dirty
-        let should_render = if user_input_pending {
-            true // Always render immediately on user input
-        } else if !state.render_dirty {
-            false // Nothing changed, skip render
-        } else if is_under_load {
-            // Under load: only render if enough time has passed
-            elapsed >= throttled_render_interval
-        } else {
-            // Normal operation: render at normal interval
-            elapsed >= normal_render_interval
-        };
+        // - Other changes: at most once per render interval (250ms under load)
+        // - New log lines: the same while following, once a second while scrolled back
+        let due_at = [
+            state.render_dirty.then_some(render_interval),
+            log_dirty.then_some(log_interval),
+        ]
+        .into_iter()
+        .flatten()
+        .min()
+        .map(|interval| last_render_time + interval);
+        let should_render =
+            user_input_pending || due_at.is_some_and(|at| at <= std::time::Instant::now());
+        render_at = if should_render { None } else { due_at };
 
         if should_render {
             render(
@@ -815,14 +2691,20 @@ async fn run_app(args: Args) -> Result<()> {
                 &mut state,
                 &log_buffer,
                 &mut palette_state,
-                &commands,
-                dropped_count,
+                &keybindings,
             )?;
             state.render_dirty = false;
+            log_dirty = false;
             user_input_pending = false;
             last_render_time = std::time::Instant::now();
             prev_dropped_count = dropped_count;
         }
+
+        // Tick fast only while a spinner, the perf HUD or a preview count needs it
+        let busy = state.ui_state.loading.is_some()
+            || state.ui_state.perf_visible
+            || state.ui_state.search_preview.is_counting();
+        events.set_tick_rate(if busy { ACTIVE_TICK } else { IDLE_TICK });
     }
 
     // Cleanup
@@ -830,7 +2712,14 @@ async fn run_app(args: Args) -> Result<()> {
     events.shutdown();
     tui.restore()?;
 
-    Ok(())
+    if args.stats {
+        print!("{}", state.ui_state.usage.summary());
+    }
+
+    match startup_failure {
+        Some(e) => Err(e),
+        None => Ok(()),
+    }
 }
 
 fn handle_action(
@@ -847,18 +2736,28 @@ fn handle_action(
             state.should_quit = true;
         }
         Action::GoBack => {
+            state.ui_state.switch_target = None;
             // Stop streaming if leaving log viewer
             if state.current_screen == Screen::LogViewer {
                 let _ = internal_tx.send(InternalAction::StopLogStreaming);
                 // Clear all filter state
                 state.ui_state.json_visible_keys.clear();
+                state.ui_state.json_keys_exclude = false;
                 state.ui_state.json_available_keys.clear();
                 state.ui_state.json_key_filter_active = false;
                 state.ui_state.json_key_search.clear();
                 state.ui_state.active_filter = None;
                 state.ui_state.search_input.clear();
                 state.ui_state.filter_error = None;
+                state.ui_state.bookmarks_visible = false;
+                state.ui_state.top_visible_entry = None;
+                state.ui_state.pod_panel_visible = false;
+                state.ui_state.muted_pods.clear();
+                state.ui_state.node_filter = None;
+                state.ui_state.compare = None;
             }
+            // Leaving the screen abandons whatever it was loading
+            state.ui_state.loading = None;
             if !state.go_back() {
                 state.should_quit = true;
             }
@@ -868,8 +2767,43 @@ fn handle_action(
                 let _ = internal_tx.send(InternalAction::LoadContexts);
             }
         }
+        Action::SwitchContext => {
+            if state.current_screen == Screen::Connecting {
+                return;
+            }
+            // Only namespaces/deployments actually open get reopened
+            let namespace = state.selected_namespace.clone();
+            state.ui_state.switch_target = match state.current_screen {
+                Screen::DeploymentSelect => namespace.map(|namespace| SwitchTarget {
+                    namespace,
+                    deployment: None,
+                }),
+                Screen::LogViewer => namespace.map(|namespace| SwitchTarget {
+                    namespace,
+                    deployment: state.selected_deployment.clone(),
+                }),
+                _ => None,
+            };
+            if state.current_screen == Screen::LogViewer {
+                let _ = internal_tx.send(InternalAction::StopLogStreaming);
+            }
+            state.ui_state.loading = None;
+            state.screen_stack.clear();
+            state.current_screen = Screen::ContextSelect;
+            // Start on the context we came from
+            let current = state
+                .selected_context
+                .as_ref()
+                .and_then(|name| state.contexts.iter().position(|c| &c.name == name));
+            state.ui_state.list_state.select(current.or(Some(0)));
+            if state.contexts.is_empty() {
+                let _ = internal_tx.send(InternalAction::LoadContexts);
+            }
+        }
         Action::Navigate(screen) => {
-            state.navigate_to(screen);
+            if state.current_screen != screen {
+                state.navigate_to(screen);
+            }
         }
         Action::ListUp => {
             state.list_up();
@@ -888,18 +2822,74 @@ fn handle_action(
             state.selected_namespace = Some(name.clone());
             let _ = internal_tx.send(InternalAction::LoadDeployments(name));
         }
+        Action::CycleDeploymentSort => {
+            if state.current_screen == Screen::DeploymentSelect {
+                state.ui_state.deployment_sort = state.ui_state.deployment_sort.next();
+                state.sort_pinned();
+                state.ui_state.list_state.select(Some(0));
+            }
+        }
+        Action::ToggleTerminatingNamespaces => {
+            if state.current_screen == Screen::NamespaceSelect {
+                let selected = state.selected_namespace_name();
+                state.ui_state.hide_terminating = !state.ui_state.hide_terminating;
+                state.filter_namespaces();
+                state.reselect_by_name(selected.as_deref());
+            }
+        }
+        Action::SetLabelSelector(selector) => match state.current_screen {
+            Screen::NamespaceSelect => {
+                let selected = state.selected_namespace_name();
+                state.ui_state.namespace_selector = selector;
+                state.filter_namespaces();
+                state.reselect_by_name(selected.as_deref());
+            }
+            Screen::DeploymentSelect => {
+                let selected = state.selected_deployment_name();
+                state.ui_state.deployment_selector = selector;
+                state.filter_deployments();
+                state.reselect_by_name(selected.as_deref());
+            }
+            _ => {}
+        },
+        Action::TogglePin => {
+            state.toggle_pin();
+            let _ = internal_tx.send(InternalAction::SavePins);
+        }
+        Action::OpenRecent(target) => {
+            // Clear all filter state for new deployment
+            state.ui_state.json_visible_keys.clear();
+            state.ui_state.json_keys_exclude = false;
+            state.ui_state.json_available_keys.clear();
+            state.ui_state.json_key_filter_active = false;
+            state.ui_state.json_key_search.clear();
+            state.ui_state.active_filter = None;
+            state.ui_state.search_input.clear();
+            state.ui_state.filter_error = None;
+            state.ui_state.bookmarks_visible = false;
+            state.ui_state.top_visible_entry = None;
+            state.ui_state.pod_panel_visible = false;
+            state.ui_state.muted_pods.clear();
+            state.ui_state.node_filter = None;
+            state.ui_state.compare = None;
+            let _ = internal_tx.send(InternalAction::OpenRecent(target));
+        }
         Action::SelectDeployment(name) => {
             state.selected_deployment = Some(name.clone());
             // Clear all filter state for new deployment
             state.ui_state.json_visible_keys.clear();
+            state.ui_state.json_keys_exclude = false;
             state.ui_state.json_available_keys.clear();
             state.ui_state.json_key_filter_active = false;
             state.ui_state.json_key_search.clear();
             state.ui_state.active_filter = None;
             state.ui_state.search_input.clear();
             state.ui_state.filter_error = None;
+            state.ui_state.muted_pods.clear();
+            state.ui_state.node_filter = None;
+            state.ui_state.compare = None;
             if let Some(namespace) = &state.selected_namespace
-                && let Some(deployment) = state.deployments.iter().find(|d| d.name == name)
+                && let Some(deployment) = state.all_deployments.iter().find(|d| d.name == name)
             {
                 let _ = internal_tx.send(InternalAction::LoadPods(
                     namespace.clone(),
@@ -912,6 +2902,9 @@ fn handle_action(
         Action::ScrollUp(n) => {
             state.ui_state.auto_scroll = false;
             state.ui_state.log_scroll = state.ui_state.log_scroll.saturating_sub(n);
+            if state.ui_state.log_scroll == 0 {
+                request_older_logs(state, internal_tx);
+            }
         }
         Action::ScrollDown(n) => {
             state.ui_state.auto_scroll = false;
@@ -921,6 +2914,9 @@ fn handle_action(
         Action::PageUp => {
             state.ui_state.auto_scroll = false;
             state.ui_state.log_scroll = state.ui_state.log_scroll.saturating_sub(20);
+            if state.ui_state.log_scroll == 0 {
+                request_older_logs(state, internal_tx);
+            }
         }
         Action::PageDown => {
             state.ui_state.auto_scroll = false;
@@ -930,6 +2926,12 @@ fn handle_action(
         Action::ScrollToTop => {
             state.ui_state.auto_scroll = false;
             state.ui_state.log_scroll = 0;
+            request_older_logs(state, internal_tx);
+        }
+        Action::LoadOlderLogs => {
+            // Explicit request: retry even if a previous fetch found nothing
+            state.ui_state.history_exhausted = false;
+            request_older_logs(state, internal_tx);
         }
         Action::ScrollToBottom => {
             state.ui_state.auto_scroll = false;
@@ -939,61 +2941,412 @@ fn handle_action(
         Action::ToggleAutoScroll => {
             state.ui_state.auto_scroll = !state.ui_state.auto_scroll;
         }
+        Action::ToggleStreaming => {
+            if state.current_screen == Screen::LogViewer {
+                let _ = internal_tx.send(if state.ui_state.streams_stopped_at.is_some() {
+                    InternalAction::ResumeLogStreaming
+                } else {
+                    InternalAction::SuspendLogStreaming
+                });
+            }
+        }
         Action::ToggleTimestamps => {
             state.ui_state.show_timestamps = !state.ui_state.show_timestamps;
         }
         Action::ToggleLocalTime => {
             state.ui_state.use_local_time = !state.ui_state.use_local_time;
         }
+        Action::ToggleTimeDeltas => {
+            state.ui_state.time_deltas = !state.ui_state.time_deltas;
+            // Deltas live in the time column, so make sure it's showing
+            if state.ui_state.time_deltas {
+                state.ui_state.show_timestamps = true;
+            }
+        }
         Action::TogglePodNames => {
             state.ui_state.show_pod_names = !state.ui_state.show_pod_names;
         }
+        Action::ToggleLineNumbers => {
+            state.ui_state.per_pod_line_numbers = !state.ui_state.per_pod_line_numbers;
+        }
+        Action::CycleTimeMarkers => {
+            state.ui_state.time_markers = state.ui_state.time_markers.next();
+            state.notify(
+                Severity::Info,
+                format!("Time markers: {}", state.ui_state.time_markers.label()),
+            );
+        }
         Action::ToggleJsonPrettyPrint => {
             state.ui_state.json_pretty_print = !state.ui_state.json_pretty_print;
         }
+        Action::FetchFullLine => {
+            if let Some(entry) = state.open_full_line() {
+                let _ = internal_tx.send(InternalAction::FetchFullLine(entry));
+            }
+        }
+        Action::FullLineScroll(delta) => {
+            if let Some(full_line) = state.ui_state.full_line.as_mut() {
+                full_line.scroll_by(delta);
+            }
+        }
+        Action::CloseFullLine => {
+            state.ui_state.full_line = None;
+        }
+        Action::ToggleRawView => {
+            state.toggle_raw_view();
+        }
+        Action::ToggleJsonFold => {
+            if state.ui_state.json_pretty_print {
+                state.toggle_json_fold();
+            } else {
+                state.notify(
+                    Severity::Info,
+                    "Turn on JSON pretty print (J) to fold entries",
+                );
+            }
+        }
+        Action::CycleJsonFoldDepth => {
+            state.cycle_json_fold_depth();
+            match state.ui_state.json_fold_depth {
+                Some(depth) => state.notify(
+                    Severity::Info,
+                    format!("Folding JSON nested deeper than {}", depth),
+                ),
+                None => state.notify(Severity::Info, "JSON fully expanded"),
+            }
+        }
         Action::ToggleStats => {
             state.ui_state.stats_visible = !state.ui_state.stats_visible;
         }
-        Action::ClearLogs => {
-            log_buffer.clear();
-            state.ui_state.log_scroll = 0;
+        Action::ToggleZen => {
+            state.ui_state.zen = !state.ui_state.zen;
+        }
+        Action::CycleDensity => state.cycle_density(),
+        Action::ClearLogs => {
+            state.ask_confirm(
+                "Clear logs",
+                format!("Remove all {} lines from the buffer?", log_buffer.len()),
+                "Clear",
+                Action::ClearLogs,
+            );
+        }
+        Action::GrowBuffer | Action::ShrinkBuffer => {
+            let current = log_buffer.capacity();
+            let capacity = if matches!(action, Action::GrowBuffer) {
+                current.saturating_mul(2).min(MAX_BUFFER_CAPACITY)
+            } else {
+                (current / 2).max(MIN_BUFFER_CAPACITY)
+            };
+            log_buffer.set_capacity(capacity);
+            state.ui_state.history_exhausted = false;
+            state.notify(
+                Severity::Info,
+                format!("Buffer capacity set to {} entries", capacity),
+            );
+        }
+        Action::RunPluginCommand(index) => {
+            let Some(plugins) = plugins::get() else {
+                return;
+            };
+            let entries = state.filter_pipeline().apply(log_buffer.all());
+            let ctx = plugins::CommandContext {
+                context: state.selected_context.as_deref(),
+                namespace: state.selected_namespace.as_deref(),
+                deployment: state.selected_deployment.as_deref(),
+                lines: entries
+                    .iter()
+                    .map(|e| plugins::CommandLine::new(e))
+                    .collect(),
+            };
+            let name = plugins.commands().get(index).map_or("", |c| c.name);
+            match plugins.run_command(index, &ctx) {
+                Ok(Some(message)) => state.notify(Severity::Info, message),
+                Ok(None) => {}
+                Err(e) => state.notify(Severity::Error, format!("{} failed: {}", name, e)),
+            }
+        }
+        Action::ExportLogs => {
+            let deployment = state.selected_deployment.as_deref().unwrap_or("logs");
+            let timestamp = chrono::Local::now().format("%Y%m%d_%H%M%S");
+            let filename = format!("{}_{}.log", deployment, timestamp);
+            handle_action(
+                state,
+                internal_tx,
+                log_buffer,
+                palette_state,
+                commands,
+                Action::ExportLogsTo(filename),
+            );
+        }
+        Action::OpenLineInEditor => match state.ui_state.top_visible_entry.clone() {
+            Some(entry) => {
+                let (text, extension) = handoff::entry_text(&entry);
+                let written = handoff::create_temp(&format!("line-{}.{}", entry.id, extension))
+                    .and_then(|(path, mut file)| file.write_all(text.as_bytes()).map(|()| path));
+                match written {
+                    Ok(path) => {
+                        let _ =
+                            internal_tx.send(InternalAction::OpenExternal(Viewer::Editor, path));
+                    }
+                    Err(e) => {
+                        state.notify(Severity::Error, format!("Couldn't write the line: {}", e))
+                    }
+                }
+            }
+            None => state.notify(Severity::Info, "No line to open"),
+        },
+        Action::OpenViewInPager => {
+            let written = handoff::create_temp("view.log")
+                .map_err(anyhow::Error::from)
+                .and_then(|(path, file)| export_logs(file, log_buffer, state).map(|_| path));
+            match written {
+                Ok(path) => {
+                    let _ = internal_tx.send(InternalAction::OpenExternal(Viewer::Pager, path));
+                }
+                Err(e) => state.notify(Severity::Error, format!("Couldn't write the view: {}", e)),
+            }
+        }
+        Action::CopyReproCommand => match repro::repro_command(state) {
+            Some(command) => {
+                let _ = internal_tx.send(InternalAction::CopyToClipboard(command.clone()));
+                state.notify(Severity::Success, format!("Copied: {}", command));
+            }
+            None => state.notify(Severity::Info, "Open a deployment first"),
+        },
+        Action::ExportLogsTo(filename) => {
+            if std::path::Path::new(&filename).exists() {
+                state.ask_confirm(
+                    "Overwrite export",
+                    format!("{} already exists. Overwrite it?", filename),
+                    "Overwrite",
+                    Action::ExportLogsTo(filename),
+                );
+            } else {
+                run_export(state, log_buffer, &filename);
+            }
+        }
+
+        Action::ToggleBookmark => {
+            state.toggle_bookmark();
+        }
+        Action::ToggleBookmarks => {
+            state.ui_state.bookmarks_visible = !state.ui_state.bookmarks_visible;
+        }
+        Action::BookmarkUp => {
+            state.ui_state.bookmark_selection = state.ui_state.bookmark_selection.saturating_sub(1);
+        }
+        Action::BookmarkDown => {
+            let max = state.ui_state.bookmarks.len().saturating_sub(1);
+            if state.ui_state.bookmark_selection < max {
+                state.ui_state.bookmark_selection += 1;
+            }
+        }
+        Action::BookmarkJump => {
+            if let Some(entry) = state
+                .ui_state
+                .bookmarks
+                .get(state.ui_state.bookmark_selection)
+            {
+                state.ui_state.pending_jump = Some(entry.clone());
+                state.ui_state.auto_scroll = false;
+                state.ui_state.bookmarks_visible = false;
+            }
+        }
+        Action::BookmarkDelete => {
+            let idx = state.ui_state.bookmark_selection;
+            if idx < state.ui_state.bookmarks.len() {
+                state.remove_bookmark(idx);
+                // Keep the selection on a row after deleting the last one
+                state.ui_state.bookmark_selection =
+                    idx.min(state.ui_state.bookmarks.len().saturating_sub(1));
+            }
+        }
+
+        Action::ToggleNodeNames => {
+            state.ui_state.show_node_names = !state.ui_state.show_node_names;
+        }
+        Action::CycleNodeFilter => {
+            state.cycle_node_filter();
+            match &state.ui_state.node_filter {
+                Some(node) => state.notify(
+                    Severity::Info,
+                    format!("Showing only pods on node {}", node),
+                ),
+                None => state.notify(Severity::Info, "Node filter cleared"),
+            }
+        }
+        Action::ToggleCompare => {
+            state.toggle_compare();
+        }
+        Action::CycleCompare => {
+            state.cycle_compare();
+        }
+        Action::TogglePodLegend => {
+            state.ui_state.pod_legend_visible = !state.ui_state.pod_legend_visible;
+        }
+        Action::CycleMinLevel => {
+            state.ui_state.min_level = state.ui_state.min_level.next_threshold();
+        }
+        Action::TogglePodPanel => {
+            state.ui_state.pod_panel_visible = !state.ui_state.pod_panel_visible;
+        }
+        Action::PodPanelUp => {
+            state.ui_state.pod_panel_selection =
+                state.ui_state.pod_panel_selection.saturating_sub(1);
+        }
+        Action::PodPanelDown => {
+            let max = state.pods.len().saturating_sub(1);
+            if state.ui_state.pod_panel_selection < max {
+                state.ui_state.pod_panel_selection += 1;
+            }
+        }
+        Action::PodPanelToggleMute => {
+            state.toggle_selected_pod_mute();
+        }
+        Action::PodPanelStreamPod => {
+            let added = state.sample_selected_pod();
+            stream_sampled_pods(state, internal_tx, added);
+        }
+        Action::WidenSample => {
+            if state.ui_state.sampled_pods.is_none() {
+                state.notify(Severity::Info, "Every pod is already streamed");
+            } else {
+                let added = state.widen_sample();
+                stream_sampled_pods(state, internal_tx, added);
+            }
+        }
+
+        Action::ToggleColumns => {
+            state.ui_state.columns_visible = !state.ui_state.columns_visible;
+        }
+        Action::ColumnUp => {
+            state.ui_state.column_selection = state.ui_state.column_selection.saturating_sub(1);
+        }
+        Action::ColumnDown => {
+            let max = state.ui_state.columns.columns.len().saturating_sub(1);
+            if state.ui_state.column_selection < max {
+                state.ui_state.column_selection += 1;
+            }
+        }
+        Action::ColumnMoveUp => {
+            let idx = state.ui_state.column_selection;
+            if state.ui_state.columns.move_up(idx) {
+                state.ui_state.column_selection -= 1;
+            }
+        }
+        Action::ColumnMoveDown => {
+            let idx = state.ui_state.column_selection;
+            if state.ui_state.columns.move_down(idx) {
+                state.ui_state.column_selection += 1;
+            }
+        }
+        Action::ColumnWiden => {
+            state
+                .ui_state
+                .columns
+                .resize(state.ui_state.column_selection, 1);
+        }
+        Action::ColumnNarrow => {
+            state
+                .ui_state
+                .columns
+                .resize(state.ui_state.column_selection, -1);
+        }
+        Action::ColumnToggleVisible => {
+            state.toggle_selected_column();
         }
-        Action::ExportLogs => {
-            let deployment = state.selected_deployment.as_deref().unwrap_or("logs");
-            let timestamp = chrono::Local::now().format("%Y%m%d_%H%M%S");
-            let filename = format!("{}_{}.log", deployment, timestamp);
 
-            match export_logs_to_file(&filename, log_buffer, state) {
-                Ok(count) => {
-                    state.show_error(format!("Exported {} logs to {}", count, filename));
-                }
-                Err(e) => {
-                    state.show_error(format!("Export failed: {}", e));
-                }
-            }
+        Action::ToggleMinimap => {
+            state.ui_state.minimap_visible = !state.ui_state.minimap_visible;
+        }
+        Action::JumpNextError => {
+            state.jump_to_error(true);
+        }
+        Action::JumpPrevError => {
+            state.jump_to_error(false);
         }
 
         Action::CycleTimeRange => {
-            state.ui_state.time_range = state.ui_state.time_range.next();
-            if state.current_screen == Screen::LogViewer {
-                let _ = internal_tx.send(InternalAction::RestartLogStreaming);
-            }
+            let previous = state.ui_state.time_range;
+            state.ui_state.time_range = previous.next();
+            change_time_range(state, internal_tx, log_buffer, previous);
         }
         Action::CycleTimeRangeBack => {
-            state.ui_state.time_range = state.ui_state.time_range.prev();
-            if state.current_screen == Screen::LogViewer {
-                let _ = internal_tx.send(InternalAction::RestartLogStreaming);
-            }
+            let previous = state.ui_state.time_range;
+            state.ui_state.time_range = previous.prev();
+            change_time_range(state, internal_tx, log_buffer, previous);
         }
 
         Action::ShowError(msg) => {
-            state.show_error(msg);
+            state.notify(Severity::Error, msg);
+        }
+        Action::ToggleUsageStats => {
+            state.ui_state.usage_visible = !state.ui_state.usage_visible;
+        }
+        Action::ToggleStreamParams => {
+            state.ui_state.stream_params_visible = !state.ui_state.stream_params_visible;
+            state.ui_state.stream_params_scroll = 0;
         }
+        Action::StreamParamsUp => {
+            state.ui_state.stream_params_scroll =
+                state.ui_state.stream_params_scroll.saturating_sub(1);
+        }
+        Action::StreamParamsDown => {
+            state.ui_state.stream_params_scroll =
+                state.ui_state.stream_params_scroll.saturating_add(1);
+        }
+        Action::TogglePerfHud => {
+            state.ui_state.perf_visible = !state.ui_state.perf_visible;
+            state.ui_state.perf.reset();
+        }
+        Action::ToggleDebugLogging => match debug_log::toggle_debug() {
+            Ok((true, path)) => {
+                tracing::debug!("debug logging enabled");
+                state.notify(
+                    Severity::Info,
+                    format!("Debug logging to {}", path.display()),
+                );
+            }
+            Ok((false, _)) => state.notify(Severity::Info, "Debug logging off"),
+            Err(e) => state.notify(Severity::Warning, e.to_string()),
+        },
         Action::DismissError => {
-            state.dismiss_error();
+            state.ui_state.error = None;
+        }
+        Action::RetryError => {
+            if let Some(retry) = state.ui_state.error.take().and_then(|e| e.retry) {
+                handle_action(
+                    state,
+                    internal_tx,
+                    log_buffer,
+                    palette_state,
+                    commands,
+                    retry,
+                );
+            }
+        }
+        Action::DismissNotification => {
+            state.ui_state.notifications.dismiss();
+        }
+        Action::ToggleNotifications => {
+            state.ui_state.notifications_visible = !state.ui_state.notifications_visible;
         }
         Action::ToggleHelp => {
             state.ui_state.help_visible = !state.ui_state.help_visible;
+            state.ui_state.help_scroll = 0;
+        }
+        Action::HelpUp => {
+            state.ui_state.help_scroll = state.ui_state.help_scroll.saturating_sub(1);
+        }
+        Action::HelpDown => {
+            // Clamped to the content when rendered
+            state.ui_state.help_scroll = state.ui_state.help_scroll.saturating_add(1);
+        }
+        Action::HelpPageUp => {
+            state.ui_state.help_scroll = state.ui_state.help_scroll.saturating_sub(HELP_PAGE);
+        }
+        Action::HelpPageDown => {
+            state.ui_state.help_scroll = state.ui_state.help_scroll.saturating_add(HELP_PAGE);
         }
         Action::ToggleCommandPalette => {
             if palette_state.visible {
@@ -1004,6 +3357,7 @@ fn handle_action(
         }
 
         // Command palette actions
+        Action::PaletteUp | Action::PaletteDown if palette_state.prompt.is_some() => {}
         Action::PaletteUp => {
             palette_state.move_up();
         }
@@ -1019,6 +3373,167 @@ fn handle_action(
         Action::PaletteClose => {
             palette_state.close();
         }
+        Action::PaletteSelect if palette_state.prompt.is_some() => {
+            if let Some(action) = palette_state.submit_prompt() {
+                handle_action(
+                    state,
+                    internal_tx,
+                    log_buffer,
+                    palette_state,
+                    commands,
+                    action,
+                );
+            }
+        }
+        // Only the namespace and deployment lists have labels to filter by
+        Action::OpenPrompt(PromptKind::LabelSelector)
+            if !matches!(
+                state.current_screen,
+                Screen::NamespaceSelect | Screen::DeploymentSelect
+            ) => {}
+        Action::OpenPrompt(kind) => {
+            palette_state.open_prompt(kind);
+        }
+        Action::SaveKeySet(name) => {
+            if state.ui_state.json_visible_keys.is_empty() {
+                state.notify(
+                    Severity::Warning,
+                    "Pick keys in the JSON key filter (K) before saving a set",
+                );
+            } else {
+                let pick = state.json_key_pick();
+                state.key_sets.named.insert(name.clone(), pick);
+                state.key_sets.save();
+                state.notify(Severity::Info, format!("Saved key set \"{}\"", name));
+            }
+        }
+        Action::LoadKeySet(name) => match state.key_sets.named.get(&name).cloned() {
+            Some(pick) => {
+                state.apply_json_key_pick(&pick);
+                state.remember_json_keys();
+                state.notify(Severity::Info, format!("Showing key set \"{}\"", name));
+            }
+            None if state.key_sets.named.is_empty() => {
+                state.notify(Severity::Warning, "No key sets saved yet");
+            }
+            None => {
+                let message = format!(
+                    "No key set named \"{}\" (saved: {})",
+                    name,
+                    state.key_sets.names()
+                );
+                state.notify(Severity::Warning, message);
+            }
+        },
+        Action::AddNote(text) => {
+            state.add_note(text);
+            state.notify(
+                Severity::Info,
+                "Noted (included in exports; listed in bookmarks)",
+            );
+        }
+        Action::ToggleContainerExclusion => {
+            let _ = internal_tx.send(InternalAction::ToggleContainerExclusion);
+        }
+        Action::ToggleSidecarLogs => {
+            let _ = internal_tx.send(InternalAction::ToggleSidecarLogs);
+        }
+        Action::FollowRequest => state.follow_request(),
+        Action::SetTailLines(lines) => {
+            state.ui_state.tail_lines_override = Some(lines);
+            if state.current_screen == Screen::LogViewer {
+                let _ = internal_tx.send(InternalAction::RestartLogStreaming);
+            }
+        }
+        Action::GoToTime(time) => {
+            state.jump_to_time(time);
+        }
+        Action::ScaleDeployment(replicas) => {
+            let deployment = state.selected_deployment.as_deref().unwrap_or("deployment");
+            state.ask_confirm(
+                "Scale deployment",
+                format!("Scale {} to {} replicas?", deployment, replicas),
+                "Scale",
+                Action::ScaleDeployment(replicas),
+            );
+        }
+        Action::RestartDeployment => {
+            let deployment = state.selected_deployment.as_deref().unwrap_or("deployment");
+            state.ask_confirm(
+                "Restart deployment",
+                format!(
+                    "Roll out new pods for {}? Existing pods are replaced.",
+                    deployment
+                ),
+                "Restart",
+                Action::RestartDeployment,
+            );
+        }
+        Action::DeleteSelectedPod => {
+            if let Some(pod) = state.pods.get(state.ui_state.pod_panel_selection) {
+                let name = pod.name.clone();
+                handle_action(
+                    state,
+                    internal_tx,
+                    log_buffer,
+                    palette_state,
+                    commands,
+                    Action::DeletePod(name),
+                );
+            }
+        }
+        Action::DeletePod(name) => {
+            state.ask_confirm(
+                "Delete pod",
+                format!(
+                    "Delete pod {}? Its controller will start a replacement.",
+                    name
+                ),
+                "Delete",
+                Action::DeletePod(name),
+            );
+        }
+        Action::ConfirmAccept => {
+            if let Some(confirm) = state.ui_state.confirm.take() {
+                handle_action(
+                    state,
+                    internal_tx,
+                    log_buffer,
+                    palette_state,
+                    commands,
+                    Action::Confirmed(Box::new(confirm.action)),
+                );
+            }
+        }
+        Action::ConfirmCancel => {
+            state.ui_state.confirm = None;
+        }
+        Action::Confirmed(action) => match *action {
+            Action::ClearLogs => {
+                log_buffer.clear();
+                state.ui_state.forget_entry_views();
+                state.ui_state.log_scroll = 0;
+            }
+            Action::ExportLogsTo(filename) => run_export(state, log_buffer, &filename),
+            Action::ScaleDeployment(replicas) => {
+                let _ = internal_tx.send(InternalAction::ScaleDeployment(replicas));
+            }
+            Action::RestartDeployment => {
+                let _ = internal_tx.send(InternalAction::RestartDeployment);
+            }
+            Action::DeletePod(name) => {
+                let _ = internal_tx.send(InternalAction::DeletePod(name));
+            }
+            // Not destructive: nothing to skip, just run it
+            other => handle_action(
+                state,
+                internal_tx,
+                log_buffer,
+                palette_state,
+                commands,
+                other,
+            ),
+        },
         Action::PaletteSelect => {
             if let Some(cmd) = palette_state.selected_command(commands) {
                 let action = cmd.action.clone();
@@ -1049,22 +3564,27 @@ fn handle_action(
             state.search_input_backspace();
         }
         Action::SearchClear => {
-            state.ui_state.search_input.clear();
+            state.search_input_clear();
+        }
+        Action::OpenFind => {
+            state.start_find();
         }
         Action::ApplyFilter => {
-            state.apply_filter();
-            // Reset scroll to top when applying filter
-            state.ui_state.log_scroll = 0;
+            state.submit_search();
+        }
+        Action::FindNext => {
+            state.jump_to_match(true);
+        }
+        Action::FindPrev => {
+            state.jump_to_match(false);
         }
         Action::ClearFilter => {
             state.clear_filter();
         }
         Action::ToggleCaseSensitive => {
             state.ui_state.filter_case_insensitive = !state.ui_state.filter_case_insensitive;
-            // Re-apply filter with new case sensitivity if active
-            if state.ui_state.active_filter.is_some() {
-                state.apply_filter();
-            }
+            // Re-compile filter and find query with new case sensitivity
+            state.recompile_queries();
         }
 
         // JSON key filter actions
@@ -1072,15 +3592,49 @@ fn handle_action(
             if state.ui_state.json_key_filter_active {
                 state.ui_state.json_key_filter_active = false;
                 state.ui_state.json_key_search.clear();
+                state.remember_json_keys();
             } else {
                 // Collect available keys from logs
                 state.ui_state.json_available_keys = collect_json_keys(log_buffer);
+                state.ui_state.json_key_stats = log_buffer.json_key_stats(JSON_KEY_SAMPLE);
                 state.ui_state.json_key_selection = 0;
                 state.ui_state.json_key_scroll = 0;
                 state.ui_state.json_key_search.clear();
                 state.ui_state.json_key_filter_active = true;
             }
         }
+        Action::ToggleSchemaDrift => {
+            if !state.ui_state.schema_drift_visible {
+                let (drifts, keys) = log_buffer.schema_report();
+                state.ui_state.schema_drifts = drifts;
+                state.ui_state.schema_keys = keys;
+                state.ui_state.schema_drift_scroll = 0;
+            }
+            state.ui_state.schema_drift_visible = !state.ui_state.schema_drift_visible;
+        }
+        Action::SchemaDriftUp => {
+            state.ui_state.schema_drift_scroll =
+                state.ui_state.schema_drift_scroll.saturating_sub(1);
+        }
+        Action::SchemaDriftDown => {
+            state.ui_state.schema_drift_scroll =
+                state.ui_state.schema_drift_scroll.saturating_add(1);
+        }
+        Action::ToggleParseDiagnostics => {
+            if !state.ui_state.parse_diagnostics_visible {
+                state.ui_state.parse_reports = log_buffer.parse_report();
+                state.ui_state.parse_diagnostics_scroll = 0;
+            }
+            state.ui_state.parse_diagnostics_visible = !state.ui_state.parse_diagnostics_visible;
+        }
+        Action::ParseDiagnosticsUp => {
+            state.ui_state.parse_diagnostics_scroll =
+                state.ui_state.parse_diagnostics_scroll.saturating_sub(1);
+        }
+        Action::ParseDiagnosticsDown => {
+            state.ui_state.parse_diagnostics_scroll =
+                state.ui_state.parse_diagnostics_scroll.saturating_add(1);
+        }
         Action::JsonKeyUp => {
             if state.ui_state.json_key_selection > 0 {
                 state.ui_state.json_key_selection -= 1;
@@ -1119,6 +3673,9 @@ fn handle_action(
             // Clear all selections (shows all when empty)
             state.ui_state.json_visible_keys.clear();
         }
+        Action::JsonKeyToggleExclude => {
+            state.ui_state.json_keys_exclude = !state.ui_state.json_keys_exclude;
+        }
         Action::JsonKeyInput(c) => {
             state.ui_state.json_key_search.push(c);
             state.ui_state.json_key_selection = 0;
@@ -1146,16 +3703,312 @@ fn handle_action(
             }
         }
 
-        Action::RefreshContexts
-        | Action::RefreshNamespaces
-        | Action::RefreshDeployments
-        | Action::Tick
-        | Action::Render => {
+        Action::RefreshContexts => {
+            let _ = internal_tx.send(InternalAction::LoadContexts);
+        }
+        Action::RefreshNamespaces => {
+            if let Some(context) = state.selected_context.clone() {
+                let _ = internal_tx.send(InternalAction::LoadNamespaces(context));
+            }
+        }
+        Action::RefreshDeployments => {
+            if let Some(namespace) = state.selected_namespace.clone() {
+                let _ = internal_tx.send(InternalAction::LoadDeployments(namespace));
+            }
+        }
+        Action::Tick | Action::Render => {
             // No-op for now
         }
     }
 }
 
+/// Show the namespace screen for a freshly listed context
+///
+/// A reload in place doesn't stack the screen again.
+fn show_namespaces(
+    state: &mut AppState,
+    action_tx: &mpsc::UnboundedSender<Action>,
+    use_context_namespace: bool,
+) {
+    if state.current_screen == Screen::NamespaceSelect {
+        return;
+    }
+    state.navigate_to(Screen::NamespaceSelect);
+    // Skip ahead to the namespace we switched context from, or the
+    // kubeconfig namespace if asked to (Esc comes back here)
+    let default = state.context_namespace().map(str::to_string);
+    let switching = state
+        .ui_state
+        .switch_target
+        .as_ref()
+        .map(|t| t.namespace.clone());
+    match (switching, default) {
+        (Some(namespace), _) if state.all_namespaces.iter().any(|n| n.name == namespace) => {
+            let _ = action_tx.send(Action::SelectNamespace(namespace));
+        }
+        (Some(namespace), _) => {
+            state.ui_state.switch_target = None;
+            state.select_context_namespace();
+            state.notify(
+                Severity::Warning,
+                format!("No namespace '{}' in this context", namespace),
+            );
+        }
+        (None, Some(namespace)) if use_context_namespace => {
+            let _ = action_tx.send(Action::SelectNamespace(namespace));
+        }
+        _ => state.select_context_namespace(),
+    }
+}
+
+/// Show the deployment screen for a freshly listed namespace
+fn show_deployments(state: &mut AppState, action_tx: &mpsc::UnboundedSender<Action>) {
+    if state.current_screen != Screen::DeploymentSelect {
+        state.navigate_to(Screen::DeploymentSelect);
+    }
+    // Finish a context switch by reopening the same deployment
+    if let Some(deployment) = state
+        .ui_state
+        .switch_target
+        .take()
+        .and_then(|t| t.deployment)
+    {
+        if state.all_deployments.iter().any(|d| d.name == deployment) {
+            let _ = action_tx.send(Action::SelectDeployment(deployment));
+        } else {
+            state.notify(
+                Severity::Warning,
+                format!("No deployment '{}' in this namespace", deployment),
+            );
+        }
+    }
+}
+
+/// List a namespace's deployments in the background
+///
+/// With `refresh`, the result replaces a cached list instead of navigating.
+fn spawn_deployments_load(
+    kube_client: &Arc<KubeClient>,
+    client: kube::Client,
+    context: Option<String>,
+    namespace: String,
+    internal_tx: &mpsc::UnboundedSender<InternalAction>,
+    refresh: bool,
+) -> tokio::task::JoinHandle<()> {
+    let kube_client = Arc::clone(kube_client);
+    let internal_tx = internal_tx.clone();
+    tokio::spawn(async move {
+        let deployments = retry_unauthorized(
+            &kube_client,
+            client,
+            context.as_deref(),
+            &internal_tx,
+            |client| {
+                let kube_client = kube_client.clone();
+                let namespace = namespace.clone();
+                async move { kube_client.get_deployments(&client, &namespace).await }
+            },
+        )
+        .await;
+        let _ = internal_tx.send(match deployments {
+            Ok(deployments) if refresh => {
+                InternalAction::DeploymentsRefreshed(namespace, deployments)
+            }
+            Ok(deployments) => InternalAction::DeploymentsLoaded(deployments),
+            Err(e) => InternalAction::Error(
+                format!("Failed to load deployments: {}", e),
+                ApiError::classify(&e),
+                Some(Action::SelectNamespace(namespace)),
+            ),
+        });
+    })
+}
+
+/// Connect to an extra cluster and list its pods of the deployment, tagged
+/// with the context they came from
+async fn load_cluster_pods(
+    kube_client: &KubeClient,
+    context: &str,
+    namespace: &str,
+    deployment: &str,
+) -> Result<(kube::Client, Vec<PodInfo>)> {
+    let client = kube_client.client_for_context(context).await?;
+    let deployment = kube_client
+        .get_deployment(&client, namespace, deployment)
+        .await?;
+    let mut pods = kube_client
+        .get_pods_for_deployment(&client, namespace, &deployment)
+        .await?;
+    for pod in &mut pods {
+        pod.cluster = Some(context.to_string());
+    }
+    Ok((client, pods))
+}
+
+/// Run a cluster call, rebuilding the client and trying once more if its
+/// credentials have expired
+///
+/// The rebuilt client is sent back so later calls use it too.
+async fn retry_unauthorized<T, Fut>(
+    kube_client: &KubeClient,
+    client: kube::Client,
+    context: Option<&str>,
+    internal_tx: &mpsc::UnboundedSender<InternalAction>,
+    call: impl Fn(kube::Client) -> Fut,
+) -> Result<T>
+where
+    Fut: std::future::Future<Output = Result<T>>,
+{
+    let result = call(client).await;
+    let Some(context) = context else {
+        return result;
+    };
+    match result {
+        Err(e) if ApiError::classify(&e) == Some(ApiError::AuthExpired) => {
+            tracing::info!(context, "credentials expired, rebuilding client");
+            let client = kube_client.refresh_client(context).await?;
+            let _ = internal_tx.send(InternalAction::CredentialsRefreshed(Ok(client.clone())));
+            call(client).await
+        }
+        result => result,
+    }
+}
+
+/// Track a newly spawned load, cancelling the one it supersedes
+fn replace_load_task(
+    slot: &mut Option<tokio::task::JoinHandle<()>>,
+    task: tokio::task::JoinHandle<()>,
+) {
+    if let Some(previous) = slot.replace(task) {
+        previous.abort();
+    }
+}
+
+/// Apply a new time range to the viewer
+///
+/// A wider range keeps what's buffered and only fetches the older part it
+/// adds; the follow streams carry on. A narrower one restarts the streams.
+fn change_time_range(
+    state: &mut AppState,
+    internal_tx: &mpsc::UnboundedSender<InternalAction>,
+    log_buffer: &LogBuffer,
+    previous: TimeRange,
+) {
+    if state.current_screen != Screen::LogViewer {
+        return;
+    }
+    // Cycling leaves a --since/--until window for the presets
+    if state.ui_state.time_window.is_set() {
+        state.ui_state.time_window = TimeWindow::default();
+        let _ = internal_tx.send(InternalAction::RestartLogStreaming);
+        return;
+    }
+    if state.ui_state.time_range.is_wider_than(&previous) && !log_buffer.is_empty() {
+        let _ = internal_tx.send(InternalAction::ExpandTimeRange(previous));
+    } else {
+        let _ = internal_tx.send(InternalAction::RestartLogStreaming);
+    }
+}
+
+/// Start streams for pods just added to the sample
+fn stream_sampled_pods(
+    state: &mut AppState,
+    internal_tx: &mpsc::UnboundedSender<InternalAction>,
+    added: Vec<PodInfo>,
+) {
+    if added.is_empty() {
+        return;
+    }
+    let streamed = state.streamed_pods().len();
+    state.notify(
+        Severity::Info,
+        format!(
+            "Streaming {} more {} ({} of {})",
+            added.len(),
+            if added.len() == 1 { "pod" } else { "pods" },
+            streamed,
+            state.pods.len()
+        ),
+    );
+    let _ = internal_tx.send(InternalAction::StreamPods(added));
+}
+
+/// Ask for older history if the viewer is at the top and a fetch makes sense
+fn request_older_logs(state: &mut AppState, internal_tx: &mpsc::UnboundedSender<InternalAction>) {
+    // Time-windowed streams already fetched everything in their window
+    if state.current_screen != Screen::LogViewer
+        || state.ui_state.time_range != TimeRange::All
+        || state.ui_state.time_window.since.is_some()
+        || state.ui_state.history_loading
+        || state.ui_state.history_exhausted
+    {
+        return;
+    }
+
+    state.ui_state.history_loading = true;
+    let _ = internal_tx.send(InternalAction::LoadOlderLogs);
+}
+
+/// Remember the current context/namespace/deployment in the recent targets list
+/// The open context, namespace and deployment, which every hook payload starts with
+fn hook_payload(state: &AppState) -> serde_json::Map<String, serde_json::Value> {
+    let mut payload = serde_json::Map::new();
+    payload.insert("context".into(), serde_json::json!(state.selected_context));
+    payload.insert(
+        "namespace".into(),
+        serde_json::json!(state.selected_namespace),
+    );
+    payload.insert(
+        "deployment".into(),
+        serde_json::json!(state.selected_deployment),
+    );
+    payload
+}
+
+fn record_recent_target(state: &mut AppState) {
+    if let Some(target) = state.current_target() {
+        state.recent_targets.record(target);
+        state.recent_targets.save();
+    }
+}
+
+/// Check in the background whether the pods still have logs back to the
+/// start of the time range, reporting any that don't
+fn spawn_retention_check(
+    internal_tx: &mpsc::UnboundedSender<InternalAction>,
+    client: &kube::Client,
+    namespace: &str,
+    pods: &[PodInfo],
+    excluded_containers: &[String],
+    since_seconds: i64,
+) {
+    let since = chrono::Utc::now() - chrono::Duration::seconds(since_seconds);
+    let excluded_containers = excluded_containers.to_vec();
+    let internal_tx = internal_tx.clone();
+    let client = client.clone();
+    let namespace = namespace.to_string();
+    // Extra clusters' pods would be looked up in the wrong cluster
+    let pods: Vec<PodInfo> = pods
+        .iter()
+        .filter(|p| p.cluster.is_none())
+        .cloned()
+        .collect();
+    tokio::spawn(async move {
+        let gaps =
+            logs::find_retention_gaps(client, &namespace, &pods, since, &excluded_containers).await;
+        if !gaps.is_empty() {
+            let _ = internal_tx.send(InternalAction::RetentionGaps(gaps));
+        }
+    });
+}
+
+/// Reset scrollback tracking for a fresh set of streams
+fn reset_history(ui_state: &mut UiState, tail_lines: i64) {
+    ui_state.history_depth = tail_lines;
+    ui_state.history_loading = false;
+    ui_state.history_exhausted = false;
+}
+
 fn handle_list_select(state: &mut AppState, internal_tx: &mpsc::UnboundedSender<InternalAction>) {
     match state.current_screen {
         Screen::ContextSelect => {
@@ -1182,8 +4035,16 @@ fn handle_list_select(state: &mut AppState, internal_tx: &mpsc::UnboundedSender<
                 let _ = state.action_tx.send(Action::SelectDeployment(name));
             }
         }
-        Screen::LogViewer => {
-            // No selection in log viewer
+        Screen::RecentSelect => {
+            if let Some(idx) = state.selected_index()
+                && let Some(target) = state.recent_targets.targets.get(idx)
+            {
+                let target = target.clone();
+                let _ = state.action_tx.send(Action::OpenRecent(target));
+            }
+        }
+        Screen::LogViewer | Screen::Connecting => {
+            // Nothing to select
         }
     }
     let _ = internal_tx;
@@ -1194,10 +4055,11 @@ fn render(
     state: &mut AppState,
     log_buffer: &LogBuffer,
     palette_state: &mut CommandPaletteState,
-    commands: &[Command],
-    dropped_count: u64,
+    keybindings: &KeyBindings,
 ) -> Result<()> {
-    tui.terminal().draw(|frame| {
+    let commands = commands_for(state.current_screen.clone());
+    let started = std::time::Instant::now();
+    let drawn = tui.terminal().draw(|frame| {
         match state.current_screen {
             Screen::ContextSelect => {
                 ContextSelectScreen::render(frame, state);
@@ -1209,8 +4071,19 @@ fn render(
                 DeploymentSelectScreen::render(frame, state);
             }
             Screen::LogViewer => {
-                LogViewerScreen::render(frame, state, log_buffer, dropped_count);
+                LogViewerScreen::render(frame, state, log_buffer);
+            }
+            Screen::RecentSelect => {
+                RecentSelectScreen::render(frame, state);
             }
+            Screen::Connecting => {
+                ConnectingScreen::render(frame, state);
+            }
+        }
+
+        // Render pod color legend if visible
+        if state.ui_state.pod_legend_visible && state.current_screen == Screen::LogViewer {
+            PodLegend::render(frame, state);
         }
 
         // Render JSON key filter overlay if visible
@@ -1218,16 +4091,82 @@ fn render(
             JsonKeyFilter::render(frame, state);
         }
 
+        // Render the full line overlay while one is open
+        if let Some(full_line) = state.ui_state.full_line.as_mut() {
+            FullLineView::render(frame, full_line);
+        }
+
+        // Render schema drift report if visible
+        if state.ui_state.schema_drift_visible {
+            SchemaDriftReport::render(frame, state);
+        }
+
+        // Render parse diagnostics if visible
+        if state.ui_state.parse_diagnostics_visible {
+            ParseDiagnosticsReport::render(frame, state);
+        }
+
+        // Render columns overlay if visible
+        if state.ui_state.columns_visible {
+            ColumnsOverlay::render(frame, state);
+        }
+
+        // Render bookmarks overlay if visible
+        if state.ui_state.bookmarks_visible {
+            BookmarkList::render(frame, state);
+        }
+
         // Render command palette overlay if visible
         if palette_state.visible {
-            CommandPalette::render(frame, palette_state, commands);
+            CommandPalette::render(frame, palette_state, &commands);
+        }
+
+        // Toasts float over the screen; the history overlay replaces them
+        if state.ui_state.notifications_visible {
+            NotificationHistory::render(frame, &state.ui_state.notifications);
+        } else {
+            Toasts::render(frame, &state.ui_state.notifications);
+        }
+
+        // Errors stay up until dismissed
+        if let Some(error) = &state.ui_state.error {
+            ErrorModal::render(frame, error);
+        }
+
+        // Confirmation modal sits above everything but help
+        if let Some(confirm) = &state.ui_state.confirm {
+            ConfirmDialog::render(frame, confirm);
         }
 
         // Render help overlay if visible
         if state.ui_state.help_visible {
-            HelpOverlay::render(frame);
+            HelpOverlay::render(frame, keybindings, &mut state.ui_state.help_scroll);
+        }
+
+        if state.ui_state.usage_visible {
+            UsageReport::render(frame, &state.ui_state.usage);
+        }
+
+        if state.ui_state.stream_params_visible {
+            StreamParamsReport::render(
+                frame,
+                &state.ui_state.stream_params,
+                &mut state.ui_state.stream_params_scroll,
+            );
+        }
+
+        // Performance HUD goes over everything so it can be read with any overlay open
+        if state.ui_state.perf_visible {
+            PerfHud::render(frame, &state.ui_state.perf, log_buffer);
         }
     })?;
+    let links = take_hyperlinks();
+    if !links.is_empty() {
+        let buffer = drawn.buffer.clone();
+        tui.draw_hyperlinks(&buffer, &links)?;
+    }
+    tui.set_title(&state.window_title())?;
+    state.ui_state.perf.record_render(started.elapsed());
 
     Ok(())
 }
@@ -1248,50 +4187,168 @@ fn get_filtered_json_keys(state: &AppState) -> Vec<String> {
     }
 }
 
+/// Export to `filename` and report the outcome
+fn run_export(state: &mut AppState, log_buffer: &LogBuffer, filename: &str) {
+    match export_logs_to_file(filename, log_buffer, state) {
+        Ok(count) => {
+            state.notify(
+                Severity::Success,
+                format!("Exported {} logs to {}", count, filename),
+            );
+        }
+        Err(e) => {
+            state.notify(Severity::Error, format!("Export failed: {}", e));
+        }
+    }
+}
+
 fn export_logs_to_file(filename: &str, log_buffer: &LogBuffer, state: &AppState) -> Result<usize> {
-    let mut file = File::create(filename)?;
-    let logs = log_buffer.all();
+    export_logs(File::create(filename)?, log_buffer, state)
+}
 
-    // Apply text filter if active
-    let text_filtered: Vec<_> = if let Some(filter) = &state.ui_state.active_filter {
-        logs.iter().filter(|e| filter.matches(e)).collect()
-    } else {
-        logs.iter().collect()
+/// Write the view to `file`, returning the number of lines written
+fn export_logs(mut file: File, log_buffer: &LogBuffer, state: &AppState) -> Result<usize> {
+    // Export exactly what the view shows
+    let filtered = state.filter_pipeline().apply(log_buffer.all());
+    let write_note = |file: &mut File, note: &app::Note| {
+        let line = note
+            .entry
+            .as_ref()
+            .map(|e| format!("{} #{} ", e.pod_name(), e.id))
+            .unwrap_or_default();
+        writeln!(
+            file,
+            "{} [NOTE] {}| {}",
+            note.at.format("%Y-%m-%dT%H:%M:%S%.3fZ"),
+            line,
+            note.text
+        )
     };
 
-    // Apply JSON key filter if active
-    let filtered: Vec<_> = if !state.ui_state.json_visible_keys.is_empty() {
-        text_filtered
-            .into_iter()
-            .filter(|e| {
-                if let Some(fields) = &e.fields {
-                    fields
-                        .keys()
-                        .any(|k| state.ui_state.json_visible_keys.contains(k))
-                } else {
-                    false
-                }
-            })
-            .collect()
-    } else {
-        text_filtered
-    };
+    // Notes go after their line, or at their time when it isn't in the view
+    let mut unplaced: Vec<&app::Note> = state
+        .ui_state
+        .notes
+        .iter()
+        .filter(|note| {
+            note.entry
+                .as_ref()
+                .is_none_or(|noted| !filtered.iter().any(|e| Arc::ptr_eq(e, noted)))
+        })
+        .collect();
+    unplaced.sort_by_key(|note| note.at);
+    let mut unplaced = unplaced.into_iter().peekable();
 
     for entry in &filtered {
+        if let Some(ts) = entry.timestamp {
+            while let Some(note) = unplaced.next_if(|note| note.at < ts) {
+                write_note(&mut file, note)?;
+            }
+        }
         let ts = entry
             .timestamp
             .map(|t| t.format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string())
             .unwrap_or_default();
 
+        // Buffer id orders lines across pods; line number is the position in the pod's log
         writeln!(
             file,
-            "{} [{}] {} | {}",
+            "{} [{}] {} #{} L{} | {}",
             ts,
             entry.level.as_str(),
-            entry.pod_name,
+            entry.pod_name(),
+            entry.id,
+            entry.line_number,
             entry.raw
         )?;
+        for note in state.notes_for(entry) {
+            write_note(&mut file, note)?;
+        }
+    }
+    for note in unplaced {
+        write_note(&mut file, note)?;
     }
 
     Ok(filtered.len())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reload(old: &Config, new: &Config) -> Result<(Vec<&'static str>, Vec<&'static str>)> {
+        let (tx, _rx) = mpsc::unbounded_channel();
+        let mut state = AppState::new(tx);
+        let mut hooks = Hooks::new(&[]).unwrap();
+        apply_config_changes(&mut state, &mut hooks, old, new)
+    }
+
+    #[test]
+    fn test_destructive_actions_wait_for_confirmation() {
+        let (tx, _rx) = mpsc::unbounded_channel();
+        let mut state = AppState::new(tx);
+        let (internal_tx, mut internal_rx) = mpsc::unbounded_channel();
+        let log_buffer = LogBuffer::new(100);
+        let mut palette = CommandPaletteState::default();
+        let mut dispatch = |state: &mut AppState, action| {
+            handle_action(state, &internal_tx, &log_buffer, &mut palette, &[], action)
+        };
+
+        dispatch(&mut state, Action::DeletePod("api-1".into()));
+        assert!(state.ui_state.confirm.is_some());
+        assert!(internal_rx.try_recv().is_err());
+
+        dispatch(&mut state, Action::ConfirmCancel);
+        assert!(state.ui_state.confirm.is_none());
+        assert!(internal_rx.try_recv().is_err());
+
+        dispatch(&mut state, Action::DeletePod("api-1".into()));
+        dispatch(&mut state, Action::ConfirmAccept);
+        assert!(state.ui_state.confirm.is_none());
+        assert!(matches!(
+            internal_rx.try_recv(),
+            Ok(InternalAction::DeletePod(pod)) if pod == "api-1"
+        ));
+    }
+
+    #[test]
+    fn test_bad_config_edit_changes_nothing() {
+        let (tx, _rx) = mpsc::unbounded_channel();
+        let mut state = AppState::new(tx);
+        let mut hooks = Hooks::new(&[]).unwrap();
+        let old = Config::default();
+        let new = Config {
+            filter: Some("(unclosed".to_string()),
+            pod_colors: [("canary".to_string(), "red".to_string())].into(),
+            ..Config::default()
+        };
+        assert!(apply_config_changes(&mut state, &mut hooks, &old, &new).is_err());
+        assert!(state.ui_state.active_filter.is_none());
+        assert!(state.ui_state.search_input.is_empty());
+        assert!(state.pod_color_overrides.is_empty());
+    }
+
+    #[test]
+    fn test_config_edits_applied_or_left_for_restart() {
+        let old = Config::default();
+        let new = Config {
+            filter: Some("error".to_string()),
+            ..Config::default()
+        };
+        assert_eq!(reload(&old, &new).unwrap(), (vec!["filter"], vec![]));
+
+        let new = Config {
+            tail_lines: Some(500),
+            level_rules: vec![LevelRuleSpec {
+                pattern: Some("GET /healthz".to_string()),
+                level: Some("trace".to_string()),
+                ..LevelRuleSpec::default()
+            }],
+            ..Config::default()
+        };
+        assert_eq!(
+            reload(&old, &new).unwrap(),
+            (vec![], vec!["level_rules", "other settings"])
+        );
+    }
+}
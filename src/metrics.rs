@@ -0,0 +1,124 @@
+//! Prometheus metrics for `kubescope tail`
+//!
+//! Counts ingested lines by pod and level and serves them in the Prometheus
+//! text format on `/metrics`, so a long-running log relay can be monitored.
+
+use std::collections::BTreeMap;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use parking_lot::Mutex;
+
+use crate::http::{self, Response};
+use crate::logs::IngestStats;
+use crate::types::LogEntry;
+
+/// Line counters for the metrics page
+pub struct TailMetrics {
+    /// Lines read, by pod and level
+    lines: Mutex<BTreeMap<(String, &'static str), u64>>,
+    /// Lines that passed the filter and were printed
+    printed: AtomicU64,
+    /// Drops on the way from the streams (backpressure)
+    ingest: Arc<IngestStats>,
+    started: DateTime<Utc>,
+}
+
+impl TailMetrics {
+    pub fn new(ingest: Arc<IngestStats>) -> Self {
+        Self {
+            lines: Mutex::new(BTreeMap::new()),
+            printed: AtomicU64::new(0),
+            ingest,
+            started: Utc::now(),
+        }
+    }
+
+    /// Count a line read from a pod, and whether it was printed
+    pub fn record(&self, entry: &LogEntry, printed: bool) {
+        *self
+            .lines
+            .lock()
+            .entry((entry.pod_name().to_string(), entry.level.name()))
+            .or_default() += 1;
+        if printed {
+            self.printed.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// The counters in the Prometheus text exposition format
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        out.push_str("# HELP kubescope_lines_total Log lines read, by pod and level.\n");
+        out.push_str("# TYPE kubescope_lines_total counter\n");
+        for ((pod, level), count) in self.lines.lock().iter() {
+            out.push_str(&format!(
+                "kubescope_lines_total{{pod=\"{}\",level=\"{}\"}} {}\n",
+                escape_label(pod),
+                level,
+                count
+            ));
+        }
+        out.push_str("# HELP kubescope_printed_lines_total Log lines that passed the filter.\n");
+        out.push_str("# TYPE kubescope_printed_lines_total counter\n");
+        out.push_str(&format!(
+            "kubescope_printed_lines_total {}\n",
+            self.printed.load(Ordering::Relaxed)
+        ));
+        out.push_str(
+            "# HELP kubescope_dropped_lines_total Log lines dropped because output fell behind.\n",
+        );
+        out.push_str("# TYPE kubescope_dropped_lines_total counter\n");
+        out.push_str(&format!(
+            "kubescope_dropped_lines_total {}\n",
+            self.ingest.dropped_count()
+        ));
+        out.push_str("# HELP kubescope_start_time_seconds When streaming started.\n");
+        out.push_str("# TYPE kubescope_start_time_seconds gauge\n");
+        out.push_str(&format!(
+            "kubescope_start_time_seconds {}\n",
+            self.started.timestamp()
+        ));
+        out
+    }
+}
+
+/// Bind `addr`, then serve `/metrics` in the background
+pub async fn serve(addr: SocketAddr, metrics: Arc<TailMetrics>) -> Result<()> {
+    http::serve(addr, move |request| match request.path.as_str() {
+        "/metrics" => Response::ok("text/plain; version=0.0.4; charset=utf-8", metrics.render()),
+        _ => Response::not_found(),
+    })
+    .await
+}
+
+/// Escape a label value (backslash, quote and newline)
+fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logs::LogParser;
+
+    #[test]
+    fn test_metrics_count_lines_by_pod_and_level() {
+        let metrics = TailMetrics::new(Arc::new(IngestStats::default()));
+        metrics.record(&LogParser::parse(r#"{"level":"error"}"#, "api-1", 1), true);
+        metrics.record(&LogParser::parse(r#"{"level":"error"}"#, "api-1", 2), false);
+        metrics.record(&LogParser::parse("INFO ready", "api-\"2\"", 1), true);
+
+        let page = metrics.render();
+        assert!(page.contains("kubescope_lines_total{pod=\"api-1\",level=\"error\"} 2\n"));
+        assert!(page.contains("kubescope_lines_total{pod=\"api-\\\"2\\\"\",level=\"info\"} 1\n"));
+        assert!(page.contains("kubescope_printed_lines_total 2\n"));
+        assert!(page.contains("kubescope_dropped_lines_total 0\n"));
+    }
+}
@@ -0,0 +1,199 @@
+//! External fuzzy pickers for choosing the target before the TUI starts
+//!
+//! With `--picker external`, whatever of context, namespace and deployment
+//! wasn't given is chosen in fzf or skim (or the command in
+//! `KUBESCOPE_PICKER`), one list after the other, and the viewer opens on the
+//! result.
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+use anyhow::{Context, Result};
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+use crate::k8s::KubeClient;
+use crate::shell::shell_command;
+
+/// How the context, namespace and deployment are chosen
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum PickerMode {
+    /// The list screens in the TUI
+    #[default]
+    Builtin,
+    /// fzf, skim or `$KUBESCOPE_PICKER`, before the TUI starts
+    External,
+}
+
+/// Picker commands tried in order when `KUBESCOPE_PICKER` isn't set
+const KNOWN_PICKERS: &[&str] = &["fzf", "sk"];
+
+/// The target chosen so far, filled in by [`pick_target`]
+pub struct Target<'a> {
+    pub context: &'a mut Option<String>,
+    pub namespace: &'a mut Option<String>,
+    pub deployment: &'a mut Option<String>,
+}
+
+/// Fill in the missing parts of `target` with the external picker
+///
+/// Returns false when the user backed out of a picker.
+pub async fn pick_target(target: Target<'_>, use_context_namespace: bool) -> Result<bool> {
+    if target.context.is_some() && target.namespace.is_some() && target.deployment.is_some() {
+        return Ok(true);
+    }
+    let picker = Picker::find()?;
+    let kube_client = KubeClient::new().await?;
+
+    let contexts = kube_client.get_contexts();
+    if target.context.is_none() {
+        // The kubeconfig's current context first, so Enter picks it
+        let mut names: Vec<String> = contexts.iter().map(|c| c.name.clone()).collect();
+        if let Some(current) = kube_client.current_context()
+            && let Some(index) = names.iter().position(|n| n == current)
+        {
+            let current = names.remove(index);
+            names.insert(0, current);
+        }
+        let Some(context) = picker.choose("context", &names)? else {
+            return Ok(false);
+        };
+        *target.context = Some(context);
+    }
+    let context = target.context.as_deref().unwrap_or_default();
+    if target.namespace.is_none() && use_context_namespace {
+        *target.namespace = contexts
+            .iter()
+            .find(|c| c.name == context)
+            .and_then(|c| c.namespace.clone());
+    }
+    let client = kube_client.client_for_context(context).await?;
+
+    if target.namespace.is_none() {
+        let names: Vec<String> = kube_client
+            .get_namespaces(&client)
+            .await?
+            .into_iter()
+            .map(|ns| ns.name)
+            .collect();
+        let Some(namespace) = picker.choose("namespace", &names)? else {
+            return Ok(false);
+        };
+        *target.namespace = Some(namespace);
+    }
+    let namespace = target.namespace.as_deref().unwrap_or_default();
+
+    if target.deployment.is_none() {
+        let names: Vec<String> = kube_client
+            .get_deployments(&client, namespace)
+            .await?
+            .into_iter()
+            .map(|d| d.name)
+            .collect();
+        if names.is_empty() {
+            anyhow::bail!("No deployments in namespace {}", namespace);
+        }
+        let Some(deployment) = picker.choose("deployment", &names)? else {
+            return Ok(false);
+        };
+        *target.deployment = Some(deployment);
+    }
+    Ok(true)
+}
+
+/// A fuzzy picker command line
+struct Picker {
+    command: String,
+    /// Whether it takes fzf's `--prompt`
+    prompt: bool,
+}
+
+impl Picker {
+    /// `KUBESCOPE_PICKER`, or the first known picker on `PATH`
+    fn find() -> Result<Self> {
+        if let Some(command) = std::env::var("KUBESCOPE_PICKER")
+            .ok()
+            .filter(|c| !c.trim().is_empty())
+        {
+            return Ok(Self {
+                command,
+                prompt: false,
+            });
+        }
+        KNOWN_PICKERS
+            .iter()
+            .find(|name| which(name))
+            .map(|name| Self {
+                command: name.to_string(),
+                prompt: true,
+            })
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "--picker external needs fzf or sk on PATH, or a picker command in KUBESCOPE_PICKER"
+                )
+            })
+    }
+
+    /// Let the user choose one of `items`; None if they backed out
+    fn choose(&self, what: &str, items: &[String]) -> Result<Option<String>> {
+        let mut command = self.command.clone();
+        if self.prompt {
+            command.push_str(&format!(" --prompt '{}> '", what));
+        }
+        // The picker draws on the terminal itself; only the list goes through pipes
+        let mut child = shell_command::<Command>(&command)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::inherit())
+            .spawn()
+            .with_context(|| format!("Failed to run picker `{}`", self.command))?;
+        if let Some(mut stdin) = child.stdin.take() {
+            // A picker that exits early closes the pipe; its choice still counts
+            let _ = stdin.write_all(items.join("\n").as_bytes());
+        }
+        let output = child.wait_with_output()?;
+        let choice = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        // fzf exits 1 for no match and 130 on Esc/Ctrl-C
+        if !output.status.success() || choice.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(choice))
+    }
+}
+
+/// Whether `name` is an executable on `PATH`
+fn which(name: &str) -> bool {
+    let Some(path) = std::env::var_os("PATH") else {
+        return false;
+    };
+    std::env::split_paths(&path).any(|dir| {
+        let candidate = dir.join(name);
+        candidate.is_file() || (cfg!(windows) && candidate.with_extension("exe").is_file())
+    })
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_picker_returns_the_chosen_line() {
+        let items = ["api".to_string(), "worker".to_string()];
+        let picker = Picker {
+            command: "grep worker".into(),
+            prompt: false,
+        };
+        assert_eq!(
+            picker.choose("deployment", &items).unwrap().as_deref(),
+            Some("worker")
+        );
+
+        // No match, like a picker closed with Esc
+        let picker = Picker {
+            command: "grep cron".into(),
+            prompt: false,
+        };
+        assert_eq!(picker.choose("deployment", &items).unwrap(), None);
+    }
+}
@@ -0,0 +1,376 @@
+//! Lua plugins for custom line parsers, field extractors and palette commands
+//!
+//! Every `*.lua` file in the plugin directory (`~/.config/kubescope/plugins`,
+//! `%APPDATA%\kubescope\plugins` on Windows) runs once at startup and
+//! registers what it provides through the global `kubescope` table:
+//!
+//! - `kubescope.parser(name, fn(text))` claims lines that aren't JSON by
+//!   returning a table of fields, which are then treated like a JSON line's
+//! - `kubescope.extractor(name, fn(text, fields))` adds field `name` to every
+//!   line it returns a value for
+//! - `kubescope.command(name, description, fn(ctx))` adds a palette command in
+//!   the log viewer; `ctx` holds the open target and the lines on screen, and
+//!   a returned string is shown as a notification
+//!
+//! Parsers and extractors run on every streamed line, so they should be cheap.
+//! All plugins share one Lua state, and the pods' stream tasks take turns
+//! calling into it: a call holds the others up for as long as it runs, so a
+//! parser or extractor call is stopped after about a million instructions
+//! (a palette command after a hundred times that) and the line is left as
+//! it was. With no parsers or extractors registered, lines never touch it.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, OnceLock};
+
+use anyhow::Result;
+use mlua::{Function, HookTriggers, Lua, LuaSerdeExt, VmState};
+use parking_lot::{Mutex, MutexGuard};
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::types::LogEntry;
+
+static PLUGINS: OnceLock<Plugins> = OnceLock::new();
+
+/// Lua instructions between checks of a call's budget
+const HOOK_INTERVAL: u32 = 10_000;
+
+/// Checks a parser or extractor call gets, about a million instructions
+const LINE_BUDGET: u32 = 100;
+
+/// Checks a palette command gets, as it may walk every line on screen
+const COMMAND_BUDGET: u32 = 10_000;
+
+/// Directory plugins are loaded from
+pub fn plugin_dir() -> Option<PathBuf> {
+    if cfg!(windows) {
+        return Some(crate::session::kubescope_dir()?.join("plugins"));
+    }
+    let config = std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .filter(|p| p.is_absolute())
+        .or_else(|| Some(dirs::home_dir()?.join(".config")))?;
+    Some(config.join("kubescope").join("plugins"))
+}
+
+/// Load the plugins in the plugin directory for the rest of the session
+///
+/// Returns a message for each plugin that failed to load.
+pub fn init() -> Vec<String> {
+    let mut sources = Vec::new();
+    let mut errors = Vec::new();
+    if let Some(entries) = plugin_dir().and_then(|dir| std::fs::read_dir(dir).ok()) {
+        let mut paths: Vec<PathBuf> = entries
+            .filter_map(|e| e.ok().map(|e| e.path()))
+            .filter(|p| p.extension().is_some_and(|ext| ext == "lua"))
+            .collect();
+        paths.sort();
+        for path in paths {
+            let name = path
+                .file_name()
+                .map(|n| n.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            match std::fs::read_to_string(&path) {
+                Ok(source) => sources.push((name, source)),
+                Err(e) => errors.push(format!("Plugin {}: {}", name, e)),
+            }
+        }
+    }
+    if sources.is_empty() {
+        return errors;
+    }
+    match Plugins::load(&sources) {
+        Ok((plugins, load_errors)) => {
+            errors.extend(load_errors);
+            let _ = PLUGINS.set(plugins);
+        }
+        Err(e) => errors.push(format!("Plugins: {}", e)),
+    }
+    errors
+}
+
+/// The loaded plugins, if any were
+pub fn get() -> Option<&'static Plugins> {
+    PLUGINS.get()
+}
+
+/// A palette command provided by a plugin
+pub struct PluginCommand {
+    pub name: &'static str,
+    pub description: &'static str,
+    function: Function,
+}
+
+/// What a plugin command is called with
+#[derive(Serialize)]
+pub struct CommandContext<'a> {
+    pub context: Option<&'a str>,
+    pub namespace: Option<&'a str>,
+    pub deployment: Option<&'a str>,
+    /// The lines the view shows (after filters), oldest first
+    pub lines: Vec<CommandLine<'a>>,
+}
+
+#[derive(Serialize)]
+pub struct CommandLine<'a> {
+    pub pod: &'a str,
+    pub text: &'a str,
+    pub level: &'static str,
+    pub timestamp: Option<String>,
+    pub fields: Option<&'a HashMap<String, Value>>,
+}
+
+impl<'a> CommandLine<'a> {
+    pub fn new(entry: &'a LogEntry) -> Self {
+        Self {
+            pod: entry.pod_name(),
+            text: entry.display_text(),
+            level: entry.level.as_str(),
+            timestamp: entry.timestamp.map(|ts| ts.to_rfc3339()),
+            fields: entry.fields.as_ref(),
+        }
+    }
+}
+
+/// What the plugin scripts registered
+#[derive(Default)]
+struct Registry {
+    parsers: Vec<(String, Function)>,
+    extractors: Vec<(String, Function)>,
+    commands: Vec<PluginCommand>,
+}
+
+/// Loaded plugins and the Lua state they live in
+pub struct Plugins {
+    lua: Lua,
+    /// Held for each call, so the budget is the running call's
+    calls: Mutex<()>,
+    /// Budget checks left for the running call
+    budget: Arc<AtomicU32>,
+    parsers: Vec<(String, Function)>,
+    extractors: Vec<(String, Function)>,
+    commands: Vec<PluginCommand>,
+}
+
+impl Plugins {
+    /// Run each `(name, source)` script, collecting what they register
+    ///
+    /// A script that fails is reported and skipped; the others still load.
+    fn load(sources: &[(String, String)]) -> Result<(Self, Vec<String>)> {
+        let lua = Lua::new();
+        let registry = Arc::new(Mutex::new(Registry::default()));
+
+        let api = lua.create_table()?;
+        let parsers = Arc::clone(&registry);
+        api.set(
+            "parser",
+            lua.create_function(move |_, (name, function): (String, Function)| {
+                parsers.lock().parsers.push((name, function));
+                Ok(())
+            })?,
+        )?;
+        let extractors = Arc::clone(&registry);
+        api.set(
+            "extractor",
+            lua.create_function(move |_, (name, function): (String, Function)| {
+                extractors.lock().extractors.push((name, function));
+                Ok(())
+            })?,
+        )?;
+        let commands = Arc::clone(&registry);
+        api.set(
+            "command",
+            lua.create_function(
+                move |_, (name, description, function): (String, String, Function)| {
+                    // Palette entries are static; plugins load once per run
+                    commands.lock().commands.push(PluginCommand {
+                        name: Box::leak(name.into_boxed_str()),
+                        description: Box::leak(description.into_boxed_str()),
+                        function,
+                    });
+                    Ok(())
+                },
+            )?,
+        )?;
+        lua.globals().set("kubescope", api)?;
+
+        // A call that loops forever gives up instead of hanging its stream
+        let budget = Arc::new(AtomicU32::new(COMMAND_BUDGET));
+        let remaining = Arc::clone(&budget);
+        lua.set_hook(
+            HookTriggers::new().every_nth_instruction(HOOK_INTERVAL),
+            move |_, _| {
+                // Stays at 0 once spent, so each later check fails too
+                match remaining
+                    .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |n| n.checked_sub(1))
+                {
+                    Ok(_) => Ok(VmState::Continue),
+                    Err(_) => Err(mlua::Error::runtime("plugin ran too long")),
+                }
+            },
+        )?;
+
+        let mut errors = Vec::new();
+        for (name, source) in sources {
+            budget.store(COMMAND_BUDGET, Ordering::Relaxed);
+            if let Err(e) = lua.load(source.as_str()).set_name(name).exec() {
+                errors.push(format!("Plugin {}: {}", name, e));
+            }
+        }
+
+        let registry = std::mem::take(&mut *registry.lock());
+        Ok((
+            Self {
+                lua,
+                calls: Mutex::new(()),
+                budget,
+                parsers: registry.parsers,
+                extractors: registry.extractors,
+                commands: registry.commands,
+            },
+            errors,
+        ))
+    }
+
+    /// Start a call with `budget` checks to run for
+    fn begin_call(&self, budget: u32) -> MutexGuard<'_, ()> {
+        let call = self.calls.lock();
+        self.budget.store(budget, Ordering::Relaxed);
+        call
+    }
+
+    /// Fields for a line, from the first parser that claims it
+    pub fn parse(&self, text: &str) -> Option<HashMap<String, Value>> {
+        self.parsers.iter().find_map(|(name, parser)| {
+            let _call = self.begin_call(LINE_BUDGET);
+            let result = parser
+                .call::<mlua::Value>(text)
+                .and_then(|value| self.lua.from_value::<Option<Value>>(value));
+            match result {
+                Ok(Some(Value::Object(fields))) => Some(fields.into_iter().collect()),
+                Ok(_) => None,
+                Err(e) => {
+                    tracing::debug!(parser = %name, error = %e, "plugin parser failed");
+                    None
+                }
+            }
+        })
+    }
+
+    /// Run the extractors over a line, adding the fields they find
+    pub fn extract(&self, text: &str, fields: &mut Option<HashMap<String, Value>>) {
+        for (name, extractor) in &self.extractors {
+            let _call = self.begin_call(LINE_BUDGET);
+            let result = self
+                .lua
+                .to_value(&*fields)
+                .and_then(|current| extractor.call::<mlua::Value>((text, current)))
+                .and_then(|value| self.lua.from_value::<Option<Value>>(value));
+            match result {
+                Ok(Some(value)) => {
+                    fields.get_or_insert_default().insert(name.clone(), value);
+                }
+                Ok(None) => {}
+                Err(e) => tracing::debug!(extractor = %name, error = %e, "plugin extractor failed"),
+            }
+        }
+    }
+
+    pub fn commands(&self) -> &[PluginCommand] {
+        &self.commands
+    }
+
+    /// Run a plugin command, returning the message it produced
+    pub fn run_command(&self, index: usize, ctx: &CommandContext) -> Result<Option<String>> {
+        let command = self
+            .commands
+            .get(index)
+            .ok_or_else(|| anyhow::anyhow!("No plugin command {}", index))?;
+        let _call = self.begin_call(COMMAND_BUDGET);
+        let ctx = self.lua.to_value(ctx)?;
+        Ok(command.function.call::<Option<String>>(ctx)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PLUGIN: &str = r#"
+        kubescope.parser("access", function(text)
+            local method, path, status = text:match("^(%u+) (%S+) (%d+)$")
+            if method then
+                return { method = method, path = path, status = tonumber(status), level = "info" }
+            end
+        end)
+        kubescope.extractor("tenant", function(text, fields)
+            return text:match("tenant=(%w+)")
+        end)
+        kubescope.command("Count 5xx", "Server errors on screen", function(ctx)
+            local n = 0
+            for _, line in ipairs(ctx.lines) do
+                if line.fields and line.fields.status and line.fields.status >= 500 then
+                    n = n + 1
+                end
+            end
+            return n .. " server errors in " .. ctx.deployment
+        end)
+    "#;
+
+    #[test]
+    fn test_plugins_parse_extract_and_run_commands() {
+        let sources = [
+            ("access.lua".to_string(), PLUGIN.to_string()),
+            ("broken.lua".to_string(), "kubescope.parser(".to_string()),
+        ];
+        let (plugins, errors) = Plugins::load(&sources).unwrap();
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].starts_with("Plugin broken.lua"));
+
+        let fields = plugins.parse("GET /health 503").unwrap();
+        assert_eq!(fields["method"], "GET");
+        assert_eq!(fields["status"], 503);
+        assert!(plugins.parse("not an access line").is_none());
+
+        let mut extracted = None;
+        plugins.extract("user login tenant=acme", &mut extracted);
+        assert_eq!(extracted.unwrap()["tenant"], "acme");
+
+        let mut entry = LogEntry::new("api-1".into(), 1, "GET /health 503".into());
+        entry.fields = Some(fields);
+        let ctx = CommandContext {
+            context: Some("prod"),
+            namespace: Some("payments"),
+            deployment: Some("api"),
+            lines: vec![CommandLine::new(&entry)],
+        };
+        assert_eq!(plugins.commands()[0].name, "Count 5xx");
+        assert_eq!(
+            plugins.run_command(0, &ctx).unwrap().as_deref(),
+            Some("1 server errors in api")
+        );
+    }
+
+    #[test]
+    fn test_runaway_parser_is_stopped() {
+        let sources = [(
+            "spin.lua".to_string(),
+            r#"
+                kubescope.parser("spin", function(text)
+                    if text == "spin" then
+                        while true do end
+                    end
+                    return { text = text }
+                end)
+            "#
+            .to_string(),
+        )];
+        let (plugins, errors) = Plugins::load(&sources).unwrap();
+        assert!(errors.is_empty());
+        assert!(plugins.parse("spin").is_none());
+        // The next call gets a fresh budget
+        assert_eq!(plugins.parse("ok").unwrap()["text"], "ok");
+    }
+}
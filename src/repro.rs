@@ -0,0 +1,114 @@
+//! The command line that reopens the current view, for pasting to a teammate
+//!
+//! Covers what has a flag: the target, the filter and its toggles, the time
+//! range or `--since`/`--until` window, the pod sample, old ReplicaSets and
+//! the tail size. View-only state (muted pods, level threshold, JSON keys)
+//! has no flag and is left out.
+
+use crate::app::AppState;
+use crate::types::TimeRange;
+
+/// `kubescope CONTEXT NAMESPACE DEPLOYMENT [flags]`, or None with no deployment open
+pub fn repro_command(state: &AppState) -> Option<String> {
+    let ui = &state.ui_state;
+    let mut words = vec![
+        "kubescope".to_string(),
+        state.selected_context.clone()?,
+        state.selected_namespace.clone()?,
+        state.selected_deployment.clone()?,
+    ];
+    let mut flag = |name: &str, value: Option<String>| {
+        words.push(name.to_string());
+        words.extend(value);
+    };
+
+    if let Some(filter) = &ui.active_filter {
+        flag("-e", Some(filter.pattern().to_string()));
+        if ui.filter_case_insensitive {
+            flag("-i", None);
+        }
+        if filter.is_inverted() {
+            flag("-v", None);
+        }
+    }
+
+    let window = ui.time_window;
+    if window.is_set() {
+        let time = |t: chrono::DateTime<chrono::Utc>| t.format("%Y-%m-%dT%H:%M:%SZ").to_string();
+        if let Some(since) = window.since {
+            flag("--since", Some(time(since)));
+        }
+        if let Some(until) = window.until {
+            flag("--until", Some(time(until)));
+        }
+    } else if ui.time_range != TimeRange::All {
+        flag("--since", Some(ui.time_range.label().to_string()));
+    }
+
+    if let Some(sampling) = &state.sampling {
+        flag("--sample", Some(sampling.size.to_string()));
+        flag("--sample-mode", Some(sampling.mode.label().to_string()));
+    }
+    if state.skip_old_replicasets {
+        flag("--skip-old-replicasets", None);
+    }
+    if let Some(lines) = ui.tail_lines_override {
+        flag("--tail-lines", Some(lines.to_string()));
+    }
+
+    Some(
+        words
+            .iter()
+            .map(|word| shell_quote(word))
+            .collect::<Vec<_>>()
+            .join(" "),
+    )
+}
+
+/// Quote a word for POSIX shells when it needs it
+fn shell_quote(word: &str) -> String {
+    let plain = !word.is_empty()
+        && word
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "-_./:=@,+".contains(c));
+    if plain {
+        word.to_string()
+    } else {
+        format!("'{}'", word.replace('\'', r"'\''"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logs::CompiledFilter;
+    use tokio::sync::mpsc;
+
+    #[test]
+    fn test_repro_command_reproduces_the_view() {
+        let (tx, _rx) = mpsc::unbounded_channel();
+        let mut state = AppState::new(tx);
+        assert_eq!(repro_command(&state), None);
+
+        state.selected_context = Some("prod-eu".into());
+        state.selected_namespace = Some("payments".into());
+        state.selected_deployment = Some("api".into());
+        assert_eq!(
+            repro_command(&state).unwrap(),
+            "kubescope prod-eu payments api"
+        );
+
+        state.ui_state.active_filter = Some(
+            CompiledFilter::new_case_insensitive("can't connect|timeout")
+                .unwrap()
+                .inverted(),
+        );
+        state.ui_state.filter_case_insensitive = true;
+        state.ui_state.time_range = TimeRange::Last15m;
+        state.skip_old_replicasets = true;
+        assert_eq!(
+            repro_command(&state).unwrap(),
+            r#"kubescope prod-eu payments api -e 'can'\''t connect|timeout' -i -v --since 15m --skip-old-replicasets"#
+        );
+    }
+}
@@ -0,0 +1,418 @@
+//! Session persistence for `--resume`, recent targets and JSON key sets
+//!
+//! Saves the navigation state of the log viewer on exit so an accidental quit
+//! can be undone with `kubescope --resume`, keeps a short history of viewed
+//! deployments for the recent targets screen, and remembers pins, JSON key
+//! filter picks and line density per deployment along with named key sets.
+//! Namespace and deployment listings are cached briefly so familiar clusters
+//! open instantly.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::app::AppState;
+use crate::config::Density;
+use crate::types::{DeploymentInfo, NamespaceInfo, Pins, RecentTarget, TimeRange};
+
+/// Maximum number of recent targets to remember
+const MAX_RECENT_TARGETS: usize = 15;
+
+/// Cached listings older than this aren't shown
+const LISTING_TTL_SECS: i64 = 600;
+
+/// Directory kubescope keeps its own files in
+///
+/// `~/.kubescope` on Unix; on Windows, where dot directories in the profile
+/// are out of place, `%APPDATA%\kubescope`.
+pub fn kubescope_dir() -> Option<PathBuf> {
+    if cfg!(windows) {
+        Some(dirs::config_dir()?.join("kubescope"))
+    } else {
+        Some(dirs::home_dir()?.join(".kubescope"))
+    }
+}
+
+/// Directory for files that only make sense on this machine (credentials)
+///
+/// The same as `kubescope_dir` except on Windows, where it's
+/// `%LOCALAPPDATA%\kubescope` so it doesn't follow a roaming profile.
+pub fn kubescope_cache_dir() -> Option<PathBuf> {
+    if cfg!(windows) {
+        Some(dirs::cache_dir()?.join("kubescope"))
+    } else {
+        kubescope_dir()
+    }
+}
+
+/// Path of a file in the kubescope directory
+pub fn kubescope_path(file: &str) -> Option<PathBuf> {
+    Some(kubescope_dir()?.join(file))
+}
+
+/// Write JSON to a file in the kubescope directory
+fn write_json<T: Serialize>(file: &str, value: &T) -> io::Result<()> {
+    let path = kubescope_path(file)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no home directory"))?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    write_atomic(&path, serde_json::to_string_pretty(value)?.as_bytes())
+}
+
+/// Replace a file's contents all at once
+///
+/// Written to a temporary file in the same directory and renamed over the
+/// old one, so a crash or a second kubescope exiting at the same moment
+/// leaves the old contents or the new ones, never half of either.
+fn write_atomic(path: &Path, content: &[u8]) -> io::Result<()> {
+    let name = path.file_name().unwrap_or_default().to_string_lossy();
+    let tmp = path.with_file_name(format!(".{}.{}.tmp", name, std::process::id()));
+    let result = fs::write(&tmp, content).and_then(|()| fs::rename(&tmp, path));
+    if result.is_err() {
+        let _ = fs::remove_file(&tmp);
+    }
+    result
+}
+
+/// Write JSON to a file in the kubescope directory, ignoring failures
+fn save_json<T: Serialize>(file: &str, value: &T) {
+    let _ = write_json(file, value);
+}
+
+/// Read JSON from a file in the kubescope directory
+fn load_json<T: for<'de> Deserialize<'de>>(file: &str) -> Option<T> {
+    kubescope_path(file)
+        .and_then(|path| fs::read_to_string(path).ok())
+        .and_then(|content| serde_json::from_str(&content).ok())
+}
+
+/// Last viewer session stored on disk
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Session {
+    pub context: String,
+    pub namespace: String,
+    pub deployment: String,
+    #[serde(default)]
+    pub filter: Option<String>,
+    #[serde(default)]
+    pub ignore_case: bool,
+    #[serde(default)]
+    pub invert_match: bool,
+    #[serde(default)]
+    pub time_range: TimeRange,
+}
+
+impl Session {
+    /// Capture the session from app state, if a deployment is selected
+    pub fn from_state(state: &AppState) -> Option<Self> {
+        let filter = state.ui_state.active_filter.as_ref();
+        Some(Self {
+            context: state.selected_context.clone()?,
+            namespace: state.selected_namespace.clone()?,
+            deployment: state.selected_deployment.clone()?,
+            filter: filter
+                .filter(|f| f.has_pattern())
+                .map(|f| f.pattern().to_string()),
+            ignore_case: filter.is_some_and(|f| f.is_case_insensitive()),
+            invert_match: filter.is_some_and(|f| f.is_inverted()),
+            time_range: state.ui_state.time_range,
+        })
+    }
+
+    /// Load the last saved session
+    pub fn load() -> Option<Self> {
+        load_json("session.json")
+    }
+
+    /// Save the session to disk
+    pub fn save(&self) {
+        save_json("session.json", self);
+    }
+}
+
+/// Recently viewed targets, most recent first
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct RecentTargets {
+    pub targets: Vec<RecentTarget>,
+}
+
+impl RecentTargets {
+    /// Load recent targets from disk
+    pub fn load() -> Self {
+        load_json("recent.json").unwrap_or_default()
+    }
+
+    /// Save recent targets to disk
+    pub fn save(&self) {
+        save_json("recent.json", self);
+    }
+
+    /// Move a target to the front of the list, dropping the oldest beyond the limit
+    pub fn record(&mut self, target: RecentTarget) {
+        self.targets.retain(|t| *t != target);
+        self.targets.insert(0, target);
+        self.targets.truncate(MAX_RECENT_TARGETS);
+    }
+}
+
+/// Load the pins toggled with `p`
+pub fn load_pins() -> Pins {
+    load_json("pins.json").unwrap_or_default()
+}
+
+/// Save the pins toggled with `p`, next to the recent targets rather than in
+/// the project's `.kubescope`
+pub fn save_pins(pins: &Pins) -> io::Result<()> {
+    write_json("pins.json", pins)
+}
+
+/// JSON keys picked in the key filter
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeyPick {
+    pub keys: BTreeSet<String>,
+    /// The keys are hidden rather than the only ones shown
+    #[serde(default)]
+    pub exclude: bool,
+}
+
+/// JSON key filter selections that outlive a session
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct KeySets {
+    /// Last key selection per "context/namespace/deployment"
+    #[serde(default)]
+    pub deployments: BTreeMap<String, KeyPick>,
+    /// Key sets saved under a name from the palette
+    #[serde(default)]
+    pub named: BTreeMap<String, KeyPick>,
+}
+
+impl KeySets {
+    /// Load key sets from disk
+    pub fn load() -> Self {
+        load_json("key_sets.json").unwrap_or_default()
+    }
+
+    /// Save key sets to disk
+    pub fn save(&self) {
+        save_json("key_sets.json", self);
+    }
+
+    fn target_key(target: &RecentTarget) -> String {
+        format!(
+            "{}/{}/{}",
+            target.context, target.namespace, target.deployment
+        )
+    }
+
+    /// Keys last picked for a deployment
+    pub fn for_target(&self, target: &RecentTarget) -> Option<KeyPick> {
+        self.deployments.get(&Self::target_key(target)).cloned()
+    }
+
+    /// Remember the keys picked for a deployment (an empty pick forgets it)
+    pub fn remember(&mut self, target: &RecentTarget, pick: KeyPick) {
+        let key = Self::target_key(target);
+        if pick.keys.is_empty() {
+            self.deployments.remove(&key);
+        } else {
+            self.deployments.insert(key, pick);
+        }
+    }
+
+    /// Names of the saved key sets, for error messages
+    pub fn names(&self) -> String {
+        self.named.keys().cloned().collect::<Vec<_>>().join(", ")
+    }
+}
+
+/// Line density picked per "context/namespace/deployment"
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Densities {
+    #[serde(default)]
+    deployments: BTreeMap<String, Density>,
+}
+
+impl Densities {
+    pub fn load() -> Self {
+        load_json("density.json").unwrap_or_default()
+    }
+
+    pub fn save(&self) {
+        save_json("density.json", self);
+    }
+
+    pub fn for_target(&self, target: &RecentTarget) -> Option<Density> {
+        self.deployments.get(&KeySets::target_key(target)).copied()
+    }
+
+    /// Remember a deployment's density (normal, the default, forgets it)
+    pub fn remember(&mut self, target: &RecentTarget, density: Density) {
+        let key = KeySets::target_key(target);
+        if density == Density::Normal {
+            self.deployments.remove(&key);
+        } else {
+            self.deployments.insert(key, density);
+        }
+    }
+}
+
+/// A list as fetched from the cluster, and when
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Listing<T> {
+    fetched_at: DateTime<Utc>,
+    items: Vec<T>,
+}
+
+impl<T: Clone> Listing<T> {
+    fn new(items: Vec<T>) -> Self {
+        Self {
+            fetched_at: Utc::now(),
+            items,
+        }
+    }
+
+    fn is_fresh(&self, now: DateTime<Utc>) -> bool {
+        (now - self.fetched_at).num_seconds() < LISTING_TTL_SECS
+    }
+
+    fn fresh(&self) -> Option<Vec<T>> {
+        self.is_fresh(Utc::now()).then(|| self.items.clone())
+    }
+}
+
+/// Namespace and deployment lists per context, shown straight away on the
+/// next visit while the live ones load
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ListingCache {
+    #[serde(default)]
+    namespaces: BTreeMap<String, Listing<NamespaceInfo>>,
+    /// By "context/namespace"
+    #[serde(default)]
+    deployments: BTreeMap<String, Listing<DeploymentInfo>>,
+}
+
+impl ListingCache {
+    /// Load cached listings from disk
+    pub fn load() -> Self {
+        load_json("listings.json").unwrap_or_default()
+    }
+
+    /// Save cached listings to disk, dropping the stale ones
+    pub fn save(&mut self) {
+        let now = Utc::now();
+        self.namespaces.retain(|_, l| l.is_fresh(now));
+        self.deployments.retain(|_, l| l.is_fresh(now));
+        save_json("listings.json", self);
+    }
+
+    /// A context's namespaces, if fetched recently
+    pub fn namespaces(&self, context: &str) -> Option<Vec<NamespaceInfo>> {
+        self.namespaces.get(context)?.fresh()
+    }
+
+    /// A namespace's deployments, if fetched recently
+    pub fn deployments(&self, context: &str, namespace: &str) -> Option<Vec<DeploymentInfo>> {
+        self.deployments
+            .get(&format!("{}/{}", context, namespace))?
+            .fresh()
+    }
+
+    pub fn set_namespaces(&mut self, context: &str, namespaces: Vec<NamespaceInfo>) {
+        self.namespaces
+            .insert(context.to_string(), Listing::new(namespaces));
+    }
+
+    pub fn set_deployments(
+        &mut self,
+        context: &str,
+        namespace: &str,
+        deployments: Vec<DeploymentInfo>,
+    ) {
+        self.deployments.insert(
+            format!("{}/{}", context, namespace),
+            Listing::new(deployments),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn target(deployment: &str) -> RecentTarget {
+        RecentTarget {
+            context: "ctx".to_string(),
+            namespace: "ns".to_string(),
+            deployment: deployment.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_write_atomic_replaces_and_cleans_up() {
+        let dir = std::env::temp_dir().join(format!("kubescope-atomic-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("recent.json");
+        write_atomic(&path, b"old").unwrap();
+        write_atomic(&path, b"new").unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "new");
+        // Only the file itself is left behind
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 1);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_listing_cache_expires() {
+        let mut cache = ListingCache::default();
+        cache.set_namespaces(
+            "prod",
+            vec![NamespaceInfo::new("payments".into(), "Active".into())],
+        );
+        cache.set_deployments("prod", "payments", Vec::new());
+        assert_eq!(cache.namespaces("prod").map(|n| n.len()), Some(1));
+        assert!(cache.deployments("prod", "payments").is_some());
+        assert!(cache.namespaces("staging").is_none());
+
+        cache.namespaces.get_mut("prod").unwrap().fetched_at -=
+            chrono::Duration::seconds(LISTING_TTL_SECS);
+        assert!(cache.namespaces("prod").is_none());
+    }
+
+    #[test]
+    fn test_key_sets_remember_per_target() {
+        let mut sets = KeySets::default();
+        let pick = KeyPick {
+            keys: ["msg".to_string(), "status".to_string()].into(),
+            exclude: true,
+        };
+        sets.remember(&target("api"), pick.clone());
+        assert_eq!(sets.for_target(&target("api")), Some(pick));
+        assert_eq!(sets.for_target(&target("worker")), None);
+
+        sets.remember(&target("api"), KeyPick::default());
+        assert_eq!(sets.for_target(&target("api")), None);
+    }
+
+    #[test]
+    fn test_record_moves_to_front_and_caps() {
+        let mut recent = RecentTargets::default();
+        for i in 0..MAX_RECENT_TARGETS + 2 {
+            recent.record(target(&format!("deploy-{}", i)));
+        }
+        assert_eq!(recent.targets.len(), MAX_RECENT_TARGETS);
+
+        recent.record(target("deploy-5"));
+        assert_eq!(recent.targets[0], target("deploy-5"));
+        assert_eq!(
+            recent
+                .targets
+                .iter()
+                .filter(|t| **t == target("deploy-5"))
+                .count(),
+            1
+        );
+    }
+}
@@ -0,0 +1,181 @@
+//! First-run checks for a machine with no usable kubeconfig
+//!
+//! When the kubeconfig is missing, broken or has no contexts, the connecting
+//! screen shows what was checked (the files kubectl would read and the
+//! credential plugins around) and what to do next, instead of a bare error.
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use kube::config::Kubeconfig;
+
+/// Credential plugins cloud CLIs set up, looked for when there's no
+/// kubeconfig saying which one is used
+const KNOWN_PLUGINS: [&str; 6] = [
+    "aws",
+    "gke-gcloud-auth-plugin",
+    "kubelogin",
+    "az",
+    "doctl",
+    "oc",
+];
+
+/// What was checked, and the steps that would get kubescope connected
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetupReport {
+    /// What's missing, in a sentence
+    pub problem: String,
+    /// Kubeconfig files looked for, and whether each exists
+    pub paths: Vec<(PathBuf, bool)>,
+    /// Credential plugins, and whether each is on the PATH
+    pub plugins: Vec<(String, bool)>,
+    pub steps: Vec<String>,
+}
+
+impl SetupReport {
+    /// Check the kubeconfig kubectl would use; `None` if it has contexts
+    pub fn check() -> Option<Self> {
+        let paths = kubeconfig_paths()
+            .into_iter()
+            .map(|path| {
+                let exists = path.is_file();
+                (path, exists)
+            })
+            .collect();
+        let kubeconfig = Kubeconfig::read().map_err(|e| e.to_string());
+        Self::build(paths, kubeconfig.as_ref(), on_path)
+    }
+
+    fn build(
+        paths: Vec<(PathBuf, bool)>,
+        kubeconfig: Result<&Kubeconfig, &String>,
+        installed: impl Fn(&str) -> bool,
+    ) -> Option<Self> {
+        let mut steps = Vec::new();
+        let problem = match kubeconfig {
+            Ok(kubeconfig) if !kubeconfig.contexts.is_empty() => return None,
+            Ok(_) => {
+                steps.push(
+                    "Add a context with your cluster's tool, e.g. `aws eks update-kubeconfig \
+                     --name <cluster>`, `gcloud container clusters get-credentials <cluster>` \
+                     or `az aks get-credentials -g <group> -n <cluster>`"
+                        .to_string(),
+                );
+                "The kubeconfig has no contexts".to_string()
+            }
+            Err(error) if paths.iter().any(|(_, exists)| *exists) => {
+                steps.push("Run `kubectl config view` to see where the file is broken".to_string());
+                format!("The kubeconfig couldn't be read: {}", error)
+            }
+            Err(_) => {
+                steps.push(
+                    "Create one with your cluster's tool, e.g. `aws eks update-kubeconfig \
+                     --name <cluster>`, `gcloud container clusters get-credentials <cluster>` \
+                     or `kind create cluster` for a local cluster"
+                        .to_string(),
+                );
+                steps.push("Or point KUBECONFIG at an existing file".to_string());
+                "No kubeconfig found".to_string()
+            }
+        };
+
+        // The plugins the kubeconfig's users run, or else the usual ones
+        let mut wanted: Vec<String> = kubeconfig
+            .map(|kubeconfig| {
+                kubeconfig
+                    .auth_infos
+                    .iter()
+                    .filter_map(|named| named.auth_info.as_ref()?.exec.as_ref()?.command.clone())
+                    .collect()
+            })
+            .unwrap_or_default();
+        let from_kubeconfig = !wanted.is_empty();
+        if !from_kubeconfig {
+            wanted = KNOWN_PLUGINS.iter().map(|name| name.to_string()).collect();
+        }
+        wanted.sort();
+        wanted.dedup();
+        let plugins: Vec<(String, bool)> = wanted
+            .into_iter()
+            .map(|name| {
+                let found = installed(&name);
+                (name, found)
+            })
+            .filter(|(_, found)| from_kubeconfig || *found)
+            .collect();
+        for (name, _) in plugins.iter().filter(|(_, found)| !found) {
+            steps.push(format!(
+                "Install `{}`, which the kubeconfig runs for credentials",
+                name
+            ));
+        }
+        steps.push("Then restart kubescope".to_string());
+
+        Some(Self {
+            problem,
+            paths,
+            plugins,
+            steps,
+        })
+    }
+}
+
+impl fmt::Display for SetupReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.problem)
+    }
+}
+
+impl std::error::Error for SetupReport {}
+
+/// The files kubectl reads: KUBECONFIG's list, or `~/.kube/config`
+fn kubeconfig_paths() -> Vec<PathBuf> {
+    let from_env: Vec<PathBuf> = std::env::var_os("KUBECONFIG")
+        .map(|value| {
+            std::env::split_paths(&value)
+                .filter(|path| !path.as_os_str().is_empty())
+                .collect()
+        })
+        .unwrap_or_default();
+    if !from_env.is_empty() {
+        return from_env;
+    }
+    dirs::home_dir()
+        .map(|home| vec![home.join(".kube").join("config")])
+        .unwrap_or_default()
+}
+
+/// Whether a command runs: a path that exists, or a name on the PATH
+fn on_path(command: &str) -> bool {
+    if command.contains(std::path::MAIN_SEPARATOR) {
+        return Path::new(command).is_file();
+    }
+    std::env::var_os("PATH")
+        .is_some_and(|path| std::env::split_paths(&path).any(|dir| dir.join(command).is_file()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_setup_report_explains_what_is_missing() {
+        let path = || vec![(PathBuf::from("/home/ada/.kube/config"), false)];
+        let missing =
+            SetupReport::build(path(), Err(&"no file".to_string()), |name| name == "aws").unwrap();
+        assert_eq!(missing.problem, "No kubeconfig found");
+        assert_eq!(missing.plugins, vec![("aws".to_string(), true)]);
+        assert!(missing.steps.iter().any(|s| s.contains("KUBECONFIG")));
+
+        let users = "users:\n- name: eks\n  user:\n    exec:\n      apiVersion: client.authentication.k8s.io/v1beta1\n      command: aws\n";
+        let kubeconfig = Kubeconfig::from_yaml(users).unwrap();
+        let empty = SetupReport::build(path(), Ok(&kubeconfig), |_| false).unwrap();
+        assert_eq!(empty.problem, "The kubeconfig has no contexts");
+        assert_eq!(empty.plugins, vec![("aws".to_string(), false)]);
+        assert!(empty.steps.iter().any(|s| s.starts_with("Install `aws`")));
+
+        let contexts = format!("{}contexts:\n- name: prod\n", users);
+        let kubeconfig = Kubeconfig::from_yaml(&contexts).unwrap();
+        assert!(SetupReport::build(path(), Ok(&kubeconfig), |_| true).is_none());
+    }
+}
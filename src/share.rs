@@ -0,0 +1,265 @@
+//! Log buffer sharing over HTTP for `kubescope serve`
+//!
+//! `GET /logs` returns the buffered lines as newline-delimited JSON, oldest
+//! first, so a teammate can `curl` the slice being looked at. Lines can be
+//! narrowed with query parameters:
+//!
+//! - `q`: regex on the line (`i=1` ignores case, `v=1` inverts)
+//! - `pod`: only these pods (repeatable)
+//! - `level`: minimum level, e.g. `warn`
+//! - `since`: RFC 3339 time or a duration back from now (`90s`, `15m`, `2h`, `1d`)
+//! - `limit`: only the newest N lines
+//! - `view=1`: apply the filters the TUI shows right now (filter bar, level
+//!   threshold, muted pods, JSON key and node filters)
+
+use std::collections::HashSet;
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use parking_lot::RwLock;
+use serde_json::{Map, Value, json};
+
+use crate::app::AppState;
+use crate::http::{self, Request, Response};
+use crate::logs::{CompiledFilter, FilterPipeline, LogBuffer};
+use crate::types::{ArcLogEntry, LogEntry, LogLevel, parse_time_point};
+
+/// Copy of the TUI's view filters, for `view=1`
+#[derive(Clone)]
+pub struct ViewFilters {
+    text: Option<CompiledFilter>,
+    json_keys: HashSet<String>,
+    muted_pods: HashSet<String>,
+    node: Option<String>,
+    min_level: LogLevel,
+}
+
+impl Default for ViewFilters {
+    fn default() -> Self {
+        Self {
+            text: None,
+            json_keys: HashSet::new(),
+            muted_pods: HashSet::new(),
+            node: None,
+            min_level: LogLevel::Trace,
+        }
+    }
+}
+
+impl ViewFilters {
+    pub fn from_state(state: &AppState) -> Self {
+        Self {
+            text: state.ui_state.active_filter.clone(),
+            json_keys: state.json_filter_keys().clone(),
+            muted_pods: state.ui_state.muted_pods.clone(),
+            node: state.ui_state.node_filter.clone(),
+            min_level: state.ui_state.min_level,
+        }
+    }
+
+    fn pipeline(&self) -> FilterPipeline<'_> {
+        FilterPipeline {
+            text: self.text.as_ref(),
+            json_keys: &self.json_keys,
+            muted_pods: &self.muted_pods,
+            node: self.node.as_deref(),
+            min_level: self.min_level,
+        }
+    }
+}
+
+/// View filters kept up to date by the TUI
+pub type SharedView = Arc<RwLock<ViewFilters>>;
+
+/// Bind `addr`, then serve `buffer` on `/logs` in the background
+pub async fn serve(addr: SocketAddr, buffer: LogBuffer, view: SharedView) -> Result<()> {
+    http::serve(addr, move |request| match request.path.as_str() {
+        "/logs" => match LogQuery::parse(request, Utc::now()) {
+            Ok(query) => {
+                let body = query.render(&buffer, &view.read());
+                Response::ok("application/x-ndjson", body)
+            }
+            Err(message) => Response::bad_request(message),
+        },
+        _ => Response::not_found(),
+    })
+    .await
+}
+
+/// The narrowing asked for in a request's query
+#[derive(Default)]
+struct LogQuery {
+    filter: Option<CompiledFilter>,
+    pods: HashSet<String>,
+    min_level: Option<LogLevel>,
+    since: Option<DateTime<Utc>>,
+    limit: Option<usize>,
+    view: bool,
+}
+
+impl LogQuery {
+    fn parse(request: &Request, now: DateTime<Utc>) -> Result<Self, String> {
+        let flag = |key| request.param(key).is_some_and(|v| v == "1" || v == "true");
+        let filter = match request.param("q").filter(|q| !q.is_empty()) {
+            Some(pattern) => {
+                let filter = if flag("i") {
+                    CompiledFilter::new_case_insensitive(pattern)
+                } else {
+                    CompiledFilter::new(pattern)
+                }
+                .map_err(|e| format!("Invalid q pattern '{}': {}", pattern, e))?;
+                Some(if flag("v") { filter.inverted() } else { filter })
+            }
+            None => None,
+        };
+        let min_level = match request.param("level") {
+            Some(level) => match LogLevel::from_str(level) {
+                LogLevel::Unknown => return Err(format!("Unknown level '{}'", level)),
+                level => Some(level),
+            },
+            None => None,
+        };
+        let since = request
+            .param("since")
+            .map(|since| {
+                parse_time_point(since, now)
+                    .map_err(|_| format!("Invalid since '{}' (use RFC 3339 or e.g. 15m)", since))
+            })
+            .transpose()?;
+        let limit = request
+            .param("limit")
+            .map(|limit| {
+                limit
+                    .parse()
+                    .map_err(|_| format!("Invalid limit '{}'", limit))
+            })
+            .transpose()?;
+        Ok(Self {
+            filter,
+            pods: request.params("pod").map(str::to_string).collect(),
+            min_level,
+            since,
+            limit,
+            view: flag("view"),
+        })
+    }
+
+    fn matches(&self, entry: &LogEntry, view: &ViewFilters) -> bool {
+        (self.pods.is_empty() || self.pods.contains(entry.pod_name()))
+            && self
+                .since
+                .is_none_or(|since| entry.timestamp.is_some_and(|ts| ts >= since))
+            && self.filter.as_ref().is_none_or(|f| f.matches(entry))
+            && (!self.view || view.pipeline().matches(entry))
+    }
+
+    /// The matching lines as ndjson
+    fn render(&self, buffer: &LogBuffer, view: &ViewFilters) -> String {
+        let entries: Vec<ArcLogEntry> = match self.min_level {
+            Some(level) => buffer.by_level(level),
+            None => buffer.all(),
+        };
+        let entries: Vec<&ArcLogEntry> = entries.iter().filter(|e| self.matches(e, view)).collect();
+        let skip = self
+            .limit
+            .map_or(0, |limit| entries.len().saturating_sub(limit));
+
+        let mut out = String::new();
+        for entry in &entries[skip..] {
+            out.push_str(&entry_json(entry).to_string());
+            out.push('\n');
+        }
+        out
+    }
+}
+
+fn entry_json(entry: &LogEntry) -> Value {
+    let mut line = Map::new();
+    line.insert("id".into(), json!(entry.id));
+    line.insert("timestamp".into(), json!(entry.timestamp));
+    line.insert("pod".into(), json!(entry.pod_name()));
+    line.insert("container".into(), json!(entry.source.container));
+    line.insert("node".into(), json!(entry.node_name));
+    line.insert("level".into(), json!(entry.level.name()));
+    line.insert("line".into(), json!(entry.display_text()));
+    if let Some(fields) = &entry.fields {
+        line.insert("fields".into(), json!(fields));
+    }
+    Value::Object(line)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logs::LogParser;
+
+    fn request(query: &str) -> Request {
+        Request {
+            path: "/logs".into(),
+            query: query
+                .split('&')
+                .filter(|pair| !pair.is_empty())
+                .map(|pair| {
+                    let (k, v) = pair.split_once('=').unwrap_or((pair, ""));
+                    (k.to_string(), v.to_string())
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_log_query_narrows_the_buffer() {
+        let buffer = LogBuffer::new(100);
+        for (pod, line) in [
+            ("api-1", "2024-01-15T10:00:00Z INFO started"),
+            ("api-2", "2024-01-15T10:01:00Z ERROR timeout calling db"),
+            ("api-1", "2024-01-15T10:02:00Z WARN slow timeout"),
+            ("api-1", "2024-01-15T10:03:00Z ERROR Timeout again"),
+        ] {
+            buffer.push(LogParser::parse(line, pod, 1));
+        }
+        buffer.flush();
+        let now = "2024-01-15T10:05:00Z".parse().unwrap();
+        let view = ViewFilters::default();
+        let lines = |query: &str| -> Vec<Value> {
+            LogQuery::parse(&request(query), now)
+                .unwrap()
+                .render(&buffer, &view)
+                .lines()
+                .map(|l| serde_json::from_str(l).unwrap())
+                .collect()
+        };
+
+        assert_eq!(lines("").len(), 4);
+        assert_eq!(lines("q=timeout&i=1&pod=api-1").len(), 2);
+        assert_eq!(
+            lines("level=warn&limit=1")[0]["line"],
+            "ERROR Timeout again"
+        );
+        let recent = lines("since=3m");
+        assert_eq!(recent.len(), 2);
+        assert_eq!(recent[0]["level"], "warn");
+
+        // The TUI's own filters, muted pods included
+        let view = ViewFilters {
+            muted_pods: HashSet::from(["api-2".to_string()]),
+            ..ViewFilters::default()
+        };
+        let query = LogQuery::parse(&request("view=1&level=error"), now).unwrap();
+        assert_eq!(query.render(&buffer, &view).lines().count(), 1);
+
+        // Lines still in the pending batch show up on both paths
+        buffer.push(LogParser::parse(
+            "2024-01-15T10:04:00Z ERROR pending",
+            "api-1",
+            1,
+        ));
+        assert_eq!(lines("level=error&limit=1")[0]["line"], "ERROR pending");
+        assert_eq!(lines("").len(), 5);
+
+        assert!(LogQuery::parse(&request("level=loud"), now).is_err());
+        assert!(LogQuery::parse(&request("since=soon"), now).is_err());
+    }
+}
@@ -0,0 +1,25 @@
+//! Running user-supplied command lines through the platform shell
+//!
+//! Hooks, the external picker and `$EDITOR`/`$PAGER` are all configured as a
+//! command line rather than a program and its arguments, so `EDITOR="code -w"`
+//! and pipes in a picker command work as they would at a prompt.
+
+use std::process::Command;
+
+/// A command that runs `command` with `sh -c` (`cmd /C` on Windows)
+///
+/// Builds either a std or a tokio `Command`; more arguments and the stdio
+/// setup are added by the caller.
+pub fn shell_command<C: From<Command>>(command: &str) -> C {
+    let mut shell = if cfg!(windows) {
+        let mut shell = Command::new("cmd");
+        shell.arg("/C");
+        shell
+    } else {
+        let mut shell = Command::new("sh");
+        shell.arg("-c");
+        shell
+    };
+    shell.arg(command);
+    C::from(shell)
+}
@@ -41,9 +41,7 @@ pub struct TokenCache {
 impl TokenCache {
     /// Get the cache file path
     fn cache_path() -> Option<PathBuf> {
-        let home = dirs::home_dir()?;
-        let cache_dir = home.join(".kubescope");
-        Some(cache_dir.join("token-cache.json"))
+        Some(crate::session::kubescope_cache_dir()?.join("token-cache.json"))
     }
 
     /// Load the token cache from disk
@@ -2,7 +2,7 @@ use std::time::Duration;
 
 use crossterm::event::{self, Event as CrosstermEvent, KeyEvent, KeyEventKind};
 use futures::{FutureExt, StreamExt};
-use tokio::sync::mpsc;
+use tokio::sync::{mpsc, watch};
 use tokio_util::sync::CancellationToken;
 
 /// Terminal events
@@ -23,10 +23,13 @@ pub enum Event {
 pub struct EventHandler {
     /// Event receiver
     receiver: mpsc::UnboundedReceiver<Event>,
+    /// Sender the input task forwards events to
+    sender: mpsc::UnboundedSender<Event>,
     /// Cancellation token for graceful shutdown
     cancel: CancellationToken,
+    /// Current tick interval (the task picks up changes)
+    tick_rate: watch::Sender<Duration>,
     /// Task handle
-    #[allow(dead_code)]
     task: tokio::task::JoinHandle<()>,
 }
 
@@ -35,60 +38,43 @@ impl EventHandler {
     pub fn new(tick_rate: Duration) -> Self {
         let (sender, receiver) = mpsc::unbounded_channel();
         let cancel = CancellationToken::new();
-
-        let task = {
-            let sender = sender.clone();
-            let cancel = cancel.clone();
-
-            tokio::spawn(async move {
-                let mut reader = event::EventStream::new();
-                let mut tick_interval = tokio::time::interval(tick_rate);
-
-                loop {
-                    let tick = tick_interval.tick();
-                    let crossterm_event = reader.next().fuse();
-
-                    tokio::select! {
-                        _ = cancel.cancelled() => break,
-
-                        _ = tick => {
-                            let _ = sender.send(Event::Tick);
-                        }
-
-                        maybe_event = crossterm_event => {
-                            match maybe_event {
-                                Some(Ok(evt)) => {
-                                    match evt {
-                                        CrosstermEvent::Key(key) => {
-                                            // Filter out release events (important for Windows)
-                                            if key.kind == KeyEventKind::Press {
-                                                let _ = sender.send(Event::Key(key));
-                                            }
-                                        }
-                                        CrosstermEvent::Resize(w, h) => {
-                                            let _ = sender.send(Event::Resize(w, h));
-                                        }
-                                        _ => {}
-                                    }
-                                }
-                                Some(Err(e)) => {
-                                    let _ = sender.send(Event::Error(e.to_string()));
-                                }
-                                None => break,
-                            }
-                        }
-                    }
-                }
-            })
-        };
+        let (tick_rate, _) = watch::channel(tick_rate);
+        let task = spawn_input_task(sender.clone(), cancel.clone(), tick_rate.subscribe());
 
         Self {
             receiver,
+            sender,
             cancel,
+            tick_rate,
             task,
         }
     }
 
+    /// Stop reading the terminal, so another program can have it
+    pub async fn suspend(&mut self) {
+        self.cancel.cancel();
+        let _ = (&mut self.task).await;
+    }
+
+    /// Read the terminal again after [`suspend`](Self::suspend)
+    pub fn resume(&mut self) {
+        self.cancel = CancellationToken::new();
+        self.task = spawn_input_task(
+            self.sender.clone(),
+            self.cancel.clone(),
+            self.tick_rate.subscribe(),
+        );
+    }
+
+    /// Change the tick interval (no-op if it is already `rate`)
+    pub fn set_tick_rate(&self, rate: Duration) {
+        self.tick_rate.send_if_modified(|current| {
+            let changed = *current != rate;
+            *current = rate;
+            changed
+        });
+    }
+
     /// Receive the next event
     pub async fn next(&mut self) -> Option<Event> {
         self.receiver.recv().await
@@ -99,3 +85,58 @@ impl EventHandler {
         self.cancel.cancel();
     }
 }
+
+/// Forward terminal input and ticks to `sender` until `cancel` fires
+fn spawn_input_task(
+    sender: mpsc::UnboundedSender<Event>,
+    cancel: CancellationToken,
+    mut tick_rx: watch::Receiver<Duration>,
+) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut reader = event::EventStream::new();
+        let mut tick_interval = tokio::time::interval(*tick_rx.borrow_and_update());
+
+        loop {
+            let tick = tick_interval.tick();
+            let crossterm_event = reader.next().fuse();
+
+            tokio::select! {
+                _ = cancel.cancelled() => break,
+
+                _ = tick => {
+                    let _ = sender.send(Event::Tick);
+                }
+
+                Ok(()) = tick_rx.changed() => {
+                    // Next tick one new period from now, not immediately
+                    let rate = *tick_rx.borrow_and_update();
+                    tick_interval = tokio::time::interval_at(
+                        tokio::time::Instant::now() + rate,
+                        rate,
+                    );
+                }
+
+                maybe_event = crossterm_event => {
+                    match maybe_event {
+                        Some(Ok(evt)) => {
+                            match evt {
+                                // Filter out release events (important for Windows)
+                                CrosstermEvent::Key(key) if key.kind == KeyEventKind::Press => {
+                                    let _ = sender.send(Event::Key(key));
+                                }
+                                CrosstermEvent::Resize(w, h) => {
+                                    let _ = sender.send(Event::Resize(w, h));
+                                }
+                                _ => {}
+                            }
+                        }
+                        Some(Err(e)) => {
+                            let _ = sender.send(Event::Error(e.to_string()));
+                        }
+                        None => break,
+                    }
+                }
+            }
+        }
+    })
+}
@@ -2,6 +2,7 @@
 
 mod event;
 mod terminal;
+mod title;
 
 pub use event::{Event, EventHandler};
 pub use terminal::Tui;
@@ -1,14 +1,21 @@
-use std::io::{self, Stdout, stdout};
+use std::io::{self, Stdout, Write, stdout};
 
 use crossterm::{
-    execute,
+    cursor::MoveTo,
+    execute, queue,
+    style::{
+        Attribute, Color, Print, ResetColor, SetAttribute, SetBackgroundColor, SetForegroundColor,
+    },
     terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
 };
-use ratatui::{Terminal, backend::CrosstermBackend};
+use ratatui::{Terminal, backend::CrosstermBackend, buffer::Buffer, layout::Rect, style::Modifier};
+
+use super::title::WindowTitle;
 
 /// A wrapper around the terminal that handles setup and teardown
 pub struct Tui {
     terminal: Terminal<CrosstermBackend<Stdout>>,
+    title: WindowTitle,
 }
 
 impl Tui {
@@ -20,7 +27,21 @@ impl Tui {
         let backend = CrosstermBackend::new(stdout());
         let terminal = Terminal::new(backend)?;
 
-        Ok(Self { terminal })
+        Ok(Self {
+            terminal,
+            title: WindowTitle::default(),
+        })
+    }
+
+    /// Start setting the terminal title (and the tmux pane title, if `tmux`)
+    pub fn enable_title(&mut self, tmux: bool) -> io::Result<()> {
+        self.title = WindowTitle::new(self.terminal.backend_mut(), tmux)?;
+        Ok(())
+    }
+
+    /// Update the title, when titles are enabled
+    pub fn set_title(&mut self, title: &str) -> io::Result<()> {
+        self.title.set(self.terminal.backend_mut(), title)
     }
 
     /// Get a mutable reference to the terminal for drawing
@@ -28,8 +49,76 @@ impl Tui {
         &mut self.terminal
     }
 
+    /// Put text on the system clipboard with OSC 52 (terminals that don't support it ignore it)
+    pub fn copy_to_clipboard(&mut self, text: &str) -> io::Result<()> {
+        let out = self.terminal.backend_mut();
+        queue!(
+            out,
+            Print(format!("\x1b]52;c;{}\x07", base64(text.as_bytes())))
+        )?;
+        out.flush()
+    }
+
+    /// Print the linked areas of a drawn frame again, wrapped in OSC 8 hyperlinks
+    pub fn draw_hyperlinks(&mut self, buffer: &Buffer, links: &[(Rect, String)]) -> io::Result<()> {
+        let out = self.terminal.backend_mut();
+        for (area, url) in links {
+            let area = area.intersection(buffer.area);
+            for y in area.top()..area.bottom() {
+                queue!(
+                    out,
+                    MoveTo(area.x, y),
+                    Print(format!("\x1b]8;;{}\x1b\\", url))
+                )?;
+                for x in area.left()..area.right() {
+                    let cell = &buffer[(x, y)];
+                    if cell.skip {
+                        continue;
+                    }
+                    queue!(
+                        out,
+                        SetAttribute(Attribute::Reset),
+                        SetForegroundColor(Color::from(cell.fg)),
+                        SetBackgroundColor(Color::from(cell.bg)),
+                    )?;
+                    for (modifier, attribute) in [
+                        (Modifier::BOLD, Attribute::Bold),
+                        (Modifier::DIM, Attribute::Dim),
+                        (Modifier::ITALIC, Attribute::Italic),
+                        (Modifier::UNDERLINED, Attribute::Underlined),
+                        (Modifier::REVERSED, Attribute::Reverse),
+                        (Modifier::CROSSED_OUT, Attribute::CrossedOut),
+                    ] {
+                        if cell.modifier.contains(modifier) {
+                            queue!(out, SetAttribute(attribute))?;
+                        }
+                    }
+                    queue!(out, Print(cell.symbol()))?;
+                }
+                queue!(out, Print("\x1b]8;;\x1b\\"))?;
+            }
+        }
+        queue!(out, SetAttribute(Attribute::Reset), ResetColor)?;
+        out.flush()
+    }
+
+    /// Hand the terminal back to the shell while another program runs
+    pub fn suspend(&mut self) -> io::Result<()> {
+        disable_raw_mode()?;
+        execute!(self.terminal.backend_mut(), LeaveAlternateScreen)?;
+        self.terminal.show_cursor()
+    }
+
+    /// Take the terminal back after [`suspend`](Self::suspend), redrawing from scratch
+    pub fn resume(&mut self) -> io::Result<()> {
+        enable_raw_mode()?;
+        execute!(self.terminal.backend_mut(), EnterAlternateScreen)?;
+        self.terminal.clear()
+    }
+
     /// Restore the terminal to its original state
     pub fn restore(&mut self) -> io::Result<()> {
+        self.title.restore(self.terminal.backend_mut())?;
         disable_raw_mode()?;
         execute!(self.terminal.backend_mut(), LeaveAlternateScreen)?;
         self.terminal.show_cursor()?;
@@ -43,3 +132,23 @@ impl Drop for Tui {
         let _ = self.restore();
     }
 }
+
+/// Standard base64 with padding, as OSC 52 expects
+fn base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let n = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |n, (i, &b)| n | (b as u32) << (16 - 8 * i));
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(ALPHABET[(n >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
@@ -0,0 +1,74 @@
+use std::io::{self, Write};
+use std::process::{Command, Stdio};
+
+use crossterm::{queue, terminal::SetTitle};
+
+/// Keeps the terminal (and optionally tmux pane) title in step with the view
+///
+/// The terminal's own title is pushed onto xterm's title stack when titles are
+/// turned on and popped again on restore; the tmux pane title is read up front
+/// and set back.
+#[derive(Default)]
+pub struct WindowTitle {
+    enabled: bool,
+    /// Pane title to put back, when tmux titles are on and we're inside tmux
+    tmux_original: Option<String>,
+    current: Option<String>,
+}
+
+impl WindowTitle {
+    pub fn new(out: &mut impl Write, tmux: bool) -> io::Result<Self> {
+        // Save the current title (XTWINOPS 22)
+        write!(out, "\x1b[22;0t")?;
+        let tmux_original = tmux
+            .then(|| std::env::var_os("TMUX"))
+            .flatten()
+            .and_then(|_| tmux_command(&["display-message", "-p", "#{pane_title}"]));
+        Ok(Self {
+            enabled: true,
+            tmux_original,
+            current: None,
+        })
+    }
+
+    /// Set the title, doing nothing if it hasn't changed
+    pub fn set(&mut self, out: &mut impl Write, title: &str) -> io::Result<()> {
+        if !self.enabled || self.current.as_deref() == Some(title) {
+            return Ok(());
+        }
+        queue!(out, SetTitle(title))?;
+        if self.tmux_original.is_some() {
+            tmux_command(&["select-pane", "-T", title]);
+        }
+        self.current = Some(title.to_string());
+        out.flush()
+    }
+
+    /// Put the titles back the way they were
+    pub fn restore(&mut self, out: &mut impl Write) -> io::Result<()> {
+        if !std::mem::take(&mut self.enabled) {
+            return Ok(());
+        }
+        if let Some(original) = self.tmux_original.take() {
+            tmux_command(&["select-pane", "-T", &original]);
+        }
+        // Restore the saved title (XTWINOPS 23)
+        write!(out, "\x1b[23;0t")?;
+        out.flush()
+    }
+}
+
+/// Run a tmux command, returning its trimmed output on success
+fn tmux_command(args: &[&str]) -> Option<String> {
+    let output = Command::new("tmux")
+        .args(args)
+        .stdin(Stdio::null())
+        .stderr(Stdio::null())
+        .output()
+        .ok()?;
+    output.status.success().then(|| {
+        String::from_utf8_lossy(&output.stdout)
+            .trim_end()
+            .to_string()
+    })
+}
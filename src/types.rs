@@ -1,8 +1,9 @@
 //! Shared types for kubescope
 
 use chrono::{DateTime, Utc};
-use ratatui::style::Color;
-use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::collections::{BTreeSet, HashMap};
 use std::sync::Arc;
 
 /// Type alias for reference-counted log entries to avoid expensive clones
@@ -42,12 +43,14 @@ impl ContextInfo {
 }
 
 /// Namespace information
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[allow(dead_code)]
 pub struct NamespaceInfo {
     pub name: String,
     pub status: String,
     pub labels: HashMap<String, String>,
+    #[serde(default)]
+    pub created_at: Option<DateTime<Utc>>,
 }
 
 impl NamespaceInfo {
@@ -56,12 +59,81 @@ impl NamespaceInfo {
             name,
             status,
             labels: HashMap::new(),
+            created_at: None,
         }
     }
+
+    pub fn is_terminating(&self) -> bool {
+        self.status == "Terminating"
+    }
+}
+
+/// One term of a label selector
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum LabelRequirement {
+    Equals(String, String),
+    NotEquals(String, String),
+    Exists(String),
+    NotExists(String),
+}
+
+/// Equality-based label selector, in kubectl's syntax ("team=payments,tier!=db,!legacy")
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LabelSelector {
+    text: String,
+    requirements: Vec<LabelRequirement>,
+}
+
+impl LabelSelector {
+    pub fn parse(input: &str) -> Result<Self, String> {
+        let mut requirements = Vec::new();
+        for term in input.split(',').map(str::trim) {
+            let requirement = if let Some((key, value)) = term.split_once("!=") {
+                LabelRequirement::NotEquals(key.trim().to_string(), value.trim().to_string())
+            } else if let Some((key, value)) =
+                term.split_once("==").or_else(|| term.split_once('='))
+            {
+                LabelRequirement::Equals(key.trim().to_string(), value.trim().to_string())
+            } else if let Some(key) = term.strip_prefix('!') {
+                LabelRequirement::NotExists(key.trim().to_string())
+            } else {
+                LabelRequirement::Exists(term.to_string())
+            };
+            let key = match &requirement {
+                LabelRequirement::Equals(key, _)
+                | LabelRequirement::NotEquals(key, _)
+                | LabelRequirement::Exists(key)
+                | LabelRequirement::NotExists(key) => key,
+            };
+            if key.is_empty() {
+                return Err(format!("Missing label key in \"{}\"", term));
+            }
+            requirements.push(requirement);
+        }
+        Ok(Self {
+            text: input.trim().to_string(),
+            requirements,
+        })
+    }
+
+    /// Whether a set of labels satisfies every term
+    pub fn matches(&self, labels: &HashMap<String, String>) -> bool {
+        self.requirements.iter().all(|req| match req {
+            LabelRequirement::Equals(key, value) => labels.get(key) == Some(value),
+            LabelRequirement::NotEquals(key, value) => labels.get(key) != Some(value),
+            LabelRequirement::Exists(key) => labels.contains_key(key),
+            LabelRequirement::NotExists(key) => !labels.contains_key(key),
+        })
+    }
+
+    /// The selector as typed
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
 }
 
 /// Deployment information
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[allow(dead_code)]
 pub struct DeploymentInfo {
     pub name: String,
@@ -71,6 +143,7 @@ pub struct DeploymentInfo {
     pub ready_replicas: i32,
     pub labels: HashMap<String, String>,
     pub selector: HashMap<String, String>,
+    pub created_at: Option<DateTime<Utc>>,
 }
 
 #[allow(dead_code)]
@@ -84,6 +157,7 @@ impl DeploymentInfo {
             ready_replicas: 0,
             labels: HashMap::new(),
             selector: HashMap::new(),
+            created_at: None,
         }
     }
 
@@ -91,6 +165,62 @@ impl DeploymentInfo {
     pub fn replica_status(&self) -> String {
         format!("{}/{}", self.ready_replicas, self.replicas)
     }
+
+    /// Fraction of desired replicas that are ready (scaled-to-zero counts as fully ready)
+    pub fn ready_ratio(&self) -> f64 {
+        if self.replicas <= 0 {
+            1.0
+        } else {
+            self.ready_replicas as f64 / self.replicas as f64
+        }
+    }
+}
+
+/// Sort order for the deployment list
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum DeploymentSort {
+    /// Alphabetical by name
+    #[default]
+    Name,
+    /// Least ready first, so unhealthy deployments surface at the top
+    ReadyRatio,
+    /// Most replicas first
+    Replicas,
+    /// Newest first
+    Age,
+}
+
+impl DeploymentSort {
+    /// Get display label for this sort order
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Name => "name",
+            Self::ReadyRatio => "ready",
+            Self::Replicas => "replicas",
+            Self::Age => "age",
+        }
+    }
+
+    /// Cycle to the next sort order
+    pub fn next(&self) -> Self {
+        match self {
+            Self::Name => Self::ReadyRatio,
+            Self::ReadyRatio => Self::Replicas,
+            Self::Replicas => Self::Age,
+            Self::Age => Self::Name,
+        }
+    }
+
+    /// Compare two deployments under this sort order, falling back to name
+    pub fn compare(&self, a: &DeploymentInfo, b: &DeploymentInfo) -> std::cmp::Ordering {
+        let primary = match self {
+            Self::Name => std::cmp::Ordering::Equal,
+            Self::ReadyRatio => a.ready_ratio().total_cmp(&b.ready_ratio()),
+            Self::Replicas => b.replicas.cmp(&a.replicas),
+            Self::Age => b.created_at.cmp(&a.created_at),
+        };
+        primary.then_with(|| a.name.cmp(&b.name))
+    }
 }
 
 /// Pod information
@@ -103,6 +233,15 @@ pub struct PodInfo {
     pub containers: Vec<ContainerInfo>,
     pub node_name: Option<String>,
     pub pod_ip: Option<String>,
+    pub created_at: Option<DateTime<Utc>>,
+    /// Context of the extra cluster the pod was listed from (None for the
+    /// selected context)
+    pub cluster: Option<String>,
+    /// ReplicaSet that owns the pod
+    pub replica_set: Option<String>,
+    /// Owned by one of the deployment's previous ReplicaSets (a rollout that
+    /// hasn't finished, or is stuck)
+    pub old_replica_set: bool,
 }
 
 impl PodInfo {
@@ -114,8 +253,21 @@ impl PodInfo {
             containers: Vec::new(),
             node_name: None,
             pod_ip: None,
+            created_at: None,
+            cluster: None,
+            replica_set: None,
+            old_replica_set: false,
         }
     }
+
+    /// The container whose log is read: the first not in `excluded` (mesh
+    /// sidecars), or the first if every one is
+    pub fn log_container(&self, excluded: &[String]) -> Option<&ContainerInfo> {
+        self.containers
+            .iter()
+            .find(|c| !excluded.contains(&c.name))
+            .or(self.containers.first())
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -144,6 +296,8 @@ pub struct ContainerInfo {
     pub name: String,
     pub ready: bool,
     pub restart_count: i32,
+    /// When the running container started (unset unless it is running)
+    pub started_at: Option<DateTime<Utc>>,
 }
 
 impl ContainerInfo {
@@ -152,16 +306,70 @@ impl ContainerInfo {
             name,
             ready: false,
             restart_count: 0,
+            started_at: None,
         }
     }
 }
 
+/// Pinned contexts, namespaces and deployments (matched by name)
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Pins {
+    #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+    pub contexts: BTreeSet<String>,
+    #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+    pub namespaces: BTreeSet<String>,
+    #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+    pub deployments: BTreeSet<String>,
+}
+
+impl Pins {
+    pub fn is_empty(&self) -> bool {
+        self.contexts.is_empty() && self.namespaces.is_empty() && self.deployments.is_empty()
+    }
+
+    /// Pins in either set
+    pub fn union(&self, other: &Pins) -> Pins {
+        Pins {
+            contexts: &self.contexts | &other.contexts,
+            namespaces: &self.namespaces | &other.namespaces,
+            deployments: &self.deployments | &other.deployments,
+        }
+    }
+
+    /// Pins in this set that aren't in the other
+    pub fn difference(&self, other: &Pins) -> Pins {
+        Pins {
+            contexts: &self.contexts - &other.contexts,
+            namespaces: &self.namespaces - &other.namespaces,
+            deployments: &self.deployments - &other.deployments,
+        }
+    }
+}
+
+/// A context/namespace/deployment triple that was viewed recently
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecentTarget {
+    pub context: String,
+    pub namespace: String,
+    pub deployment: String,
+}
+
+impl RecentTarget {
+    /// Display label for lists
+    pub fn label(&self) -> String {
+        format!(
+            "{} / {} / {}",
+            self.context, self.namespace, self.deployment
+        )
+    }
+}
+
 // ============================================================================
 // Log Types
 // ============================================================================
 
 /// Time range for log filtering
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum TimeRange {
     /// Show all available logs (uses tail_lines)
     #[default]
@@ -194,6 +402,15 @@ impl TimeRange {
         }
     }
 
+    /// Whether this range reaches further back than `other` (All is widest)
+    pub fn is_wider_than(&self, other: &TimeRange) -> bool {
+        match (self.as_seconds(), other.as_seconds()) {
+            (None, other) => other.is_some(),
+            (Some(_), None) => false,
+            (Some(this), Some(other)) => this > other,
+        }
+    }
+
     /// Get display label for this time range
     pub fn label(&self) -> &'static str {
         match self {
@@ -234,6 +451,74 @@ impl TimeRange {
     }
 }
 
+/// A fixed window from `--since`/`--until`, used instead of a TimeRange preset
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TimeWindow {
+    pub since: Option<DateTime<Utc>>,
+    pub until: Option<DateTime<Utc>>,
+}
+
+impl TimeWindow {
+    pub fn is_set(&self) -> bool {
+        self.since.is_some() || self.until.is_some()
+    }
+
+    /// Whether a line stamped `timestamp` is past the end of the window
+    ///
+    /// Lines without a timestamp are never past it.
+    pub fn is_after(&self, timestamp: Option<DateTime<Utc>>) -> bool {
+        matches!((self.until, timestamp), (Some(until), Some(ts)) if ts > until)
+    }
+
+    /// Display label in UTC or local time, e.g. "01-15 10:00→11:30"
+    pub fn label(&self, local: bool) -> String {
+        let naive = |time: DateTime<Utc>| {
+            if local {
+                time.with_timezone(&chrono::Local).naive_local()
+            } else {
+                time.naive_utc()
+            }
+        };
+        let since = self.since.map(naive);
+        let until = self.until.map(naive);
+        let format = |time: Option<chrono::NaiveDateTime>, open: &str| {
+            time.map_or(open.to_string(), |t| t.format("%m-%d %H:%M").to_string())
+        };
+        let until_label = match (since, until) {
+            // The same day needn't be repeated
+            (Some(since), Some(until)) if since.date() == until.date() => {
+                until.format("%H:%M").to_string()
+            }
+            _ => format(until, "now"),
+        };
+        format!("{}→{}", format(since, "…"), until_label)
+    }
+}
+
+/// An RFC 3339 time, or a duration like `15m` back from `now`
+pub fn parse_time_point(input: &str, now: DateTime<Utc>) -> Result<DateTime<Utc>, String> {
+    if let Ok(time) = DateTime::parse_from_rfc3339(input) {
+        return Ok(time.with_timezone(&Utc));
+    }
+    let invalid = || {
+        format!(
+            "Invalid time '{}' (use RFC 3339, e.g. 2024-01-15T10:00:00Z, or a duration back from now like 90s, 15m, 2h, 1d)",
+            input
+        )
+    };
+    let unit_len = input.chars().last().map_or(0, char::len_utf8);
+    let (amount, unit) = input.split_at(input.len() - unit_len);
+    let amount: i64 = amount.parse().map_err(|_| invalid())?;
+    let duration = match unit {
+        "s" => chrono::Duration::seconds(amount),
+        "m" => chrono::Duration::minutes(amount),
+        "h" => chrono::Duration::hours(amount),
+        "d" => chrono::Duration::days(amount),
+        _ => return Err(invalid()),
+    };
+    Ok(now - duration)
+}
+
 /// Log severity level
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
 pub enum LogLevel {
@@ -261,16 +546,40 @@ impl LogLevel {
         }
     }
 
-    /// Get display color for this level
-    pub fn color(&self) -> Color {
+    /// Lowercase full name, for machine-readable output
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Trace => "trace",
+            Self::Debug => "debug",
+            Self::Info => "info",
+            Self::Warn => "warn",
+            Self::Error => "error",
+            Self::Fatal => "fatal",
+            Self::Unknown => "unknown",
+        }
+    }
+
+    /// Ordinal for severity comparisons
+    pub fn ordinal(&self) -> u8 {
         match self {
-            Self::Trace => Color::DarkGray,
-            Self::Debug => Color::Cyan,
-            Self::Info => Color::Green,
-            Self::Warn => Color::Yellow,
-            Self::Error => Color::Red,
-            Self::Fatal => Color::Magenta,
-            Self::Unknown => Color::White,
+            Self::Trace => 0,
+            Self::Debug => 1,
+            Self::Info => 2,
+            Self::Warn => 3,
+            Self::Error => 4,
+            Self::Fatal => 5,
+            Self::Unknown => 2, // Treat unknown as info level
+        }
+    }
+
+    /// Next minimum level for the threshold control (trace, debug, info, warn, error)
+    pub fn next_threshold(&self) -> Self {
+        match self {
+            Self::Trace => Self::Debug,
+            Self::Debug => Self::Info,
+            Self::Info | Self::Unknown => Self::Warn,
+            Self::Warn => Self::Error,
+            Self::Error | Self::Fatal => Self::Trace,
         }
     }
 
@@ -288,6 +597,74 @@ impl LogLevel {
     }
 }
 
+/// How a log line was fetched
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum SourceKind {
+    /// Followed live from the pod's log stream
+    #[default]
+    Live,
+    /// Backfilled by a one-shot scrollback fetch
+    History,
+    /// Followed live from a mesh sidecar next to the app container
+    Sidecar,
+}
+
+/// Where a log line came from
+///
+/// Built once per pod stream and shared by every entry it produces, so
+/// grouping, coloring and filtering all key off the same value.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct LogSource {
+    pub namespace: Option<String>,
+    /// Workload (deployment) owning the pod
+    pub workload: Option<String>,
+    pub pod: String,
+    pub container: Option<String>,
+    pub kind: SourceKind,
+    /// Context of an extra cluster in a multi-cluster view
+    pub cluster: Option<String>,
+}
+
+impl LogSource {
+    /// A source known only by its pod name
+    pub fn pod(name: impl Into<String>) -> Self {
+        Self {
+            pod: name.into(),
+            ..Default::default()
+        }
+    }
+
+    /// The stream a line came from: the pod, or `pod/container` for a sidecar
+    ///
+    /// Sidecar lines share their pod's name, so per-stream bookkeeping (line
+    /// numbering, resume and scrollback points, line counts) goes by this.
+    pub fn stream_key(&self) -> Cow<'_, str> {
+        match (&self.kind, &self.container) {
+            (SourceKind::Sidecar, Some(container)) => {
+                Cow::Owned(format!("{}/{}", self.pod, container))
+            }
+            _ => Cow::Borrowed(&self.pod),
+        }
+    }
+
+    /// "namespace/workload" for telling merged sources apart ("-" when unknown)
+    ///
+    /// Lines from an extra cluster end in "@context", which survives the
+    /// column keeping only the end of the label.
+    pub fn label(&self) -> String {
+        let label = match (&self.namespace, &self.workload) {
+            (Some(ns), Some(workload)) => format!("{}/{}", ns, workload),
+            (Some(ns), None) => ns.clone(),
+            (None, Some(workload)) => workload.clone(),
+            (None, None) => "-".to_string(),
+        };
+        match &self.cluster {
+            Some(cluster) => format!("{}@{}", label, cluster),
+            None => label,
+        }
+    }
+}
+
 /// A single log entry
 #[derive(Clone, Debug)]
 #[allow(dead_code)]
@@ -295,18 +672,22 @@ pub struct LogEntry {
     /// Unique sequential ID
     pub id: u64,
 
-    /// Line number within the pod's log stream
-    pub line_number: u64,
+    /// Line number within the pod's log stream, counted from the first streamed
+    /// line (scrollback history counts down from there, so it can be zero or negative)
+    pub line_number: i64,
 
-    /// Source pod name
-    pub pod_name: String,
+    /// Namespace, workload, pod and container the line came from
+    pub source: Arc<LogSource>,
 
-    /// Container name (if multiple containers)
-    pub container_name: Option<String>,
+    /// Node the source pod is scheduled on
+    pub node_name: Option<String>,
 
     /// Original raw log line
     pub raw: String,
 
+    /// Byte offset in `raw` where the text after the timestamp prefix starts
+    pub text_start: usize,
+
     /// Parsed timestamp (if available)
     pub timestamp: Option<DateTime<Utc>>,
 
@@ -321,23 +702,49 @@ pub struct LogEntry {
 
     /// Pretty-printed version (cached)
     pub pretty_printed: Option<String>,
+
+    /// Line exceeded the maximum length and was cut short
+    pub truncated: bool,
+
+    /// Bytes cut off the end of a truncated line
+    pub truncated_bytes: usize,
+
+    /// Line looked like JSON but could not be parsed
+    pub parse_failed: bool,
+
+    /// Invalid UTF-8 or control characters were replaced or dropped
+    pub sanitized: bool,
+
+    /// The line as received, with the replaced bytes escaped (sanitized lines only)
+    pub original: Option<String>,
 }
 
 #[allow(dead_code)]
 impl LogEntry {
     /// Create a new log entry with minimal fields
-    pub fn new(pod_name: String, line_number: u64, raw: String) -> Self {
+    pub fn new(pod_name: String, line_number: i64, raw: String) -> Self {
+        Self::from_source(Arc::new(LogSource::pod(pod_name)), line_number, raw)
+    }
+
+    /// Create a new log entry sharing an existing source
+    pub fn from_source(source: Arc<LogSource>, line_number: i64, raw: String) -> Self {
         Self {
             id: 0,
             line_number,
-            pod_name,
-            container_name: None,
+            source,
             raw,
+            text_start: 0,
             timestamp: None,
             level: LogLevel::Unknown,
             fields: None,
             is_json: false,
             pretty_printed: None,
+            node_name: None,
+            truncated: false,
+            truncated_bytes: 0,
+            parse_failed: false,
+            sanitized: false,
+            original: None,
         }
     }
 
@@ -345,7 +752,20 @@ impl LogEntry {
     pub fn short_pod_name(&self) -> &str {
         // Pod names are usually like: deployment-name-replicaset-hash-pod-hash
         // We want to show just the last part for brevity
-        self.pod_name.rsplit('-').next().unwrap_or(&self.pod_name)
+        let pod = self.pod_name();
+        pod.rsplit('-').next().unwrap_or(pod)
+    }
+
+    /// Source pod name
+    pub fn pod_name(&self) -> &str {
+        &self.source.pod
+    }
+
+    /// The line as the log view shows it, without the timestamp prefix
+    ///
+    /// Filters match against this too, so highlights line up with what matched.
+    pub fn display_text(&self) -> &str {
+        self.raw.get(self.text_start..).unwrap_or(&self.raw)
     }
 
     /// Get the message content (from JSON field or raw line)
@@ -0,0 +1,146 @@
+use chrono::Local;
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+
+use crate::app::AppState;
+use crate::ui::Theme;
+
+/// Bookmarks overlay - lists bookmarked lines and jumps back to them
+pub struct BookmarkList;
+
+impl BookmarkList {
+    pub fn render(frame: &mut Frame, state: &mut AppState) {
+        let area = frame.area();
+
+        let popup_width = 80.min(area.width.saturating_sub(4));
+        let popup_height = 20.min(area.height.saturating_sub(4));
+
+        let x = area.x + (area.width.saturating_sub(popup_width)) / 2;
+        let y = area.y + (area.height.saturating_sub(popup_height)) / 2;
+        let popup_area = Rect::new(x, y, popup_width, popup_height);
+
+        // Clear the background
+        frame.render_widget(Clear, popup_area);
+
+        let bookmarks = &state.ui_state.bookmarks;
+        let footer_lines = 2; // Spacer + help text
+        let viewport_height = (popup_height as usize).saturating_sub(footer_lines + 2); // -2 for borders
+
+        // Clamp selection to valid range
+        if state.ui_state.bookmark_selection >= bookmarks.len() {
+            state.ui_state.bookmark_selection = bookmarks.len().saturating_sub(1);
+        }
+        let selection = state.ui_state.bookmark_selection;
+        let scroll = selection.saturating_sub(viewport_height.saturating_sub(1));
+
+        let mut lines = Vec::new();
+
+        if bookmarks.is_empty() {
+            lines.push(Line::from(Span::styled(
+                " No bookmarks yet - press [m] to bookmark the top visible line",
+                Style::default().fg(Theme::current().fg_dim),
+            )));
+        }
+
+        let max_msg_len = (popup_width as usize).saturating_sub(30);
+
+        for (idx, entry) in bookmarks
+            .iter()
+            .enumerate()
+            .skip(scroll)
+            .take(viewport_height)
+        {
+            let is_cursor = idx == selection;
+            let cursor = if is_cursor { "▸" } else { " " };
+
+            let line_style = if is_cursor {
+                Style::default().bg(Theme::current().surface)
+            } else {
+                Style::default()
+            };
+
+            let ts = entry
+                .timestamp
+                .map(|t| {
+                    if state.ui_state.use_local_time {
+                        t.with_timezone(&Local).format("%H:%M:%S").to_string()
+                    } else {
+                        t.format("%H:%M:%S").to_string()
+                    }
+                })
+                .unwrap_or_else(|| "--:--:--".to_string());
+
+            // A noted line shows its latest note instead of the message
+            let note = state.notes_for(entry).last().map(|note| note.text.as_str());
+            let message = note.unwrap_or_else(|| entry.message().trim());
+            let snippet: String = if message.chars().count() > max_msg_len {
+                let truncated: String = message
+                    .chars()
+                    .take(max_msg_len.saturating_sub(3))
+                    .collect();
+                format!("{}...", truncated)
+            } else {
+                message.to_string()
+            };
+
+            lines.push(Line::from(vec![
+                Span::styled(format!(" {} ", cursor), line_style),
+                Span::styled(
+                    format!("{} ", ts),
+                    Style::default().fg(Theme::current().fg_dim),
+                ),
+                Span::styled(
+                    format!("{:>10} ", entry.short_pod_name()),
+                    Style::default().fg(Theme::current().primary),
+                ),
+                Span::styled(
+                    format!("{:>3} ", entry.level.as_str()),
+                    Style::default()
+                        .fg(Theme::current().level_color(entry.level))
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    if note.is_some() { "✎ " } else { "" },
+                    Style::default().fg(Theme::current().highlight),
+                ),
+                Span::styled(snippet, Style::default().fg(Theme::current().fg)),
+            ]));
+        }
+
+        // Pad with empty lines so the help text stays at the bottom
+        while lines.len() < viewport_height + 1 {
+            lines.push(Line::from(""));
+        }
+
+        // Help text
+        lines.push(Line::from(vec![
+            Span::styled(" [Enter]", Style::default().fg(Theme::current().highlight)),
+            Span::styled("Jump ", Style::default().fg(Theme::current().fg_dim)),
+            Span::styled("[d]", Style::default().fg(Theme::current().highlight)),
+            Span::styled("Delete ", Style::default().fg(Theme::current().fg_dim)),
+            Span::styled("[Esc]", Style::default().fg(Theme::current().highlight)),
+            Span::styled("Close", Style::default().fg(Theme::current().fg_dim)),
+        ]));
+
+        let title = format!(" Bookmarks ({}) ", bookmarks.len());
+
+        let paragraph = Paragraph::new(lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Theme::current().primary))
+                .title(Span::styled(
+                    title,
+                    Style::default()
+                        .fg(Theme::current().primary)
+                        .add_modifier(Modifier::BOLD),
+                )),
+        );
+
+        frame.render_widget(paragraph, popup_area);
+    }
+}
@@ -0,0 +1,104 @@
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+
+use crate::app::AppState;
+use crate::config::Column;
+use crate::ui::Theme;
+
+/// Columns overlay - reorder, resize and show/hide the log line prefix columns
+pub struct ColumnsOverlay;
+
+impl ColumnsOverlay {
+    pub fn render(frame: &mut Frame, state: &mut AppState) {
+        let area = frame.area();
+
+        let columns = &state.ui_state.columns.columns;
+        let popup_width = 48.min(area.width.saturating_sub(4));
+        // Header + one row per column + spacer + two help lines + borders
+        let popup_height = (columns.len() as u16 + 6).min(area.height.saturating_sub(4));
+
+        let x = area.x + (area.width.saturating_sub(popup_width)) / 2;
+        let y = area.y + (area.height.saturating_sub(popup_height)) / 2;
+        let popup_area = Rect::new(x, y, popup_width, popup_height);
+
+        frame.render_widget(Clear, popup_area);
+
+        if state.ui_state.column_selection >= columns.len() {
+            state.ui_state.column_selection = columns.len().saturating_sub(1);
+        }
+        let selection = state.ui_state.column_selection;
+
+        let mut lines = vec![Line::from(Span::styled(
+            format!("       {:<14} {:>5}", "COLUMN", "WIDTH"),
+            Style::default().fg(Theme::current().fg_dim),
+        ))];
+
+        for (idx, (column, width)) in columns.iter().enumerate() {
+            let is_cursor = idx == selection;
+            let enabled = state.column_enabled(*column);
+
+            let line_style = if is_cursor {
+                Style::default().bg(Theme::current().surface)
+            } else {
+                Style::default()
+            };
+            // Line number and level can't be hidden
+            let checkbox = match column {
+                Column::Line | Column::Level => "   ",
+                _ if enabled => "[x]",
+                _ => "[ ]",
+            };
+            let label_style = if enabled {
+                Style::default().fg(Theme::current().fg)
+            } else {
+                Style::default().fg(Theme::current().fg_dim)
+            };
+
+            lines.push(Line::from(vec![
+                Span::styled(if is_cursor { " ▸ " } else { "   " }, line_style),
+                Span::styled(
+                    format!("{} ", checkbox),
+                    Style::default().fg(Theme::current().success),
+                ),
+                Span::styled(format!("{:<14}", column.label()), label_style),
+                Span::styled(
+                    format!(" {:>5}", width),
+                    Style::default().fg(Theme::current().primary),
+                ),
+            ]));
+        }
+
+        lines.push(Line::from(""));
+        lines.push(Line::from(vec![
+            Span::styled(" [Space]", Style::default().fg(Theme::current().highlight)),
+            Span::styled("Show/hide ", Style::default().fg(Theme::current().fg_dim)),
+            Span::styled("[J/K]", Style::default().fg(Theme::current().highlight)),
+            Span::styled("Move", Style::default().fg(Theme::current().fg_dim)),
+        ]));
+        lines.push(Line::from(vec![
+            Span::styled(" [←/→]", Style::default().fg(Theme::current().highlight)),
+            Span::styled("Width ", Style::default().fg(Theme::current().fg_dim)),
+            Span::styled("[Esc]", Style::default().fg(Theme::current().highlight)),
+            Span::styled("Close", Style::default().fg(Theme::current().fg_dim)),
+        ]));
+
+        let paragraph = Paragraph::new(lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Theme::current().primary))
+                .title(Span::styled(
+                    " Columns ",
+                    Style::default()
+                        .fg(Theme::current().primary)
+                        .add_modifier(Modifier::BOLD),
+                )),
+        );
+
+        frame.render_widget(paragraph, popup_area);
+    }
+}
@@ -1,12 +1,13 @@
 use ratatui::{
     Frame,
     layout::{Constraint, Direction, Layout, Rect},
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
 };
 
-use crate::app::Action;
+use crate::app::{Action, PromptKind, Screen};
+use crate::ui::Theme;
 
 /// A command that can be executed from the palette
 #[derive(Clone)]
@@ -23,6 +24,10 @@ pub struct CommandPaletteState {
     pub search_input: String,
     pub list_state: ListState,
     pub filtered_indices: Vec<usize>,
+    /// Argument prompt for a parameterized command (replaces the list while open)
+    pub prompt: Option<PromptKind>,
+    pub prompt_input: String,
+    pub prompt_error: Option<String>,
 }
 
 impl Default for CommandPaletteState {
@@ -34,6 +39,9 @@ impl Default for CommandPaletteState {
             search_input: String::new(),
             list_state,
             filtered_indices: Vec::new(),
+            prompt: None,
+            prompt_input: String::new(),
+            prompt_error: None,
         }
     }
 }
@@ -49,32 +57,52 @@ impl CommandPaletteState {
     pub fn close(&mut self) {
         self.visible = false;
         self.search_input.clear();
+        self.prompt = None;
+        self.prompt_input.clear();
+        self.prompt_error = None;
+    }
+
+    /// Switch to the argument prompt for a parameterized command
+    pub fn open_prompt(&mut self, kind: PromptKind) {
+        self.visible = true;
+        self.prompt = Some(kind);
+        self.prompt_input.clear();
+        self.prompt_error = None;
+    }
+
+    /// Parse the prompt input into the command's action
+    ///
+    /// On a parse error the prompt stays open with the error shown.
+    pub fn submit_prompt(&mut self) -> Option<Action> {
+        let kind = self.prompt?;
+        match kind.parse(&self.prompt_input) {
+            Ok(action) => {
+                self.close();
+                Some(action)
+            }
+            Err(e) => {
+                self.prompt_error = Some(e);
+                None
+            }
+        }
     }
 
     pub fn update_filtered(&mut self, commands: &[Command]) {
-        let query = self.search_input.to_lowercase();
-        self.filtered_indices = commands
+        let query = self.search_input.trim();
+        let mut scored: Vec<(i64, usize)> = commands
             .iter()
             .enumerate()
-            .filter(|(_, cmd)| {
-                if query.is_empty() {
-                    true
-                } else {
-                    cmd.name.to_lowercase().contains(&query)
-                        || cmd.description.to_lowercase().contains(&query)
-                }
-            })
-            .map(|(i, _)| i)
+            .filter_map(|(i, cmd)| command_score(query, cmd).map(|score| (score, i)))
             .collect();
+        // Best match first; ties keep the list's own order
+        scored.sort_by_key(|&(score, i)| (std::cmp::Reverse(score), i));
+        self.filtered_indices = scored.into_iter().map(|(_, i)| i).collect();
 
-        // Reset selection if out of bounds
+        // Ranking reorders the list, so start again from the best match
         if self.filtered_indices.is_empty() {
             self.list_state.select(None);
         } else {
-            let current = self.list_state.selected().unwrap_or(0);
-            if current >= self.filtered_indices.len() {
-                self.list_state.select(Some(0));
-            }
+            self.list_state.select(Some(0));
         }
     }
 
@@ -119,11 +147,34 @@ impl CommandPaletteState {
     }
 
     pub fn input_char(&mut self, c: char, commands: &[Command]) {
+        if self.prompt.is_some() {
+            self.prompt_input.push(c);
+            self.prompt_error = None;
+            return;
+        }
         self.search_input.push(c);
-        self.update_filtered(commands);
+        // A prompt's prefix (`t:`) opens it, if one of the commands does
+        let prompt = commands.iter().find_map(|cmd| match cmd.action {
+            Action::OpenPrompt(kind) if kind.prefix() == Some(self.search_input.trim_start()) => {
+                Some(kind)
+            }
+            _ => None,
+        });
+        match prompt {
+            Some(kind) => {
+                self.search_input.clear();
+                self.open_prompt(kind);
+            }
+            None => self.update_filtered(commands),
+        }
     }
 
     pub fn input_backspace(&mut self, commands: &[Command]) {
+        if self.prompt.is_some() {
+            self.prompt_input.pop();
+            self.prompt_error = None;
+            return;
+        }
         self.search_input.pop();
         self.update_filtered(commands);
     }
@@ -134,6 +185,11 @@ pub struct CommandPalette;
 
 impl CommandPalette {
     pub fn render(frame: &mut Frame, state: &mut CommandPaletteState, commands: &[Command]) {
+        if let Some(kind) = state.prompt {
+            Self::render_prompt(frame, state, kind);
+            return;
+        }
+
         let area = frame.area();
 
         // Center the palette
@@ -158,23 +214,26 @@ impl CommandPalette {
         let search_text = if state.search_input.is_empty() {
             vec![Span::styled(
                 "Type to filter...",
-                Style::default().fg(Color::DarkGray),
+                Style::default().fg(Theme::current().fg_dim),
             )]
         } else {
             vec![
-                Span::styled(&state.search_input, Style::default().fg(Color::White)),
-                Span::styled("█", Style::default().fg(Color::Yellow)),
+                Span::styled(
+                    &state.search_input,
+                    Style::default().fg(Theme::current().fg),
+                ),
+                Span::styled("█", Style::default().fg(Theme::current().highlight)),
             ]
         };
 
         let search_widget = Paragraph::new(Line::from(search_text)).block(
             Block::default()
                 .borders(Borders::ALL)
-                .border_style(Style::default().fg(Color::Yellow))
+                .border_style(Style::default().fg(Theme::current().highlight))
                 .title(Span::styled(
                     " Command Palette ",
                     Style::default()
-                        .fg(Color::Yellow)
+                        .fg(Theme::current().highlight)
                         .add_modifier(Modifier::BOLD),
                 )),
         );
@@ -186,18 +245,16 @@ impl CommandPalette {
             .iter()
             .map(|&idx| {
                 let cmd = &commands[idx];
-                let line = Line::from(vec![
-                    Span::styled(
-                        format!("{:<20}", cmd.name),
-                        Style::default().add_modifier(Modifier::BOLD),
-                    ),
-                    Span::styled(cmd.description, Style::default().fg(Color::Gray)),
-                    Span::styled(
-                        format!("  {}", cmd.key_hint),
-                        Style::default().fg(Color::DarkGray),
-                    ),
-                ]);
-                ListItem::new(line)
+                let mut spans = highlighted_name(cmd, &state.search_input);
+                spans.push(Span::styled(
+                    cmd.description,
+                    Style::default().fg(Theme::current().fg),
+                ));
+                spans.push(Span::styled(
+                    format!("  {}", cmd.key_hint),
+                    Style::default().fg(Theme::current().fg_dim),
+                ));
+                ListItem::new(Line::from(spans))
             })
             .collect();
 
@@ -205,18 +262,199 @@ impl CommandPalette {
             .block(
                 Block::default()
                     .borders(Borders::LEFT | Borders::RIGHT | Borders::BOTTOM)
-                    .border_style(Style::default().fg(Color::Yellow)),
+                    .border_style(Style::default().fg(Theme::current().highlight)),
             )
             .highlight_style(
                 Style::default()
-                    .bg(Color::Yellow)
-                    .fg(Color::Black)
+                    .bg(Theme::current().highlight)
+                    .fg(Theme::current().on_highlight)
                     .add_modifier(Modifier::BOLD),
             )
             .highlight_symbol("▸ ");
 
         frame.render_stateful_widget(list, chunks[1], &mut state.list_state);
     }
+
+    /// Secondary input for a parameterized command
+    fn render_prompt(frame: &mut Frame, state: &CommandPaletteState, kind: PromptKind) {
+        let area = frame.area();
+        let popup_width = 60.min(area.width.saturating_sub(4));
+        let popup_height = 5.min(area.height.saturating_sub(4));
+        let popup_area = centered_rect(popup_width, popup_height, area);
+
+        frame.render_widget(Clear, popup_area);
+
+        let input = if state.prompt_input.is_empty() {
+            Line::from(vec![
+                Span::styled("█", Style::default().fg(Theme::current().highlight)),
+                Span::styled(
+                    kind.placeholder(),
+                    Style::default().fg(Theme::current().fg_dim),
+                ),
+            ])
+        } else {
+            Line::from(vec![
+                Span::styled(
+                    &state.prompt_input,
+                    Style::default().fg(Theme::current().fg),
+                ),
+                Span::styled("█", Style::default().fg(Theme::current().highlight)),
+            ])
+        };
+        let footer = match &state.prompt_error {
+            Some(err) => Line::from(Span::styled(
+                format!("⚠ {}", err),
+                Style::default().fg(Theme::current().error),
+            )),
+            None => Line::from(Span::styled(
+                "[Enter] Run  [Esc] Cancel",
+                Style::default().fg(Theme::current().fg_dim),
+            )),
+        };
+
+        let prompt = Paragraph::new(vec![input, Line::from(""), footer]).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Theme::current().highlight))
+                .title(Span::styled(
+                    format!(" {} ", kind.label()),
+                    Style::default()
+                        .fg(Theme::current().highlight)
+                        .add_modifier(Modifier::BOLD),
+                )),
+        );
+        frame.render_widget(prompt, popup_area);
+    }
+}
+
+/// Command name padded to its column, with fuzzy-matched characters highlighted
+fn highlighted_name(cmd: &Command, query: &str) -> Vec<Span<'static>> {
+    let name = cmd.name;
+    let base = Style::default().add_modifier(Modifier::BOLD);
+    // Underline too, so matches still stand out on the selected row
+    let matched = base
+        .fg(Theme::current().accent)
+        .add_modifier(Modifier::UNDERLINED);
+    let query = query.trim();
+    let positions = fuzzy_match(query, name)
+        .or_else(|| fuzzy_match(query, &searchable_text(cmd)))
+        .map(|(_, positions)| positions)
+        .unwrap_or_default();
+
+    let mut spans: Vec<Span> = name
+        .chars()
+        .enumerate()
+        .map(|(i, c)| {
+            let style = if positions.contains(&i) {
+                matched
+            } else {
+                base
+            };
+            Span::styled(c.to_string(), style)
+        })
+        .collect();
+    let width = name.chars().count();
+    if width < NAME_WIDTH {
+        spans.push(Span::styled(" ".repeat(NAME_WIDTH - width), base));
+    }
+    spans
+}
+
+/// Width of the command name column
+const NAME_WIDTH: usize = 20;
+
+/// Rank a command against the palette query (None = filtered out)
+///
+/// Name matches always outrank matches that need the description too.
+fn command_score(query: &str, cmd: &Command) -> Option<i64> {
+    if query.is_empty() {
+        return Some(0);
+    }
+    fuzzy_match(query, cmd.name)
+        .map(|(score, _)| score + 1_000)
+        .or_else(|| fuzzy_match(query, &searchable_text(cmd)).map(|(score, _)| score))
+}
+
+/// Name followed by description, so a query can run on from one into the other
+fn searchable_text(cmd: &Command) -> String {
+    format!("{} {}", cmd.name, cmd.description)
+}
+
+/// Skim-style fuzzy match of `query` as a case-insensitive subsequence of `text`
+///
+/// Returns the best score and the matched character positions. Matches at word
+/// starts and runs of consecutive characters score higher; gaps cost a little.
+fn fuzzy_match(query: &str, text: &str) -> Option<(i64, Vec<usize>)> {
+    const MATCH: i64 = 16;
+    const WORD_START: i64 = 24;
+    const CONSECUTIVE: i64 = 16;
+    const GAP: i64 = 1;
+
+    let query: Vec<char> = query
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .collect();
+    let text: Vec<char> = text.chars().collect();
+    if query.is_empty() {
+        return Some((0, Vec::new()));
+    }
+    if query.len() > text.len() {
+        return None;
+    }
+
+    let lower: Vec<char> = text
+        .iter()
+        .map(|c| c.to_lowercase().next().unwrap_or(*c))
+        .collect();
+    let word_start = |j: usize| {
+        j == 0
+            || !text[j - 1].is_alphanumeric()
+            || (text[j].is_uppercase() && text[j - 1].is_lowercase())
+    };
+
+    // best[i][j]: best score with query[i] matched at text[j], plus where query[i - 1] went
+    let mut best: Vec<Vec<Option<(i64, usize)>>> = vec![vec![None; text.len()]; query.len()];
+    for (i, &qc) in query.iter().enumerate() {
+        for j in 0..text.len() {
+            if lower[j] != qc {
+                continue;
+            }
+            let bonus = MATCH + if word_start(j) { WORD_START } else { 0 };
+            if i == 0 {
+                // Small penalty for how far into the text the match starts
+                best[i][j] = Some((bonus - GAP * j as i64, 0));
+                continue;
+            }
+            best[i][j] = (0..j)
+                .filter_map(|k| best[i - 1][k].map(|(score, _)| (score, k)))
+                .map(|(score, k)| {
+                    let link = if k + 1 == j {
+                        CONSECUTIVE
+                    } else {
+                        -GAP * (j - k - 1) as i64
+                    };
+                    (score + bonus + link, k)
+                })
+                .max_by_key(|&(score, k)| (score, std::cmp::Reverse(k)));
+        }
+    }
+
+    let last = query.len() - 1;
+    let (mut j, &(score, _)) = best[last]
+        .iter()
+        .enumerate()
+        .filter_map(|(j, cell)| cell.as_ref().map(|c| (j, c)))
+        .max_by_key(|(j, (score, _))| (*score, std::cmp::Reverse(*j)))?;
+
+    let mut positions = vec![0; query.len()];
+    for i in (0..query.len()).rev() {
+        positions[i] = j;
+        if i > 0 {
+            j = best[i][j].map(|(_, prev)| prev)?;
+        }
+    }
+    Some((score, positions))
 }
 
 /// Helper to create a centered rect
@@ -226,15 +464,141 @@ fn centered_rect(width: u16, height: u16, area: Rect) -> Rect {
     Rect::new(x, y, width, height)
 }
 
-/// Get log viewer commands
-pub fn log_viewer_commands() -> Vec<Command> {
+/// Commands for the palette on a given screen
+pub fn commands_for(screen: Screen) -> Vec<Command> {
+    match screen {
+        Screen::LogViewer => log_viewer_commands(),
+        Screen::ContextSelect => list_commands(vec![Command {
+            name: "Refresh Contexts",
+            description: "Re-read contexts from kubeconfig",
+            key_hint: "",
+            action: Action::RefreshContexts,
+        }]),
+        Screen::NamespaceSelect => list_commands(vec![
+            Command {
+                name: "Refresh Namespaces",
+                description: "Reload namespaces from the cluster",
+                key_hint: "",
+                action: Action::RefreshNamespaces,
+            },
+            Command {
+                name: "Hide Terminating",
+                description: "Hide/show namespaces that are being deleted",
+                key_hint: "t",
+                action: Action::ToggleTerminatingNamespaces,
+            },
+            Command {
+                name: "Filter by Labels…",
+                description: "Only list namespaces matching a label selector",
+                key_hint: ":",
+                action: Action::OpenPrompt(PromptKind::LabelSelector),
+            },
+        ]),
+        Screen::DeploymentSelect => list_commands(vec![
+            Command {
+                name: "Refresh Deployments",
+                description: "Reload deployments and their readiness",
+                key_hint: "",
+                action: Action::RefreshDeployments,
+            },
+            Command {
+                name: "Cycle Sort",
+                description: "Sort by name, ready ratio, replicas or age",
+                key_hint: "s",
+                action: Action::CycleDeploymentSort,
+            },
+            Command {
+                name: "Filter by Labels…",
+                description: "Only list deployments matching a label selector",
+                key_hint: ":",
+                action: Action::OpenPrompt(PromptKind::LabelSelector),
+            },
+        ]),
+        Screen::RecentSelect | Screen::Connecting => navigation_commands(),
+    }
+}
+
+/// Screen-specific commands followed by those shared by the context/namespace/deployment lists
+fn list_commands(mut commands: Vec<Command>) -> Vec<Command> {
+    commands.extend([
+        Command {
+            name: "Pin/Unpin",
+            description: "Keep the selected item at the top",
+            key_hint: "p",
+            action: Action::TogglePin,
+        },
+        Command {
+            name: "Recent Targets",
+            description: "Switch to a recently viewed deployment",
+            key_hint: "r",
+            action: Action::Navigate(Screen::RecentSelect),
+        },
+    ]);
+    commands.extend(navigation_commands());
+    commands
+}
+
+/// Help, back and quit, available everywhere
+fn navigation_commands() -> Vec<Command> {
     vec![
+        Command {
+            name: "Show Help",
+            description: "Display keybindings",
+            key_hint: "?",
+            action: Action::ToggleHelp,
+        },
+        switch_context_command(),
+        Command {
+            name: "Go Back",
+            description: "Return to the previous screen",
+            key_hint: "Esc",
+            action: Action::GoBack,
+        },
+        Command {
+            name: "Toggle Debug Logging",
+            description: "Write debug events to the --log-file",
+            key_hint: "",
+            action: Action::ToggleDebugLogging,
+        },
+        Command {
+            name: "Session Stats",
+            description: "Lines, peak rate, errors, filters and duration of this session",
+            key_hint: "F11",
+            action: Action::ToggleUsageStats,
+        },
+        Command {
+            name: "Quit",
+            description: "Exit kubescope",
+            key_hint: "q",
+            action: Action::Quit,
+        },
+    ]
+}
+
+fn switch_context_command() -> Command {
+    Command {
+        name: "Switch Context",
+        description: "Pick another context, reopening the same namespace/deployment there",
+        key_hint: "",
+        action: Action::SwitchContext,
+    }
+}
+
+/// Get log viewer commands
+fn log_viewer_commands() -> Vec<Command> {
+    let mut commands = vec![
         Command {
             name: "Toggle Follow",
             description: "Auto-scroll to new logs",
             key_hint: "f",
             action: Action::ToggleAutoScroll,
         },
+        Command {
+            name: "Stop/Resume Streaming",
+            description: "Disconnect the log streams, resume without a gap",
+            key_hint: "x",
+            action: Action::ToggleStreaming,
+        },
         Command {
             name: "Toggle Timestamps",
             description: "Show/hide timestamps",
@@ -247,12 +611,30 @@ pub fn log_viewer_commands() -> Vec<Command> {
             key_hint: "T",
             action: Action::ToggleLocalTime,
         },
+        Command {
+            name: "Time Deltas",
+            description: "Show time since the previous line to spot stalls",
+            key_hint: "^T",
+            action: Action::ToggleTimeDeltas,
+        },
         Command {
             name: "Toggle Pod Names",
             description: "Show/hide pod names",
             key_hint: "p",
             action: Action::TogglePodNames,
         },
+        Command {
+            name: "Toggle Line Numbers",
+            description: "Switch buffer ids/per-pod line numbers",
+            key_hint: "#",
+            action: Action::ToggleLineNumbers,
+        },
+        Command {
+            name: "Time Markers",
+            description: "Separator rows at day changes, every hour, or off",
+            key_hint: "D",
+            action: Action::CycleTimeMarkers,
+        },
         Command {
             name: "Toggle JSON",
             description: "Pretty print JSON logs",
@@ -265,12 +647,90 @@ pub fn log_viewer_commands() -> Vec<Command> {
             key_hint: "K",
             action: Action::ToggleJsonKeyFilter,
         },
+        Command {
+            name: "Save Key Set…",
+            description: "Name the current JSON key filter picks",
+            key_hint: "",
+            action: Action::OpenPrompt(PromptKind::SaveKeySet),
+        },
+        Command {
+            name: "Load Key Set…",
+            description: "Switch the JSON key filter to a saved set",
+            key_hint: "",
+            action: Action::OpenPrompt(PromptKind::LoadKeySet),
+        },
+        Command {
+            name: "Schema Drift",
+            description: "JSON keys that changed type or stopped appearing",
+            key_hint: "S",
+            action: Action::ToggleSchemaDrift,
+        },
+        Command {
+            name: "Parse Diagnostics",
+            description: "Lines that failed to parse or had no level, with samples",
+            key_hint: "U",
+            action: Action::ToggleParseDiagnostics,
+        },
+        Command {
+            name: "Fold JSON Entry",
+            description: "Expand or collapse the top pretty-printed entry",
+            key_hint: "z",
+            action: Action::ToggleJsonFold,
+        },
+        Command {
+            name: "Raw Line",
+            description: "Show the top line as received, control bytes escaped",
+            key_hint: "v",
+            action: Action::ToggleRawView,
+        },
+        Command {
+            name: "Full Line",
+            description: "Fetch the truncated top line in full from the pod",
+            key_hint: "w",
+            action: Action::FetchFullLine,
+        },
+        Command {
+            name: "JSON Fold Depth",
+            description: "Fold nested objects past depth 1, 2, 3 or none",
+            key_hint: "Z",
+            action: Action::CycleJsonFoldDepth,
+        },
         Command {
             name: "Toggle Stats",
             description: "Show/hide stats bar",
             key_hint: "s",
             action: Action::ToggleStats,
         },
+        Command {
+            name: "Toggle Zen Mode",
+            description: "Hide/restore header, stats and filter bars",
+            key_hint: "b",
+            action: Action::ToggleZen,
+        },
+        Command {
+            name: "Cycle Density",
+            description: "Compact, normal or detailed line prefix",
+            key_hint: "d",
+            action: Action::CycleDensity,
+        },
+        Command {
+            name: "Toggle Minimap",
+            description: "Show/hide level minimap",
+            key_hint: "M",
+            action: Action::ToggleMinimap,
+        },
+        Command {
+            name: "Next Error",
+            description: "Jump to next error line",
+            key_hint: "]",
+            action: Action::JumpNextError,
+        },
+        Command {
+            name: "Previous Error",
+            description: "Jump to previous error line",
+            key_hint: "[",
+            action: Action::JumpPrevError,
+        },
         Command {
             name: "Cycle Time Range",
             description: "Change log time window",
@@ -283,9 +743,27 @@ pub fn log_viewer_commands() -> Vec<Command> {
             key_hint: "/",
             action: Action::OpenSearch,
         },
+        Command {
+            name: "Find",
+            description: "Highlight matches and count them",
+            key_hint: "F",
+            action: Action::OpenFind,
+        },
+        Command {
+            name: "Next Match",
+            description: "Jump to next find match",
+            key_hint: ".",
+            action: Action::FindNext,
+        },
+        Command {
+            name: "Previous Match",
+            description: "Jump to previous find match",
+            key_hint: ",",
+            action: Action::FindPrev,
+        },
         Command {
             name: "Clear Filter",
-            description: "Remove active filter",
+            description: "Remove active filter and find",
             key_hint: "n",
             action: Action::ClearFilter,
         },
@@ -295,6 +773,84 @@ pub fn log_viewer_commands() -> Vec<Command> {
             key_hint: "i",
             action: Action::ToggleCaseSensitive,
         },
+        Command {
+            name: "Toggle Bookmark",
+            description: "Bookmark the line at the top of the view",
+            key_hint: "m",
+            action: Action::ToggleBookmark,
+        },
+        Command {
+            name: "Bookmarks",
+            description: "List and jump to bookmarks",
+            key_hint: "'",
+            action: Action::ToggleBookmarks,
+        },
+        Command {
+            name: "Load Older Logs",
+            description: "Fetch more history per pod",
+            key_hint: "g",
+            action: Action::LoadOlderLogs,
+        },
+        Command {
+            name: "Grow Buffer",
+            description: "Double log buffer capacity",
+            key_hint: "",
+            action: Action::GrowBuffer,
+        },
+        Command {
+            name: "Shrink Buffer",
+            description: "Halve capacity (drops oldest)",
+            key_hint: "",
+            action: Action::ShrinkBuffer,
+        },
+        Command {
+            name: "Set Tail Lines…",
+            description: "Restart streams with a new tail size",
+            key_hint: "t:",
+            action: Action::OpenPrompt(PromptKind::TailLines),
+        },
+        Command {
+            name: "Toggle Sidecar Exclusion",
+            description: "Read mesh sidecars (exclude_containers) too, or skip them again",
+            key_hint: "",
+            action: Action::ToggleContainerExclusion,
+        },
+        Command {
+            name: "Toggle Sidecar Access Logs",
+            description: "Stream excluded sidecars' logs too, tagged as sidecar lines",
+            key_hint: "",
+            action: Action::ToggleSidecarLogs,
+        },
+        Command {
+            name: "Follow Request",
+            description: "Filter to the top line's request id, proxy and app lines alike",
+            key_hint: "",
+            action: Action::FollowRequest,
+        },
+        Command {
+            name: "Add Note…",
+            description: "Write down an observation on the top line, kept in exports",
+            key_hint: "A",
+            action: Action::OpenPrompt(PromptKind::AddNote),
+        },
+        Command {
+            name: "Go to Time…",
+            description: "Jump to the first line at a time of day",
+            key_hint: "",
+            action: Action::OpenPrompt(PromptKind::GoToTime),
+        },
+        Command {
+            name: "Scale to…",
+            description: "Set the deployment's replica count",
+            key_hint: "",
+            action: Action::OpenPrompt(PromptKind::ScaleTo),
+        },
+        Command {
+            name: "Restart Deployment",
+            description: "Rolling restart (asks first)",
+            key_hint: "",
+            action: Action::RestartDeployment,
+        },
         Command {
             name: "Clear Logs",
             description: "Clear all log entries",
@@ -307,6 +863,30 @@ pub fn log_viewer_commands() -> Vec<Command> {
             key_hint: "e",
             action: Action::ExportLogs,
         },
+        Command {
+            name: "Open Line in Editor",
+            description: "Top line in $EDITOR, JSON pretty-printed",
+            key_hint: "o",
+            action: Action::OpenLineInEditor,
+        },
+        Command {
+            name: "Open View in Pager",
+            description: "Lines on screen in $PAGER",
+            key_hint: "E",
+            action: Action::OpenViewInPager,
+        },
+        Command {
+            name: "Stream Parameters",
+            description: "The log request each stream was opened with (tail, since, container)",
+            key_hint: "F10",
+            action: Action::ToggleStreamParams,
+        },
+        Command {
+            name: "Copy Repro Command",
+            description: "Copy the kubescope command line that reopens this view",
+            key_hint: "",
+            action: Action::CopyReproCommand,
+        },
         Command {
             name: "Show Help",
             description: "Display keybindings",
@@ -325,17 +905,131 @@ pub fn log_viewer_commands() -> Vec<Command> {
             key_hint: "G",
             action: Action::ScrollToBottom,
         },
+        Command {
+            name: "Pods",
+            description: "Pod status, restarts and line counts; mute pods",
+            key_hint: "P",
+            action: Action::TogglePodPanel,
+        },
+        Command {
+            name: "Widen Pod Sample",
+            description: "Stream twice as many pods of a sampled deployment",
+            key_hint: "+",
+            action: Action::WidenSample,
+        },
+        Command {
+            name: "Pod Colors",
+            description: "Legend mapping colors to pods",
+            key_hint: "l",
+            action: Action::TogglePodLegend,
+        },
+        Command {
+            name: "Cycle Minimum Level",
+            description: "Hide lines below trace/debug/info/warn/error",
+            key_hint: "L",
+            action: Action::CycleMinLevel,
+        },
+        Command {
+            name: "Toggle Node Names",
+            description: "Show/hide the node each pod runs on",
+            key_hint: "N",
+            action: Action::ToggleNodeNames,
+        },
+        Command {
+            name: "Filter by Node",
+            description: "Cycle through nodes, showing only their pods",
+            key_hint: "O",
+            action: Action::CycleNodeFilter,
+        },
+        Command {
+            name: "Compare Pods",
+            description: "Two pods side by side, scrolled in step by time",
+            key_hint: "V",
+            action: Action::ToggleCompare,
+        },
+        Command {
+            name: "Columns",
+            description: "Reorder, resize and show/hide log line columns",
+            key_hint: "C",
+            action: Action::ToggleColumns,
+        },
+        Command {
+            name: "Recent Targets",
+            description: "Switch to a recently viewed deployment",
+            key_hint: "",
+            action: Action::Navigate(Screen::RecentSelect),
+        },
+        switch_context_command(),
         Command {
             name: "Go Back",
             description: "Return to deployment list",
             key_hint: "Esc",
             action: Action::GoBack,
         },
+        Command {
+            name: "Toggle Debug Logging",
+            description: "Write debug events to the --log-file",
+            key_hint: "",
+            action: Action::ToggleDebugLogging,
+        },
+        Command {
+            name: "Session Stats",
+            description: "Lines, peak rate, errors, filters and duration of this session",
+            key_hint: "F11",
+            action: Action::ToggleUsageStats,
+        },
         Command {
             name: "Quit",
             description: "Exit kubescope",
             key_hint: "q",
             action: Action::Quit,
         },
-    ]
+    ];
+    // Commands from Lua plugins go last
+    if let Some(plugins) = crate::plugins::get() {
+        commands.extend(
+            plugins
+                .commands()
+                .iter()
+                .enumerate()
+                .map(|(index, command)| Command {
+                    name: command.name,
+                    description: command.description,
+                    key_hint: "",
+                    action: Action::RunPluginCommand(index),
+                }),
+        );
+    }
+    commands
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fuzzy_match_word_starts() {
+        let (_, positions) = fuzzy_match("tjp", "Toggle JSON Pretty Print").unwrap();
+        assert_eq!(positions, vec![0, 7, 12]);
+        assert!(fuzzy_match("xyz", "Toggle JSON Pretty Print").is_none());
+        // Order matters: subsequence, not anagram
+        assert!(fuzzy_match("pt", "Toggle").is_none());
+    }
+
+    #[test]
+    fn test_palette_ranks_fuzzy_matches() {
+        let commands = log_viewer_commands();
+        let mut palette = CommandPaletteState::default();
+        palette.open(&commands);
+        for c in "tjp".chars() {
+            palette.input_char(c, &commands);
+        }
+        let top = palette.selected_command(&commands).unwrap();
+        assert_eq!(top.name, "Toggle JSON");
+
+        // Word-start matches beat scattered ones
+        let a = fuzzy_match("cl", "Clear Logs").unwrap().0;
+        let b = fuzzy_match("cl", "Toggle Local Time").unwrap().0;
+        assert!(a > b);
+    }
 }
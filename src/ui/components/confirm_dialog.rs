@@ -0,0 +1,65 @@
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+};
+
+use crate::app::PendingConfirm;
+use crate::ui::Theme;
+
+/// Confirmation modal shown before a destructive action runs
+pub struct ConfirmDialog;
+
+impl ConfirmDialog {
+    pub fn render(frame: &mut Frame, confirm: &PendingConfirm) {
+        let area = frame.area();
+
+        let popup_width = 56.min(area.width.saturating_sub(4));
+        // Message (wrapped) + spacer + key hints + borders
+        let text_width = popup_width.saturating_sub(4).max(1) as usize;
+        let message_rows = confirm
+            .message
+            .lines()
+            .map(|l| l.chars().count().div_ceil(text_width).max(1))
+            .sum::<usize>() as u16;
+        let popup_height = (message_rows + 4).min(area.height.saturating_sub(4));
+
+        let x = area.x + (area.width.saturating_sub(popup_width)) / 2;
+        let y = area.y + (area.height.saturating_sub(popup_height)) / 2;
+        let popup_area = Rect::new(x, y, popup_width, popup_height);
+
+        frame.render_widget(Clear, popup_area);
+
+        let mut lines: Vec<Line> = confirm
+            .message
+            .lines()
+            .map(|l| Line::from(Span::styled(format!(" {}", l), Theme::current().text())))
+            .collect();
+        lines.push(Line::from(""));
+        lines.push(Line::from(vec![
+            Span::styled(" [y/Enter]", Style::default().fg(Theme::current().warning)),
+            Span::styled(
+                format!(" {}  ", confirm.confirm_label),
+                Style::default().fg(Theme::current().fg),
+            ),
+            Span::styled("[n/Esc]", Style::default().fg(Theme::current().highlight)),
+            Span::styled(" Cancel", Style::default().fg(Theme::current().fg_dim)),
+        ]));
+
+        let dialog = Paragraph::new(lines).wrap(Wrap { trim: false }).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Theme::current().warning))
+                .title(Span::styled(
+                    format!(" {} ", confirm.title),
+                    Style::default()
+                        .fg(Theme::current().warning)
+                        .add_modifier(Modifier::BOLD),
+                )),
+        );
+
+        frame.render_widget(dialog, popup_area);
+    }
+}
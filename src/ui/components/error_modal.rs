@@ -0,0 +1,89 @@
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+};
+
+use crate::app::ErrorBanner;
+use crate::ui::Theme;
+
+/// Error modal, shown over any screen until dismissed
+pub struct ErrorModal;
+
+impl ErrorModal {
+    pub fn render(frame: &mut Frame, error: &ErrorBanner) {
+        let area = frame.area();
+
+        let popup_width = 64.min(area.width.saturating_sub(4));
+        let text_width = popup_width.saturating_sub(4).max(1) as usize;
+        let rows = |text: &str| {
+            text.lines()
+                .map(|l| l.chars().count().div_ceil(text_width).max(1))
+                .sum::<usize>() as u16
+        };
+        // Guidance + spacer, message (wrapped) + spacer + key hints + borders
+        let guidance_rows = error.kind.map_or(0, |kind| rows(kind.guidance()) + 1);
+        let popup_height =
+            (guidance_rows + rows(&error.message) + 4).min(area.height.saturating_sub(4));
+
+        let x = area.x + (area.width.saturating_sub(popup_width)) / 2;
+        let y = area.y + (area.height.saturating_sub(popup_height)) / 2;
+        let popup_area = Rect::new(x, y, popup_width, popup_height);
+
+        frame.render_widget(Clear, popup_area);
+
+        let mut lines = Vec::new();
+        // Say what to do first; the raw error follows, dimmed, for the details
+        let message_style = if let Some(kind) = error.kind {
+            lines.push(Line::from(Span::styled(
+                format!(" {}", kind.guidance()),
+                Theme::current().text(),
+            )));
+            lines.push(Line::from(""));
+            Style::default().fg(Theme::current().fg_dim)
+        } else {
+            Theme::current().text()
+        };
+        lines.extend(
+            error
+                .message
+                .lines()
+                .map(|l| Line::from(Span::styled(format!(" {}", l), message_style))),
+        );
+        lines.push(Line::from(""));
+        let mut hints = vec![
+            Span::styled(
+                " [Enter/Esc]",
+                Style::default().fg(Theme::current().highlight),
+            ),
+            Span::styled(" Dismiss", Style::default().fg(Theme::current().fg_dim)),
+        ];
+        if error.retry.is_some() {
+            hints.push(Span::styled(
+                "  [r]",
+                Style::default().fg(Theme::current().highlight),
+            ));
+            hints.push(Span::styled(
+                " Retry",
+                Style::default().fg(Theme::current().fg_dim),
+            ));
+        }
+        lines.push(Line::from(hints));
+
+        let modal = Paragraph::new(lines).wrap(Wrap { trim: false }).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Theme::current().error))
+                .title(Span::styled(
+                    format!(" {} ", error.kind.map_or("Error", |kind| kind.title())),
+                    Style::default()
+                        .fg(Theme::current().error)
+                        .add_modifier(Modifier::BOLD),
+                )),
+        );
+
+        frame.render_widget(modal, popup_area);
+    }
+}
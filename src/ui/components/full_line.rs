@@ -0,0 +1,100 @@
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+
+use super::hyperlink;
+use crate::app::FullLine;
+use crate::ui::Theme;
+
+/// Overlay showing a truncated line in full, wrapped to the popup width
+pub struct FullLineView;
+
+impl FullLineView {
+    pub fn render(frame: &mut Frame, full_line: &mut FullLine) {
+        let area = frame.area();
+        let popup_area = Rect::new(
+            area.x + 2,
+            area.y + 1,
+            area.width.saturating_sub(4),
+            area.height.saturating_sub(2),
+        );
+        frame.render_widget(Clear, popup_area);
+
+        let width = popup_area.width.saturating_sub(2) as usize;
+        // The last row holds the line's links, if it has any
+        let link_rows = usize::from(!full_line.links.is_empty());
+        let height = (popup_area.height.saturating_sub(2) as usize).saturating_sub(link_rows);
+
+        let (lines, position) = if full_line.text.is_none() {
+            let waiting = Line::from(Span::styled(
+                " Fetching the full line…",
+                Style::default().fg(Theme::current().fg_dim),
+            ));
+            (vec![waiting], String::new())
+        } else {
+            // Wrapping clamps the scroll, so read it after
+            full_line.rows(width);
+            let scroll = full_line.scroll;
+            let rows = full_line.rows(width);
+            let total = rows.len();
+            let lines = rows
+                .iter()
+                .skip(scroll)
+                .take(height)
+                .map(|row| Line::from(Span::styled(row.clone(), Theme::current().text())))
+                .collect();
+            let last = (scroll + height).min(total);
+            (
+                lines,
+                format!(" {}-{} of {} rows ", scroll + 1, last, total),
+            )
+        };
+
+        let paragraph = Paragraph::new(lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Theme::current().primary))
+                .title(Span::styled(
+                    " Full Line ",
+                    Style::default()
+                        .fg(Theme::current().primary)
+                        .add_modifier(Modifier::BOLD),
+                ))
+                .title_bottom(Span::styled(
+                    format!("{} [j/k/PgUp/PgDn] Scroll  [Esc] Close ", position),
+                    Style::default().fg(Theme::current().fg_dim),
+                )),
+        );
+
+        frame.render_widget(paragraph, popup_area);
+
+        if link_rows > 0 {
+            let row = Rect::new(
+                popup_area.x + 1,
+                popup_area.bottom().saturating_sub(2),
+                popup_area.width.saturating_sub(2),
+                1,
+            );
+            let mut spans = Vec::new();
+            let mut x = row.x;
+            for (label, url) in &full_line.links {
+                let text = format!(" ↗ {} ", label);
+                let link_width = Span::raw(text.as_str()).width() as u16;
+                hyperlink(Rect::new(x, row.y, link_width, 1).intersection(row), url);
+                x += link_width + 1;
+                spans.push(Span::styled(
+                    text,
+                    Style::default()
+                        .fg(Theme::current().highlight)
+                        .add_modifier(Modifier::UNDERLINED),
+                ));
+                spans.push(Span::raw(" "));
+            }
+            frame.render_widget(Paragraph::new(Line::from(spans)), row);
+        }
+    }
+}
@@ -1,21 +1,349 @@
 use ratatui::{
     Frame,
     layout::Rect,
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Clear, Paragraph},
 };
 
-/// Help overlay showing keybindings
+use crate::app::{Action, PromptKind, Screen};
+use crate::config::{KeyBindings, KeyContext};
+use crate::ui::Theme;
+
+/// Width of the key column
+const KEY_WIDTH: usize = 12;
+
+/// A titled group of described actions, looked up in one keybinding context
+struct HelpSection {
+    title: &'static str,
+    context: KeyContext,
+    entries: Vec<(Action, &'static str)>,
+}
+
+impl HelpSection {
+    fn new(title: &'static str, context: KeyContext, entries: Vec<(Action, &'static str)>) -> Self {
+        Self {
+            title,
+            context,
+            entries,
+        }
+    }
+}
+
+/// What each bound action does, grouped the way the help lists them
+fn sections() -> Vec<HelpSection> {
+    use KeyContext::*;
+    vec![
+        HelpSection::new(
+            "Everywhere",
+            Global,
+            vec![
+                (Action::ToggleCommandPalette, "Command palette"),
+                (Action::ToggleHelp, "Toggle this help"),
+                (Action::ToggleNotifications, "Notification history"),
+                (Action::DismissNotification, "Dismiss newest notification"),
+                (Action::ToggleStreamParams, "Stream parameters"),
+                (Action::ToggleUsageStats, "Session stats"),
+                (Action::TogglePerfHud, "Performance HUD"),
+                (Action::GoBack, "Go back"),
+                (Action::Quit, "Quit"),
+            ],
+        ),
+        HelpSection::new(
+            "Selection screens",
+            ListNavigation,
+            vec![
+                (Action::ListDown, "Down"),
+                (Action::ListUp, "Up"),
+                (Action::ListSelect, "Select"),
+                (Action::OpenSearch, "Search"),
+                (Action::Navigate(Screen::RecentSelect), "Recent targets"),
+                (Action::TogglePin, "Pin/unpin"),
+                (Action::CycleDeploymentSort, "Cycle deployment sort"),
+                (
+                    Action::ToggleTerminatingNamespaces,
+                    "Hide/show terminating namespaces",
+                ),
+                (
+                    Action::OpenPrompt(PromptKind::LabelSelector),
+                    "Filter namespaces/deployments by labels",
+                ),
+            ],
+        ),
+        HelpSection::new(
+            "Navigation (less-style)",
+            LogViewer,
+            vec![
+                (Action::ScrollDown(1), "Scroll down"),
+                (Action::ScrollUp(1), "Scroll up"),
+                (Action::PageDown, "Page down"),
+                (Action::PageUp, "Page up"),
+                (Action::ScrollToTop, "Go to top (loads older)"),
+                (Action::ScrollToBottom, "Go to bottom"),
+                (Action::JumpNextError, "Next error"),
+                (Action::JumpPrevError, "Previous error"),
+            ],
+        ),
+        HelpSection::new(
+            "Display",
+            LogViewer,
+            vec![
+                (Action::ToggleAutoScroll, "Toggle follow mode"),
+                (Action::ToggleStreaming, "Stop/resume log streams"),
+                (Action::ToggleTimestamps, "Toggle timestamps"),
+                (Action::ToggleLocalTime, "Toggle local/UTC time"),
+                (Action::ToggleTimeDeltas, "Time since previous line"),
+                (Action::TogglePodNames, "Toggle pod names"),
+                (Action::ToggleLineNumbers, "Buffer ids/pod line numbers"),
+                (Action::CycleTimeMarkers, "Day/hour markers"),
+                (Action::ToggleJsonPrettyPrint, "Toggle JSON pretty print"),
+                (Action::ToggleJsonKeyFilter, "JSON key filter"),
+                (Action::ToggleSchemaDrift, "JSON schema drift report"),
+                (Action::ToggleParseDiagnostics, "Parse diagnostics"),
+                (Action::ToggleJsonFold, "Fold/expand top JSON entry"),
+                (Action::CycleJsonFoldDepth, "Cycle JSON fold depth"),
+                (
+                    Action::ToggleRawView,
+                    "Show raw top line (escaped if sanitized)",
+                ),
+                (Action::FetchFullLine, "Fetch truncated top line in full"),
+                (Action::ToggleStats, "Toggle stats bar"),
+                (Action::ToggleZen, "Zen mode (hide header and bars)"),
+                (
+                    Action::CycleDensity,
+                    "Line density (compact/normal/detailed)",
+                ),
+                (Action::ToggleMinimap, "Toggle level minimap"),
+                (Action::CycleTimeRange, "Next time range"),
+                (Action::CycleTimeRangeBack, "Previous time range"),
+                (Action::TogglePodPanel, "Pod panel (mute pods)"),
+                (Action::WidenSample, "Stream twice as many sampled pods"),
+                (Action::TogglePodLegend, "Pod color legend"),
+                (Action::CycleMinLevel, "Minimum level (trace → error)"),
+                (Action::ToggleNodeNames, "Toggle node names"),
+                (Action::CycleNodeFilter, "Cycle node filter"),
+                (Action::ToggleCompare, "Compare two pods side by side"),
+                (Action::CycleCompare, "Next pod in compare pane"),
+                (Action::ToggleColumns, "Columns (order, width)"),
+            ],
+        ),
+        HelpSection::new(
+            "Search",
+            LogViewer,
+            vec![
+                (Action::OpenSearch, "Search/filter logs"),
+                (Action::OpenFind, "Find (highlight, count matches)"),
+                (Action::FindNext, "Next find match"),
+                (Action::FindPrev, "Previous find match"),
+                (Action::ClearFilter, "Clear filter/find"),
+                (Action::ToggleCaseSensitive, "Toggle case sensitivity"),
+            ],
+        ),
+        HelpSection::new(
+            "Bookmarks",
+            LogViewer,
+            vec![
+                (
+                    Action::ToggleBookmark,
+                    "Bookmark the line at the top of the view (toggle)",
+                ),
+                (
+                    Action::OpenPrompt(PromptKind::AddNote),
+                    "Note on top visible line",
+                ),
+                (Action::ToggleBookmarks, "List/jump to bookmarks"),
+            ],
+        ),
+        HelpSection::new(
+            "Actions",
+            LogViewer,
+            vec![
+                (Action::ClearLogs, "Clear logs (asks first)"),
+                (Action::ExportLogs, "Export logs to file"),
+                (Action::OpenLineInEditor, "Open top line in $EDITOR"),
+                (Action::OpenViewInPager, "Open view in $PAGER"),
+            ],
+        ),
+        HelpSection::new(
+            "Filter input",
+            FilterInput,
+            vec![
+                (Action::ApplyFilter, "Apply"),
+                (Action::CloseSearch, "Cancel"),
+                (Action::SearchBackspace, "Delete character"),
+                (Action::SearchClear, "Clear input"),
+            ],
+        ),
+        HelpSection::new(
+            "Bookmark list",
+            Bookmarks,
+            vec![
+                (Action::BookmarkDown, "Down"),
+                (Action::BookmarkUp, "Up"),
+                (Action::BookmarkJump, "Jump to bookmark"),
+                (Action::BookmarkDelete, "Delete bookmark"),
+                (Action::ToggleBookmarks, "Close"),
+            ],
+        ),
+        HelpSection::new(
+            "Pod panel",
+            PodPanel,
+            vec![
+                (Action::PodPanelDown, "Down"),
+                (Action::PodPanelUp, "Up"),
+                (Action::PodPanelToggleMute, "Mute/unmute pod"),
+                (Action::PodPanelStreamPod, "Add pod to the sample"),
+                (Action::DeleteSelectedPod, "Delete pod (asks first)"),
+                (Action::TogglePodPanel, "Close"),
+            ],
+        ),
+        HelpSection::new(
+            "Columns overlay",
+            Columns,
+            vec![
+                (Action::ColumnDown, "Cursor down"),
+                (Action::ColumnUp, "Cursor up"),
+                (Action::ColumnMoveDown, "Move column down"),
+                (Action::ColumnMoveUp, "Move column up"),
+                (Action::ColumnWiden, "Widen"),
+                (Action::ColumnNarrow, "Narrow"),
+                (Action::ColumnToggleVisible, "Show/hide"),
+                (Action::ToggleColumns, "Close"),
+            ],
+        ),
+        HelpSection::new(
+            "JSON key filter",
+            JsonKeyFilter,
+            vec![
+                (Action::JsonKeyDown, "Down"),
+                (Action::JsonKeyUp, "Up"),
+                (Action::JsonKeyToggle, "Toggle key"),
+                (Action::JsonKeySelectPattern, "Select keys matching search"),
+                (Action::JsonKeySelectAll, "Select all"),
+                (Action::JsonKeyClearAll, "Clear selection"),
+                (Action::JsonKeyToggleExclude, "Show only / hide picked keys"),
+                (Action::JsonKeyBackspace, "Delete character"),
+                (Action::JsonKeyClearSearch, "Clear search"),
+                (Action::ToggleJsonKeyFilter, "Close"),
+            ],
+        ),
+        HelpSection::new(
+            "Schema drift report",
+            SchemaDrift,
+            vec![
+                (Action::SchemaDriftDown, "Scroll down"),
+                (Action::SchemaDriftUp, "Scroll up"),
+                (Action::ToggleSchemaDrift, "Close"),
+            ],
+        ),
+        HelpSection::new(
+            "Parse diagnostics",
+            ParseDiagnostics,
+            vec![
+                (Action::ParseDiagnosticsDown, "Scroll down"),
+                (Action::ParseDiagnosticsUp, "Scroll up"),
+                (Action::ToggleParseDiagnostics, "Close"),
+            ],
+        ),
+        HelpSection::new(
+            "Stream parameters",
+            StreamParams,
+            vec![
+                (Action::StreamParamsDown, "Scroll down"),
+                (Action::StreamParamsUp, "Scroll up"),
+                (Action::ToggleStreamParams, "Close"),
+            ],
+        ),
+        HelpSection::new(
+            "Full line",
+            FullLine,
+            vec![
+                (Action::FullLineScroll(1), "Scroll down"),
+                (Action::FullLineScroll(-1), "Scroll up"),
+                (Action::FullLineScroll(20), "Page down"),
+                (Action::FullLineScroll(-20), "Page up"),
+                (Action::FullLineScroll(isize::MIN), "Top"),
+                (Action::FullLineScroll(isize::MAX), "Bottom"),
+                (Action::CloseFullLine, "Close"),
+            ],
+        ),
+        HelpSection::new(
+            "Command palette",
+            CommandPalette,
+            vec![
+                (Action::PaletteDown, "Down"),
+                (Action::PaletteUp, "Up"),
+                (Action::PaletteSelect, "Run command"),
+                (Action::PaletteBackspace, "Delete character"),
+                (Action::PaletteClose, "Close"),
+            ],
+        ),
+        HelpSection::new(
+            "Error",
+            Error,
+            vec![
+                (Action::DismissError, "Dismiss"),
+                (Action::RetryError, "Retry (when offered)"),
+            ],
+        ),
+        HelpSection::new(
+            "Confirmation",
+            Confirm,
+            vec![
+                (Action::ConfirmAccept, "Confirm"),
+                (Action::ConfirmCancel, "Cancel"),
+            ],
+        ),
+        HelpSection::new(
+            "Notification history",
+            Notifications,
+            vec![(Action::ToggleNotifications, "Close")],
+        ),
+        HelpSection::new(
+            "This help",
+            Help,
+            vec![
+                (Action::HelpDown, "Scroll down"),
+                (Action::HelpUp, "Scroll up"),
+                (Action::HelpPageDown, "Page down"),
+                (Action::HelpPageUp, "Page up"),
+                (Action::ToggleHelp, "Close"),
+            ],
+        ),
+    ]
+}
+
+/// Bound actions that no section describes, per context
+fn undescribed(keybindings: &KeyBindings) -> Vec<(KeyContext, Vec<Action>)> {
+    let sections = sections();
+    KeyContext::ALL
+        .iter()
+        .filter_map(|context| {
+            let mut missing: Vec<Action> = Vec::new();
+            for action in keybindings.actions_in(context) {
+                let described = sections
+                    .iter()
+                    .filter(|s| s.context == *context)
+                    .any(|s| s.entries.iter().any(|(a, _)| a == action));
+                if !described && !missing.contains(action) {
+                    missing.push(action.clone());
+                }
+            }
+            (!missing.is_empty()).then(|| (context.clone(), missing))
+        })
+        .collect()
+}
+
+/// Help overlay showing the keybindings currently in effect
 pub struct HelpOverlay;
 
 impl HelpOverlay {
-    pub fn render(frame: &mut Frame) {
+    pub fn render(frame: &mut Frame, keybindings: &KeyBindings, scroll: &mut u16) {
         let area = frame.area();
 
         // Center the help popup
-        let popup_width = 50.min(area.width.saturating_sub(4));
-        let popup_height = 34.min(area.height.saturating_sub(4));
+        let popup_width = 56.min(area.width.saturating_sub(4));
+        let popup_height = 53.min(area.height.saturating_sub(4));
 
         let x = area.x + (area.width.saturating_sub(popup_width)) / 2;
         let y = area.y + (area.height.saturating_sub(popup_height)) / 2;
@@ -24,77 +352,117 @@ impl HelpOverlay {
         // Clear the background
         frame.render_widget(Clear, popup_area);
 
-        let help_text = vec![
+        let help_text = Self::lines(keybindings);
+
+        // Keep the scroll offset within the content
+        let visible = popup_height.saturating_sub(2) as usize;
+        let max_scroll = help_text.len().saturating_sub(visible) as u16;
+        *scroll = (*scroll).min(max_scroll);
+
+        let mut block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Theme::current().primary))
+            .title(Span::styled(
+                " Help ",
+                Style::default()
+                    .fg(Theme::current().primary)
+                    .add_modifier(Modifier::BOLD),
+            ));
+        if max_scroll > 0 {
+            block = block.title_bottom(Span::styled(
+                format!(" ↑/↓ scroll {}/{} ", *scroll, max_scroll),
+                Style::default().fg(Theme::current().fg_dim),
+            ));
+        }
+
+        let help_widget = Paragraph::new(help_text).block(block).scroll((*scroll, 0));
+
+        frame.render_widget(help_widget, popup_area);
+    }
+
+    /// Help content generated from the keybinding tables
+    fn lines(keybindings: &KeyBindings) -> Vec<Line<'static>> {
+        let mut lines = vec![
             Line::from(Span::styled(
                 "Keybindings",
                 Style::default().add_modifier(Modifier::BOLD),
             )),
             Line::from(""),
-            Line::from(vec![Span::styled(
-                "Navigation (less-style)",
-                Style::default().fg(Color::Yellow),
-            )]),
-            Self::key_line("j/↓/Enter", "Scroll down"),
-            Self::key_line("k/↑", "Scroll up"),
-            Self::key_line("Ctrl+f/d", "Page down"),
-            Self::key_line("Ctrl+b/u", "Page up"),
-            Self::key_line("PgDn/PgUp", "Page down/up"),
-            Self::key_line("g/<", "Go to top"),
-            Self::key_line("G/>", "Go to bottom"),
-            Self::key_line("Home/End", "Top/bottom"),
-            Line::from(""),
-            Line::from(vec![Span::styled(
-                "Display",
-                Style::default().fg(Color::Yellow),
-            )]),
-            Self::key_line("f", "Toggle follow mode"),
-            Self::key_line("t", "Toggle timestamps"),
-            Self::key_line("T", "Toggle local/UTC time"),
-            Self::key_line("p", "Toggle pod names"),
-            Self::key_line("J", "Toggle JSON pretty print"),
-            Self::key_line("K", "JSON key filter"),
-            Self::key_line("s", "Toggle stats bar"),
-            Self::key_line("r/R", "Cycle time range"),
-            Line::from(""),
-            Line::from(vec![Span::styled(
-                "Search",
-                Style::default().fg(Color::Yellow),
-            )]),
-            Self::key_line("/", "Search/filter logs"),
-            Self::key_line("n", "Clear filter"),
-            Self::key_line("i", "Toggle case sensitivity"),
-            Line::from(""),
-            Line::from(vec![Span::styled(
-                "Actions",
-                Style::default().fg(Color::Yellow),
-            )]),
-            Self::key_line("Space", "Command palette"),
-            Self::key_line("c", "Clear logs"),
-            Self::key_line("e", "Export logs to file"),
-            Self::key_line("?", "Toggle this help"),
-            Self::key_line("Esc", "Go back"),
-            Self::key_line("q", "Quit"),
         ];
 
-        let help_widget = Paragraph::new(help_text).block(
-            Block::default()
-                .borders(Borders::ALL)
-                .border_style(Style::default().fg(Color::Cyan))
-                .title(Span::styled(
-                    " Help ",
-                    Style::default()
-                        .fg(Color::Cyan)
-                        .add_modifier(Modifier::BOLD),
-                )),
-        );
+        for section in sections() {
+            let entries: Vec<(String, &str)> = section
+                .entries
+                .iter()
+                .filter_map(|(action, desc)| {
+                    let keys = keybindings.keys_for(&section.context, action);
+                    (!keys.is_empty()).then(|| (keys.join("/"), *desc))
+                })
+                .collect();
+            // Actions whose keys were all unbound drop out, as can whole sections
+            if entries.is_empty() {
+                continue;
+            }
+            lines.push(Self::section_line(section.title));
+            for (keys, desc) in entries {
+                lines.push(Self::key_line(keys, desc.to_string()));
+            }
+            lines.push(Line::from(""));
+        }
 
-        frame.render_widget(help_widget, popup_area);
+        // Anything bound that the sections above don't describe yet
+        for (context, actions) in undescribed(keybindings) {
+            lines.push(Self::section_line(format!("Other ({:?})", context)));
+            for action in actions {
+                let keys = keybindings.keys_for(&context, &action).join("/");
+                lines.push(Self::key_line(keys, format!("{:?}", action)));
+            }
+            lines.push(Line::from(""));
+        }
+
+        lines.pop();
+        lines
     }
 
-    fn key_line<'a>(key: &'a str, desc: &'a str) -> Line<'a> {
+    fn section_line(title: impl Into<String>) -> Line<'static> {
+        Line::from(Span::styled(
+            title.into(),
+            Style::default().fg(Theme::current().highlight),
+        ))
+    }
+
+    fn key_line(key: String, desc: String) -> Line<'static> {
         Line::from(vec![
-            Span::styled(format!("  {:>8}", key), Style::default().fg(Color::Green)),
-            Span::styled(format!("  {}", desc), Style::default().fg(Color::White)),
+            Span::styled(
+                format!("  {:>width$}", key, width = KEY_WIDTH),
+                Style::default().fg(Theme::current().success),
+            ),
+            Span::styled(
+                format!("  {}", desc),
+                Style::default().fg(Theme::current().fg),
+            ),
         ])
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_every_default_binding_is_described() {
+        let keybindings = KeyBindings::new();
+        let missing = undescribed(&keybindings);
+        assert!(missing.is_empty(), "undescribed bindings: {:?}", missing);
+    }
+
+    #[test]
+    fn test_keys_for_merges_and_orders_labels() {
+        let keybindings = KeyBindings::new();
+        let keys = keybindings.keys_for(&KeyContext::LogViewer, &Action::PageDown);
+        assert_eq!(keys, vec!["PgDn", "Ctrl+d", "Ctrl+f"]);
+        // '#' is bound both with and without shift but listed once
+        let keys = keybindings.keys_for(&KeyContext::LogViewer, &Action::ToggleLineNumbers);
+        assert_eq!(keys, vec!["#"]);
+    }
+}
@@ -0,0 +1,36 @@
+use std::cell::RefCell;
+use std::sync::LazyLock;
+
+use ratatui::layout::Rect;
+
+/// Whether the terminal is likely to understand OSC 8 hyperlinks
+///
+/// Most terminals ignore sequences they don't know, but the Linux console and
+/// dumb terminals print them, so links are left out there.
+/// `KUBESCOPE_HYPERLINKS=0` turns them off anywhere.
+static SUPPORTED: LazyLock<bool> = LazyLock::new(|| {
+    if std::env::var("KUBESCOPE_HYPERLINKS").is_ok_and(|v| v == "0") {
+        return false;
+    }
+    !matches!(std::env::var("TERM").as_deref(), Ok("linux" | "dumb"))
+});
+
+thread_local! {
+    static LINKS: RefCell<Vec<(Rect, String)>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Make whatever the frame shows in `area` a link to `url`
+///
+/// ratatui counts escape sequences towards a cell's width, so links can't go
+/// in the buffer; the area is re-printed wrapped in OSC 8 once the frame is
+/// drawn (see `Tui::draw_hyperlinks`).
+pub fn hyperlink(area: Rect, url: &str) {
+    if *SUPPORTED && area.area() > 0 {
+        LINKS.with(|links| links.borrow_mut().push((area, url.to_string())));
+    }
+}
+
+/// The links registered while rendering the last frame
+pub fn take_hyperlinks() -> Vec<(Rect, String)> {
+    LINKS.with(|links| std::mem::take(&mut *links.borrow_mut()))
+}
@@ -1,13 +1,14 @@
 use ratatui::{
     Frame,
     layout::Rect,
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Clear, Paragraph},
 };
 
 use crate::app::AppState;
 use crate::logs::LogBuffer;
+use crate::ui::Theme;
 
 /// JSON key filter overlay - handles high cardinality key sets
 pub struct JsonKeyFilter;
@@ -16,8 +17,8 @@ impl JsonKeyFilter {
     pub fn render(frame: &mut Frame, state: &mut AppState) {
         let area = frame.area();
 
-        // Larger popup for better usability
-        let popup_width = 60.min(area.width.saturating_sub(4));
+        // Larger popup for better usability (wide enough for value previews)
+        let popup_width = 90.min(area.width.saturating_sub(4));
         let popup_height = 30.min(area.height.saturating_sub(4));
 
         let x = area.x + (area.width.saturating_sub(popup_width)) / 2;
@@ -71,15 +72,15 @@ impl JsonKeyFilter {
 
         // Search input line
         let search_line = Line::from(vec![
-            Span::styled(" Search: ", Style::default().fg(Color::Yellow)),
+            Span::styled(" Search: ", Style::default().fg(Theme::current().highlight)),
             Span::styled(
                 &state.ui_state.json_key_search,
-                Style::default().fg(Color::White),
+                Style::default().fg(Theme::current().fg),
             ),
             Span::styled(
                 "█",
                 Style::default()
-                    .fg(Color::Yellow)
+                    .fg(Theme::current().highlight)
                     .add_modifier(Modifier::SLOW_BLINK),
             ),
             Span::styled(
@@ -87,7 +88,7 @@ impl JsonKeyFilter {
                     "  ({}/{} keys, {} selected)",
                     filtered_count, total_keys, selected_count
                 ),
-                Style::default().fg(Color::DarkGray),
+                Style::default().fg(Theme::current().fg_dim),
             ),
         ]);
         lines.push(search_line);
@@ -95,7 +96,7 @@ impl JsonKeyFilter {
         // Separator
         lines.push(Line::from(Span::styled(
             "─".repeat(popup_width.saturating_sub(2) as usize),
-            Style::default().fg(Color::DarkGray),
+            Style::default().fg(Theme::current().fg_dim),
         )));
 
         // Visible keys in viewport
@@ -109,45 +110,65 @@ impl JsonKeyFilter {
         for (viewport_idx, key) in visible_keys {
             let actual_idx = state.ui_state.json_key_scroll + viewport_idx;
             let is_cursor = actual_idx == state.ui_state.json_key_selection;
-            let is_selected = state.ui_state.json_visible_keys.is_empty()
-                || state.ui_state.json_visible_keys.contains(*key);
+            // Checked means shown; in hide mode the picked keys are the ones left out
+            let picked = state.ui_state.json_visible_keys.contains(*key);
+            let is_selected = if state.ui_state.json_keys_exclude {
+                !picked
+            } else {
+                state.ui_state.json_visible_keys.is_empty() || picked
+            };
 
             let checkbox = if is_selected { "[✓]" } else { "[ ]" };
             let cursor = if is_cursor { "▸" } else { " " };
 
             let line_style = if is_cursor {
-                Style::default().bg(Color::DarkGray)
+                Style::default().bg(Theme::current().surface)
             } else {
                 Style::default()
             };
 
             let checkbox_style = if is_selected {
-                Style::default().fg(Color::Green)
+                Style::default().fg(Theme::current().success)
             } else {
-                Style::default().fg(Color::DarkGray)
+                Style::default().fg(Theme::current().fg_dim)
             };
 
             let key_style = if is_cursor {
                 Style::default()
-                    .fg(Color::Cyan)
+                    .fg(Theme::current().primary)
                     .add_modifier(Modifier::BOLD)
             } else {
-                Style::default().fg(Color::Cyan)
+                Style::default().fg(Theme::current().primary)
             };
 
-            // Truncate key if too long
-            let max_key_len = (popup_width as usize).saturating_sub(10);
-            let display_key = if key.len() > max_key_len {
-                format!("{}...", &key[..max_key_len.saturating_sub(3)])
+            // Key column takes up to half the row, share and sample value the rest
+            let row_width = (popup_width as usize).saturating_sub(8);
+            let key_width = (row_width / 2).max(8);
+            let display_key = if key.chars().count() > key_width {
+                let kept: String = key.chars().take(key_width.saturating_sub(1)).collect();
+                format!("{}…", kept)
             } else {
                 (*key).clone()
             };
 
-            lines.push(Line::from(vec![
+            let mut spans = vec![
                 Span::styled(format!(" {}", cursor), line_style),
                 Span::styled(format!("{} ", checkbox), checkbox_style),
-                Span::styled(display_key, key_style),
-            ]));
+                Span::styled(format!("{:<key_width$}", display_key), key_style),
+            ];
+            if let Some(stat) = state.ui_state.json_key_stats.get(*key) {
+                let sample_width = row_width.saturating_sub(key_width + 6);
+                let sample: String = stat.sample.chars().take(sample_width).collect();
+                spans.push(Span::styled(
+                    format!(" {:>3.0}% ", stat.share * 100.0),
+                    Style::default().fg(Theme::current().highlight),
+                ));
+                spans.push(Span::styled(
+                    sample,
+                    Style::default().fg(Theme::current().fg_dim),
+                ));
+            }
+            lines.push(Line::from(spans));
         }
 
         // Pad with empty lines if needed
@@ -165,7 +186,7 @@ impl JsonKeyFilter {
             );
             lines.push(Line::from(Span::styled(
                 scroll_info,
-                Style::default().fg(Color::DarkGray),
+                Style::default().fg(Theme::current().fg_dim),
             )));
         } else {
             lines.push(Line::from(""));
@@ -173,33 +194,40 @@ impl JsonKeyFilter {
 
         // Help text
         lines.push(Line::from(vec![
-            Span::styled(" [Tab]", Style::default().fg(Color::Yellow)),
-            Span::styled("Toggle ", Style::default().fg(Color::DarkGray)),
-            Span::styled("[Enter]", Style::default().fg(Color::Yellow)),
-            Span::styled("Select matching ", Style::default().fg(Color::DarkGray)),
-            Span::styled("[^A]", Style::default().fg(Color::Yellow)),
-            Span::styled("All ", Style::default().fg(Color::DarkGray)),
-            Span::styled("[^X]", Style::default().fg(Color::Yellow)),
-            Span::styled("Clear ", Style::default().fg(Color::DarkGray)),
-            Span::styled("[Esc]", Style::default().fg(Color::Yellow)),
-            Span::styled("Close", Style::default().fg(Color::DarkGray)),
+            Span::styled(" [Tab]", Style::default().fg(Theme::current().highlight)),
+            Span::styled("Toggle ", Style::default().fg(Theme::current().fg_dim)),
+            Span::styled("[Enter]", Style::default().fg(Theme::current().highlight)),
+            Span::styled(
+                "Select matching ",
+                Style::default().fg(Theme::current().fg_dim),
+            ),
+            Span::styled("[^A]", Style::default().fg(Theme::current().highlight)),
+            Span::styled("All ", Style::default().fg(Theme::current().fg_dim)),
+            Span::styled("[^X]", Style::default().fg(Theme::current().highlight)),
+            Span::styled("Clear ", Style::default().fg(Theme::current().fg_dim)),
+            Span::styled("[^E]", Style::default().fg(Theme::current().highlight)),
+            Span::styled("Hide mode ", Style::default().fg(Theme::current().fg_dim)),
+            Span::styled("[Esc]", Style::default().fg(Theme::current().highlight)),
+            Span::styled("Close", Style::default().fg(Theme::current().fg_dim)),
         ]));
 
         // Title with selection status
-        let title = if state.ui_state.json_visible_keys.is_empty() {
-            " JSON Keys (showing all) "
+        let title = if selected_count == 0 {
+            " JSON Keys (showing all) ".to_string()
+        } else if state.ui_state.json_keys_exclude {
+            format!(" JSON Keys (hiding {}) ", selected_count)
         } else {
-            " JSON Keys (filtered) "
+            " JSON Keys (filtered) ".to_string()
         };
 
         let paragraph = Paragraph::new(lines).block(
             Block::default()
                 .borders(Borders::ALL)
-                .border_style(Style::default().fg(Color::Cyan))
+                .border_style(Style::default().fg(Theme::current().primary))
                 .title(Span::styled(
                     title,
                     Style::default()
-                        .fg(Color::Cyan)
+                        .fg(Theme::current().primary)
                         .add_modifier(Modifier::BOLD),
                 )),
         );
@@ -1,6 +1,8 @@
+use chrono::TimeDelta;
 use ratatui::{
     buffer::Buffer,
     layout::Rect,
+    style::Style,
     text::{Line, Span},
     widgets::{Block, Borders, List, ListItem, ListState, StatefulWidget},
 };
@@ -34,9 +36,9 @@ impl<'a> ListSelector<'a> {
             .map(|(text, is_current)| {
                 let text = text.into();
                 let style = if is_current {
-                    Theme::list_item_current()
+                    Theme::current().list_item_current()
                 } else {
-                    Theme::list_item()
+                    Theme::current().list_item()
                 };
 
                 let content = if is_current {
@@ -52,6 +54,19 @@ impl<'a> ListSelector<'a> {
         self
     }
 
+    /// Add items from an iterator of (display_text, style) tuples
+    pub fn styled_items<I, S>(mut self, items: I) -> Self
+    where
+        I: IntoIterator<Item = (S, Style)>,
+        S: Into<String>,
+    {
+        self.items = items
+            .into_iter()
+            .map(|(text, style)| ListItem::new(Line::from(Span::styled(text.into(), style))))
+            .collect();
+        self
+    }
+
     /// Set the highlight symbol
     #[allow(dead_code)]
     pub fn highlight_symbol(mut self, symbol: &'a str) -> Self {
@@ -66,18 +81,34 @@ impl StatefulWidget for ListSelector<'_> {
     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
         let block = Block::default()
             .borders(Borders::ALL)
-            .border_style(Theme::border_focused())
-            .title(Span::styled(self.title, Theme::title()));
+            .border_style(Theme::current().border_focused())
+            .title(Span::styled(self.title, Theme::current().title()));
 
         let list = List::new(self.items)
             .block(block)
-            .highlight_style(Theme::list_item_selected())
+            .highlight_style(Theme::current().list_item_selected())
             .highlight_symbol(self.highlight_symbol);
 
         StatefulWidget::render(list, area, buf, state);
     }
 }
 
+/// Prefix for list items marking pinned entries
+pub fn pin_marker(pinned: bool) -> &'static str {
+    if pinned { "★ " } else { "  " }
+}
+
+/// Compact kubectl-style age ("45s", "12m", "5h", "3d")
+pub fn format_age(age: TimeDelta) -> String {
+    let secs = age.num_seconds().max(0);
+    match secs {
+        0..60 => format!("{}s", secs),
+        60..3600 => format!("{}m", secs / 60),
+        3600..86400 => format!("{}h", secs / 3600),
+        _ => format!("{}d", secs / 86400),
+    }
+}
+
 /// Extension trait to render ListSelector more easily
 pub trait ListSelectorExt {
     fn render_list_selector(&mut self, area: Rect, selector: ListSelector, state: &mut ListState);
@@ -0,0 +1,155 @@
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Color, Style},
+    text::Span,
+};
+
+use crate::types::{ArcLogEntry, LogLevel};
+use crate::ui::Theme;
+
+/// Vertical strip summarizing log levels across the whole filtered buffer
+pub struct Minimap;
+
+impl Minimap {
+    /// Render the minimap into a single-column area
+    ///
+    /// `viewport` is the (first, last) entry index currently on screen, shown as a
+    /// highlighted band so the user can see where they are relative to the clusters.
+    pub fn render(
+        frame: &mut Frame,
+        area: Rect,
+        entries: &[ArcLogEntry],
+        viewport: Option<(usize, usize)>,
+    ) {
+        let rows = area.height as usize;
+        if rows == 0 || entries.is_empty() {
+            return;
+        }
+
+        let cells = minimap_levels(entries, rows);
+        let viewport_rows = viewport.map(|(first, last)| {
+            (
+                first * rows / entries.len(),
+                last.min(entries.len() - 1) * rows / entries.len(),
+            )
+        });
+
+        let buf = frame.buffer_mut();
+        for (row, level) in cells.into_iter().enumerate() {
+            let in_viewport = viewport_rows.is_some_and(|(start, end)| row >= start && row <= end);
+
+            let mut style = match level {
+                Some(level) => Style::default().fg(minimap_color(level)),
+                None => Style::default().fg(Theme::current().fg_dim),
+            };
+            if in_viewport {
+                style = style.bg(Theme::current().surface);
+            }
+
+            let symbol = if level.is_some() { "▐" } else { " " };
+            buf.set_span(area.x, area.y + row as u16, &Span::styled(symbol, style), 1);
+        }
+    }
+}
+
+/// Compute the dominant level for each minimap row
+///
+/// Entries are split into `rows` evenly sized regions. Warnings and errors are
+/// weighted heavily so that a handful of failures stands out against a sea of
+/// info lines, which is what the minimap is for.
+pub fn minimap_levels(entries: &[ArcLogEntry], rows: usize) -> Vec<Option<LogLevel>> {
+    if rows == 0 {
+        return Vec::new();
+    }
+
+    let total = entries.len();
+    (0..rows)
+        .map(|row| {
+            let start = row * total / rows;
+            let end = ((row + 1) * total / rows).max(start + 1).min(total);
+            if start >= total {
+                return None;
+            }
+
+            let mut scores = [0usize; 7];
+            for entry in &entries[start..end] {
+                scores[level_index(entry.level)] += level_weight(entry.level);
+            }
+
+            scores
+                .iter()
+                .enumerate()
+                .filter(|(_, score)| **score > 0)
+                // Ties resolve toward the more severe level
+                .max_by_key(|(idx, score)| (**score, *idx))
+                .map(|(idx, _)| LEVELS[idx])
+        })
+        .collect()
+}
+
+const LEVELS: [LogLevel; 7] = [
+    LogLevel::Unknown,
+    LogLevel::Trace,
+    LogLevel::Debug,
+    LogLevel::Info,
+    LogLevel::Warn,
+    LogLevel::Error,
+    LogLevel::Fatal,
+];
+
+fn level_index(level: LogLevel) -> usize {
+    LEVELS.iter().position(|l| *l == level).unwrap_or(0)
+}
+
+fn level_weight(level: LogLevel) -> usize {
+    match level {
+        LogLevel::Fatal | LogLevel::Error => 20,
+        LogLevel::Warn => 5,
+        _ => 1,
+    }
+}
+
+fn minimap_color(level: LogLevel) -> Color {
+    match level {
+        // Unknown lines are the common plain-text case, keep them quiet
+        LogLevel::Unknown => Theme::current().fg_dim,
+        other => Theme::current().level_color(other),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::LogEntry;
+    use std::sync::Arc;
+
+    fn entry(level: LogLevel) -> ArcLogEntry {
+        let mut e = LogEntry::new("pod".to_string(), 1, "line".to_string());
+        e.level = level;
+        Arc::new(e)
+    }
+
+    #[test]
+    fn test_minimap_highlights_error_clusters() {
+        let mut entries: Vec<ArcLogEntry> = (0..90).map(|_| entry(LogLevel::Info)).collect();
+        // A few errors in the last region should win over the info lines
+        for e in entries.iter_mut().skip(80).take(3) {
+            *e = entry(LogLevel::Error);
+        }
+
+        let cells = minimap_levels(&entries, 9);
+        assert_eq!(cells.len(), 9);
+        assert_eq!(cells[0], Some(LogLevel::Info));
+        assert_eq!(cells[8], Some(LogLevel::Error));
+    }
+
+    #[test]
+    fn test_minimap_more_rows_than_entries() {
+        let entries = vec![entry(LogLevel::Warn), entry(LogLevel::Info)];
+        let cells = minimap_levels(&entries, 4);
+        assert_eq!(cells.len(), 4);
+        assert!(cells.iter().all(|c| c.is_some()));
+        assert_eq!(cells[0], Some(LogLevel::Warn));
+    }
+}
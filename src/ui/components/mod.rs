@@ -1,13 +1,43 @@
 //! Reusable UI components
 
+mod bookmarks;
+mod columns_overlay;
 mod command_palette;
+mod confirm_dialog;
+mod error_modal;
+mod full_line;
 mod help_overlay;
+mod hyperlink;
 mod json_key_filter;
 mod list_selector;
+mod minimap;
+mod notifications;
+mod parse_diagnostics;
+mod perf_hud;
+mod pod_legend;
+mod pod_panel;
+mod schema_drift;
 mod status_bar;
+mod stream_params;
+mod usage_stats;
 
-pub use command_palette::{Command, CommandPalette, CommandPaletteState, log_viewer_commands};
+pub use bookmarks::BookmarkList;
+pub use columns_overlay::ColumnsOverlay;
+pub use command_palette::{Command, CommandPalette, CommandPaletteState, commands_for};
+pub use confirm_dialog::ConfirmDialog;
+pub use error_modal::ErrorModal;
+pub use full_line::FullLineView;
 pub use help_overlay::HelpOverlay;
+pub use hyperlink::{hyperlink, take_hyperlinks};
 pub use json_key_filter::{JsonKeyFilter, collect_json_keys};
-pub use list_selector::{ListSelector, ListSelectorExt};
+pub use list_selector::{ListSelector, ListSelectorExt, format_age, pin_marker};
+pub use minimap::Minimap;
+pub use notifications::{NotificationHistory, Toasts};
+pub use parse_diagnostics::ParseDiagnosticsReport;
+pub use perf_hud::PerfHud;
+pub use pod_legend::PodLegend;
+pub use pod_panel::PodPanel;
+pub use schema_drift::SchemaDriftReport;
 pub use status_bar::{StatusBar, list_nav_hints};
+pub use stream_params::StreamParamsReport;
+pub use usage_stats::UsageReport;
@@ -0,0 +1,117 @@
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+
+use crate::app::{Notifications, Severity};
+use crate::ui::Theme;
+
+/// Widest a toast gets before its message is truncated
+const TOAST_MAX_WIDTH: u16 = 60;
+
+fn severity_color(severity: Severity) -> Color {
+    match severity {
+        Severity::Info => Theme::current().primary,
+        Severity::Success => Theme::current().success,
+        Severity::Warning => Theme::current().warning,
+        Severity::Error => Theme::current().error,
+    }
+}
+
+/// Stacked toasts in the top-right corner, newest at the bottom
+pub struct Toasts;
+
+impl Toasts {
+    pub fn render(frame: &mut Frame, notifications: &Notifications) {
+        let area = frame.area();
+        // Start below the header's top border
+        for (y, notification) in (area.y + 1..).zip(notifications.active()) {
+            if y >= area.bottom().saturating_sub(1) {
+                break;
+            }
+            let color = severity_color(notification.severity);
+            let text = format!(
+                " {} {} ",
+                notification.severity.icon(),
+                notification.message
+            );
+            let max_width = TOAST_MAX_WIDTH.min(area.width.saturating_sub(4));
+            let width = (Span::raw(text.as_str()).width() as u16).min(max_width);
+            let x = area.right().saturating_sub(width + 2);
+            let toast_area = Rect::new(x, y, width, 1);
+
+            frame.render_widget(Clear, toast_area);
+            let toast = Paragraph::new(Line::from(Span::styled(
+                text,
+                Style::default()
+                    .fg(color)
+                    .bg(Theme::current().surface)
+                    .add_modifier(Modifier::BOLD),
+            )));
+            frame.render_widget(toast, toast_area);
+        }
+    }
+}
+
+/// Overlay listing every notification shown this session, newest first
+pub struct NotificationHistory;
+
+impl NotificationHistory {
+    pub fn render(frame: &mut Frame, notifications: &Notifications) {
+        let area = frame.area();
+
+        let popup_width = 80.min(area.width.saturating_sub(4));
+        let popup_height = 20.min(area.height.saturating_sub(4));
+
+        let x = area.x + (area.width.saturating_sub(popup_width)) / 2;
+        let y = area.y + (area.height.saturating_sub(popup_height)) / 2;
+        let popup_area = Rect::new(x, y, popup_width, popup_height);
+
+        frame.render_widget(Clear, popup_area);
+
+        let mut lines: Vec<Line> = notifications
+            .history()
+            .map(|n| {
+                Line::from(vec![
+                    Span::styled(
+                        format!(" {} ", n.at.format("%H:%M:%S")),
+                        Style::default().fg(Theme::current().fg_dim),
+                    ),
+                    Span::styled(
+                        format!("{} ", n.severity.icon()),
+                        Style::default().fg(severity_color(n.severity)),
+                    ),
+                    Span::styled(n.message.clone(), Theme::current().text()),
+                ])
+            })
+            .collect();
+        if lines.is_empty() {
+            lines.push(Line::from(Span::styled(
+                " No notifications yet",
+                Style::default().fg(Theme::current().fg_dim),
+            )));
+        }
+
+        let title = format!(" Notifications ({}) ", notifications.history_len());
+        let paragraph = Paragraph::new(lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Theme::current().primary))
+                .title(Span::styled(
+                    title,
+                    Style::default()
+                        .fg(Theme::current().primary)
+                        .add_modifier(Modifier::BOLD),
+                ))
+                .title_bottom(Span::styled(
+                    " [Esc] Close ",
+                    Style::default().fg(Theme::current().fg_dim),
+                )),
+        );
+
+        frame.render_widget(paragraph, popup_area);
+    }
+}
@@ -0,0 +1,110 @@
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+};
+
+use crate::app::AppState;
+use crate::logs::ParseProblem;
+use crate::ui::Theme;
+
+/// Pods listed per problem
+const TOP_PODS: usize = 5;
+
+/// Overlay summarizing lines that failed to parse or had no level, with samples
+pub struct ParseDiagnosticsReport;
+
+impl ParseDiagnosticsReport {
+    pub fn render(frame: &mut Frame, state: &AppState) {
+        let area = frame.area();
+
+        let popup_width = 90.min(area.width.saturating_sub(4));
+        let popup_height = 28.min(area.height.saturating_sub(4));
+
+        let x = area.x + (area.width.saturating_sub(popup_width)) / 2;
+        let y = area.y + (area.height.saturating_sub(popup_height)) / 2;
+        let popup_area = Rect::new(x, y, popup_width, popup_height);
+
+        frame.render_widget(Clear, popup_area);
+
+        let ui = &state.ui_state;
+        let dim = Style::default().fg(Theme::current().fg_dim);
+
+        let mut lines = Vec::new();
+        if ui.parse_reports.is_empty() {
+            lines.push(Line::from(Span::styled(
+                "  Every line so far parsed and had a level",
+                dim,
+            )));
+        }
+        for report in &ui.parse_reports {
+            let color = match report.problem {
+                ParseProblem::BrokenJson => Theme::current().error,
+                ParseProblem::NoLevel => Theme::current().warning,
+            };
+            if !lines.is_empty() {
+                lines.push(Line::from(""));
+            }
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!(" {}", report.problem.label()),
+                    Style::default().fg(color).add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(format!("  {} lines", report.count), dim),
+            ]));
+
+            let pods: Vec<String> = report
+                .pods
+                .iter()
+                .take(TOP_PODS)
+                .map(|(pod, count)| format!("{} ({})", pod, count))
+                .collect();
+            let more = report.pods.len().saturating_sub(TOP_PODS);
+            let mut pods = pods.join(", ");
+            if more > 0 {
+                pods.push_str(&format!(", +{} more", more));
+            }
+            lines.push(Line::from(vec![
+                Span::styled("  Pods: ", dim),
+                Span::styled(pods, Theme::current().text()),
+            ]));
+
+            for sample in report.samples.iter().rev() {
+                lines.push(Line::from(vec![
+                    Span::styled(format!("  {} ", sample.at.format("%H:%M:%S")), dim),
+                    Span::styled(format!("{}  ", sample.pod), dim),
+                    Span::styled(sample.detail.clone(), Style::default().fg(color)),
+                ]));
+                lines.push(Line::from(Span::styled(
+                    format!("    {}", sample.text),
+                    Theme::current().text(),
+                )));
+            }
+        }
+
+        let total: u64 = ui.parse_reports.iter().map(|r| r.count).sum();
+        let title = format!(" Parse Diagnostics ({}) ", total);
+        let paragraph = Paragraph::new(lines)
+            .wrap(Wrap { trim: false })
+            .scroll((ui.parse_diagnostics_scroll, 0))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Theme::current().primary))
+                    .title(Span::styled(
+                        title,
+                        Style::default()
+                            .fg(Theme::current().primary)
+                            .add_modifier(Modifier::BOLD),
+                    ))
+                    .title_bottom(Span::styled(
+                        " [j/k] Scroll  [Esc] Close ",
+                        Style::default().fg(Theme::current().fg_dim),
+                    )),
+            );
+
+        frame.render_widget(paragraph, popup_area);
+    }
+}
@@ -0,0 +1,110 @@
+use std::time::Duration;
+
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+
+use crate::app::PerfStats;
+use crate::logs::LogBuffer;
+use crate::ui::Theme;
+use crate::ui::screens::format_bytes;
+
+/// Pods listed with their ingest rate
+const MAX_PODS: usize = 5;
+
+/// Performance HUD (F12) - render/filter timings, buffer and ingest numbers
+pub struct PerfHud;
+
+impl PerfHud {
+    pub fn render(frame: &mut Frame, perf: &PerfStats, log_buffer: &LogBuffer) {
+        let area = frame.area();
+
+        let label = |text: &'static str| {
+            Span::styled(
+                format!(" {:<10}", text),
+                Style::default().fg(Theme::current().fg_dim),
+            )
+        };
+        let value = |text: String| Span::styled(text, Style::default().fg(Theme::current().fg));
+
+        let filter = match perf.filter {
+            Some((took, kept)) => format!("{} ({} lines)", millis(took), kept),
+            None => "-".to_string(),
+        };
+        let mut lines = vec![
+            Line::from(vec![
+                label("render"),
+                value(format!(
+                    "{} (max {})",
+                    millis(perf.render),
+                    millis(perf.render_max)
+                )),
+            ]),
+            Line::from(vec![label("filter"), value(filter)]),
+            Line::from(vec![
+                label("buffer"),
+                value(format!(
+                    "{}/{} ({})",
+                    log_buffer.len(),
+                    log_buffer.capacity(),
+                    format_bytes(log_buffer.approx_bytes())
+                )),
+            ]),
+            Line::from(vec![
+                label("queue"),
+                value(format!(
+                    "{}/{} (dropped {})",
+                    perf.queue.0,
+                    perf.queue.1,
+                    log_buffer.dropped_count()
+                )),
+            ]),
+        ];
+
+        let total: f64 = perf.rates.iter().map(|(_, rate)| rate).sum();
+        lines.push(Line::from(vec![
+            label("ingest"),
+            value(format!("{:.0} lines/s", total)),
+        ]));
+        for (pod, rate) in perf.rates.iter().take(MAX_PODS) {
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("   {:>7.0}/s ", rate),
+                    Style::default().fg(Theme::current().primary),
+                ),
+                Span::styled(pod.as_str(), Style::default().fg(Theme::current().fg_dim)),
+            ]));
+        }
+
+        let width = 52.min(area.width.saturating_sub(2));
+        let height = (lines.len() as u16 + 2).min(area.height.saturating_sub(2));
+        // Bottom right, clear of the status bar
+        let x = area.x + area.width.saturating_sub(width + 1);
+        let y = area.y + area.height.saturating_sub(height + 1);
+        let hud_area = Rect::new(x, y, width, height);
+
+        frame.render_widget(Clear, hud_area);
+
+        let hud = Paragraph::new(lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Theme::current().primary))
+                .title(Span::styled(
+                    " Perf ",
+                    Style::default()
+                        .fg(Theme::current().primary)
+                        .add_modifier(Modifier::BOLD),
+                )),
+        );
+
+        frame.render_widget(hud, hud_area);
+    }
+}
+
+fn millis(duration: Duration) -> String {
+    format!("{:.1}ms", duration.as_secs_f64() * 1000.0)
+}
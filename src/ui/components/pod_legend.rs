@@ -0,0 +1,75 @@
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+
+use crate::app::AppState;
+use crate::ui::Theme;
+
+/// Legend overlay mapping pod colors to pod names (top-right corner, non-modal)
+pub struct PodLegend;
+
+impl PodLegend {
+    pub fn render(frame: &mut Frame, state: &AppState) {
+        let area = frame.area();
+
+        let longest = state
+            .pods
+            .iter()
+            .map(|p| p.name.chars().count())
+            .max()
+            .unwrap_or(0);
+        // " ■ " + name + borders
+        let popup_width = (longest as u16 + 5).clamp(20, 60).min(area.width);
+        let popup_height =
+            (state.pods.len() as u16 + 2).clamp(3, area.height.saturating_sub(4).max(3));
+
+        // Below the header, flush with the right edge
+        let x = area.x + area.width.saturating_sub(popup_width + 1);
+        let y = area.y + 3.min(area.height);
+        let popup_area = Rect::new(
+            x,
+            y,
+            popup_width,
+            popup_height.min(area.height.saturating_sub(y)),
+        );
+
+        frame.render_widget(Clear, popup_area);
+
+        let mut lines: Vec<Line> = state
+            .pods
+            .iter()
+            .map(|pod| {
+                let color = state.pod_color(&pod.name);
+                Line::from(vec![
+                    Span::styled(" ■ ", Style::default().fg(color)),
+                    Span::styled(pod.name.clone(), Style::default().fg(color)),
+                ])
+            })
+            .collect();
+
+        if lines.is_empty() {
+            lines.push(Line::from(Span::styled(
+                " No pods",
+                Style::default().fg(Theme::current().fg_dim),
+            )));
+        }
+
+        let paragraph = Paragraph::new(lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Theme::current().primary))
+                .title(Span::styled(
+                    " Pod Colors ",
+                    Style::default()
+                        .fg(Theme::current().primary)
+                        .add_modifier(Modifier::BOLD),
+                )),
+        );
+
+        frame.render_widget(paragraph, popup_area);
+    }
+}
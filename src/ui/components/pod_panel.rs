@@ -0,0 +1,206 @@
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+
+use crate::app::AppState;
+use crate::logs::{Backfill, LogBuffer};
+use crate::types::{PodInfo, PodStatus};
+use crate::ui::Theme;
+
+/// Side panel listing streamed pods - status, restarts, line counts and mute toggles
+pub struct PodPanel;
+
+impl PodPanel {
+    pub fn render(frame: &mut Frame, area: Rect, state: &mut AppState, log_buffer: &LogBuffer) {
+        frame.render_widget(Clear, area);
+
+        let pods = &state.pods;
+        let footer_lines = 2; // Spacer + help text
+        let viewport_height = (area.height as usize).saturating_sub(footer_lines + 3); // borders + column header
+
+        // Clamp selection to valid range
+        if state.ui_state.pod_panel_selection >= pods.len() {
+            state.ui_state.pod_panel_selection = pods.len().saturating_sub(1);
+        }
+        let selection = state.ui_state.pod_panel_selection;
+        let scroll = selection.saturating_sub(viewport_height.saturating_sub(1));
+
+        // cursor(3) + mute(2) + status dot(2) + restarts(5) + lines(8) + borders(2)
+        let name_width = (area.width as usize).saturating_sub(22).max(4);
+
+        let mut lines = vec![Line::from(Span::styled(
+            format!(
+                "       {:<name_width$} {:>4} {:>7}",
+                "POD",
+                "RST",
+                "LINES",
+                name_width = name_width
+            ),
+            Style::default().fg(Theme::current().fg_dim),
+        ))];
+
+        for (idx, pod) in pods.iter().enumerate().skip(scroll).take(viewport_height) {
+            let is_cursor = idx == selection;
+            let muted = state.ui_state.muted_pods.contains(&pod.name);
+            let streamed = state.is_streamed(&pod.name);
+
+            let line_style = if is_cursor {
+                Style::default().bg(Theme::current().surface)
+            } else {
+                Style::default()
+            };
+            let name_style = if muted {
+                Style::default()
+                    .fg(Theme::current().fg_dim)
+                    .add_modifier(Modifier::CROSSED_OUT)
+            } else if !streamed {
+                Style::default().fg(Theme::current().fg_dim)
+            } else {
+                Style::default().fg(state.pod_color(&pod.name))
+            };
+
+            let restarts = restart_count(pod);
+            let restart_style = if restarts > 0 {
+                Theme::current().health_degraded()
+            } else {
+                Style::default().fg(Theme::current().fg_dim)
+            };
+
+            lines.push(Line::from(vec![
+                Span::styled(if is_cursor { " ▸ " } else { "   " }, line_style),
+                Span::styled(
+                    if muted { "🔇" } else { "  " },
+                    Style::default().fg(Theme::current().fg_dim),
+                ),
+                Span::styled("● ", pod_status_style(&pod.status)),
+                Span::styled(
+                    format!(
+                        "{:<name_width$}",
+                        truncate_name(&pod.name, name_width),
+                        name_width = name_width
+                    ),
+                    name_style,
+                ),
+                Span::styled(format!(" {:>4}", restarts), restart_style),
+                if streamed {
+                    lines_cell(state.ui_state.backfill.get(&pod.name), || {
+                        log_buffer.pod_line_count(&pod.name)
+                    })
+                } else {
+                    Span::styled(
+                        format!(" {:>7}", "—"),
+                        Style::default().fg(Theme::current().fg_dim),
+                    )
+                },
+                // Leftovers of the previous version during a rollout
+                Span::styled(
+                    if pod.old_replica_set { "  old" } else { "" },
+                    Theme::current().health_degraded(),
+                ),
+                // Pods from the extra clusters of a multi-cluster view
+                Span::styled(
+                    pod.cluster
+                        .as_ref()
+                        .map(|c| format!("  @{}", c))
+                        .unwrap_or_default(),
+                    Style::default().fg(Theme::current().fg_dim),
+                ),
+            ]));
+        }
+
+        // Pad with empty lines so the help text stays at the bottom
+        while lines.len() < viewport_height + 2 {
+            lines.push(Line::from(""));
+        }
+
+        // Help text
+        let mut help = vec![
+            Span::styled(" [Space]", Style::default().fg(Theme::current().highlight)),
+            Span::styled("Mute ", Style::default().fg(Theme::current().fg_dim)),
+        ];
+        if state.ui_state.sampled_pods.is_some() {
+            help.push(Span::styled(
+                "[a]",
+                Style::default().fg(Theme::current().highlight),
+            ));
+            help.push(Span::styled(
+                "Stream ",
+                Style::default().fg(Theme::current().fg_dim),
+            ));
+        }
+        help.extend([
+            Span::styled("[D]", Style::default().fg(Theme::current().highlight)),
+            Span::styled("Delete ", Style::default().fg(Theme::current().fg_dim)),
+            Span::styled("[Esc]", Style::default().fg(Theme::current().highlight)),
+            Span::styled("Close", Style::default().fg(Theme::current().fg_dim)),
+        ]);
+        lines.push(Line::from(help));
+
+        let title = match &state.ui_state.sampled_pods {
+            Some(sampled) => format!(" Pods ({} of {} streamed) ", sampled.len(), pods.len()),
+            None => format!(" Pods ({}) ", pods.len()),
+        };
+
+        let paragraph = Paragraph::new(lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Theme::current().primary))
+                .title(Span::styled(
+                    title,
+                    Style::default()
+                        .fg(Theme::current().primary)
+                        .add_modifier(Modifier::BOLD),
+                )),
+        );
+
+        frame.render_widget(paragraph, area);
+    }
+}
+
+/// The LINES cell: backfill progress until the pod has caught up, then its line count
+fn lines_cell(backfill: Option<&Backfill>, count: impl Fn() -> u64) -> Span<'static> {
+    let loading = Style::default().fg(Theme::current().warning);
+    match backfill {
+        Some(Backfill::Connecting) => Span::styled(format!(" {:>7}", "⟳"), loading),
+        Some(Backfill::Loading(lines)) => {
+            Span::styled(format!(" {:>7}", format!("⟳{}", lines)), loading)
+        }
+        Some(Backfill::Failed) => Span::styled(
+            format!(" {:>7}", "failed"),
+            Style::default().fg(Theme::current().error),
+        ),
+        Some(Backfill::Done(_)) | None => Span::styled(
+            format!(" {:>7}", count()),
+            Style::default().fg(Theme::current().primary),
+        ),
+    }
+}
+
+/// Total container restarts for a pod
+fn restart_count(pod: &PodInfo) -> i32 {
+    pod.containers.iter().map(|c| c.restart_count).sum()
+}
+
+fn pod_status_style(status: &PodStatus) -> Style {
+    match status {
+        PodStatus::Running => Theme::current().health_ok(),
+        PodStatus::Pending => Theme::current().health_degraded(),
+        PodStatus::Failed => Theme::current().health_down(),
+        PodStatus::Succeeded | PodStatus::Unknown => Theme::current().list_item_dim(),
+    }
+}
+
+/// Keep the distinguishing suffix of long pod names
+fn truncate_name(name: &str, width: usize) -> String {
+    let len = name.chars().count();
+    if len <= width {
+        return name.to_string();
+    }
+    let keep = width.saturating_sub(1);
+    let suffix: String = name.chars().skip(len - keep).collect();
+    format!("…{}", suffix)
+}
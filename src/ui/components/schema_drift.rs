@@ -0,0 +1,115 @@
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+
+use crate::app::AppState;
+use crate::logs::DriftChange;
+use crate::ui::Theme;
+
+/// Overlay listing JSON schema drift (newest first) and every key's types
+pub struct SchemaDriftReport;
+
+impl SchemaDriftReport {
+    pub fn render(frame: &mut Frame, state: &AppState) {
+        let area = frame.area();
+
+        let popup_width = 80.min(area.width.saturating_sub(4));
+        let popup_height = 24.min(area.height.saturating_sub(4));
+
+        let x = area.x + (area.width.saturating_sub(popup_width)) / 2;
+        let y = area.y + (area.height.saturating_sub(popup_height)) / 2;
+        let popup_area = Rect::new(x, y, popup_width, popup_height);
+
+        frame.render_widget(Clear, popup_area);
+
+        let ui = &state.ui_state;
+        let heading = |text: &'static str| {
+            Line::from(Span::styled(
+                text,
+                Style::default()
+                    .fg(Theme::current().highlight)
+                    .add_modifier(Modifier::BOLD),
+            ))
+        };
+
+        let mut lines = vec![heading(" Changes")];
+        if ui.schema_drifts.is_empty() {
+            lines.push(Line::from(Span::styled(
+                "  No drift seen yet",
+                Style::default().fg(Theme::current().fg_dim),
+            )));
+        }
+        for drift in ui.schema_drifts.iter().rev() {
+            let color = match drift.change {
+                DriftChange::TypeChanged { .. } => Theme::current().warning,
+                DriftChange::Disappeared => Theme::current().error,
+            };
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("  {} ", drift.at.format("%H:%M:%S")),
+                    Style::default().fg(Theme::current().fg_dim),
+                ),
+                Span::styled(drift.describe(), Style::default().fg(color)),
+                Span::styled(
+                    format!("  ({})", drift.pod),
+                    Style::default().fg(Theme::current().fg_dim),
+                ),
+            ]));
+        }
+
+        lines.push(Line::from(""));
+        lines.push(heading(" Keys"));
+        for key in &ui.schema_keys {
+            let types: Vec<&str> = key.types.iter().map(|t| t.label()).collect();
+            let types = if types.is_empty() {
+                "null".to_string()
+            } else {
+                types.join(" | ")
+            };
+            let style = if key.missing {
+                Style::default().fg(Theme::current().error)
+            } else if key.types.len() > 1 {
+                Style::default().fg(Theme::current().warning)
+            } else {
+                Theme::current().text()
+            };
+            let mut spans = vec![
+                Span::styled(format!("  {:<30} ", key.key), style),
+                Span::styled(format!("{:<24} ", types), style),
+                Span::styled(
+                    format!("{} lines", key.seen),
+                    Style::default().fg(Theme::current().fg_dim),
+                ),
+            ];
+            if key.missing {
+                spans.push(Span::styled("  gone", style));
+            }
+            lines.push(Line::from(spans));
+        }
+
+        let title = format!(" Schema Drift ({}) ", ui.schema_drifts.len());
+        let paragraph = Paragraph::new(lines)
+            .scroll((ui.schema_drift_scroll, 0))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Theme::current().primary))
+                    .title(Span::styled(
+                        title,
+                        Style::default()
+                            .fg(Theme::current().primary)
+                            .add_modifier(Modifier::BOLD),
+                    ))
+                    .title_bottom(Span::styled(
+                        " [j/k] Scroll  [Esc] Close ",
+                        Style::default().fg(Theme::current().fg_dim),
+                    )),
+            );
+
+        frame.render_widget(paragraph, popup_area);
+    }
+}
@@ -7,9 +7,19 @@ use ratatui::{
 
 use crate::ui::Theme;
 
+/// Gap between hints
+const HINT_GAP: usize = 2;
+
+/// A keyboard hint: (key, description, priority)
+///
+/// Lower priorities are more important; when the bar is too narrow the hints
+/// with the highest priority value are dropped first.
+pub type Hint<'a> = (&'a str, &'a str, u8);
+
 /// Status bar showing keyboard shortcuts
 pub struct StatusBar<'a> {
-    hints: Vec<(&'a str, &'a str)>,
+    hints: Vec<Hint<'a>>,
+    badges: Vec<Span<'a>>,
     right_text: Option<String>,
 }
 
@@ -17,20 +27,27 @@ impl<'a> StatusBar<'a> {
     pub fn new() -> Self {
         Self {
             hints: Vec::new(),
+            badges: Vec::new(),
             right_text: None,
         }
     }
 
-    /// Add keyboard hints as (key, description) pairs
+    /// Add keyboard hints as (key, description, priority) triples
     pub fn hints<I>(mut self, hints: I) -> Self
     where
-        I: IntoIterator<Item = (&'a str, &'a str)>,
+        I: IntoIterator<Item = Hint<'a>>,
     {
         self.hints = hints.into_iter().collect();
         self
     }
 
-    /// Set text to display on the right side
+    /// Add styled badges shown after the hints (kept in preference to hints)
+    pub fn badges(mut self, badges: Vec<Span<'a>>) -> Self {
+        self.badges = badges;
+        self
+    }
+
+    /// Set text to display on the right side (always kept visible)
     pub fn right<S: Into<String>>(mut self, text: S) -> Self {
         self.right_text = Some(text.into());
         self
@@ -46,42 +63,113 @@ impl Default for StatusBar<'_> {
 impl Widget for StatusBar<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
         // Fill background
-        buf.set_style(area, Theme::status_bar());
+        buf.set_style(area, Theme::current().status_bar());
+
+        // One column of margin on each side
+        let inner_width = area.width.saturating_sub(2) as usize;
+
+        // Right text is placed first so it never gets pushed off by hints
+        let right_width = self
+            .right_text
+            .as_ref()
+            .map(|r| Span::raw(r.as_str()).width().min(inner_width))
+            .unwrap_or(0);
+        if let Some(right) = &self.right_text {
+            let right_x = area.x + 1 + (inner_width - right_width) as u16;
+            let right_span = Span::styled(right.as_str(), Theme::current().status_bar());
+            buf.set_span(right_x, area.y, &right_span, right_width as u16);
+        }
+
+        // Whatever is left (minus a gap before the right text) goes to badges, then hints
+        let gap = if right_width > 0 { HINT_GAP } else { 0 };
+        let left_budget = inner_width.saturating_sub(right_width + gap);
+        let badge_width: usize = self.badges.iter().map(|b| b.width() + 1).sum::<usize>();
+        let hint_budget = left_budget.saturating_sub(badge_width);
+        let kept = fit_hints(&self.hints, hint_budget);
 
-        // Build hints
         let mut spans = Vec::new();
-        for (i, (key, desc)) in self.hints.iter().enumerate() {
+        for (i, (key, desc, _)) in kept.iter().enumerate() {
             if i > 0 {
-                spans.push(Span::styled("  ", Theme::status_bar()));
+                spans.push(Span::styled(
+                    " ".repeat(HINT_GAP),
+                    Theme::current().status_bar(),
+                ));
             }
-            spans.push(Span::styled(format!("[{}]", key), Theme::status_bar_key()));
-            spans.push(Span::styled(format!(" {}", desc), Theme::status_bar()));
+            spans.push(Span::styled(
+                format!("[{}]", key),
+                Theme::current().status_bar_key(),
+            ));
+            spans.push(Span::styled(
+                format!(" {}", desc),
+                Theme::current().status_bar(),
+            ));
+        }
+        for badge in self.badges {
+            spans.push(Span::styled(" ", Theme::current().status_bar()));
+            spans.push(badge);
         }
 
+        // Render hints and badges on the left, clipped before the right text
         let line = Line::from(spans);
-        let line_width = line.width() as u16;
+        buf.set_line(area.x + 1, area.y, &line, left_budget as u16);
+    }
+}
 
-        // Render hints on the left
-        buf.set_line(area.x + 1, area.y, &line, area.width.saturating_sub(2));
+/// Display width of a single hint ("[key] desc")
+fn hint_width((key, desc, _): &Hint) -> usize {
+    Span::raw(*key).width() + Span::raw(*desc).width() + 3
+}
 
-        // Render right text if present
-        if let Some(right) = self.right_text {
-            let right_span = Span::styled(&right, Theme::status_bar());
-            let right_x = area.x + area.width.saturating_sub(right.len() as u16 + 2);
-            if right_x > area.x + line_width + 2 {
-                buf.set_span(right_x, area.y, &right_span, right.len() as u16);
-            }
-        }
+/// Drop the least important hints until the rest fit in `width`, keeping display order
+///
+/// Among hints of equal priority, later ones are dropped first.
+fn fit_hints<'a>(hints: &[Hint<'a>], width: usize) -> Vec<Hint<'a>> {
+    let mut kept: Vec<Hint<'a>> = hints.to_vec();
+    let total = |kept: &[Hint]| -> usize {
+        let hints: usize = kept.iter().map(hint_width).sum();
+        hints + HINT_GAP * kept.len().saturating_sub(1)
+    };
+
+    while !kept.is_empty() && total(&kept) > width {
+        let (drop_idx, _) = kept
+            .iter()
+            .enumerate()
+            .max_by_key(|(idx, (_, _, priority))| (*priority, *idx))
+            .expect("kept is non-empty");
+        kept.remove(drop_idx);
     }
+    kept
 }
 
 /// Default hints for list navigation screens
-pub fn list_nav_hints() -> Vec<(&'static str, &'static str)> {
+pub fn list_nav_hints() -> Vec<Hint<'static>> {
     vec![
-        ("↑/k", "Up"),
-        ("↓/j", "Down"),
-        ("Enter", "Select"),
-        ("Esc", "Back"),
-        ("q", "Quit"),
+        ("↑/k", "Up", 3),
+        ("↓/j", "Down", 3),
+        ("Enter", "Select", 0),
+        ("p", "Pin", 4),
+        ("r", "Recent", 4),
+        ("Esc", "Back", 1),
+        ("q", "Quit", 2),
     ]
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fit_hints_drops_least_important_first() {
+        let hints = [("a", "One", 0), ("b", "Two", 2), ("c", "Three", 1)];
+        // Everything fits: "[a] One  [b] Two  [c] Three"
+        assert_eq!(fit_hints(&hints, 27).len(), 3);
+
+        let kept = fit_hints(&hints, 20);
+        assert_eq!(kept, vec![("a", "One", 0), ("c", "Three", 1)]);
+
+        let kept = fit_hints(&hints, 8);
+        assert_eq!(kept, vec![("a", "One", 0)]);
+
+        assert!(fit_hints(&hints, 3).is_empty());
+    }
+}
@@ -0,0 +1,85 @@
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+
+use crate::logs::StreamParams;
+use crate::ui::Theme;
+
+/// Stream parameters overlay (F10) - what each pod's log was requested with
+pub struct StreamParamsReport;
+
+impl StreamParamsReport {
+    pub fn render(frame: &mut Frame, streams: &[StreamParams], scroll: &mut u16) {
+        let area = frame.area();
+
+        let mut lines: Vec<Line> = streams
+            .iter()
+            .map(|stream| {
+                let container = stream.params.container.as_deref().unwrap_or("-");
+                let mut name = Style::default().fg(Theme::current().fg);
+                if stream.sidecar {
+                    name = name.add_modifier(Modifier::ITALIC);
+                }
+                Line::from(vec![
+                    Span::styled(format!(" {}/{}", stream.pod, container), name),
+                    Span::styled(
+                        format!("  {}", stream.summary()),
+                        Style::default().fg(Theme::current().fg_dim),
+                    ),
+                ])
+            })
+            .collect();
+        if lines.is_empty() {
+            lines.push(Line::from(Span::styled(
+                " No log streams open",
+                Style::default().fg(Theme::current().fg_dim),
+            )));
+        }
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            " tailLines is per pod; \"Set Tail Lines…\" (t:) changes it",
+            Style::default().fg(Theme::current().fg_dim),
+        )));
+
+        let width = 96.min(area.width.saturating_sub(4));
+        let height = (lines.len() as u16 + 2).min(area.height.saturating_sub(4));
+        let x = area.x + (area.width.saturating_sub(width)) / 2;
+        let y = area.y + (area.height.saturating_sub(height)) / 2;
+        let popup_area = Rect::new(x, y, width, height);
+
+        frame.render_widget(Clear, popup_area);
+
+        // Keep the scroll offset within the rows, and say how many are out of view
+        let visible = height.saturating_sub(2) as usize;
+        let max_scroll = lines.len().saturating_sub(visible) as u16;
+        *scroll = (*scroll).min(max_scroll);
+        let hidden = lines.len().saturating_sub(visible + *scroll as usize);
+        let footer = match hidden {
+            _ if max_scroll == 0 => " [F10] Close ".to_string(),
+            0 => " ↑/↓ scroll  [F10] Close ".to_string(),
+            hidden => format!(" ↑/↓ scroll, {} more below  [F10] Close ", hidden),
+        };
+
+        let report = Paragraph::new(lines).scroll((*scroll, 0)).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Theme::current().primary))
+                .title(Span::styled(
+                    format!(" Stream Parameters ({}) ", streams.len()),
+                    Style::default()
+                        .fg(Theme::current().primary)
+                        .add_modifier(Modifier::BOLD),
+                ))
+                .title_bottom(Span::styled(
+                    footer,
+                    Style::default().fg(Theme::current().fg_dim),
+                )),
+        );
+
+        frame.render_widget(report, popup_area);
+    }
+}
@@ -0,0 +1,59 @@
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+};
+
+use crate::app::UsageStats;
+use crate::ui::Theme;
+
+/// Session stats overlay (F11) - what this session saw, for incident notes
+pub struct UsageReport;
+
+impl UsageReport {
+    pub fn render(frame: &mut Frame, usage: &UsageStats) {
+        let area = frame.area();
+
+        let lines: Vec<Line> = usage
+            .rows()
+            .into_iter()
+            .map(|(label, value)| {
+                Line::from(vec![
+                    Span::styled(
+                        format!(" {:<10}", label),
+                        Style::default().fg(Theme::current().fg_dim),
+                    ),
+                    Span::styled(value, Style::default().fg(Theme::current().fg)),
+                ])
+            })
+            .collect();
+
+        let width = 64.min(area.width.saturating_sub(4));
+        let height = (lines.len() as u16 + 4).min(area.height.saturating_sub(4));
+        let x = area.x + (area.width.saturating_sub(width)) / 2;
+        let y = area.y + (area.height.saturating_sub(height)) / 2;
+        let popup_area = Rect::new(x, y, width, height);
+
+        frame.render_widget(Clear, popup_area);
+
+        let report = Paragraph::new(lines).wrap(Wrap { trim: false }).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Theme::current().primary))
+                .title(Span::styled(
+                    " Session Stats ",
+                    Style::default()
+                        .fg(Theme::current().primary)
+                        .add_modifier(Modifier::BOLD),
+                ))
+                .title_bottom(Span::styled(
+                    " Local only  [F11] Close ",
+                    Style::default().fg(Theme::current().fg_dim),
+                )),
+        );
+
+        frame.render_widget(report, popup_area);
+    }
+}
@@ -6,4 +6,4 @@ pub mod screens;
 mod theme;
 
 pub use layout::Layout;
-pub use theme::Theme;
+pub use theme::{Theme, ThemeName, assign_pod_colors, hashed_pod_color};
@@ -0,0 +1,145 @@
+use ratatui::{
+    Frame,
+    layout::Rect,
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Wrap},
+};
+
+use crate::{
+    app::AppState,
+    setup::SetupReport,
+    ui::{Layout, Theme, components::StatusBar},
+};
+
+/// Splash screen shown while the kubeconfig and CLI target load
+pub struct ConnectingScreen;
+
+impl ConnectingScreen {
+    pub fn render(frame: &mut Frame, state: &AppState) {
+        let area = frame.area();
+        let (header_area, content_area, status_area) = Layout::main(area);
+
+        Self::render_header(frame, header_area, state.ui_state.setup.is_some());
+        match &state.ui_state.setup {
+            Some(report) => Self::render_setup(frame, content_area, report),
+            None => Self::render_steps(frame, content_area, state),
+        }
+
+        let status = StatusBar::new().hints([("q", "Quit", 0)]);
+        frame.render_widget(status, status_area);
+    }
+
+    fn render_header(frame: &mut Frame, area: Rect, setup: bool) {
+        let title = Line::from(vec![
+            Span::styled("kubescope", Theme::current().title()),
+            Span::styled(" │ ", Theme::current().text_dim()),
+            Span::styled(
+                if setup { "Setup" } else { "Connecting" },
+                Theme::current().text(),
+            ),
+        ]);
+
+        let header = Paragraph::new(title).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Theme::current().border()),
+        );
+
+        frame.render_widget(header, area);
+    }
+
+    /// Finished steps, then the running one or the failure
+    fn render_steps(frame: &mut Frame, area: Rect, state: &AppState) {
+        let ui = &state.ui_state;
+        let mut lines: Vec<Line> = ui
+            .startup_steps
+            .iter()
+            .map(|step| {
+                Line::from(vec![
+                    Span::styled("✓ ", Theme::current().health_ok()),
+                    Span::styled(step.as_str(), Theme::current().text_dim()),
+                ])
+            })
+            .collect();
+
+        if let Some(loading) = &ui.loading {
+            lines.push(Line::from(Span::styled(
+                loading.display(),
+                Theme::current().text(),
+            )));
+        }
+        if let Some(error) = &ui.startup_error {
+            lines.push(Line::from(vec![
+                Span::styled("✗ ", Theme::current().health_down()),
+                Span::styled(error.as_str(), Theme::current().text()),
+            ]));
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled(
+                "Press q to quit",
+                Theme::current().text_dim(),
+            )));
+        }
+
+        let steps = Paragraph::new(lines).wrap(Wrap { trim: false });
+        frame.render_widget(steps, Layout::centered_list(area, 60));
+    }
+
+    /// First-run guide: what was checked and how to get connected
+    fn render_setup(frame: &mut Frame, area: Rect, report: &SetupReport) {
+        let check = |ok: bool| {
+            if ok {
+                Span::styled("✓ ", Theme::current().health_ok())
+            } else {
+                Span::styled("✗ ", Theme::current().health_down())
+            }
+        };
+        let heading = |text: &'static str| Line::from(Span::styled(text, Theme::current().title()));
+
+        let mut lines = vec![
+            Line::from(vec![
+                check(false),
+                Span::styled(report.problem.as_str(), Theme::current().text()),
+            ]),
+            Line::from(""),
+            heading("Kubeconfig files checked"),
+        ];
+        for (path, exists) in &report.paths {
+            lines.push(Line::from(vec![
+                Span::raw("  "),
+                check(*exists),
+                Span::styled(path.display().to_string(), Theme::current().text()),
+            ]));
+        }
+        if !report.plugins.is_empty() {
+            lines.push(Line::from(""));
+            lines.push(heading("Credential plugins"));
+            for (name, found) in &report.plugins {
+                lines.push(Line::from(vec![
+                    Span::raw("  "),
+                    check(*found),
+                    Span::styled(name.as_str(), Theme::current().text()),
+                    Span::styled(
+                        if *found { "" } else { "  (not on PATH)" },
+                        Theme::current().text_dim(),
+                    ),
+                ]));
+            }
+        }
+        lines.push(Line::from(""));
+        lines.push(heading("Next steps"));
+        for (i, step) in report.steps.iter().enumerate() {
+            lines.push(Line::from(vec![
+                Span::styled(format!("  {}. ", i + 1), Theme::current().text_dim()),
+                Span::styled(step.as_str(), Theme::current().text()),
+            ]));
+        }
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            "Press q to quit",
+            Theme::current().text_dim(),
+        )));
+
+        let guide = Paragraph::new(lines).wrap(Wrap { trim: false });
+        frame.render_widget(guide, Layout::centered_list(area, 80));
+    }
+}
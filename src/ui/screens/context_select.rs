@@ -1,6 +1,7 @@
 use ratatui::{
     Frame,
     layout::Rect,
+    style::Style,
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph},
 };
@@ -9,7 +10,7 @@ use crate::{
     app::AppState,
     ui::{
         Layout, Theme,
-        components::{ListSelector, ListSelectorExt, StatusBar, list_nav_hints},
+        components::{ListSelector, ListSelectorExt, StatusBar, list_nav_hints, pin_marker},
     },
 };
 
@@ -33,15 +34,15 @@ impl ContextSelectScreen {
 
     fn render_header(frame: &mut Frame, area: Rect) {
         let title = Line::from(vec![
-            Span::styled("kubescope", Theme::title()),
-            Span::styled(" │ ", Theme::text_dim()),
-            Span::styled("Select Context", Theme::text()),
+            Span::styled("kubescope", Theme::current().title()),
+            Span::styled(" │ ", Theme::current().text_dim()),
+            Span::styled("Select Context", Theme::current().text()),
         ]);
 
         let header = Paragraph::new(title).block(
             Block::default()
                 .borders(Borders::ALL)
-                .border_style(Theme::border()),
+                .border_style(Theme::current().border()),
         );
 
         frame.render_widget(header, area);
@@ -50,20 +51,33 @@ impl ContextSelectScreen {
     fn render_list(frame: &mut Frame, area: Rect, state: &mut AppState) {
         let list_area = Layout::centered_list(area, 80);
 
-        let items: Vec<(String, bool)> = state
+        let items: Vec<(String, Style)> = state
             .contexts
             .iter()
             .map(|ctx| {
-                let display = if let Some(ns) = &ctx.namespace {
-                    format!("{} (namespace: {})", ctx.name, ns)
+                let pinned = state.pinned.contexts.contains(&ctx.name);
+                // Current context gets its own marker unless a pin star is already there
+                let marker = if ctx.is_current && !pinned {
+                    "● "
                 } else {
-                    ctx.name.clone()
+                    pin_marker(pinned)
                 };
-                (display, ctx.is_current)
+                let mut display = if let Some(ns) = &ctx.namespace {
+                    format!("{}{} (namespace: {})", marker, ctx.name, ns)
+                } else {
+                    format!("{}{}", marker, ctx.name)
+                };
+                let style = if ctx.is_current {
+                    display.push_str(" (current)");
+                    Theme::current().list_item_current()
+                } else {
+                    Theme::current().list_item()
+                };
+                (display, style)
             })
             .collect();
 
-        let selector = ListSelector::new(" Kubernetes Contexts ").items(items);
+        let selector = ListSelector::new(" Kubernetes Contexts ").styled_items(items);
 
         frame.render_list_selector(list_area, selector, &mut state.ui_state.list_state);
     }
@@ -73,7 +87,7 @@ impl ContextSelectScreen {
 
         let status = StatusBar::new()
             .hints(list_nav_hints())
-            .right(context_count);
+            .right(state.status_text(context_count));
 
         frame.render_widget(status, area);
     }
@@ -1,15 +1,20 @@
+use chrono::Utc;
 use ratatui::{
     Frame,
     layout::Rect,
+    style::Style,
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph},
 };
 
 use crate::{
     app::AppState,
+    types::{DeploymentInfo, DeploymentSort},
     ui::{
         Layout, Theme,
-        components::{ListSelector, ListSelectorExt, StatusBar, list_nav_hints},
+        components::{
+            ListSelector, ListSelectorExt, StatusBar, format_age, list_nav_hints, pin_marker,
+        },
     },
 };
 
@@ -36,19 +41,19 @@ impl DeploymentSelectScreen {
         let namespace = state.selected_namespace.as_deref().unwrap_or("unknown");
 
         let title = Line::from(vec![
-            Span::styled("kubescope", Theme::title()),
-            Span::styled(" │ ", Theme::text_dim()),
-            Span::styled(context_name, Theme::text()),
-            Span::styled(" │ ", Theme::text_dim()),
-            Span::styled(namespace, Theme::text_highlight()),
-            Span::styled(" │ ", Theme::text_dim()),
-            Span::styled("Select Deployment", Theme::text()),
+            Span::styled("kubescope", Theme::current().title()),
+            Span::styled(" │ ", Theme::current().text_dim()),
+            Span::styled(context_name, Theme::current().text()),
+            Span::styled(" │ ", Theme::current().text_dim()),
+            Span::styled(namespace, Theme::current().text_highlight()),
+            Span::styled(" │ ", Theme::current().text_dim()),
+            Span::styled("Select Deployment", Theme::current().text()),
         ]);
 
         let header = Paragraph::new(title).block(
             Block::default()
                 .borders(Borders::ALL)
-                .border_style(Theme::border()),
+                .border_style(Theme::current().border()),
         );
 
         frame.render_widget(header, area);
@@ -56,31 +61,74 @@ impl DeploymentSelectScreen {
 
     fn render_list(frame: &mut Frame, area: Rect, state: &mut AppState) {
         let list_area = Layout::centered_list(area, 80);
+        let sort = state.ui_state.deployment_sort;
+        let now = Utc::now();
 
-        let items: Vec<(String, bool)> = state
+        let items: Vec<(String, Style)> = state
             .deployments
             .iter()
             .map(|deploy| {
-                let display = format!(
-                    "{} ({}/{})",
-                    deploy.name, deploy.ready_replicas, deploy.replicas
+                let mut display = format!(
+                    "{}{} ({}/{})",
+                    pin_marker(state.pinned.deployments.contains(&deploy.name)),
+                    deploy.name,
+                    deploy.ready_replicas,
+                    deploy.replicas
                 );
-                // Highlight if all replicas are ready
-                let is_healthy = deploy.ready_replicas == deploy.replicas && deploy.replicas > 0;
-                (display, is_healthy)
+                // Show age when it's what the list is sorted by
+                if sort == DeploymentSort::Age
+                    && let Some(created) = deploy.created_at
+                {
+                    display.push_str(&format!(" {}", format_age(now - created)));
+                }
+                (display, deployment_style(deploy))
             })
             .collect();
 
-        let selector = ListSelector::new(" Deployments ").items(items);
+        let title = match &state.ui_state.deployment_selector {
+            Some(selector) => format!(
+                " Deployments (sort: {}, {}) ",
+                sort.label(),
+                selector.as_str()
+            ),
+            None => format!(" Deployments (sort: {}) ", sort.label()),
+        };
+        let selector = ListSelector::new(&title).styled_items(items);
 
         frame.render_list_selector(list_area, selector, &mut state.ui_state.list_state);
     }
 
     fn render_status_bar(frame: &mut Frame, area: Rect, state: &AppState) {
-        let deploy_count = format!("{} deployments", state.deployments.len());
-
-        let status = StatusBar::new().hints(list_nav_hints()).right(deploy_count);
+        let shown = state.deployments.len();
+        let total = state.all_deployments.len();
+        let deploy_count = if shown == total {
+            format!("{} deployments", total)
+        } else {
+            format!("{} of {} deployments", shown, total)
+        };
+
+        let mut hints = list_nav_hints();
+        hints.insert(3, ("s", "Sort", 4));
+        hints.insert(4, (":", "Labels", 4));
+
+        let status = StatusBar::new()
+            .hints(hints)
+            .right(state.status_text(deploy_count));
 
         frame.render_widget(status, area);
     }
 }
+
+/// Color a deployment by how many of its desired replicas are ready
+fn deployment_style(deploy: &DeploymentInfo) -> Style {
+    if deploy.replicas == 0 {
+        // Scaled to zero - nothing to be unhealthy about
+        Theme::current().list_item_dim()
+    } else if deploy.ready_replicas >= deploy.replicas {
+        Theme::current().health_ok()
+    } else if deploy.ready_replicas > 0 {
+        Theme::current().health_degraded()
+    } else {
+        Theme::current().health_down()
+    }
+}
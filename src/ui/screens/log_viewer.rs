@@ -1,31 +1,34 @@
-use chrono::Local;
+use chrono::{DateTime, Local, Timelike, Utc};
 use ratatui::{
     Frame,
-    layout::{Constraint, Direction, Layout as RatatuiLayout, Rect},
-    style::{Color, Modifier, Style},
+    layout::{Constraint, Direction, Layout as RatatuiLayout, Margin, Rect},
+    style::{Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState},
+    widgets::{Block, Borders, Clear, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState},
 };
+use std::sync::Arc;
 
-use crate::app::AppState;
-use crate::logs::LogBuffer;
-use crate::types::{ArcLogEntry, LogEntry, LogLevel};
+use crate::app::{AppState, JsonFold, SearchMode, Severity, TimeMarkers};
+use crate::config::{Column, Density};
+use crate::logs::{JsonFormat, LogBuffer};
+use crate::types::{ArcLogEntry, LogEntry, LogLevel, PodStatus, SourceKind};
 use crate::ui::Theme;
+use crate::ui::components::{Minimap, PodPanel, StatusBar, hyperlink};
 
 /// Log viewer screen
 pub struct LogViewerScreen;
 
-/// Safely slice a string from a byte position, finding the nearest valid UTF-8 boundary
-fn safe_slice_from(s: &str, byte_pos: usize) -> &str {
-    if byte_pos >= s.len() {
-        return "";
-    }
-    // Find the next valid char boundary at or after byte_pos
-    let mut pos = byte_pos;
-    while pos < s.len() && !s.is_char_boundary(pos) {
-        pos += 1;
-    }
-    &s[pos..]
+/// Width below which the viewer switches to a compact layout: pod, node and
+/// timestamp columns are hidden and the header is split over two lines
+const NARROW_WIDTH: u16 = 90;
+
+/// Index of the first entry at or after `at` (entries are in arrival order, so
+/// timestamps are only roughly sorted; the first match is good enough to align on)
+fn aligned_row(entries: &[ArcLogEntry], at: DateTime<Utc>) -> usize {
+    entries
+        .iter()
+        .position(|e| e.timestamp.is_some_and(|ts| ts >= at))
+        .unwrap_or(entries.len())
 }
 
 /// Safely truncate a string to a maximum byte length, finding the nearest valid UTF-8 boundary
@@ -42,23 +45,31 @@ fn safe_truncate(s: &str, max_bytes: usize) -> &str {
 }
 
 impl LogViewerScreen {
-    pub fn render(
-        frame: &mut Frame,
-        state: &mut AppState,
-        log_buffer: &LogBuffer,
-        dropped_count: u64,
-    ) {
+    pub fn render(frame: &mut Frame, state: &mut AppState, log_buffer: &LogBuffer) {
         let area = frame.area();
 
         // Determine if we need the filter bar
         let show_filter_bar = state.ui_state.search_active
             || state.ui_state.active_filter.is_some()
+            || state.ui_state.min_level != LogLevel::Trace
+            || state.ui_state.find.query.is_some()
             || state.ui_state.filter_error.is_some();
 
-        // Build constraints based on what's visible
-        let mut constraints = vec![Constraint::Length(3)]; // Header always
+        let narrow = area.width < NARROW_WIDTH;
+
+        // Zen mode keeps only the logs and status bar, and the filter bar while typing
+        let zen = state.ui_state.zen;
+        let show_header = !zen;
+        let show_stats = state.ui_state.stats_visible && !zen;
+        let show_filter_bar = show_filter_bar && (!zen || state.ui_state.search_active);
 
-        if state.ui_state.stats_visible {
+        // Build constraints based on what's visible
+        let header_height = if narrow { 4 } else { 3 };
+        let mut constraints = Vec::new();
+        if show_header {
+            constraints.push(Constraint::Length(header_height));
+        }
+        if show_stats {
             constraints.push(Constraint::Length(3)); // Stats bar
         }
         if show_filter_bar {
@@ -75,11 +86,13 @@ impl LogViewerScreen {
         let mut idx = 0;
 
         // Header
-        Self::render_header(frame, chunks[idx], state);
-        idx += 1;
+        if show_header {
+            Self::render_header(frame, chunks[idx], state, narrow);
+            idx += 1;
+        }
 
         // Stats bar (if visible)
-        if state.ui_state.stats_visible {
+        if show_stats {
             Self::render_stats_bar(frame, chunks[idx], log_buffer);
             idx += 1;
         }
@@ -90,44 +103,163 @@ impl LogViewerScreen {
             idx += 1;
         }
 
-        // Logs
-        Self::render_logs(frame, chunks[idx], state, log_buffer);
+        // Logs, with the pod panel beside them when open
+        if state.ui_state.pod_panel_visible {
+            let panel_width = 44.min(chunks[idx].width / 2);
+            let split = RatatuiLayout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Min(1), Constraint::Length(panel_width)])
+                .split(chunks[idx]);
+            Self::render_logs(frame, split[0], state, log_buffer);
+            PodPanel::render(frame, split[1], state, log_buffer);
+        } else {
+            Self::render_logs(frame, chunks[idx], state, log_buffer);
+        }
         idx += 1;
 
         // Status bar
-        Self::render_status_bar(frame, chunks[idx], state, log_buffer, dropped_count);
+        Self::render_status_bar(frame, chunks[idx], state, log_buffer);
+
+        if state.ui_state.idle {
+            Self::render_idle_banner(frame, area);
+        }
+    }
+
+    /// Banner shown while the streams are stopped for inactivity
+    fn render_idle_banner(frame: &mut Frame, area: Rect) {
+        let text = " Idle — log streams stopped, press any key to resume ";
+        let width = (text.chars().count() as u16 + 2).min(area.width);
+        let banner = Rect::new(
+            area.x + area.width.saturating_sub(width) / 2,
+            area.y + area.height.saturating_sub(3) / 2,
+            width,
+            3.min(area.height),
+        );
+        frame.render_widget(Clear, banner);
+        frame.render_widget(
+            Paragraph::new(Span::styled(
+                text,
+                Style::default()
+                    .fg(Theme::current().highlight)
+                    .add_modifier(Modifier::BOLD),
+            ))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Theme::current().highlight)),
+            ),
+            banner,
+        );
     }
 
-    fn render_header(frame: &mut Frame, area: Rect, state: &AppState) {
+    fn render_header(frame: &mut Frame, area: Rect, state: &AppState, narrow: bool) {
         let context_name = state.selected_context.as_deref().unwrap_or("?");
         let namespace = state.selected_namespace.as_deref().unwrap_or("?");
         let deployment = state.selected_deployment.as_deref().unwrap_or("?");
-        let pod_count = state.pods.len();
-        let time_range = state.ui_state.time_range.label();
-
-        let title = Line::from(vec![
-            Span::styled("kubescope", Theme::title()),
-            Span::styled(" │ ", Theme::text_dim()),
-            Span::styled(context_name, Theme::text()),
-            Span::styled(" │ ", Theme::text_dim()),
-            Span::styled(namespace, Theme::text()),
-            Span::styled(" │ ", Theme::text_dim()),
-            Span::styled(deployment, Theme::text_highlight()),
-            Span::styled(" │ ", Theme::text_dim()),
-            Span::styled(format!("{} pods", pod_count), Theme::text()),
-            Span::styled(" │ ", Theme::text_dim()),
-            Span::styled(
-                format!("⏱ {}", time_range),
+        let running = state
+            .pods
+            .iter()
+            .filter(|p| p.status == PodStatus::Running)
+            .count();
+        let mut pod_summary = format!("{}/{} pods running", running, state.pods.len());
+        if !state.ui_state.muted_pods.is_empty() {
+            pod_summary.push_str(&format!(" · {} muted", state.ui_state.muted_pods.len()));
+        }
+        let pod_style = if running < state.pods.len() {
+            Theme::current().health_degraded()
+        } else {
+            Theme::current().text()
+        };
+        let time_range = if state.ui_state.time_window.is_set() {
+            state
+                .ui_state
+                .time_window
+                .label(state.ui_state.use_local_time)
+        } else {
+            state.ui_state.time_range.label().to_string()
+        };
+        if let Some(node) = &state.ui_state.node_filter {
+            pod_summary.push_str(&format!(" · node {}", short_node_name(node)));
+        }
+        let mut pod_spans = vec![Span::styled(pod_summary, pod_style)];
+        // A sample must never pass for the whole deployment
+        if let (Some(sampled), Some(sampling)) = (&state.ui_state.sampled_pods, &state.sampling) {
+            pod_spans.push(Span::styled(
+                format!(
+                    " · streaming {} ({} sample)",
+                    sampled.len(),
+                    sampling.mode.label()
+                ),
                 Style::default()
-                    .fg(Color::Cyan)
+                    .fg(Theme::current().warning)
                     .add_modifier(Modifier::BOLD),
-            ),
-        ]);
+            ));
+        }
+
+        let separator = Span::styled(" │ ", Theme::current().text_dim());
+        let time_span = Span::styled(
+            format!("⏱ {}", time_range),
+            Style::default()
+                .fg(Theme::current().primary)
+                .add_modifier(Modifier::BOLD),
+        );
+
+        // Narrow terminals get the deployment on the first line and its location below
+        let title = if narrow {
+            let mut location = vec![
+                Span::styled(
+                    format!("{} / {}", context_name, namespace),
+                    Theme::current().text(),
+                ),
+                separator.clone(),
+            ];
+            location.extend(pod_spans);
+            vec![
+                Line::from(vec![
+                    Span::styled("kubescope", Theme::current().title()),
+                    separator.clone(),
+                    Span::styled(deployment, Theme::current().text_highlight()),
+                    separator,
+                    time_span,
+                ]),
+                Line::from(location),
+            ]
+        } else {
+            let mut spans = vec![
+                Span::styled("kubescope", Theme::current().title()),
+                separator.clone(),
+                Span::styled(context_name, Theme::current().text()),
+                separator.clone(),
+                Span::styled(namespace, Theme::current().text()),
+                separator.clone(),
+                Span::styled(deployment, Theme::current().text_highlight()),
+                separator.clone(),
+            ];
+            spans.extend(pod_spans);
+            spans.push(separator);
+            spans.push(time_span);
+            vec![Line::from(spans)]
+        };
+
+        // Link the deployment name to its dashboard
+        if let Some(url) = state.deployment_link() {
+            let position = if narrow { 2 } else { 6 };
+            let spans = &title[0].spans;
+            let offset: usize = spans[..position].iter().map(Span::width).sum();
+            let link = Rect::new(
+                area.x + 1 + offset as u16,
+                area.y + 1,
+                spans[position].width() as u16,
+                1,
+            )
+            .intersection(area.inner(Margin::new(1, 1)));
+            hyperlink(link, &url);
+        }
 
         let header = Paragraph::new(title).block(
             Block::default()
                 .borders(Borders::ALL)
-                .border_style(Theme::border()),
+                .border_style(Theme::current().border()),
         );
 
         frame.render_widget(header, area);
@@ -136,80 +268,178 @@ impl LogViewerScreen {
     fn render_filter_bar(frame: &mut Frame, area: Rect, state: &AppState) {
         let mut spans = vec![];
 
+        let finding =
+            state.ui_state.search_active && state.ui_state.search_mode == SearchMode::Find;
+        // Only the level threshold is set
+        let level_only = !state.ui_state.search_active
+            && state.ui_state.active_filter.is_none()
+            && state.ui_state.find.query.is_none();
+
         // Prompt
-        if state.ui_state.search_active {
+        if level_only {
+            spans.push(Span::styled(" Filter:", Theme::current().text_dim()));
+        } else if finding {
+            spans.push(Span::styled(
+                " Find: ",
+                Style::default()
+                    .fg(Theme::current().highlight)
+                    .add_modifier(Modifier::BOLD),
+            ));
+        } else if state.ui_state.search_active {
             spans.push(Span::styled(
                 " /",
                 Style::default()
-                    .fg(Color::Yellow)
+                    .fg(Theme::current().highlight)
                     .add_modifier(Modifier::BOLD),
             ));
+        } else if state.ui_state.active_filter.is_some() {
+            spans.push(Span::styled(" Filter: ", Theme::current().text_dim()));
         } else {
-            spans.push(Span::styled(" Filter: ", Theme::text_dim()));
+            spans.push(Span::styled(" Find: ", Theme::current().text_dim()));
         }
 
-        // Input or current filter pattern
+        // Input or current filter (or find) pattern
         let pattern = if state.ui_state.search_active {
             &state.ui_state.search_input
         } else if let Some(filter) = &state.ui_state.active_filter {
             filter.pattern()
+        } else if let Some(query) = &state.ui_state.find.query {
+            query.pattern()
         } else {
             ""
         };
 
-        spans.push(Span::styled(pattern.to_string(), Theme::text_highlight()));
+        spans.push(Span::styled(
+            pattern.to_string(),
+            Theme::current().text_highlight(),
+        ));
+
+        // Level threshold, e.g. "level>=WARN"
+        if state.ui_state.min_level != LogLevel::Trace && !state.ui_state.search_active {
+            let level = state.ui_state.min_level;
+            spans.push(Span::styled(
+                format!(" level>={}", level.name().to_uppercase()),
+                Style::default()
+                    .fg(Theme::current().level_color(level))
+                    .add_modifier(Modifier::BOLD),
+            ));
+        }
 
         // Cursor when active
         if state.ui_state.search_active {
             spans.push(Span::styled(
                 "█",
                 Style::default()
-                    .fg(Color::Yellow)
+                    .fg(Theme::current().highlight)
                     .add_modifier(Modifier::SLOW_BLINK),
             ));
         }
 
+        // Live preview while typing: match count, or why the pattern doesn't compile
+        if state.ui_state.search_active && state.ui_state.filter_error.is_none() {
+            let preview = &state.ui_state.search_preview;
+            if let Some(err) = &preview.error {
+                let summary = err.lines().last().unwrap_or_default().trim();
+                spans.push(Span::styled(
+                    format!("  ⚠ {}", summary),
+                    Style::default().fg(Theme::current().warning),
+                ));
+            } else if let Some(count) = preview.count {
+                spans.push(Span::styled(
+                    format!("  {} matching", count),
+                    Style::default().fg(Theme::current().accent),
+                ));
+            } else if preview.is_counting() {
+                spans.push(Span::styled("  counting…", Theme::current().text_dim()));
+            }
+        }
+
         // Error message
         if let Some(err) = &state.ui_state.filter_error {
-            spans.push(Span::styled(" ", Theme::text()));
+            spans.push(Span::styled(" ", Theme::current().text()));
             spans.push(Span::styled(
                 format!("⚠ {}", err),
-                Style::default().fg(Color::Red),
+                Style::default().fg(Theme::current().error),
+            ));
+        }
+
+        // Find match count, e.g. "match 12 of 345"
+        if let Some(query) = &state.ui_state.find.query
+            && !finding
+        {
+            let find = &state.ui_state.find;
+            let count = if find.matches.is_empty() {
+                "no matches".to_string()
+            } else {
+                let position = find.position(&state.ui_state.filter_cache.cached_entries);
+                match position {
+                    Some(pos) => format!("match {} of {}", pos, find.matches.len()),
+                    None => format!("{} matches", find.matches.len()),
+                }
+            };
+            if state.ui_state.active_filter.is_some() {
+                spans.push(Span::styled("  Find: ", Theme::current().text_dim()));
+                spans.push(Span::styled(
+                    query.pattern().to_string(),
+                    Theme::current().text_highlight(),
+                ));
+            }
+            spans.push(Span::styled(
+                format!("  {}", count),
+                Style::default()
+                    .fg(Theme::current().accent)
+                    .add_modifier(Modifier::BOLD),
             ));
         }
 
         // Case sensitivity indicator
-        if state.ui_state.active_filter.is_some() || state.ui_state.search_active {
-            spans.push(Span::styled("  ", Theme::text()));
+        if state.ui_state.active_filter.is_some()
+            || state.ui_state.find.query.is_some()
+            || state.ui_state.search_active
+        {
+            spans.push(Span::styled("  ", Theme::current().text()));
             let case_text = if state.ui_state.filter_case_insensitive {
                 "[i] case-insensitive"
             } else {
                 "[I] case-sensitive"
             };
-            spans.push(Span::styled(case_text, Theme::text_dim()));
+            spans.push(Span::styled(case_text, Theme::current().text_dim()));
         }
 
         // Hints
         if state.ui_state.search_active {
             spans.push(Span::styled(
                 "  [Enter] Apply  [Esc] Cancel",
-                Theme::text_dim(),
+                Theme::current().text_dim(),
+            ));
+        } else if state.ui_state.find.query.is_some() {
+            spans.push(Span::styled(
+                "  [./,] Next/Prev  [n] Clear",
+                Theme::current().text_dim(),
             ));
         } else if state.ui_state.active_filter.is_some() {
-            spans.push(Span::styled("  [n] Clear  [/] Edit", Theme::text_dim()));
+            spans.push(Span::styled(
+                "  [n] Clear  [/] Edit",
+                Theme::current().text_dim(),
+            ));
+        } else if level_only {
+            spans.push(Span::styled(
+                "  [L] Next level",
+                Theme::current().text_dim(),
+            ));
         }
 
         let filter_bar = Paragraph::new(Line::from(spans)).block(
             Block::default()
                 .borders(Borders::ALL)
                 .border_style(if state.ui_state.search_active {
-                    Style::default().fg(Color::Yellow)
+                    Style::default().fg(Theme::current().highlight)
                 } else if state.ui_state.filter_error.is_some() {
-                    Style::default().fg(Color::Red)
+                    Style::default().fg(Theme::current().error)
                 } else {
-                    Theme::border()
+                    Theme::current().border()
                 })
-                .title(Span::styled(" Search/Filter ", Theme::title())),
+                .title(Span::styled(" Search/Filter ", Theme::current().title())),
         );
 
         frame.render_widget(filter_bar, area);
@@ -222,49 +452,43 @@ impl LogViewerScreen {
         let needs_refresh = state.ui_state.filter_cache.needs_refresh(
             state.ui_state.active_filter.as_ref(),
             state.ui_state.filter_case_insensitive,
-            &state.ui_state.json_visible_keys,
+            state.json_filter_keys(),
+            &state.ui_state.muted_pods,
+            state.ui_state.node_filter.as_deref(),
+            state.ui_state.min_level,
             current_log_count,
         );
 
         // Only recompute filtered logs when cache is invalid
         if needs_refresh {
-            let all_logs = log_buffer.all();
-
-            // Apply text filter if active (Arc clones are cheap)
-            let text_filtered: Vec<ArcLogEntry> =
-                if let Some(filter) = &state.ui_state.active_filter {
-                    all_logs.into_iter().filter(|e| filter.matches(e)).collect()
-                } else {
-                    all_logs
-                };
-
-            // Apply JSON key filter if active (only show entries with selected keys)
-            let filtered_logs: Vec<ArcLogEntry> = if !state.ui_state.json_visible_keys.is_empty() {
-                text_filtered
-                    .into_iter()
-                    .filter(|e| {
-                        // Keep entry if it has any of the selected keys
-                        if let Some(fields) = &e.fields {
-                            fields
-                                .keys()
-                                .any(|k| state.ui_state.json_visible_keys.contains(k))
-                        } else {
-                            false // No fields = no match when filtering
-                        }
-                    })
-                    .collect()
-            } else {
-                text_filtered
-            };
+            let started = std::time::Instant::now();
+            let filtered_logs = state.filter_pipeline().apply(log_buffer.all());
+            let json_keys = state.json_filter_keys().clone();
 
             // Update the cache
             state.ui_state.filter_cache.update(
                 state.ui_state.active_filter.as_ref(),
                 state.ui_state.filter_case_insensitive,
-                &state.ui_state.json_visible_keys,
+                &json_keys,
+                &state.ui_state.muted_pods,
+                state.ui_state.node_filter.as_deref(),
+                state.ui_state.min_level,
                 current_log_count,
                 filtered_logs,
             );
+            let kept = state.ui_state.filter_cache.cached_entries.len();
+            state.ui_state.perf.record_filter(started.elapsed(), kept);
+        }
+
+        // Keep find matches in step with the view so the count updates as lines arrive
+        if needs_refresh || state.ui_state.find.is_stale() {
+            let ui = &mut state.ui_state;
+            ui.find.refresh(&ui.filter_cache.cached_entries);
+        }
+
+        if let Some((left, right)) = state.ui_state.compare.clone() {
+            Self::render_compare(frame, area, state, &left, &right);
+            return;
         }
 
         let total_entries = state.ui_state.filter_cache.cached_entries.len();
@@ -272,19 +496,50 @@ impl LogViewerScreen {
         // Calculate visible area (accounting for border)
         let inner_height = area.height.saturating_sub(2) as usize;
         let inner_width = area.width.saturating_sub(4) as usize; // 2 for borders, 2 for scrollbar
+        // Also compact when the pod panel squeezes the logs pane
+        let compact = area.width < NARROW_WIDTH;
 
-        // When JSON pretty print is enabled, we need line-based scrolling
-        // because each entry can produce multiple lines
-        let (lines, total_lines, scroll_position, max_scroll) = if state.ui_state.json_pretty_print
-        {
-            // Pre-compute all lines to get accurate count and enable line-based scrolling
-            let all_lines: Vec<Line> = state
+        // Resolve a pending bookmark jump to an entry index in the filtered view
+        let jump_index = state.ui_state.pending_jump.take().and_then(|target| {
+            let idx = state
                 .ui_state
                 .filter_cache
                 .cached_entries
                 .iter()
-                .flat_map(|entry| Self::format_log_lines(entry, state, inner_width))
-                .collect();
+                .position(|e| Arc::ptr_eq(e, &target));
+            if idx.is_none() {
+                state.notify(
+                    Severity::Warning,
+                    "Bookmarked line is no longer in the buffer or is hidden by a filter",
+                );
+            }
+            idx
+        });
+
+        // First and last entry index on screen (for the minimap viewport band)
+        let viewport_entries: Option<(usize, usize)>;
+
+        // When JSON pretty print is enabled, we need line-based scrolling
+        // because each entry can produce multiple lines
+        let (lines, total_lines, scroll_position, max_scroll) = if state.ui_state.json_pretty_print
+        {
+            // Pre-compute all lines to get accurate count and enable line-based scrolling,
+            // remembering where each entry starts so bookmarks can map to a line offset
+            let mut entry_starts = Vec::with_capacity(total_entries);
+            let mut all_lines: Vec<Line> = Vec::new();
+            let mut prev: Option<&LogEntry> = None;
+            for entry in &state.ui_state.filter_cache.cached_entries {
+                all_lines.extend(Self::time_marker(prev, entry, state, inner_width));
+                entry_starts.push(all_lines.len());
+                all_lines.extend(Self::format_log_lines(
+                    entry,
+                    prev,
+                    state,
+                    inner_width,
+                    compact,
+                ));
+                prev = Some(entry);
+            }
 
             let total_lines = all_lines.len();
             let max_scroll = total_lines.saturating_sub(inner_height);
@@ -294,11 +549,30 @@ impl LogViewerScreen {
                 state.ui_state.log_scroll = max_scroll;
             }
 
+            if let Some(idx) = jump_index {
+                state.ui_state.log_scroll = entry_starts[idx];
+            }
+
             // Clamp scroll position
             if state.ui_state.log_scroll > max_scroll {
                 state.ui_state.log_scroll = max_scroll;
             }
 
+            // Track the entries owning the first and last visible lines
+            let top_idx = entry_starts
+                .partition_point(|&start| start <= state.ui_state.log_scroll)
+                .saturating_sub(1);
+            let bottom_idx = entry_starts
+                .partition_point(|&start| start < state.ui_state.log_scroll + inner_height)
+                .saturating_sub(1);
+            viewport_entries = Some((top_idx, bottom_idx));
+            state.ui_state.top_visible_entry = state
+                .ui_state
+                .filter_cache
+                .cached_entries
+                .get(top_idx)
+                .cloned();
+
             // Get visible lines (line-based viewport)
             let visible_lines: Vec<Line> = all_lines
                 .into_iter()
@@ -321,21 +595,45 @@ impl LogViewerScreen {
                 state.ui_state.log_scroll = max_scroll;
             }
 
+            if let Some(idx) = jump_index {
+                state.ui_state.log_scroll = idx;
+            }
+
             // Clamp scroll position
             if state.ui_state.log_scroll > max_scroll {
                 state.ui_state.log_scroll = max_scroll;
             }
 
-            // Get visible logs from cache (viewport-first: skip/take from cached results)
-            let visible_lines: Vec<Line> = state
+            viewport_entries = Some((
+                state.ui_state.log_scroll,
+                state.ui_state.log_scroll + inner_height.saturating_sub(1),
+            ));
+            state.ui_state.top_visible_entry = state
                 .ui_state
                 .filter_cache
                 .cached_entries
-                .iter()
-                .skip(state.ui_state.log_scroll)
-                .take(inner_height)
-                .flat_map(|entry| Self::format_log_lines(entry, state, inner_width))
-                .collect();
+                .get(state.ui_state.log_scroll)
+                .cloned();
+
+            // Get visible logs from cache (viewport-first: skip/take from cached results)
+            let entries = &state.ui_state.filter_cache.cached_entries;
+            let scroll = state.ui_state.log_scroll;
+            let mut visible_lines: Vec<Line> = Vec::with_capacity(inner_height);
+            for (idx, entry) in entries.iter().enumerate().skip(scroll).take(inner_height) {
+                let prev = idx.checked_sub(1).map(|i| entries[i].as_ref());
+                visible_lines.extend(Self::time_marker(prev, entry, state, inner_width));
+                visible_lines.extend(Self::format_log_lines(
+                    entry,
+                    prev,
+                    state,
+                    inner_width,
+                    compact,
+                ));
+            }
+            // Markers and raw views add rows; keep the newest lines in view when following
+            if state.ui_state.auto_scroll && visible_lines.len() > inner_height {
+                visible_lines.drain(..visible_lines.len() - inner_height);
+            }
 
             (
                 visible_lines,
@@ -346,23 +644,63 @@ impl LogViewerScreen {
         };
 
         // Title shows filter status
-        let title = if state.ui_state.active_filter.is_some()
-            || !state.ui_state.json_visible_keys.is_empty()
-        {
-            format!(" Logs ({} matching) ", total_entries)
-        } else {
-            format!(" Logs ({}) ", total_entries)
-        };
+        let mut title =
+            if state.ui_state.active_filter.is_some() || !state.json_filter_keys().is_empty() {
+                format!(" Logs ({} matching) ", total_entries)
+            } else {
+                format!(" Logs ({}) ", total_entries)
+            };
+        if state.ui_state.history_loading {
+            title.push_str("⟳ loading older logs... ");
+        }
+        let backfilling = state.backfilling();
+        if backfilling > 0 {
+            title.push_str(&format!(
+                "⟳ backfilling {} of {} pods... ",
+                backfilling,
+                state.ui_state.backfill.len()
+            ));
+        }
 
-        let logs_widget = Paragraph::new(lines).block(
-            Block::default()
-                .borders(Borders::ALL)
-                .border_style(Theme::border())
-                .title(Span::styled(title, Theme::title())),
-        );
+        let mut block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Theme::current().border())
+            .title(Span::styled(title, Theme::current().title()));
+        // Where in time the view is, once it's scrolled away from the tail
+        if !state.ui_state.auto_scroll
+            && let Some(at) = state
+                .ui_state
+                .top_visible_entry
+                .as_ref()
+                .and_then(|entry| entry.timestamp)
+        {
+            block = block.title(
+                Line::from(Span::styled(
+                    format!(
+                        " ◷ {} ",
+                        format_scroll_time(at, state.ui_state.use_local_time)
+                    ),
+                    Style::default().fg(Theme::current().highlight),
+                ))
+                .right_aligned(),
+            );
+        }
+        let logs_widget = Paragraph::new(lines).block(block);
 
         frame.render_widget(logs_widget, area);
 
+        // Render minimap in the gutter column just left of the scrollbar
+        if state.ui_state.minimap_visible && area.width > 4 && inner_height > 0 {
+            let minimap_area =
+                Rect::new(area.x + area.width - 2, area.y + 1, 1, inner_height as u16);
+            Minimap::render(
+                frame,
+                minimap_area,
+                &state.ui_state.filter_cache.cached_entries,
+                viewport_entries,
+            );
+        }
+
         // Render scrollbar
         if total_lines > inner_height {
             let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
@@ -389,145 +727,431 @@ impl LogViewerScreen {
         let total = counts.total();
 
         // Build horizontal stats display
-        let mut spans = vec![Span::styled(" ", Theme::text())];
+        let mut spans = vec![Span::styled(" ", Theme::current().text())];
 
         // Fatal (only if > 0)
         if counts.fatal > 0 {
             spans.push(Span::styled(
                 "FTL:",
                 Style::default()
-                    .fg(Color::Magenta)
+                    .fg(Theme::current().level_color(LogLevel::Fatal))
                     .add_modifier(Modifier::BOLD),
             ));
-            spans.push(Span::styled(format!("{} ", counts.fatal), Theme::text()));
+            spans.push(Span::styled(
+                format!("{} ", counts.fatal),
+                Theme::current().text(),
+            ));
         }
 
         // Error
         spans.push(Span::styled(
             "ERR:",
-            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            Style::default()
+                .fg(Theme::current().level_color(LogLevel::Error))
+                .add_modifier(Modifier::BOLD),
+        ));
+        spans.push(Span::styled(
+            format!("{} ", counts.error),
+            Theme::current().text(),
         ));
-        spans.push(Span::styled(format!("{} ", counts.error), Theme::text()));
 
         // Warn
         spans.push(Span::styled(
             "WRN:",
             Style::default()
-                .fg(Color::Yellow)
+                .fg(Theme::current().level_color(LogLevel::Warn))
                 .add_modifier(Modifier::BOLD),
         ));
-        spans.push(Span::styled(format!("{} ", counts.warn), Theme::text()));
+        spans.push(Span::styled(
+            format!("{} ", counts.warn),
+            Theme::current().text(),
+        ));
 
         // Info
         spans.push(Span::styled(
             "INF:",
             Style::default()
-                .fg(Color::Green)
+                .fg(Theme::current().level_color(LogLevel::Info))
                 .add_modifier(Modifier::BOLD),
         ));
-        spans.push(Span::styled(format!("{} ", counts.info), Theme::text()));
+        spans.push(Span::styled(
+            format!("{} ", counts.info),
+            Theme::current().text(),
+        ));
 
         // Debug
         spans.push(Span::styled(
             "DBG:",
             Style::default()
-                .fg(Color::Cyan)
+                .fg(Theme::current().level_color(LogLevel::Debug))
                 .add_modifier(Modifier::BOLD),
         ));
-        spans.push(Span::styled(format!("{} ", counts.debug), Theme::text()));
+        spans.push(Span::styled(
+            format!("{} ", counts.debug),
+            Theme::current().text(),
+        ));
 
         // Trace (only if > 0)
         if counts.trace > 0 {
             spans.push(Span::styled(
                 "TRC:",
                 Style::default()
-                    .fg(Color::Blue)
+                    .fg(Theme::current().level_color(LogLevel::Trace))
                     .add_modifier(Modifier::BOLD),
             ));
-            spans.push(Span::styled(format!("{} ", counts.trace), Theme::text()));
+            spans.push(Span::styled(
+                format!("{} ", counts.trace),
+                Theme::current().text(),
+            ));
         }
 
         // Separator and total
-        spans.push(Span::styled("│ ", Theme::text_dim()));
-        spans.push(Span::styled("Total:", Theme::text_dim()));
-        spans.push(Span::styled(format!("{}", total), Theme::text()));
+        spans.push(Span::styled("│ ", Theme::current().text_dim()));
+        spans.push(Span::styled("Total:", Theme::current().text_dim()));
+        spans.push(Span::styled(format!("{} ", total), Theme::current().text()));
+
+        // Buffer usage
+        spans.push(Span::styled("│ ", Theme::current().text_dim()));
+        spans.push(Span::styled("Buffer:", Theme::current().text_dim()));
+        spans.push(Span::styled(
+            format!(
+                "{}/{} (~{})",
+                log_buffer.len(),
+                log_buffer.capacity(),
+                format_bytes(log_buffer.approx_bytes())
+            ),
+            Theme::current().text(),
+        ));
 
         let stats_widget = Paragraph::new(Line::from(spans)).block(
             Block::default()
                 .borders(Borders::ALL)
-                .border_style(Theme::border())
-                .title(Span::styled(" Stats ", Theme::title())),
+                .border_style(Theme::current().border())
+                .title(Span::styled(" Stats ", Theme::current().title())),
         );
 
         frame.render_widget(stats_widget, area);
     }
 
+    /// Two pods side by side; the right pane follows the left pane's top timestamp
+    fn render_compare(
+        frame: &mut Frame,
+        area: Rect,
+        state: &mut AppState,
+        left: &str,
+        right: &str,
+    ) {
+        let panes = RatatuiLayout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(area);
+
+        let entries_for = |pod: &str| -> Vec<ArcLogEntry> {
+            state
+                .ui_state
+                .filter_cache
+                .cached_entries
+                .iter()
+                .filter(|e| e.pod_name() == pod)
+                .cloned()
+                .collect()
+        };
+        let left_entries = entries_for(left);
+        let right_entries = entries_for(right);
+
+        let inner_height = panes[0].height.saturating_sub(2) as usize;
+        let left_max = left_entries.len().saturating_sub(inner_height);
+        let right_max = right_entries.len().saturating_sub(inner_height);
+
+        if state.ui_state.auto_scroll {
+            state.ui_state.log_scroll = left_max;
+        }
+        state.ui_state.log_scroll = state.ui_state.log_scroll.min(left_max);
+        let left_top = state.ui_state.log_scroll;
+        state.ui_state.top_visible_entry = left_entries.get(left_top).cloned();
+
+        // Line the right pane up on the same moment in time, falling back to
+        // the same offset when the left top has no timestamp
+        let right_top = if state.ui_state.auto_scroll {
+            right_max
+        } else {
+            left_entries
+                .get(left_top)
+                .and_then(|e| e.timestamp)
+                .map(|at| aligned_row(&right_entries, at))
+                .unwrap_or(left_top)
+                .min(right_max)
+        };
+
+        for (pane, pod, entries, top) in [
+            (panes[0], left, &left_entries, left_top),
+            (panes[1], right, &right_entries, right_top),
+        ] {
+            let inner_width = pane.width.saturating_sub(2) as usize;
+            let compact = pane.width < NARROW_WIDTH;
+            let lines: Vec<Line> = (top..entries.len())
+                .take(inner_height)
+                .flat_map(|idx| {
+                    let prev = idx.checked_sub(1).map(|i| entries[i].as_ref());
+                    Self::format_log_lines(&entries[idx], prev, state, inner_width, compact)
+                })
+                .take(inner_height)
+                .collect();
+
+            let title = Span::styled(
+                format!(" {} ({}) ", pod, entries.len()),
+                Style::default()
+                    .fg(state.pod_color(pod))
+                    .add_modifier(Modifier::BOLD),
+            );
+            let widget = Paragraph::new(lines).block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Theme::current().border())
+                    .title(title),
+            );
+            frame.render_widget(widget, pane);
+        }
+    }
+
+    /// Separator row where the time crosses into a new day (or hour) between
+    /// two consecutive entries
+    ///
+    /// Merged pods are only roughly in time order, so only a step forward
+    /// gets a marker.
+    fn time_marker(
+        prev: Option<&LogEntry>,
+        entry: &LogEntry,
+        state: &AppState,
+        width: usize,
+    ) -> Option<Line<'static>> {
+        let markers = state.ui_state.time_markers;
+        if markers == TimeMarkers::Off {
+            return None;
+        }
+        let (prev, at) = (prev?.timestamp?, entry.timestamp?);
+        let local = state.ui_state.use_local_time;
+        let (prev, at) = if local {
+            (
+                prev.with_timezone(&Local).naive_local(),
+                at.with_timezone(&Local).naive_local(),
+            )
+        } else {
+            (prev.naive_utc(), at.naive_utc())
+        };
+
+        let new_day = at.date() > prev.date();
+        let label = if new_day {
+            at.format("%a %Y-%m-%d").to_string()
+        } else if markers == TimeMarkers::Hours
+            && at.date() == prev.date()
+            && at.hour() > prev.hour()
+        {
+            at.format("%H:00").to_string()
+        } else {
+            return None;
+        };
+        let label = format!("── {}{} ", label, if local { "" } else { " UTC" });
+        let fill = width.saturating_sub(label.chars().count());
+        Some(Line::from(Span::styled(
+            format!("{}{}", label, "─".repeat(fill)),
+            Style::default().fg(if new_day {
+                Theme::current().primary
+            } else {
+                Theme::current().fg_dim
+            }),
+        )))
+    }
+
     /// Format a log entry into one or more display lines
     /// Returns multiple lines when JSON pretty print is enabled for JSON entries
+    ///
+    /// `prev` is the line drawn above this one, for the time delta column.
     fn format_log_lines(
         entry: &LogEntry,
+        prev: Option<&LogEntry>,
         state: &AppState,
         available_width: usize,
+        compact: bool,
+    ) -> Vec<Line<'static>> {
+        let mut lines = Self::format_entry_lines(entry, prev, state, available_width, compact);
+        if state.ui_state.raw_views.contains(&entry.id) {
+            // The line as received, under the message
+            let raw = entry.original.as_deref().unwrap_or(&entry.raw);
+            let label = if entry.sanitized {
+                "raw (escaped): "
+            } else {
+                "raw: "
+            };
+            lines.push(Line::from(vec![
+                Span::styled(format!("  {}", label), Theme::current().text_dim()),
+                Span::styled(
+                    safe_truncate(raw, available_width.saturating_sub(label.len() + 2)).to_string(),
+                    Theme::current().text(),
+                ),
+            ]));
+        }
+        lines
+    }
+
+    fn format_entry_lines(
+        entry: &LogEntry,
+        prev: Option<&LogEntry>,
+        state: &AppState,
+        available_width: usize,
+        compact: bool,
     ) -> Vec<Line<'static>> {
         let mut prefix_spans = Vec::new();
         let mut prefix_width: usize = 0;
 
-        // Line number (compact) - 5 chars
-        prefix_spans.push(Span::styled(format!("{:>5}", entry.id), Theme::text_dim()));
-        prefix_width += 5;
-
-        // Timestamp (if enabled and available) - " HH:MM:SS" = 9 chars
-        if state.ui_state.show_timestamps
-            && let Some(ts) = &entry.timestamp
-        {
-            let time_str = if state.ui_state.use_local_time {
-                ts.with_timezone(&Local).format("%H:%M:%S").to_string()
-            } else {
-                ts.format("%H:%M:%S").to_string()
+        // Prefix columns in the configured order and widths, separated by spaces
+        let density = state.ui_state.density;
+        for &(column, width) in &state.ui_state.columns.columns {
+            let width = density.width(column, width);
+            if !state.column_shown(column)
+                || (compact
+                    && matches!(
+                        column,
+                        Column::Time | Column::Pod | Column::Node | Column::Source
+                    ))
+            {
+                continue;
+            }
+            let (text, style) = match column {
+                // Highlighted when bookmarked; wide numbers overflow rather than truncate
+                Column::Line => {
+                    // In compact mode the pod column is hidden, so carry the pod color here
+                    let is_current_match = state
+                        .ui_state
+                        .find
+                        .current
+                        .as_ref()
+                        .is_some_and(|current| std::ptr::eq(current.as_ref(), entry));
+                    let style = if is_current_match {
+                        Theme::current()
+                            .text_highlight()
+                            .add_modifier(Modifier::REVERSED)
+                    } else if state.is_bookmarked(entry) {
+                        Theme::current().bookmark()
+                    } else if compact {
+                        Style::default().fg(state.pod_color(entry.pod_name()))
+                    } else {
+                        Theme::current().text_dim()
+                    };
+                    let number = if state.ui_state.per_pod_line_numbers {
+                        entry.line_number.to_string()
+                    } else {
+                        entry.id.to_string()
+                    };
+                    (number, style)
+                }
+                // Blank when the line has no timestamp, so columns stay aligned
+                Column::Time if state.ui_state.time_deltas => {
+                    let delta = entry
+                        .timestamp
+                        .zip(prev.and_then(|p| p.timestamp))
+                        .map(|(at, before)| at - before);
+                    // Long gaps stand out, they're what the deltas are for
+                    let style = match delta.map(|d| d.num_milliseconds()) {
+                        Some(10_000..) => Style::default().fg(Theme::current().error),
+                        Some(1_000..) => Style::default().fg(Theme::current().warning),
+                        _ => Theme::current().text_dim(),
+                    };
+                    (delta.map(format_delta).unwrap_or_default(), style)
+                }
+                Column::Time => {
+                    let text = entry
+                        .timestamp
+                        .map(|ts| format_time_column(ts, state.ui_state.use_local_time, width))
+                        .unwrap_or_default();
+                    (text, Theme::current().text_dim())
+                }
+                // Sidecar lines always name their container, in italics
+                Column::Pod
+                    if density == Density::Detailed || entry.source.kind == SourceKind::Sidecar =>
+                {
+                    let name = match &entry.source.container {
+                        Some(container) => format!("{}/{}", entry.pod_name(), container),
+                        None => entry.pod_name().to_string(),
+                    };
+                    let mut style = Style::default().fg(state.pod_color(entry.pod_name()));
+                    if entry.source.kind == SourceKind::Sidecar {
+                        style = style.add_modifier(Modifier::ITALIC);
+                    }
+                    (keep_suffix(&name, width), style)
+                }
+                Column::Pod => {
+                    // Short hash suffix by default, more of the name when widened
+                    let short = entry.short_pod_name();
+                    let name = if width > short.chars().count() {
+                        keep_suffix(entry.pod_name(), width)
+                    } else {
+                        keep_suffix(short, width)
+                    };
+                    (name, Style::default().fg(state.pod_color(entry.pod_name())))
+                }
+                Column::Node => {
+                    let node = entry
+                        .node_name
+                        .as_deref()
+                        .map(short_node_name)
+                        .unwrap_or("-");
+                    (
+                        safe_truncate(node, width).to_string(),
+                        Theme::current().text_dim(),
+                    )
+                }
+                Column::Source => (
+                    keep_suffix(&entry.source.label(), width),
+                    Theme::current().text_dim(),
+                ),
+                Column::Level => (
+                    safe_truncate(entry.level.as_str(), width).to_string(),
+                    Style::default()
+                        .fg(Theme::current().level_color(entry.level))
+                        .add_modifier(Modifier::BOLD),
+                ),
             };
-            prefix_spans.push(Span::styled(format!(" {}", time_str), Theme::text_dim()));
-            prefix_width += 9;
+            let leading = if prefix_spans.is_empty() { "" } else { " " };
+            let cell = format!("{}{:>width$}", leading, text, width = width);
+            prefix_width += cell.chars().count();
+            prefix_spans.push(Span::styled(cell, style));
         }
 
-        // Pod name (if enabled) - " XXXXXXXXXX" = 11 chars
-        if state.ui_state.show_pod_names {
+        // Separator - " │ " = 3 chars, marked when the line had to be sanitized
+        if entry.sanitized {
             prefix_spans.push(Span::styled(
-                format!(" {:>10}", entry.short_pod_name()),
-                Style::default().fg(pod_color(&entry.pod_name)),
+                " ␦ ",
+                Style::default().fg(Theme::current().warning),
             ));
-            prefix_width += 11;
+        } else {
+            prefix_spans.push(Span::styled(" │ ", Theme::current().text_dim()));
         }
-
-        // Log level (fixed width) - " XXX" = 4 chars
-        prefix_spans.push(Span::styled(
-            format!(" {:>3}", entry.level.as_str()),
-            Style::default()
-                .fg(entry.level.color())
-                .add_modifier(Modifier::BOLD),
-        ));
-        prefix_width += 4;
-
-        // Separator - " │ " = 3 chars
-        prefix_spans.push(Span::styled(" │ ", Theme::text_dim()));
         prefix_width += 3;
 
         // Calculate remaining width for message content
         let message_width = available_width.saturating_sub(prefix_width);
 
-        // Message content - handle JSON pretty printing
-        if state.ui_state.json_pretty_print && entry.is_json {
-            // Get JSON content (remove timestamp prefix if present)
-            let json_str = if entry.timestamp.is_some() && entry.raw.len() > 31 {
-                safe_slice_from(&entry.raw, 31)
-            } else {
-                &entry.raw
-            };
+        // Message content - handle JSON pretty printing (unless this entry is collapsed)
+        let fold = state.json_fold(entry);
+        if state.ui_state.json_pretty_print && entry.is_json && fold != JsonFold::Collapsed {
+            let json_str = entry.display_text();
 
             // Pretty print the JSON with indentation
+            let fold_depth = match fold {
+                JsonFold::Depth(depth) => Some(depth),
+                _ => None,
+            };
+            // An expanded entry shows values in full
+            let elide = fold != JsonFold::Expanded;
             let pretty_json = format_json_pretty(
                 json_str,
                 &state.ui_state.json_visible_keys,
+                state.ui_state.json_keys_exclude,
                 entry.fields.as_ref(),
+                &state.ui_state.json_format,
+                fold_depth,
+                elide,
             );
 
             // Split into lines and create formatted output
@@ -567,12 +1191,25 @@ impl LogViewerScreen {
             // Regular message handling (single line)
             let mut spans = prefix_spans;
 
-            let message = if entry.timestamp.is_some() && entry.raw.len() > 31 {
-                safe_slice_from(&entry.raw, 31).to_string()
+            let message = entry.display_text().to_string();
+            // Shorten huge JSON values so the keys after them stay on screen
+            let message = if entry.is_json {
+                state
+                    .ui_state
+                    .json_format
+                    .compact_elided(&message)
+                    .unwrap_or(message)
             } else {
-                entry.raw.clone()
+                message
             };
 
+            // Lines cut at ingest say how much is missing (w fetches the rest)
+            let cut_marker = entry
+                .truncated
+                .then(|| format!(" [truncated {}]", format_bytes(entry.truncated_bytes)));
+            let message_width =
+                message_width.saturating_sub(cut_marker.as_ref().map_or(0, |m| m.len()));
+
             // Truncate message to fit viewport (use safe truncation for UTF-8)
             let display_msg = if message.len() > message_width {
                 format!(
@@ -583,14 +1220,23 @@ impl LogViewerScreen {
                 message
             };
 
-            // Apply search highlighting if filter is active
-            if let Some(filter) = &state.ui_state.active_filter {
+            // Highlight the pattern being typed, then find matches, then the filter's
+            let preview = state
+                .ui_state
+                .search_preview
+                .query
+                .as_ref()
+                .filter(|_| state.ui_state.search_active);
+            let highlighter = preview
+                .or(state.ui_state.find.query.as_ref())
+                .or(state.ui_state.active_filter.as_ref());
+            if let Some(filter) = highlighter {
                 let matches = filter.find_matches(&display_msg);
                 if !matches.is_empty() {
                     let base_style = level_text_style(entry.level);
                     let highlight_style = Style::default()
-                        .fg(Color::Black)
-                        .bg(Color::Yellow)
+                        .fg(Theme::current().on_highlight)
+                        .bg(Theme::current().highlight)
                         .add_modifier(Modifier::BOLD);
 
                     let mut last_end = 0;
@@ -619,59 +1265,60 @@ impl LogViewerScreen {
             } else {
                 spans.push(Span::styled(display_msg, level_text_style(entry.level)));
             }
+            if let Some(marker) = cut_marker {
+                spans.push(Span::styled(
+                    marker,
+                    Style::default().fg(Theme::current().warning),
+                ));
+            }
 
             vec![Line::from(spans)]
         }
     }
 
-    fn render_status_bar(
-        frame: &mut Frame,
-        area: Rect,
-        state: &AppState,
-        log_buffer: &LogBuffer,
-        dropped_count: u64,
-    ) {
+    fn render_status_bar(frame: &mut Frame, area: Rect, state: &AppState, log_buffer: &LogBuffer) {
         let counts = log_buffer.level_counts();
         let total = counts.total();
 
-        let mut spans = vec![
-            Span::styled(" ", Theme::status_bar()),
-            // Keyboard hints
-            Span::styled("[", Theme::status_bar()),
-            Span::styled("Space", Theme::status_bar_key()),
-            Span::styled("]Cmd ", Theme::status_bar()),
-            Span::styled("[", Theme::status_bar()),
-            Span::styled("/", Theme::status_bar_key()),
-            Span::styled("]Filter ", Theme::status_bar()),
-            Span::styled("[", Theme::status_bar()),
-            Span::styled("r", Theme::status_bar_key()),
-            Span::styled("]", Theme::status_bar()),
-            Span::styled(
-                format!("[{}]", state.ui_state.time_range.label()),
-                Style::default()
-                    .fg(Color::Cyan)
-                    .add_modifier(Modifier::BOLD),
-            ),
-            Span::styled(" ", Theme::status_bar()),
-            Span::styled("[", Theme::status_bar()),
-            Span::styled("e", Theme::status_bar_key()),
-            Span::styled("]Export ", Theme::status_bar()),
-            Span::styled("[", Theme::status_bar()),
-            Span::styled("?", Theme::status_bar_key()),
-            Span::styled("]Help ", Theme::status_bar()),
-            Span::styled("[", Theme::status_bar()),
-            Span::styled("Esc", Theme::status_bar_key()),
-            Span::styled("]Back", Theme::status_bar()),
+        // Keyboard hints, least important dropped first on narrow terminals
+        let hints = [
+            ("Space", "Cmd", 0),
+            ("/", "Filter", 1),
+            ("r", state.ui_state.time_range.label(), 3),
+            ("e", "Export", 4),
+            ("?", "Help", 2),
+            ("Esc", "Back", 1),
         ];
 
-        // Show dropped logs warning if any
-        if dropped_count > 0 {
-            spans.push(Span::styled(" ", Theme::status_bar()));
-            spans.push(Span::styled(
-                format!("[{}dropped]", dropped_count),
-                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+        // Show ingest warnings (dropped, truncated, unparsed, sanitized) if any
+        let ingest = log_buffer.ingest_counts();
+        let warnings = [
+            (ingest.dropped, "dropped", Theme::current().error),
+            (ingest.truncated, "truncated", Theme::current().warning),
+            (ingest.parse_failures, "unparsed", Theme::current().warning),
+            (ingest.sanitized, "sanitized", Theme::current().warning),
+        ];
+        let mut badges: Vec<Span> = Vec::new();
+        if state.ui_state.streams_stopped_at.is_some() {
+            badges.push(Span::styled(
+                "[streams stopped]",
+                Style::default()
+                    .fg(Theme::current().highlight)
+                    .bg(Theme::current().surface)
+                    .add_modifier(Modifier::BOLD),
             ));
         }
+        badges.extend(warnings.into_iter().filter(|(count, _, _)| *count > 0).map(
+            |(count, label, color)| {
+                Span::styled(
+                    format!("[{} {}]", count, label),
+                    Style::default()
+                        .fg(color)
+                        .bg(Theme::current().surface)
+                        .add_modifier(Modifier::BOLD),
+                )
+            },
+        ));
 
         // Right side: log counts
         let right_text = format!(
@@ -687,49 +1334,91 @@ impl LogViewerScreen {
             }
         );
 
-        // Calculate padding
-        let left_width: usize = spans.iter().map(|s| s.content.len()).sum();
-        let right_width = right_text.len();
-        let padding = (area.width as usize).saturating_sub(left_width + right_width + 1);
+        let status = StatusBar::new()
+            .hints(hints)
+            .badges(badges)
+            .right(right_text);
 
-        spans.push(Span::styled(" ".repeat(padding), Theme::status_bar()));
-        spans.push(Span::styled(right_text, Theme::status_bar()));
+        frame.render_widget(status, area);
+    }
+}
 
-        let status = Paragraph::new(Line::from(spans)).style(Theme::status_bar());
+/// Format a byte count with a binary unit suffix
+pub fn format_bytes(bytes: usize) -> String {
+    const KIB: f64 = 1024.0;
+    let bytes = bytes as f64;
+    if bytes >= KIB * KIB {
+        format!("{:.1} MiB", bytes / (KIB * KIB))
+    } else if bytes >= KIB {
+        format!("{:.1} KiB", bytes / KIB)
+    } else {
+        format!("{} B", bytes)
+    }
+}
 
-        frame.render_widget(status, area);
+/// Drop the domain from node names ("ip-10-0-1-23.ec2.internal" -> "ip-10-0-1-23")
+fn short_node_name(node: &str) -> &str {
+    node.split('.').next().unwrap_or(node)
+}
+
+/// Format a timestamp for the time column, with as much precision as the width allows
+fn format_time_column(ts: DateTime<Utc>, local: bool, width: usize) -> String {
+    let fmt = match width {
+        23.. => "%Y-%m-%d %H:%M:%S%.3f",
+        12.. => "%H:%M:%S%.3f",
+        8.. => "%H:%M:%S",
+        _ => "%H:%M",
+    };
+    if local {
+        ts.with_timezone(&Local).format(fmt).to_string()
+    } else {
+        ts.format(fmt).to_string()
     }
 }
 
-/// Get a consistent color for a pod name
-fn pod_color(pod_name: &str) -> ratatui::style::Color {
-    use ratatui::style::Color;
-
-    // Hash the pod name to get a consistent color
-    let hash: u32 = pod_name
-        .bytes()
-        .fold(0u32, |acc, b| acc.wrapping_add(b as u32));
-
-    let colors = [
-        Color::Cyan,
-        Color::Magenta,
-        Color::Blue,
-        Color::Yellow,
-        Color::Green,
-        Color::Red,
-        Color::LightCyan,
-        Color::LightMagenta,
-    ];
-
-    colors[(hash as usize) % colors.len()]
+/// Full date and time of the top visible line, for the scroll position
+fn format_scroll_time(ts: DateTime<Utc>, local: bool) -> String {
+    if local {
+        ts.with_timezone(&Local)
+            .format("%Y-%m-%d %H:%M:%S")
+            .to_string()
+    } else {
+        ts.format("%Y-%m-%d %H:%M:%S UTC").to_string()
+    }
+}
+
+/// Time since the previous line: "+0.003s", "+4.2s", "+12s", "+3m05s", "+2h10m"
+///
+/// Negative when merged pods put an older line below a newer one.
+fn format_delta(delta: chrono::Duration) -> String {
+    let sign = if delta < chrono::Duration::zero() {
+        "-"
+    } else {
+        "+"
+    };
+    let ms = delta.num_milliseconds().unsigned_abs();
+    let secs = ms / 1000;
+    match secs {
+        0 => format!("{}0.{:03}s", sign, ms),
+        1..10 => format!("{}{}.{}s", sign, secs, ms % 1000 / 100),
+        10..60 => format!("{}{}s", sign, secs),
+        60..3600 => format!("{}{}m{:02}s", sign, secs / 60, secs % 60),
+        _ => format!("{}{}h{:02}m", sign, secs / 3600, secs % 3600 / 60),
+    }
+}
+
+/// Keep the last `width` characters, where pod names differ
+fn keep_suffix(s: &str, width: usize) -> String {
+    let len = s.chars().count();
+    s.chars().skip(len.saturating_sub(width)).collect()
 }
 
 /// Get text style based on log level
 fn level_text_style(level: LogLevel) -> Style {
     match level {
-        LogLevel::Error | LogLevel::Fatal => Style::default().fg(ratatui::style::Color::Red),
-        LogLevel::Warn => Style::default().fg(ratatui::style::Color::Yellow),
-        _ => Style::default().fg(ratatui::style::Color::White),
+        LogLevel::Error | LogLevel::Fatal => Style::default().fg(Theme::current().error),
+        LogLevel::Warn => Style::default().fg(Theme::current().warning),
+        _ => Style::default().fg(Theme::current().fg),
     }
 }
 
@@ -737,14 +1426,18 @@ fn level_text_style(level: LogLevel) -> Style {
 fn format_json_pretty(
     json_str: &str,
     visible_keys: &std::collections::HashSet<String>,
+    exclude: bool,
     parsed_fields: Option<&std::collections::HashMap<String, serde_json::Value>>,
+    format: &JsonFormat,
+    fold_depth: Option<usize>,
+    elide: bool,
 ) -> String {
-    // If we have key filters, filter first
+    // If we have key filters, filter first (keeping or dropping the picked keys)
     if !visible_keys.is_empty() {
         if let Some(fields) = parsed_fields {
             let filtered: serde_json::Map<String, serde_json::Value> = fields
                 .iter()
-                .filter(|(k, _)| visible_keys.contains(*k))
+                .filter(|(k, _)| visible_keys.contains(*k) != exclude)
                 .map(|(k, v)| (k.clone(), v.clone()))
                 .collect();
 
@@ -752,8 +1445,7 @@ fn format_json_pretty(
                 return "{}".to_string();
             }
 
-            return serde_json::to_string_pretty(&serde_json::Value::Object(filtered))
-                .unwrap_or_else(|_| json_str.to_string());
+            return format.pretty(&serde_json::Value::Object(filtered), fold_depth, elide);
         }
 
         // Fallback: parse and filter
@@ -762,16 +1454,19 @@ fn format_json_pretty(
         {
             let filtered: serde_json::Map<String, serde_json::Value> = map
                 .into_iter()
-                .filter(|(k, _)| visible_keys.contains(k))
+                .filter(|(k, _)| visible_keys.contains(k) != exclude)
                 .collect();
-            return serde_json::to_string_pretty(&serde_json::Value::Object(filtered))
-                .unwrap_or_else(|_| json_str.to_string());
+            return format.pretty(&serde_json::Value::Object(filtered), fold_depth, elide);
         }
     }
 
     // No filtering - just pretty print
     if let Ok(parsed) = serde_json::from_str::<serde_json::Value>(json_str) {
-        serde_json::to_string_pretty(&parsed).unwrap_or_else(|_| json_str.to_string())
+        format.pretty(&parsed, fold_depth, elide)
+    } else if let Some(fields) = parsed_fields {
+        // Fields a plugin parser pulled out of a non-JSON line
+        let fields = fields.iter().map(|(k, v)| (k.clone(), v.clone())).collect();
+        format.pretty(&serde_json::Value::Object(fields), fold_depth, elide)
     } else {
         json_str.to_string()
     }
@@ -783,13 +1478,13 @@ fn colorize_json_line(line: &str) -> Vec<Span<'static>> {
     let mut chars = line.chars().peekable();
     let mut current = String::new();
 
-    let brace_style = Style::default().fg(Color::White);
-    let key_style = Style::default().fg(Color::Cyan);
-    let string_style = Style::default().fg(Color::Green);
-    let number_style = Style::default().fg(Color::Yellow);
-    let bool_style = Style::default().fg(Color::Magenta);
-    let null_style = Style::default().fg(Color::Red);
-    let punct_style = Style::default().fg(Color::DarkGray);
+    let brace_style = Style::default().fg(Theme::current().fg);
+    let key_style = Style::default().fg(Theme::current().primary);
+    let string_style = Style::default().fg(Theme::current().success);
+    let number_style = Style::default().fg(Theme::current().highlight);
+    let bool_style = Style::default().fg(Theme::current().accent);
+    let null_style = Style::default().fg(Theme::current().error);
+    let punct_style = Style::default().fg(Theme::current().fg_dim);
 
     // Track if we're expecting a key (after { or ,)
     let trimmed = line.trim_start();
@@ -922,7 +1617,7 @@ fn colorize_json(
 
             if filtered.is_empty() {
                 // No matching keys, show empty object
-                return vec![Span::styled("{}", Style::default().fg(Color::White))];
+                return vec![Span::styled("{}", Style::default().fg(Theme::current().fg))];
             }
 
             let filtered_str = serde_json::to_string(&serde_json::Value::Object(filtered))
@@ -954,13 +1649,13 @@ fn colorize_json_inner(json_str: &str, max_width: usize) -> Vec<Span<'static>> {
     let mut current = String::new();
 
     // JSON syntax colors
-    let brace_style = Style::default().fg(Color::White);
-    let key_style = Style::default().fg(Color::Cyan);
-    let string_style = Style::default().fg(Color::Green);
-    let number_style = Style::default().fg(Color::Yellow);
-    let bool_style = Style::default().fg(Color::Magenta);
-    let null_style = Style::default().fg(Color::Red);
-    let punct_style = Style::default().fg(Color::DarkGray);
+    let brace_style = Style::default().fg(Theme::current().fg);
+    let key_style = Style::default().fg(Theme::current().primary);
+    let string_style = Style::default().fg(Theme::current().success);
+    let number_style = Style::default().fg(Theme::current().highlight);
+    let bool_style = Style::default().fg(Theme::current().accent);
+    let null_style = Style::default().fg(Theme::current().error);
+    let punct_style = Style::default().fg(Theme::current().fg_dim);
 
     // Use viewport-aware max length (leave room for "...")
     let max_len = max_width.saturating_sub(3).max(10);
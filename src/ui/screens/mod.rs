@@ -1,11 +1,15 @@
 //! Screen implementations
 
+mod connecting;
 mod context_select;
 mod deployment_select;
 mod log_viewer;
 mod namespace_select;
+mod recent_select;
 
+pub use connecting::ConnectingScreen;
 pub use context_select::ContextSelectScreen;
 pub use deployment_select::DeploymentSelectScreen;
-pub use log_viewer::LogViewerScreen;
+pub use log_viewer::{LogViewerScreen, format_bytes};
 pub use namespace_select::NamespaceSelectScreen;
+pub use recent_select::RecentSelectScreen;
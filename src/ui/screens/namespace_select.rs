@@ -1,6 +1,8 @@
+use chrono::Utc;
 use ratatui::{
     Frame,
     layout::Rect,
+    style::Style,
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph},
 };
@@ -9,7 +11,9 @@ use crate::{
     app::AppState,
     ui::{
         Layout, Theme,
-        components::{ListSelector, ListSelectorExt, StatusBar, list_nav_hints},
+        components::{
+            ListSelector, ListSelectorExt, StatusBar, format_age, list_nav_hints, pin_marker,
+        },
     },
 };
 
@@ -35,17 +39,17 @@ impl NamespaceSelectScreen {
         let context_name = state.selected_context.as_deref().unwrap_or("unknown");
 
         let title = Line::from(vec![
-            Span::styled("kubescope", Theme::title()),
-            Span::styled(" │ ", Theme::text_dim()),
-            Span::styled(context_name, Theme::text_highlight()),
-            Span::styled(" │ ", Theme::text_dim()),
-            Span::styled("Select Namespace", Theme::text()),
+            Span::styled("kubescope", Theme::current().title()),
+            Span::styled(" │ ", Theme::current().text_dim()),
+            Span::styled(context_name, Theme::current().text_highlight()),
+            Span::styled(" │ ", Theme::current().text_dim()),
+            Span::styled("Select Namespace", Theme::current().text()),
         ]);
 
         let header = Paragraph::new(title).block(
             Block::default()
                 .borders(Borders::ALL)
-                .border_style(Theme::border()),
+                .border_style(Theme::current().border()),
         );
 
         frame.render_widget(header, area);
@@ -54,24 +58,66 @@ impl NamespaceSelectScreen {
     fn render_list(frame: &mut Frame, area: Rect, state: &mut AppState) {
         let list_area = Layout::centered_list(area, 80);
 
-        let items: Vec<(String, bool)> = state
+        let default = state.context_namespace();
+        let now = Utc::now();
+        let items: Vec<(String, Style)> = state
             .namespaces
             .iter()
             .map(|ns| {
-                let display = format!("{} ({})", ns.name, ns.status);
-                (display, false)
+                let marker = pin_marker(state.pinned.namespaces.contains(&ns.name));
+                let mut display = format!("{}{} ({})", marker, ns.name, ns.status);
+                if let Some(created) = ns.created_at {
+                    display.push_str(&format!(" {}", format_age(now - created)));
+                }
+                let is_default = default == Some(ns.name.as_str());
+                if is_default {
+                    display.push_str(" (context default)");
+                }
+                // Namespaces being deleted are dimmed
+                let style = if ns.is_terminating() {
+                    Theme::current().list_item_dim()
+                } else if is_default {
+                    Theme::current().list_item_current()
+                } else {
+                    Theme::current().list_item()
+                };
+                (display, style)
             })
             .collect();
 
-        let selector = ListSelector::new(" Namespaces ").items(items);
+        let ui = &state.ui_state;
+        let mut filters = Vec::new();
+        if let Some(selector) = &ui.namespace_selector {
+            filters.push(selector.as_str().to_string());
+        }
+        if ui.hide_terminating {
+            filters.push("no terminating".to_string());
+        }
+        let title = if filters.is_empty() {
+            " Namespaces ".to_string()
+        } else {
+            format!(" Namespaces ({}) ", filters.join(", "))
+        };
+        let selector = ListSelector::new(&title).styled_items(items);
 
         frame.render_list_selector(list_area, selector, &mut state.ui_state.list_state);
     }
 
     fn render_status_bar(frame: &mut Frame, area: Rect, state: &AppState) {
-        let ns_count = format!("{} namespaces", state.namespaces.len());
-
-        let status = StatusBar::new().hints(list_nav_hints()).right(ns_count);
+        let shown = state.namespaces.len();
+        let total = state.all_namespaces.len();
+        let ns_count = if shown == total {
+            format!("{} namespaces", total)
+        } else {
+            format!("{} of {} namespaces", shown, total)
+        };
+
+        let mut hints = list_nav_hints();
+        hints.insert(3, (":", "Labels", 4));
+
+        let status = StatusBar::new()
+            .hints(hints)
+            .right(state.status_text(ns_count));
 
         frame.render_widget(status, area);
     }
@@ -0,0 +1,84 @@
+use ratatui::{
+    Frame,
+    layout::Rect,
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+};
+
+use crate::{
+    app::AppState,
+    ui::{
+        Layout, Theme,
+        components::{ListSelector, ListSelectorExt, StatusBar, list_nav_hints},
+    },
+};
+
+/// Recent targets screen - jump straight back to a previously viewed deployment
+pub struct RecentSelectScreen;
+
+impl RecentSelectScreen {
+    pub fn render(frame: &mut Frame, state: &mut AppState) {
+        let area = frame.area();
+        let (header_area, content_area, status_area) = Layout::main(area);
+
+        // Render header
+        Self::render_header(frame, header_area);
+
+        // Render recent target list
+        Self::render_list(frame, content_area, state);
+
+        // Render status bar
+        Self::render_status_bar(frame, status_area, state);
+    }
+
+    fn render_header(frame: &mut Frame, area: Rect) {
+        let title = Line::from(vec![
+            Span::styled("kubescope", Theme::current().title()),
+            Span::styled(" │ ", Theme::current().text_dim()),
+            Span::styled("Recent Targets", Theme::current().text()),
+        ]);
+
+        let header = Paragraph::new(title).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Theme::current().border()),
+        );
+
+        frame.render_widget(header, area);
+    }
+
+    fn render_list(frame: &mut Frame, area: Rect, state: &mut AppState) {
+        let list_area = Layout::centered_list(area, 80);
+
+        let items: Vec<(String, bool)> = state
+            .recent_targets
+            .targets
+            .iter()
+            .map(|target| {
+                // Mark the target currently being viewed
+                let is_current = state.selected_context.as_ref() == Some(&target.context)
+                    && state.selected_namespace.as_ref() == Some(&target.namespace)
+                    && state.selected_deployment.as_ref() == Some(&target.deployment);
+                (target.label(), is_current)
+            })
+            .collect();
+
+        let selector = ListSelector::new(" Context / Namespace / Deployment ").items(items);
+
+        frame.render_list_selector(list_area, selector, &mut state.ui_state.list_state);
+    }
+
+    fn render_status_bar(frame: &mut Frame, area: Rect, state: &AppState) {
+        let recent_count = if state.recent_targets.targets.is_empty() {
+            "no recent targets".to_string()
+        } else {
+            format!("{} recent", state.recent_targets.targets.len())
+        };
+
+        let status = StatusBar::new()
+            .hints(list_nav_hints())
+            .right(state.status_text(recent_count));
+
+        frame.render_widget(status, area);
+    }
+}
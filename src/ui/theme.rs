@@ -1,98 +1,406 @@
+use clap::ValueEnum;
 use ratatui::style::{Color, Modifier, Style};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
-/// Color theme for the application
-#[allow(dead_code)]
-pub struct Theme;
+use crate::types::LogLevel;
+
+/// The built-in palettes, in `ThemeName` order, built on first use
+static PALETTES: OnceLock<[Theme; 3]> = OnceLock::new();
+
+/// Index of the active palette, shared by all screens and components
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
 
+/// Built-in color palettes
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum ThemeName {
+    /// Default palette for dark terminals
+    #[default]
+    Dark,
+    /// Palette for light terminal backgrounds
+    Light,
+    /// Deuteranopia-safe palette (no red/green pairs)
+    Colorblind,
+}
+
+/// Color theme for the application
+#[derive(Clone, Debug)]
 #[allow(dead_code)]
-impl Theme {
+pub struct Theme {
     // Base colors
-    pub const BG: Color = Color::Reset;
-    pub const FG: Color = Color::White;
-    pub const FG_DIM: Color = Color::DarkGray;
+    pub bg: Color,
+    pub fg: Color,
+    pub fg_dim: Color,
+    /// Background of bars and the cursor row
+    pub surface: Color,
 
     // Accent colors
-    pub const PRIMARY: Color = Color::Cyan;
-    pub const SECONDARY: Color = Color::Blue;
-    pub const HIGHLIGHT: Color = Color::Yellow;
+    pub primary: Color,
+    pub secondary: Color,
+    pub highlight: Color,
+    /// Text drawn on top of the highlight color
+    pub on_highlight: Color,
+    /// Extra accent for syntax coloring
+    pub accent: Color,
 
     // Status colors
-    pub const SUCCESS: Color = Color::Green;
-    pub const WARNING: Color = Color::Yellow;
-    pub const ERROR: Color = Color::Red;
+    pub success: Color,
+    pub warning: Color,
+    pub error: Color,
 
     // Log level colors
-    pub const LOG_TRACE: Color = Color::DarkGray;
-    pub const LOG_DEBUG: Color = Color::Cyan;
-    pub const LOG_INFO: Color = Color::Green;
-    pub const LOG_WARN: Color = Color::Yellow;
-    pub const LOG_ERROR: Color = Color::Red;
-    pub const LOG_FATAL: Color = Color::Magenta;
+    pub log_trace: Color,
+    pub log_debug: Color,
+    pub log_info: Color,
+    pub log_warn: Color,
+    pub log_error: Color,
+    pub log_fatal: Color,
+    pub log_unknown: Color,
+
+    /// Palette used to tell pods apart
+    pub pod_colors: [Color; 8],
+}
+
+// Okabe-Ito colors, distinguishable with the common forms of color blindness
+const OKABE_ORANGE: Color = Color::Rgb(230, 159, 0);
+const OKABE_SKY_BLUE: Color = Color::Rgb(86, 180, 233);
+const OKABE_BLUISH_GREEN: Color = Color::Rgb(0, 158, 115);
+const OKABE_YELLOW: Color = Color::Rgb(240, 228, 66);
+const OKABE_BLUE: Color = Color::Rgb(0, 114, 178);
+const OKABE_VERMILLION: Color = Color::Rgb(213, 94, 0);
+const OKABE_PURPLE: Color = Color::Rgb(204, 121, 167);
+
+// Darker shades that stay readable on a white background
+const DARK_ORANGE: Color = Color::Indexed(130);
+const DARK_CYAN: Color = Color::Indexed(30);
+const DARK_VIOLET: Color = Color::Indexed(91);
+
+#[allow(dead_code)]
+impl Theme {
+    /// Default palette for dark terminals
+    pub fn dark() -> Self {
+        Self {
+            bg: Color::Reset,
+            fg: Color::White,
+            fg_dim: Color::DarkGray,
+            surface: Color::DarkGray,
+            primary: Color::Cyan,
+            secondary: Color::Blue,
+            highlight: Color::Yellow,
+            on_highlight: Color::Black,
+            accent: Color::Magenta,
+            success: Color::Green,
+            warning: Color::Yellow,
+            error: Color::Red,
+            log_trace: Color::DarkGray,
+            log_debug: Color::Cyan,
+            log_info: Color::Green,
+            log_warn: Color::Yellow,
+            log_error: Color::Red,
+            log_fatal: Color::Magenta,
+            log_unknown: Color::White,
+            pod_colors: [
+                Color::Cyan,
+                Color::Magenta,
+                Color::Blue,
+                Color::Yellow,
+                Color::Green,
+                Color::Red,
+                Color::LightCyan,
+                Color::LightMagenta,
+            ],
+        }
+    }
+
+    /// Palette for light terminal backgrounds
+    pub fn light() -> Self {
+        Self {
+            bg: Color::White,
+            fg: Color::Black,
+            fg_dim: Color::DarkGray,
+            surface: Color::Gray,
+            primary: Color::Blue,
+            secondary: Color::Magenta,
+            highlight: Color::Magenta,
+            on_highlight: Color::White,
+            accent: DARK_VIOLET,
+            success: Color::Green,
+            warning: DARK_ORANGE,
+            error: Color::Red,
+            log_trace: Color::DarkGray,
+            log_debug: Color::Blue,
+            log_info: Color::Green,
+            log_warn: DARK_ORANGE,
+            log_error: Color::Red,
+            log_fatal: Color::Magenta,
+            log_unknown: Color::Black,
+            pod_colors: [
+                Color::Blue,
+                Color::Magenta,
+                DARK_CYAN,
+                DARK_ORANGE,
+                Color::Green,
+                Color::Red,
+                DARK_VIOLET,
+                Color::DarkGray,
+            ],
+        }
+    }
+
+    /// Deuteranopia-safe palette: status is told apart by blue vs orange, never red vs green
+    pub fn colorblind() -> Self {
+        Self {
+            bg: Color::Reset,
+            fg: Color::White,
+            fg_dim: Color::DarkGray,
+            surface: Color::DarkGray,
+            primary: OKABE_SKY_BLUE,
+            secondary: OKABE_BLUE,
+            highlight: OKABE_YELLOW,
+            on_highlight: Color::Black,
+            accent: OKABE_PURPLE,
+            success: OKABE_SKY_BLUE,
+            warning: OKABE_YELLOW,
+            error: OKABE_VERMILLION,
+            log_trace: Color::DarkGray,
+            log_debug: OKABE_BLUISH_GREEN,
+            log_info: OKABE_SKY_BLUE,
+            log_warn: OKABE_YELLOW,
+            log_error: OKABE_VERMILLION,
+            log_fatal: OKABE_PURPLE,
+            log_unknown: Color::White,
+            pod_colors: [
+                OKABE_SKY_BLUE,
+                OKABE_ORANGE,
+                OKABE_BLUISH_GREEN,
+                OKABE_YELLOW,
+                OKABE_BLUE,
+                OKABE_VERMILLION,
+                OKABE_PURPLE,
+                Color::White,
+            ],
+        }
+    }
+
+    /// Build a built-in theme by name
+    pub fn from_name(name: ThemeName) -> Self {
+        match name {
+            ThemeName::Dark => Self::dark(),
+            ThemeName::Light => Self::light(),
+            ThemeName::Colorblind => Self::colorblind(),
+        }
+    }
+
+    /// Switch the theme; the next render draws with it
+    pub fn set(name: ThemeName) {
+        let index = match name {
+            ThemeName::Dark => 0,
+            ThemeName::Light => 1,
+            ThemeName::Colorblind => 2,
+        };
+        CURRENT.store(index, Ordering::Relaxed);
+    }
+
+    /// The active theme (dark unless another was set)
+    pub fn current() -> &'static Theme {
+        let palettes = PALETTES.get_or_init(|| [Self::dark(), Self::light(), Self::colorblind()]);
+        &palettes[CURRENT.load(Ordering::Relaxed)]
+    }
+
+    /// Display color for a log level
+    pub fn level_color(&self, level: LogLevel) -> Color {
+        match level {
+            LogLevel::Trace => self.log_trace,
+            LogLevel::Debug => self.log_debug,
+            LogLevel::Info => self.log_info,
+            LogLevel::Warn => self.log_warn,
+            LogLevel::Error => self.log_error,
+            LogLevel::Fatal => self.log_fatal,
+            LogLevel::Unknown => self.log_unknown,
+        }
+    }
 
     // Border styles
-    pub fn border() -> Style {
-        Style::default().fg(Self::FG_DIM)
+    pub fn border(&self) -> Style {
+        Style::default().fg(self.fg_dim)
     }
 
-    pub fn border_focused() -> Style {
-        Style::default().fg(Self::PRIMARY)
+    pub fn border_focused(&self) -> Style {
+        Style::default().fg(self.primary)
     }
 
     // Text styles
-    pub fn title() -> Style {
+    pub fn title(&self) -> Style {
         Style::default()
-            .fg(Self::PRIMARY)
+            .fg(self.primary)
             .add_modifier(Modifier::BOLD)
     }
 
-    pub fn text() -> Style {
-        Style::default().fg(Self::FG)
+    pub fn text(&self) -> Style {
+        Style::default().fg(self.fg)
     }
 
-    pub fn text_dim() -> Style {
-        Style::default().fg(Self::FG_DIM)
+    pub fn text_dim(&self) -> Style {
+        Style::default().fg(self.fg_dim)
     }
 
-    pub fn text_highlight() -> Style {
+    pub fn text_highlight(&self) -> Style {
         Style::default()
-            .fg(Self::HIGHLIGHT)
+            .fg(self.highlight)
             .add_modifier(Modifier::BOLD)
     }
 
     // List styles
-    pub fn list_item() -> Style {
-        Style::default().fg(Self::FG)
+    pub fn list_item(&self) -> Style {
+        Style::default().fg(self.fg)
     }
 
-    pub fn list_item_selected() -> Style {
+    pub fn list_item_selected(&self) -> Style {
         Style::default()
-            .fg(Self::BG)
-            .bg(Self::PRIMARY)
+            .fg(self.bg)
+            .bg(self.primary)
             .add_modifier(Modifier::BOLD)
     }
 
-    pub fn list_item_current() -> Style {
+    pub fn list_item_current(&self) -> Style {
         Style::default()
-            .fg(Self::SUCCESS)
+            .fg(self.success)
             .add_modifier(Modifier::BOLD)
     }
 
+    pub fn list_item_dim(&self) -> Style {
+        Style::default().fg(self.fg_dim)
+    }
+
+    // Resource health styles
+    pub fn health_ok(&self) -> Style {
+        Style::default().fg(self.success)
+    }
+
+    pub fn health_degraded(&self) -> Style {
+        Style::default().fg(self.warning)
+    }
+
+    pub fn health_down(&self) -> Style {
+        Style::default().fg(self.error).add_modifier(Modifier::BOLD)
+    }
+
     // Status bar
-    pub fn status_bar() -> Style {
-        Style::default().fg(Self::FG_DIM).bg(Color::DarkGray)
+    pub fn status_bar(&self) -> Style {
+        Style::default().fg(self.fg_dim).bg(self.surface)
     }
 
-    pub fn status_bar_key() -> Style {
+    pub fn status_bar_key(&self) -> Style {
         Style::default()
-            .fg(Self::HIGHLIGHT)
-            .bg(Color::DarkGray)
+            .fg(self.highlight)
+            .bg(self.surface)
             .add_modifier(Modifier::BOLD)
     }
 
-    // Error
-    pub fn error() -> Style {
+    // Bookmarked line marker
+    pub fn bookmark(&self) -> Style {
         Style::default()
-            .fg(Self::ERROR)
+            .fg(self.on_highlight)
+            .bg(self.highlight)
             .add_modifier(Modifier::BOLD)
     }
+
+    // Error
+    pub fn error(&self) -> Style {
+        Style::default().fg(self.error).add_modifier(Modifier::BOLD)
+    }
+}
+
+/// Get a consistent color for a pod name (used for pods outside the assigned set)
+pub fn hashed_pod_color(pod_name: &str, palette: &[Color]) -> Color {
+    let hash: u32 = pod_name
+        .bytes()
+        .fold(0u32, |acc, b| acc.wrapping_add(b as u32));
+
+    palette[(hash as usize) % palette.len()]
+}
+
+/// Assign colors to a set of pods so that no two share a color until the palette runs out
+///
+/// Overrides are `(pattern, color)` pairs; a pod takes the color of the first pattern
+/// its name contains. Remaining pods get palette colors in name order, skipping colors
+/// already claimed by overrides while unclaimed ones are left.
+pub fn assign_pod_colors<'a>(
+    pod_names: impl IntoIterator<Item = &'a str>,
+    overrides: &[(String, Color)],
+    palette: &[Color],
+) -> HashMap<String, Color> {
+    let mut names: Vec<&str> = pod_names.into_iter().collect();
+    names.sort_unstable();
+    names.dedup();
+
+    let mut colors = HashMap::new();
+    let mut remaining = Vec::new();
+    for name in names {
+        match overrides
+            .iter()
+            .find(|(pattern, _)| name.contains(pattern.as_str()))
+        {
+            Some((_, color)) => {
+                colors.insert(name.to_string(), *color);
+            }
+            None => remaining.push(name),
+        }
+    }
+
+    let taken: Vec<Color> = colors.values().copied().collect();
+    let mut free: Vec<Color> = palette
+        .iter()
+        .copied()
+        .filter(|c| !taken.contains(c))
+        .collect();
+    if free.is_empty() {
+        free = palette.to_vec();
+    }
+
+    for (idx, name) in remaining.into_iter().enumerate() {
+        colors.insert(name.to_string(), free[idx % free.len()]);
+    }
+    colors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_theme_pod_palettes_distinct() {
+        for name in [ThemeName::Dark, ThemeName::Light, ThemeName::Colorblind] {
+            let palette = Theme::from_name(name).pod_colors;
+            for (i, a) in palette.iter().enumerate() {
+                assert!(!palette[i + 1..].contains(a), "{:?} repeats {:?}", name, a);
+            }
+        }
+    }
+
+    #[test]
+    fn test_assign_pod_colors_distinct() {
+        let pods = ["api-1", "api-2", "api-3"];
+        let colors = assign_pod_colors(pods, &[], &Theme::dark().pod_colors);
+        assert_eq!(colors.len(), 3);
+        assert_ne!(colors["api-1"], colors["api-2"]);
+        assert_ne!(colors["api-2"], colors["api-3"]);
+        assert_ne!(colors["api-1"], colors["api-3"]);
+    }
+
+    #[test]
+    fn test_assign_pod_colors_overrides() {
+        let overrides = vec![("canary".to_string(), Color::Cyan)];
+        let colors = assign_pod_colors(
+            ["api-canary-x", "api-1"],
+            &overrides,
+            &Theme::dark().pod_colors,
+        );
+        assert_eq!(colors["api-canary-x"], Color::Cyan);
+        // The override's color is not handed out again
+        assert_ne!(colors["api-1"], Color::Cyan);
+    }
 }